@@ -0,0 +1,65 @@
+//! Compute an identity of the compiler that `forc-pkg` is built with, for the keys of the build
+//! cache. See `pkg::cache`.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    env, fs,
+    hash::Hasher,
+    path::{Path, PathBuf},
+};
+
+/// The crates whose sources determine the output of a package build.
+const COMPILER_CRATES: &[&str] = &[
+    "forc-pkg",
+    "sway-ast",
+    "sway-core",
+    "sway-error",
+    "sway-ir",
+    "sway-parse",
+    "sway-types",
+    "sway-utils",
+];
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let workspace_dir = manifest_dir.parent().unwrap();
+    let mut hasher = DefaultHasher::new();
+    for name in COMPILER_CRATES {
+        let crate_dir = workspace_dir.join(name);
+        // Outside of the workspace, e.g. when built from crates.io, the sources of the other crates
+        // aren't available and the compiler is identified by its version alone.
+        if !crate_dir.is_dir() {
+            continue;
+        }
+        for path in [crate_dir.join("Cargo.toml"), crate_dir.join("src")] {
+            println!("cargo:rerun-if-changed={}", path.display());
+            hash_path(&mut hasher, workspace_dir, &path);
+        }
+    }
+    println!(
+        "cargo:rustc-env=FORC_PKG_COMPILER_HASH={:016x}",
+        hasher.finish()
+    );
+}
+
+/// Hash the relative path and contents of the file at `path`, or of every file within it.
+fn hash_path(hasher: &mut DefaultHasher, workspace_dir: &Path, path: &Path) {
+    if path.is_dir() {
+        let mut entries: Vec<_> = fs::read_dir(path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        entries.sort();
+        for entry in entries {
+            hash_path(hasher, workspace_dir, &entry);
+        }
+    } else if let Ok(contents) = fs::read(path) {
+        let relative = path.strip_prefix(workspace_dir).unwrap_or(path);
+        let relative = relative.to_string_lossy();
+        // Prefix each input with its length so that inputs can't run into one another.
+        for bytes in [relative.as_bytes(), &contents] {
+            hasher.write_u64(bytes.len() as u64);
+            hasher.write(bytes);
+        }
+    }
+}
@@ -9,7 +9,9 @@ use std::{
     path::{Path, PathBuf},
     sync::Arc,
 };
-use sway_core::{fuel_prelude::fuel_tx, language::parsed::TreeType, parse_tree_type, BuildTarget};
+use sway_core::{
+    fuel_prelude::fuel_tx, language::parsed::TreeType, parse_tree_type, BuildTarget, OptLevel,
+};
 use sway_error::handler::Handler;
 use sway_utils::{
     constants, find_nested_manifest_dir, find_parent_manifest_dir,
@@ -155,6 +157,7 @@ pub struct PackageManifest {
 pub struct Project {
     pub authors: Option<Vec<String>>,
     pub name: String,
+    pub version: Option<semver::Version>,
     pub organization: Option<String>,
     pub license: String,
     #[serde(default = "default_entry")]
@@ -204,6 +207,7 @@ pub struct DependencyDetails {
     pub(crate) package: Option<String>,
     pub(crate) rev: Option<String>,
     pub(crate) ipfs: Option<String>,
+    pub(crate) registry: Option<String>,
 }
 
 /// Parameters to pass through to the `sway_core::BuildConfig` during compilation.
@@ -233,6 +237,17 @@ pub struct BuildProfile {
     #[serde(default)]
     pub error_on_warnings: bool,
     pub reverse_results: bool,
+    #[serde(default)]
+    pub opt_level: OptLevel,
+    /// The names of the IR passes to run in place of those of the `opt_level`.
+    #[serde(default)]
+    pub passes: Option<Vec<toml::Spanned<String>>>,
+    /// The size in bytes which inlining mustn't grow the estimated bytecode beyond.
+    #[serde(default)]
+    pub size_budget: Option<u64>,
+    /// The path to the manifest declaring the profile, if any.
+    #[serde(skip)]
+    pub manifest_path: Option<PathBuf>,
 }
 
 impl DependencyDetails {
@@ -241,18 +256,24 @@ impl DependencyDetails {
     ///
     /// Following dependency details sets are considered to be invalid:
     /// 1. A set of dependency details which declares `branch`, `tag` or `rev` without `git`.
+    /// 2. A set of dependency details which declares `registry` without `version`.
     pub fn validate(&self) -> anyhow::Result<()> {
         let DependencyDetails {
+            version,
             git,
             branch,
             tag,
             rev,
+            registry,
             ..
         } = self;
 
         if git.is_none() && (branch.is_some() || tag.is_some() || rev.is_some()) {
             bail!("Details reserved for git sources used without a git field");
         }
+        if version.is_none() && registry.is_some() {
+            bail!("Registry used without a version field");
+        }
         Ok(())
     }
 }
@@ -525,6 +546,9 @@ impl PackageManifest {
             println_warning(&warning);
         }
         manifest.implicitly_include_std_if_missing();
+        for (_, profile) in manifest.build_profile.iter_mut().flatten() {
+            profile.manifest_path = Some(path.to_path_buf());
+        }
         manifest.implicitly_include_default_build_profiles_if_missing();
         manifest.validate()?;
         Ok(manifest)
@@ -718,6 +742,10 @@ impl BuildProfile {
             json_abi_with_callpaths: false,
             error_on_warnings: false,
             reverse_results: false,
+            opt_level: OptLevel::O1,
+            passes: None,
+            size_budget: None,
+            manifest_path: None,
         }
     }
 
@@ -736,6 +764,10 @@ impl BuildProfile {
             json_abi_with_callpaths: false,
             error_on_warnings: false,
             reverse_results: false,
+            opt_level: OptLevel::O1,
+            passes: None,
+            size_budget: None,
+            manifest_path: None,
         }
     }
 }
@@ -1059,6 +1091,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: None,
+            registry: None,
         };
 
         let dependency_details_branch = DependencyDetails {
@@ -1081,6 +1114,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: None,
+            registry: None,
         };
 
         let dependency_details_tag = DependencyDetails {
@@ -1103,6 +1137,7 @@ mod tests {
             package: None,
             ipfs: None,
             rev: Some("9f35b8e".to_string()),
+            registry: None,
         };
 
         let dependency_details_rev = DependencyDetails {
@@ -1227,6 +1262,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: None,
+            registry: None,
         };
 
         let git_source_string = "https://github.com/FuelLabs/sway".to_string();
@@ -1239,6 +1275,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: None,
+            registry: None,
         };
         let dependency_details_git_branch = DependencyDetails {
             version: None,
@@ -1249,6 +1286,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: None,
+            registry: None,
         };
         let dependency_details_git_rev = DependencyDetails {
             version: None,
@@ -1259,6 +1297,7 @@ mod tests {
             package: None,
             rev: Some("9f35b8e".to_string()),
             ipfs: None,
+            registry: None,
         };
 
         let dependency_details_ipfs = DependencyDetails {
@@ -1270,6 +1309,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: Some("QmVxgEbiDDdHpG9AesCpZAqNvHYp1P3tWLFdrpUBWPMBcc".to_string()),
+            registry: None,
         };
 
         assert!(dependency_details_path.validate().is_ok());
@@ -1278,4 +1318,41 @@ mod tests {
         assert!(dependency_details_git_rev.validate().is_ok());
         assert!(dependency_details_ipfs.validate().is_ok());
     }
+
+    #[test]
+    fn test_build_profile_opt_level() {
+        let profile = |opt_level: &str| {
+            toml::from_str::<BuildProfile>(&format!("reverse-results = false\n{opt_level}"))
+        };
+        assert_eq!(profile("").unwrap().opt_level, OptLevel::O1);
+        assert_eq!(profile("opt-level = 0").unwrap().opt_level, OptLevel::O0);
+        assert_eq!(profile("opt-level = 2").unwrap().opt_level, OptLevel::O2);
+        assert_eq!(
+            profile("opt-level = \"s\"").unwrap().opt_level,
+            OptLevel::Os
+        );
+        assert!(profile("opt-level = 3").is_err());
+        assert!(profile("opt-level = \"z\"").is_err());
+
+        let custom = profile("passes = [\"mem2reg\", \"dce\"]").unwrap();
+        let passes = custom.passes.unwrap();
+        assert_eq!(
+            passes.iter().map(|pass| pass.get_ref()).collect::<Vec<_>>(),
+            ["mem2reg", "dce"]
+        );
+        assert_eq!(passes[0].span(), 34..43);
+    }
+
+    #[test]
+    fn test_build_profile_size_budget() {
+        let profile = |size_budget: &str| {
+            toml::from_str::<BuildProfile>(&format!("reverse-results = false\n{size_budget}"))
+        };
+        assert_eq!(profile("").unwrap().size_budget, None);
+        assert_eq!(
+            profile("size-budget = 24576").unwrap().size_budget,
+            Some(24576)
+        );
+        assert!(profile("size-budget = -1").is_err());
+    }
 }
@@ -8,7 +8,7 @@ use forc_util::{
     default_output_directory, find_file_name, kebab_to_snake_case, print_compiling,
     print_on_failure, print_warnings,
 };
-use fuel_abi_types::program_abi;
+pub use fuel_abi_types::program_abi;
 use petgraph::{
     self, dot,
     visit::{Bfs, Dfs, EdgeRef, Walker},
@@ -42,7 +42,7 @@ use sway_core::{
     semantic_analysis::namespace,
     source_map::SourceMap,
     transform::AttributeKind,
-    BuildTarget, Engines, FinalizedEntry,
+    BuildTarget, Engines, FinalizedEntry, PassEntry,
 };
 use sway_error::{error::CompileError, handler::Handler, warning::CompileWarning};
 use sway_types::constants::{CORE, PRELUDE, STD};
@@ -50,6 +50,11 @@ use sway_types::{Ident, Span, Spanned};
 use sway_utils::{constants, time_expr, PerformanceData, PerformanceMetric};
 use tracing::{info, warn};
 
+mod cache;
+
+pub use cache::BUILD_CACHE_DIR_NAME;
+use cache::{BuildCache, CacheKey};
+
 type GraphIx = u32;
 type Node = Pinned;
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -89,6 +94,10 @@ pub type EdgeIx = petgraph::graph::EdgeIndex<GraphIx>;
 pub type NodeIx = petgraph::graph::NodeIndex<GraphIx>;
 pub type ManifestMap = HashMap<PinnedId, PackageManifestFile>;
 
+/// The maximum number of times the package graph is fetched while resolving the versions of
+/// registry packages. See `fetch_graph`.
+const MAX_REGISTRY_RESOLUTION_PASSES: usize = 4;
+
 /// A unique ID for a pinned package.
 ///
 /// The internal value is produced by hashing the package's name and `source::Pinned`.
@@ -111,6 +120,13 @@ pub struct BuiltPackage {
     ///
     /// For non-contract members, this is always `None`.
     pub bytecode_without_tests: Option<BuiltPackageBytecode>,
+    /// The source map for `bytecode_without_tests`. `Some` whenever `bytecode_without_tests` is.
+    source_map_without_tests: Option<SourceMap>,
+    /// The program ABI for `bytecode_without_tests`. `Some` whenever `bytecode_without_tests` is.
+    ///
+    /// The ids of logged types are assigned in the order logs appear in the compiled program, so
+    /// they may differ from those of `program_abi` when tests are included.
+    program_abi_without_tests: Option<ProgramABI>,
 }
 
 /// The package descriptors that a `BuiltPackage` holds so that the source used for building the
@@ -157,6 +173,9 @@ pub struct PkgTestEntry {
     pub pass_condition: TestPassCondition,
     pub span: Span,
     pub file_path: Arc<PathBuf>,
+    /// For fuzz tests (i.e. `#[test(fuzz)]`), an ABI whose only function describes the parameters
+    /// of the test function, from which the test inputs are generated.
+    pub fuzz_abi: Option<program_abi::ProgramABI>,
 }
 
 /// The result of successfully compiling a workspace.
@@ -309,6 +328,9 @@ pub struct BuildOpts {
     pub tests: bool,
     /// The set of options to filter by member project kind.
     pub member_filter: MemberFilter,
+    /// Reuse the packages cached by previous builds in the `cache` directory of the output
+    /// directory, and cache the packages compiled by this build.
+    pub build_cache: bool,
 }
 
 /// The set of options to filter type of projects to build in a workspace.
@@ -421,6 +443,21 @@ impl BuiltPackage {
         Ok(())
     }
 
+    /// The source map for this package's `bytecode`.
+    pub fn source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
+
+    /// The source map for this package's `bytecode_without_tests`, if it was built.
+    pub fn source_map_without_tests(&self) -> Option<&SourceMap> {
+        self.source_map_without_tests.as_ref()
+    }
+
+    /// The program ABI for this package's `bytecode_without_tests`, if it was built.
+    pub fn program_abi_without_tests(&self) -> Option<&ProgramABI> {
+        self.program_abi_without_tests.as_ref()
+    }
+
     /// Writes debug_info (source_map) of the BuiltPackage to the given `path`.
     pub fn write_debug_info(&self, path: &Path) -> Result<()> {
         let source_map_json =
@@ -1002,7 +1039,11 @@ fn validate_dep(
     let dep_source =
         Source::from_manifest_dep_patched(node_manifest, dep_name, dep_entry, manifests)?;
     let dep_pkg = graph[dep_node].unpinned(&dep_path);
-    if dep_pkg.source != dep_source {
+    let source_matches = match (&graph[dep_node].source, &dep_source) {
+        (source::Pinned::Registry(pinned), Source::Registry(source)) => pinned.satisfies(source),
+        _ => dep_pkg.source == dep_source,
+    };
+    if !source_matches {
         bail!("dependency node's source does not match manifest entry");
     }
 
@@ -1348,19 +1389,56 @@ fn fetch_graph(
     graph: &mut Graph,
     manifest_map: &mut ManifestMap,
 ) -> Result<HashSet<NodeIx>> {
-    let mut added_nodes = HashSet::default();
-    for member_pkg_manifest in member_manifests.values() {
-        added_nodes.extend(&fetch_pkg_graph(
-            member_pkg_manifest,
-            offline,
-            ipfs_node,
-            graph,
-            manifest_map,
-            member_manifests,
-        )?);
+    // Registry dependencies are pinned to a version satisfying the requirements of the other
+    // dependents on the package throughout the graph where possible, so that they share it. These
+    // requirements are only known once the whole graph is fetched, so the graph is fetched again
+    // with the requirements found until they settle, or fails if they still haven't settled after
+    // the last pass.
+    let mut registry_requirements = source::reg::Requirements::default();
+    for _ in 1..=MAX_REGISTRY_RESOLUTION_PASSES {
+        let mut pass_graph = graph.clone();
+        let mut pass_manifest_map = manifest_map.clone();
+        let mut found_requirements = source::reg::Requirements::default();
+        let mut added_nodes = HashSet::default();
+        for member_pkg_manifest in member_manifests.values() {
+            added_nodes.extend(&fetch_pkg_graph(
+                member_pkg_manifest,
+                offline,
+                ipfs_node,
+                &mut pass_graph,
+                &mut pass_manifest_map,
+                member_manifests,
+                &registry_requirements,
+                &mut found_requirements,
+            )?);
+        }
+        let settled = found_requirements == registry_requirements
+            || found_requirements.values().all(|reqs| reqs.len() <= 1);
+        if settled {
+            *graph = pass_graph;
+            *manifest_map = pass_manifest_map;
+            validate_contract_deps(graph)?;
+            return Ok(added_nodes);
+        }
+        registry_requirements = found_requirements;
     }
-    validate_contract_deps(graph)?;
-    Ok(added_nodes)
+
+    let mut conflicts: Vec<_> = registry_requirements
+        .iter()
+        .filter(|(_, reqs)| reqs.len() > 1)
+        .map(|((index, name), reqs)| {
+            let mut reqs: Vec<_> = reqs.iter().map(|req| format!("`{req}`")).collect();
+            reqs.sort();
+            format!("  {name} from registry {index}: {}", reqs.join(", "))
+        })
+        .collect();
+    conflicts.sort();
+    bail!(
+        "Failed to resolve the versions of registry packages after {} passes. \
+         The requirements on the following packages did not settle:\n{}",
+        MAX_REGISTRY_RESOLUTION_PASSES,
+        conflicts.join("\n")
+    )
 }
 
 /// Given an empty or partially completed package `graph`, complete the graph.
@@ -1375,7 +1453,12 @@ fn fetch_graph(
 /// The accompanying `path_map` should contain a path entry for every existing node within the
 /// `graph` and will `panic!` otherwise.
 ///
+/// Registry packages are pinned to versions satisfying the `registry_requirements` found by the
+/// previous fetch of the whole graph where possible. The version requirements of every registry
+/// dependency visited are added to `found_requirements`, for `fetch_graph` to compare against.
+///
 /// Upon success, returns the set of nodes that were added to the graph during traversal.
+#[allow(clippy::too_many_arguments)]
 fn fetch_pkg_graph(
     proj_manifest: &PackageManifestFile,
     offline: bool,
@@ -1383,6 +1466,8 @@ fn fetch_pkg_graph(
     graph: &mut Graph,
     manifest_map: &mut ManifestMap,
     member_manifests: &MemberManifestFiles,
+    registry_requirements: &source::reg::Requirements,
+    found_requirements: &mut source::reg::Requirements,
 ) -> Result<HashSet<NodeIx>> {
     // Retrieve the project node, or create one if it does not exist.
     let proj_node = match find_proj_node(graph, &proj_manifest.project.name) {
@@ -1422,11 +1507,17 @@ fn fetch_pkg_graph(
         &mut fetched,
         &mut visited,
         member_manifests,
+        registry_requirements,
+        found_requirements,
     )
 }
 
 /// Visit the unvisited dependencies of the given node and fetch missing nodes as necessary.
 ///
+/// Registry packages are pinned according to the `registry_requirements` found by the previous
+/// fetch of the graph, and the requirements of every registry dependency visited are added to
+/// `found_requirements`.
+///
 /// Assumes the `node`'s manifest already exists within the `manifest_map`.
 #[allow(clippy::too_many_arguments)]
 fn fetch_deps(
@@ -1440,6 +1531,8 @@ fn fetch_deps(
     fetched: &mut HashMap<Pkg, NodeIx>,
     visited: &mut HashSet<NodeIx>,
     member_manifests: &MemberManifestFiles,
+    registry_requirements: &source::reg::Requirements,
+    found_requirements: &mut source::reg::Requirements,
 ) -> Result<HashSet<NodeIx>> {
     let mut added = HashSet::default();
     let parent_id = graph[node].id();
@@ -1466,6 +1559,12 @@ fn fetch_deps(
         let source =
             Source::from_manifest_dep_patched(parent_manifest, name, &dep, member_manifests)
                 .context(format!("Failed to source dependency: {dep_name}"))?;
+        if let Source::Registry(source) = &source {
+            found_requirements
+                .entry((source.index.clone(), name.to_string()))
+                .or_default()
+                .insert(source.version.clone());
+        }
 
         // If we haven't yet fetched this dependency, fetch it, pin it and add it to the graph.
         let dep_pkg = Pkg {
@@ -1476,18 +1575,30 @@ fn fetch_deps(
             hash_map::Entry::Occupied(entry) => *entry.get(),
             hash_map::Entry::Vacant(entry) => {
                 let pkg = entry.key();
-                let ctx = source::PinCtx {
-                    fetch_id,
-                    path_root,
-                    name: &pkg.name,
-                    offline,
-                    ipfs_node,
+                // Share a version of a registry package already in the graph where possible.
+                let existing = match &pkg.source {
+                    Source::Registry(source) => find_registry_node(graph, &pkg.name, source),
+                    _ => None,
+                };
+                let dep_node = match existing {
+                    Some(dep_node) => dep_node,
+                    None => {
+                        let ctx = source::PinCtx {
+                            fetch_id,
+                            path_root,
+                            name: &pkg.name,
+                            offline,
+                            ipfs_node,
+                            registry_requirements,
+                        };
+                        let source = pkg.source.pin(ctx, manifest_map)?;
+                        let name = pkg.name.clone();
+                        let dep_pinned = Pinned { name, source };
+                        let dep_node = graph.add_node(dep_pinned);
+                        added.insert(dep_node);
+                        dep_node
+                    }
                 };
-                let source = pkg.source.pin(ctx, manifest_map)?;
-                let name = pkg.name.clone();
-                let dep_pinned = Pinned { name, source };
-                let dep_node = graph.add_node(dep_pinned);
-                added.insert(dep_node);
                 *entry.insert(dep_node)
             }
         };
@@ -1533,11 +1644,27 @@ fn fetch_deps(
             fetched,
             visited,
             member_manifests,
+            registry_requirements,
+            found_requirements,
         )?);
     }
     Ok(added)
 }
 
+/// Find the node of the newest version of the named registry package in the graph that satisfies
+/// the given source.
+fn find_registry_node(graph: &Graph, name: &str, source: &source::reg::Source) -> Option<NodeIx> {
+    graph
+        .node_indices()
+        .filter(|&n| graph[n].name == name)
+        .filter_map(|n| match &graph[n].source {
+            source::Pinned::Registry(pinned) if pinned.satisfies(source) => Some((n, pinned)),
+            _ => None,
+        })
+        .max_by(|(_, a), (_, b)| a.version.cmp(&b.version))
+        .map(|(n, _)| n)
+}
+
 /// Given a `forc_pkg::BuildProfile`, produce the necessary `sway_core::BuildConfig` required for
 /// compilation.
 pub fn sway_build_config(
@@ -1559,6 +1686,18 @@ pub fn sway_build_config(
     .print_intermediate_asm(build_profile.print_intermediate_asm)
     .print_ir(build_profile.print_ir)
     .include_tests(build_profile.include_tests)
+    .optimization_level(build_profile.opt_level)
+    .passes(build_profile.passes.as_ref().map(|passes| {
+        let manifest_path = build_profile.manifest_path.clone().map(Arc::new);
+        passes
+            .iter()
+            .map(|pass| PassEntry {
+                name: pass.get_ref().clone(),
+                manifest_span: manifest_path.clone().map(|path| (path, pass.span())),
+            })
+            .collect()
+    }))
+    .size_budget(build_profile.size_budget)
     .time_phases(build_profile.time_phases)
     .metrics(build_profile.metrics_outfile.clone());
     Ok(build_config)
@@ -1863,9 +2002,15 @@ pub fn compile(
         .as_ref()
         .map(|asm| asm.0.entries.clone())
         .unwrap_or_default();
+    let mut abi_ctx = AbiContext {
+        program: typed_program,
+        abi_with_callpaths: profile.json_abi_with_callpaths,
+    };
     let entries = entries
         .iter()
-        .map(|finalized_entry| PkgEntry::from_finalized_entry(finalized_entry, engines))
+        .map(|finalized_entry| {
+            PkgEntry::from_finalized_entry(finalized_entry, engines, &mut abi_ctx)
+        })
         .collect::<anyhow::Result<_>>()?;
 
     let asm = match asm_res {
@@ -1931,10 +2076,15 @@ impl PkgEntry {
         self.kind.test().is_some()
     }
 
-    fn from_finalized_entry(finalized_entry: &FinalizedEntry, engines: &Engines) -> Result<Self> {
+    fn from_finalized_entry(
+        finalized_entry: &FinalizedEntry,
+        engines: &Engines,
+        abi_ctx: &mut AbiContext,
+    ) -> Result<Self> {
         let pkg_entry_kind = match &finalized_entry.test_decl_ref {
             Some(test_decl_ref) => {
-                let pkg_test_entry = PkgTestEntry::from_decl(test_decl_ref.clone(), engines)?;
+                let pkg_test_entry =
+                    PkgTestEntry::from_decl(test_decl_ref.clone(), engines, abi_ctx)?;
                 PkgEntryKind::Test(pkg_test_entry)
             }
             None => PkgEntryKind::Main,
@@ -1958,11 +2108,16 @@ impl PkgEntryKind {
 }
 
 impl PkgTestEntry {
-    fn from_decl(decl_ref: DeclRefFunction, engines: &Engines) -> Result<Self> {
+    fn from_decl(
+        decl_ref: DeclRefFunction,
+        engines: &Engines,
+        abi_ctx: &mut AbiContext,
+    ) -> Result<Self> {
         let span = decl_ref.span();
         let test_function_decl = engines.de().get_function(&decl_ref);
 
         const FAILING_TEST_KEYWORD: &str = "should_revert";
+        const FUZZ_TEST_KEYWORD: &str = "fuzz";
 
         let test_args: HashMap<String, Option<String>> = test_function_decl
             .attributes
@@ -1980,21 +2135,54 @@ impl PkgTestEntry {
             })
             .collect();
 
-        let pass_condition = if test_args.is_empty() {
-            anyhow::Ok(TestPassCondition::ShouldNotRevert)
-        } else if let Some(args) = test_args.get(FAILING_TEST_KEYWORD) {
-            let expected_revert_code = args
-                .as_ref()
-                .map(|arg| {
-                    let arg_str = arg.replace('"', "");
-                    arg_str.parse::<u64>()
-                })
-                .transpose()?;
-            anyhow::Ok(TestPassCondition::ShouldRevert(expected_revert_code))
-        } else {
-            let test_name = &test_function_decl.name;
-            bail!("Invalid test argument(s) for test: {test_name}.")
-        }?;
+        let test_name = &test_function_decl.name;
+        if let Some(invalid_arg) = test_args
+            .keys()
+            .find(|arg| *arg != FAILING_TEST_KEYWORD && *arg != FUZZ_TEST_KEYWORD)
+        {
+            bail!("Invalid test argument `{invalid_arg}` for test: {test_name}.")
+        }
+
+        let is_fuzz_test = test_args.contains_key(FUZZ_TEST_KEYWORD);
+        let has_parameters = !test_function_decl.parameters.is_empty();
+        if is_fuzz_test && !has_parameters {
+            bail!("Fuzz test {test_name} must take at least one parameter.")
+        }
+        if !is_fuzz_test && has_parameters {
+            bail!("Test {test_name} takes parameters, so it must be declared as `#[test(fuzz)]`.")
+        }
+        let fuzz_abi = is_fuzz_test.then(|| {
+            let mut types = vec![];
+            let function = test_function_decl.generate_abi_function(
+                abi_ctx,
+                engines.te(),
+                engines.de(),
+                &mut types,
+            );
+            let mut fuzz_abi = program_abi::ProgramABI {
+                types,
+                functions: vec![function],
+                logged_types: None,
+                messages_types: None,
+                configurables: None,
+            };
+            standardize_json_abi_types(&mut fuzz_abi);
+            fuzz_abi
+        });
+
+        let pass_condition = match test_args.get(FAILING_TEST_KEYWORD) {
+            None => TestPassCondition::ShouldNotRevert,
+            Some(args) => {
+                let expected_revert_code = args
+                    .as_ref()
+                    .map(|arg| {
+                        let arg_str = arg.replace('"', "");
+                        arg_str.parse::<u64>()
+                    })
+                    .transpose()?;
+                TestPassCondition::ShouldRevert(expected_revert_code)
+            }
+        };
 
         let file_path = Arc::new(
             engines.se().get_path(
@@ -2006,6 +2194,7 @@ impl PkgTestEntry {
             pass_condition,
             span,
             file_path,
+            fuzz_abi,
         })
     }
 }
@@ -2116,6 +2305,7 @@ pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
         .map(PathBuf::from)
         .unwrap_or_else(|| current_dir);
 
+    let manifest_file = ManifestFile::from_dir(path)?;
     let build_plan = BuildPlan::from_build_opts(&build_options)?;
     let graph = build_plan.graph();
     let manifest_map = build_plan.manifest_map();
@@ -2144,8 +2334,21 @@ pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
     // Build it!
     let mut built_workspace = Vec::new();
     let build_start = std::time::Instant::now();
-    let built_packages = build(&build_plan, *build_target, &build_profile, &outputs)?;
     let output_dir = pkg.output_directory.as_ref().map(PathBuf::from);
+    let cache = build_options.build_cache.then(|| {
+        let cache_dir = output_dir
+            .clone()
+            .unwrap_or_else(|| default_output_directory(manifest_file.dir()))
+            .join(BUILD_CACHE_DIR_NAME);
+        BuildCache::new(cache_dir)
+    });
+    let built_packages = build_with_cache(
+        &build_plan,
+        *build_target,
+        &build_profile,
+        &outputs,
+        cache.as_ref(),
+    )?;
 
     let finished = ansi_term::Colour::Green.bold().paint("Finished");
     info!("  {finished} {profile_name} in {:?}", build_start.elapsed());
@@ -2243,6 +2446,18 @@ pub fn build(
     target: BuildTarget,
     profile: &BuildProfile,
     outputs: &HashSet<NodeIx>,
+) -> anyhow::Result<Vec<(NodeIx, BuiltPackage)>> {
+    build_with_cache(plan, target, profile, outputs, None)
+}
+
+/// Build the given outputs like `build`, reusing the packages in the given cache whenever neither
+/// they nor their dependencies changed, and caching the packages compiled.
+fn build_with_cache(
+    plan: &BuildPlan,
+    target: BuildTarget,
+    profile: &BuildProfile,
+    outputs: &HashSet<NodeIx>,
+    cache: Option<&BuildCache>,
 ) -> anyhow::Result<Vec<(NodeIx, BuiltPackage)>> {
     let mut built_packages = Vec::new();
 
@@ -2258,30 +2473,80 @@ pub fn build(
     // We will need this for `forc test`.
     let mut contract_id_value: Option<ContractIdConst> = None;
 
-    let mut lib_namespace_map = Default::default();
-    let mut compiled_contract_deps = HashMap::new();
-    for &node in plan
+    let compilation_order: Vec<NodeIx> = plan
         .compilation_order
         .iter()
         .filter(|node| required.contains(node))
-    {
+        .copied()
+        .collect();
+    let cache = cache.filter(|_| BuildCache::is_enabled(target, profile));
+    let cache_keys = match cache {
+        Some(_) => build_cache_keys(plan, target, profile, &compilation_order)?,
+        None => HashMap::new(),
+    };
+    let mut cached_packages = match cache {
+        Some(cache) => load_cached_packages(plan, target, cache, &cache_keys, &compilation_order),
+        None => HashMap::new(),
+    };
+    let namespace_required = cached_namespace_required(plan, &compilation_order, &cached_packages);
+
+    let mut lib_namespace_map = Default::default();
+    let mut compiled_contract_deps = HashMap::new();
+    for &node in &compilation_order {
         let mut source_map = SourceMap::new();
         let pkg = &plan.graph()[node];
         let manifest = &plan.manifest_map()[&pkg.id()];
         let program_ty = manifest.program_type().ok();
 
+        if let Some(built_pkg) = cached_packages.remove(&node) {
+            if namespace_required.contains(&node) {
+                let profile = node_build_profile(plan, node, profile);
+                let dep_namespace = match dependency_namespace(
+                    &lib_namespace_map,
+                    &compiled_contract_deps,
+                    plan.graph(),
+                    node,
+                    &engines,
+                    None,
+                ) {
+                    Ok(o) => o,
+                    Err(errs) => {
+                        print_on_failure(
+                            engines.se(),
+                            profile.terse,
+                            &[],
+                            &errs,
+                            profile.reverse_results,
+                        );
+                        bail!("Failed to compile {}", pkg.name);
+                    }
+                };
+                let namespace =
+                    compile_namespace(&built_pkg.descriptor, &profile, &engines, dep_namespace)?;
+                lib_namespace_map.insert(node, namespace);
+            }
+            if let Some(bytecode) = &built_pkg.bytecode_without_tests {
+                if is_contract_dependency(plan.graph(), node) {
+                    let compiled_contract_dep = CompiledContractDependency {
+                        bytecode: bytecode.bytes.clone(),
+                        storage_slots: built_pkg.storage_slots.clone(),
+                    };
+                    compiled_contract_deps.insert(node, compiled_contract_dep);
+                }
+            }
+            if outputs.contains(&node) {
+                built_packages.push((node, built_pkg));
+            }
+            continue;
+        }
+
         print_compiling(
             program_ty.as_ref(),
             &pkg.name,
             &pkg.source.display_compiling(manifest.dir()),
         );
 
-        let descriptor = PackageDescriptor {
-            name: pkg.name.clone(),
-            target,
-            pinned: pkg.clone(),
-            manifest_file: manifest.clone(),
-        };
+        let descriptor = package_descriptor(plan, node, target);
 
         let fail = |warnings, errors| {
             print_on_failure(
@@ -2297,7 +2562,8 @@ pub fn build(
         let is_contract_dependency = is_contract_dependency(plan.graph(), node);
         // If we are building a contract and tests are enabled or we are building a contract
         // dependency, we need the tests exlcuded bytecode.
-        let bytecode_without_tests = if (include_tests
+        let mut program_abi_without_tests = None;
+        let (bytecode_without_tests, source_map_without_tests) = if (include_tests
             && matches!(manifest.program_type(), Ok(TreeType::Contract)))
             || is_contract_dependency
         {
@@ -2326,13 +2592,17 @@ pub fn build(
                 Err(errs) => return fail(&[], &errs),
             };
 
-            let compiled_without_tests = compile(
+            // The bytecode without tests gets its own source map, as its program counters do not
+            // line up with those of the bytecode built with tests.
+            let mut source_map_without_tests = SourceMap::new();
+            let mut compiled_without_tests = compile(
                 &descriptor,
                 &profile,
                 &engines,
                 dep_namespace,
-                &mut source_map,
+                &mut source_map_without_tests,
             )?;
+            source_map_without_tests.insert_dependency(descriptor.manifest_file.dir());
 
             if let Some(outfile) = profile.metrics_outfile {
                 let path = Path::new(&outfile);
@@ -2361,20 +2631,20 @@ pub fn build(
                 // We finally set the contract ID value here to use for compilation later if tests are enabled.
                 contract_id_value = Some(format!("0x{contract_id}"));
             }
-            Some(compiled_without_tests.bytecode)
+            if let ProgramABI::Fuel(ref mut program_abi) = compiled_without_tests.program_abi {
+                standardize_json_abi_types(program_abi);
+            }
+            program_abi_without_tests = Some(compiled_without_tests.program_abi);
+            (
+                Some(compiled_without_tests.bytecode),
+                Some(source_map_without_tests),
+            )
         } else {
-            None
+            (None, None)
         };
 
         // Build all non member nodes with tests disabled by overriding the current profile.
-        let profile = if !plan.member_nodes().any(|member| member == node) {
-            BuildProfile {
-                include_tests: false,
-                ..profile.clone()
-            }
-        } else {
-            profile.clone()
-        };
+        let profile = node_build_profile(plan, node, profile);
 
         // Note that the contract ID value here is only Some if tests are enabled.
         let dep_namespace = match dependency_namespace(
@@ -2434,8 +2704,16 @@ pub fn build(
             bytecode: compiled.bytecode,
             warnings: compiled.warnings,
             bytecode_without_tests,
+            source_map_without_tests,
+            program_abi_without_tests,
         };
 
+        if let Some((cache, key)) = cache.zip(cache_keys.get(&node)) {
+            if let Err(e) = cache.store(key, &built_pkg) {
+                warn!("Failed to cache the build of {}: {}", pkg.name, e);
+            }
+        }
+
         if outputs.contains(&node) {
             built_packages.push((node, built_pkg));
         }
@@ -2444,6 +2722,168 @@ pub fn build(
     Ok(built_packages)
 }
 
+/// The descriptor of the package at the given node.
+fn package_descriptor(plan: &BuildPlan, node: NodeIx, target: BuildTarget) -> PackageDescriptor {
+    let pkg = &plan.graph()[node];
+    PackageDescriptor {
+        name: pkg.name.clone(),
+        target,
+        pinned: pkg.clone(),
+        manifest_file: plan.manifest_map()[&pkg.id()].clone(),
+    }
+}
+
+/// The profile used to build the package at the given node. Tests are only included in members.
+fn node_build_profile(plan: &BuildPlan, node: NodeIx, profile: &BuildProfile) -> BuildProfile {
+    if !plan.member_nodes().any(|member| member == node) {
+        BuildProfile {
+            include_tests: false,
+            ..profile.clone()
+        }
+    } else {
+        profile.clone()
+    }
+}
+
+/// Compute the build cache keys of the packages in the given compilation order.
+///
+/// Packages built with tests, and the packages depending on them, have no key as they are never
+/// cached.
+fn build_cache_keys(
+    plan: &BuildPlan,
+    target: BuildTarget,
+    profile: &BuildProfile,
+    compilation_order: &[NodeIx],
+) -> Result<HashMap<NodeIx, CacheKey>> {
+    let graph = plan.graph();
+    let lock = toml::ser::to_string(&Lock::from_graph(graph))?;
+    let mut keys: HashMap<NodeIx, CacheKey> = HashMap::new();
+    'nodes: for &node in compilation_order {
+        let profile = node_build_profile(plan, node, profile);
+        if profile.include_tests {
+            continue;
+        }
+        let mut deps = vec![];
+        for edge in graph.edges_directed(node, Direction::Outgoing) {
+            let Some(dep_key) = keys.get(&edge.target()) else {
+                continue 'nodes;
+            };
+            let Edge { name, kind } = edge.weight();
+            deps.push((name.clone(), format!("{kind:?}"), dep_key.clone()));
+        }
+        deps.sort();
+        let manifest = &plan.manifest_map()[&graph[node].id()];
+        let pinned = graph[node].source.to_string();
+        let is_contract_dependency = is_contract_dependency(graph, node);
+        let key = BuildCache::key(
+            manifest,
+            &pinned,
+            &lock,
+            target,
+            &profile,
+            is_contract_dependency,
+            &deps,
+        )?;
+        keys.insert(node, key);
+    }
+    Ok(keys)
+}
+
+/// Load the cached builds of the packages in the given compilation order.
+fn load_cached_packages(
+    plan: &BuildPlan,
+    target: BuildTarget,
+    cache: &BuildCache,
+    cache_keys: &HashMap<NodeIx, CacheKey>,
+    compilation_order: &[NodeIx],
+) -> HashMap<NodeIx, BuiltPackage> {
+    let mut cached = HashMap::new();
+    for &node in compilation_order {
+        let Some(key) = cache_keys.get(&node) else {
+            continue;
+        };
+        let descriptor = package_descriptor(plan, node, target);
+        let Ok(tree_type) = descriptor.manifest_file.program_type() else {
+            continue;
+        };
+        if let Some(built_pkg) = cache.load(key, descriptor, tree_type) {
+            cached.insert(node, built_pkg);
+        }
+    }
+    cached
+}
+
+/// The cached packages whose namespace is required to compile the packages importing them, either
+/// directly or through other cached libraries.
+fn cached_namespace_required(
+    plan: &BuildPlan,
+    compilation_order: &[NodeIx],
+    cached: &HashMap<NodeIx, BuiltPackage>,
+) -> HashSet<NodeIx> {
+    let graph = plan.graph();
+    let mut required = HashSet::new();
+    for &node in compilation_order.iter().rev() {
+        if !cached.contains_key(&node) {
+            continue;
+        }
+        let imported_by_compiled_pkg = graph
+            .edges_directed(node, Direction::Incoming)
+            .filter(|edge| edge.weight().kind == DepKind::Library)
+            .any(|edge| {
+                let importer = edge.source();
+                compilation_order.contains(&importer)
+                    && (!cached.contains_key(&importer) || required.contains(&importer))
+            });
+        if imported_by_compiled_pkg {
+            required.insert(node);
+        }
+    }
+    required
+}
+
+/// Type check the given package to produce its namespace, without generating any code.
+///
+/// Used for cached libraries imported by packages which need to be compiled.
+fn compile_namespace(
+    pkg: &PackageDescriptor,
+    profile: &BuildProfile,
+    engines: &Engines,
+    namespace: namespace::Module,
+) -> Result<namespace::Module> {
+    let entry_path = pkg.manifest_file.entry_path();
+    let sway_build_config =
+        sway_build_config(pkg.manifest_file.dir(), &entry_path, pkg.target, profile)?;
+    let source = pkg.manifest_file.entry_string()?;
+    let handler = Handler::default();
+    let programs = sway_core::compile_to_ast(
+        &handler,
+        engines,
+        source,
+        namespace,
+        Some(&sway_build_config),
+        &pkg.name,
+    );
+    match programs.map(|programs| programs.typed) {
+        Ok(Ok(typed_program)) if !handler.has_errors() => {
+            let root: namespace::Root = typed_program.root.namespace.into();
+            let mut namespace = namespace::Module::from(root);
+            namespace.name = Some(Ident::new_no_span(pkg.name.clone()));
+            Ok(namespace)
+        }
+        _ => {
+            let (errors, warnings) = handler.consume();
+            print_on_failure(
+                engines.se(),
+                profile.terse,
+                &warnings,
+                &errors,
+                profile.reverse_results,
+            );
+            bail!("Failed to compile {}", pkg.name);
+        }
+    }
+}
+
 /// Standardize the JSON ABI data structure by eliminating duplicate types. This is an iterative
 /// process because every time two types are merged, new opportunities for more merging arise.
 fn standardize_json_abi_types(json_abi_program: &mut program_abi::ProgramABI) {
@@ -2724,6 +3164,7 @@ pub fn fuel_core_not_running(node_url: &str) -> anyhow::Error {
 mod test {
     use super::*;
     use regex::Regex;
+    use url::Url;
 
     fn setup_build_plan() -> BuildPlan {
         let current_dir = env!("CARGO_MANIFEST_DIR");
@@ -2744,6 +3185,89 @@ mod test {
         .unwrap()
     }
 
+    /// The manifest of a library depending on the given packages of the registry at `index`.
+    fn registry_lib_manifest(
+        index: &Url,
+        name: &str,
+        version: &str,
+        deps: &[(&str, &str)],
+    ) -> String {
+        let mut manifest = format!(
+            "[project]\n\
+             authors = [\"Fuel Labs <contact@fuel.sh>\"]\n\
+             entry = \"lib.sw\"\n\
+             forc-version = \"0.47.0\"\n\
+             implicit-std = false\n\
+             license = \"Apache-2.0\"\n\
+             name = \"{name}\"\n\
+             version = \"{version}\"\n\n\
+             [dependencies]\n"
+        );
+        for (dep, requirement) in deps {
+            manifest.push_str(&format!(
+                "{dep} = {{ version = \"{requirement}\", registry = \"{index}\" }}\n"
+            ));
+        }
+        manifest
+    }
+
+    /// Write a library package to `dir`, returning its manifest.
+    fn write_registry_lib(
+        dir: &Path,
+        index: &Url,
+        name: &str,
+        version: &str,
+        deps: &[(&str, &str)],
+    ) -> PackageManifestFile {
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.sw"), "library;\n").unwrap();
+        let manifest = registry_lib_manifest(index, name, version, deps);
+        fs::write(dir.join(constants::MANIFEST_FILE_NAME), manifest).unwrap();
+        PackageManifestFile::from_dir(dir).unwrap()
+    }
+
+    #[test]
+    fn test_registry_versions_resolved_across_graph() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let dir = tmp_dir.path();
+        let registry_dir = dir.join("registry");
+        fs::create_dir_all(&registry_dir).unwrap();
+        let index = Url::from_directory_path(registry_dir.canonicalize().unwrap()).unwrap();
+        // The name, version and registry dependencies of each published package.
+        type Published<'a> = (&'a str, &'a str, &'a [(&'a str, &'a str)]);
+        let published: &[Published] = &[
+            ("bar", "1.0.0", &[]),
+            ("bar", "1.2.0", &[]),
+            ("bar", "1.5.0", &[]),
+            ("baz", "1.0.0", &[("bar", ">=1.0, <1.3")]),
+        ];
+        for (name, version, deps) in published {
+            let pkg_dir = dir.join(format!("{name}-{version}"));
+            let manifest = write_registry_lib(&pkg_dir, &index, name, version, deps);
+            let archive = crate::publish::package(&manifest).unwrap();
+            crate::publish::publish_to_registry(&archive, &index, None).unwrap();
+        }
+
+        // `bar` is visited first, and the newest version matching `^1` doesn't match the
+        // requirement of `baz`. Both must share the newest version matching both requirements.
+        let root_dir = dir.join("root");
+        let deps = [("bar", "^1"), ("baz", "^1")];
+        write_registry_lib(&root_dir, &index, "root", "0.1.0", &deps);
+        let manifest_file = ManifestFile::from_dir(&root_dir).unwrap();
+        let member_manifests = manifest_file.member_manifests().unwrap();
+        let plan = BuildPlan::from_manifests(&member_manifests, false, Default::default()).unwrap();
+        let graph = plan.graph();
+        let bar_versions: Vec<String> = graph
+            .node_indices()
+            .filter(|&n| graph[n].name == "bar")
+            .map(|n| match &graph[n].source {
+                source::Pinned::Registry(pinned) => pinned.version.to_string(),
+                source => panic!("unexpected source {source}"),
+            })
+            .collect();
+        assert_eq!(bar_versions, ["1.2.0"]);
+    }
+
     #[test]
     fn test_root_pkg_order() {
         let build_plan = setup_build_plan();
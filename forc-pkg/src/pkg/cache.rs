@@ -0,0 +1,387 @@
+//! An on-disk cache of the packages compiled by `build`.
+//!
+//! Each package is cached under a key hashing the compiler version and sources, the build target
+//! and profile, the lock file, the pinned source, manifest and sources of the package and the keys
+//! of its dependencies, so that a change to any package invalidates the cached builds of all
+//! packages depending on it. Only the most recently stored or loaded builds of each package are
+//! kept.
+//!
+//! The sources of the compiler are hashed by the build script of this crate, so that builds by a
+//! modified compiler of the same version, e.g. in a development checkout, aren't mixed up.
+//!
+//! Typed namespaces live in the engines of a single build and can't be cached. When a package
+//! importing a cached library needs to be compiled, the library is type checked again to produce
+//! its namespace, while its cached ABI and bytecode are reused. The cached builds of contract
+//! dependencies are always reused, as dependents only need their bytecode and storage slots to
+//! compute their contract IDs.
+//!
+//! Packages built with tests or which emitted warnings are never cached, as their test entries
+//! and warnings refer to the engines of the build that produced them.
+
+use super::{BuiltPackage, BuiltPackageBytecode, PackageDescriptor, PkgEntry, PkgEntryKind};
+use crate::{manifest::PackageManifestFile, publish, BuildProfile};
+use anyhow::Result;
+use fuel_abi_types::program_abi;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use sway_core::{
+    asm_generation::ProgramABI,
+    fuel_prelude::{fuel_crypto, fuel_tx::StorageSlot},
+    language::parsed::TreeType,
+    source_map::SourceMap,
+    BuildTarget, FinalizedEntry,
+};
+
+/// The name of the cache directory within the output directory.
+pub const BUILD_CACHE_DIR_NAME: &str = "cache";
+
+/// The number of builds of each package kept in the cache. The least recently used builds are
+/// evicted whenever a new build of the package is stored.
+const MAX_CACHED_BUILDS_PER_PACKAGE: usize = 3;
+
+/// Identifies the inputs of a package build. The hex encoded SHA-256 hash of those inputs.
+pub(crate) type CacheKey = String;
+
+/// The build cache located in a directory, usually `out/cache`.
+pub(crate) struct BuildCache {
+    dir: PathBuf,
+}
+
+/// A cached package build.
+#[derive(Serialize, Deserialize)]
+struct CachedPackage {
+    storage_slots: Vec<StorageSlot>,
+    build: CachedBuild,
+    /// The build without tests of contracts which are contract dependencies.
+    without_tests: Option<CachedBuild>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedBuild {
+    program_abi: program_abi::ProgramABI,
+    bytecode: Vec<u8>,
+    entries: Vec<CachedEntry>,
+    source_map: SourceMap,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedEntry {
+    fn_name: String,
+    imm: u64,
+    selector: Option<[u8; 4]>,
+}
+
+impl BuildCache {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Whether packages built with the given target and profile may be cached.
+    ///
+    /// Builds printing or recording compiler internals always compile every package.
+    pub(crate) fn is_enabled(target: BuildTarget, profile: &BuildProfile) -> bool {
+        target == BuildTarget::Fuel
+            && !profile.print_ast
+            && profile.print_dca_graph.is_none()
+            && !profile.print_ir
+            && !profile.print_finalized_asm
+            && !profile.print_intermediate_asm
+            && !profile.time_phases
+            && profile.metrics_outfile.is_none()
+    }
+
+    /// Compute the cache key of a package from its build inputs and the keys of its dependencies.
+    ///
+    /// `pinned` is the pinned source of the package, and `lock` the lock file of the build plan.
+    /// Each dependency is described by its dependency name, kind and key.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn key(
+        manifest: &PackageManifestFile,
+        pinned: &str,
+        lock: &str,
+        target: BuildTarget,
+        profile: &BuildProfile,
+        is_contract_dependency: bool,
+        deps: &[(String, String, CacheKey)],
+    ) -> Result<CacheKey> {
+        let mut hasher = fuel_crypto::Hasher::default();
+        let mut input = |bytes: &[u8]| {
+            // Prefix each input with its length so that inputs can't run into one another.
+            hasher.input((bytes.len() as u64).to_be_bytes());
+            hasher.input(bytes);
+        };
+        input(env!("CARGO_PKG_VERSION").as_bytes());
+        input(env!("FORC_PKG_COMPILER_HASH").as_bytes());
+        input(&serde_json::to_vec(&target)?);
+        input(&serde_json::to_vec(profile)?);
+        input(&[is_contract_dependency as u8]);
+        input(lock.as_bytes());
+        input(pinned.as_bytes());
+        input(manifest.project.name.as_bytes());
+        input(&fs::read(manifest.path())?);
+        for file in publish::package_files(manifest.dir())? {
+            input(file.to_string_lossy().as_bytes());
+            input(&fs::read(manifest.dir().join(&file))?);
+        }
+        for (dep_name, dep_kind, dep_key) in deps {
+            input(dep_name.as_bytes());
+            input(dep_kind.as_bytes());
+            input(dep_key.as_bytes());
+        }
+        Ok(hasher.finalize().to_string())
+    }
+
+    fn entry_path(&self, name: &str, key: &CacheKey) -> PathBuf {
+        self.dir.join(format!("{name}-{key}.json"))
+    }
+
+    /// Mark the cached build at `path` as used, so that it's evicted after the builds used less
+    /// recently. A build is used when it's stored or loaded, which is tracked by its mtime.
+    fn mark_used(path: &Path) -> Result<()> {
+        filetime::set_file_mtime(path, filetime::FileTime::now())?;
+        Ok(())
+    }
+
+    /// Remove all but the most recently used builds of the named package.
+    fn evict(&self, name: &str) -> Result<()> {
+        let mut entries = vec![];
+        for dir_entry in fs::read_dir(&self.dir)? {
+            let dir_entry = dir_entry?;
+            let file_name = dir_entry.file_name();
+            let Some(key) = file_name
+                .to_str()
+                .and_then(|file_name| file_name.strip_prefix(name)?.strip_prefix('-'))
+                .and_then(|rest| rest.strip_suffix(".json"))
+            else {
+                continue;
+            };
+            // Keys are hex encoded hashes, which tells apart packages whose names share a prefix.
+            if key.len() != 64 || !key.chars().all(|c| c.is_ascii_hexdigit()) {
+                continue;
+            }
+            entries.push((dir_entry.metadata()?.modified()?, dir_entry.path()));
+        }
+        entries.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+        for (_, path) in entries.into_iter().skip(MAX_CACHED_BUILDS_PER_PACKAGE) {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Load the cached build of a package, or `None` if it is missing or unreadable.
+    pub(crate) fn load(
+        &self,
+        key: &CacheKey,
+        descriptor: PackageDescriptor,
+        tree_type: TreeType,
+    ) -> Option<BuiltPackage> {
+        let path = self.entry_path(&descriptor.name, key);
+        let json = fs::read(&path).ok()?;
+        let cached: CachedPackage = serde_json::from_slice(&json).ok()?;
+        // Failing to mark the build as used only makes it more likely to be evicted.
+        let _ = Self::mark_used(&path);
+        let (bytecode, program_abi, source_map) = cached.build.into_parts();
+        let (bytecode_without_tests, program_abi_without_tests, source_map_without_tests) =
+            match cached.without_tests.map(CachedBuild::into_parts) {
+                Some((bytecode, program_abi, source_map)) => {
+                    (Some(bytecode), Some(program_abi), Some(source_map))
+                }
+                None => (None, None, None),
+            };
+        Some(BuiltPackage {
+            descriptor,
+            program_abi,
+            storage_slots: cached.storage_slots,
+            warnings: vec![],
+            source_map,
+            tree_type,
+            bytecode,
+            bytecode_without_tests,
+            source_map_without_tests,
+            program_abi_without_tests,
+        })
+    }
+
+    /// Cache the build of a package, unless it can't be cached.
+    pub(crate) fn store(&self, key: &CacheKey, built: &BuiltPackage) -> Result<()> {
+        let Some(cached) = CachedPackage::from_built(built) else {
+            return Ok(());
+        };
+        fs::create_dir_all(&self.dir)?;
+        let path = self.entry_path(&built.descriptor.name, key);
+        fs::write(path, serde_json::to_vec(&cached)?)?;
+        self.evict(&built.descriptor.name)
+    }
+}
+
+impl CachedPackage {
+    fn from_built(built: &BuiltPackage) -> Option<Self> {
+        if !built.warnings.is_empty() {
+            return None;
+        }
+        let build =
+            CachedBuild::from_built(&built.bytecode, &built.program_abi, &built.source_map)?;
+        let without_tests = match (
+            &built.bytecode_without_tests,
+            &built.program_abi_without_tests,
+            &built.source_map_without_tests,
+        ) {
+            (Some(bytecode), Some(program_abi), Some(source_map)) => {
+                Some(CachedBuild::from_built(bytecode, program_abi, source_map)?)
+            }
+            _ => None,
+        };
+        Some(Self {
+            storage_slots: built.storage_slots.clone(),
+            build,
+            without_tests,
+        })
+    }
+}
+
+impl CachedBuild {
+    fn from_built(
+        bytecode: &BuiltPackageBytecode,
+        program_abi: &ProgramABI,
+        source_map: &SourceMap,
+    ) -> Option<Self> {
+        let ProgramABI::Fuel(program_abi) = program_abi else {
+            return None;
+        };
+        let entries = bytecode
+            .entries
+            .iter()
+            .map(|entry| match entry.kind {
+                PkgEntryKind::Main => Some(CachedEntry {
+                    fn_name: entry.finalized.fn_name.clone(),
+                    imm: entry.finalized.imm,
+                    selector: entry.finalized.selector,
+                }),
+                PkgEntryKind::Test(_) => None,
+            })
+            .collect::<Option<_>>()?;
+        Some(Self {
+            program_abi: program_abi.clone(),
+            bytecode: bytecode.bytes.clone(),
+            entries,
+            source_map: source_map.clone(),
+        })
+    }
+
+    fn into_parts(self) -> (BuiltPackageBytecode, ProgramABI, SourceMap) {
+        let entries = self
+            .entries
+            .into_iter()
+            .map(|entry| PkgEntry {
+                finalized: FinalizedEntry {
+                    fn_name: entry.fn_name,
+                    imm: entry.imm,
+                    selector: entry.selector,
+                    test_decl_ref: None,
+                },
+                kind: PkgEntryKind::Main,
+            })
+            .collect();
+        let bytecode = BuiltPackageBytecode {
+            bytes: self.bytecode,
+            entries,
+        };
+        (
+            bytecode,
+            ProgramABI::Fuel(self.program_abi),
+            self.source_map,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_key() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let dir = tmp_dir.path();
+        fs::create_dir_all(dir.join("src")).unwrap();
+        for file in ["Forc.toml", "src/lib.sw"] {
+            fs::copy(
+                Path::new("./tests/publish_package").join(file),
+                dir.join(file),
+            )
+            .unwrap();
+        }
+        let profile = BuildProfile::debug();
+        let key_with = |pinned: &str, lock: &str, profile: &BuildProfile, deps: &[_]| {
+            let manifest = PackageManifestFile::from_dir(dir).unwrap();
+            BuildCache::key(
+                &manifest,
+                pinned,
+                lock,
+                BuildTarget::Fuel,
+                profile,
+                false,
+                deps,
+            )
+            .unwrap()
+        };
+        let pinned = "member";
+        let key = |profile: &BuildProfile, deps: &[(String, String, CacheKey)]| {
+            key_with(pinned, "", profile, deps)
+        };
+
+        let original = key(&profile, &[]);
+        assert_eq!(key(&profile, &[]), original);
+        // Build outputs don't affect the key.
+        fs::create_dir_all(dir.join("out")).unwrap();
+        fs::write(dir.join("out/lib.bin"), [0]).unwrap();
+        assert_eq!(key(&profile, &[]), original);
+
+        let terse = BuildProfile {
+            terse: true,
+            ..profile.clone()
+        };
+        assert_ne!(key(&terse, &[]), original);
+        let dep = ("dep".to_string(), "Library".to_string(), original.clone());
+        assert_ne!(key(&profile, &[dep]), original);
+        assert_ne!(key_with("path+from-root-0", "", &profile, &[]), original);
+        assert_ne!(key_with(pinned, "[[package]]", &profile, &[]), original);
+
+        fs::write(dir.join("src/lib.sw"), "library;\n").unwrap();
+        assert_ne!(key(&profile, &[]), original);
+    }
+
+    #[test]
+    fn test_cache_eviction() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let dir = tmp_dir.path();
+        let cache = BuildCache::new(dir.to_path_buf());
+        let key = |n: u8| format!("{n:064x}");
+        for n in 0..5 {
+            let path = cache.entry_path("foo", &key(n));
+            fs::write(&path, "{}").unwrap();
+            // Builds are evicted by their modification times, set them to be distinct.
+            let mtime = filetime::FileTime::from_unix_time(1_000_000 + i64::from(n), 0);
+            filetime::set_file_mtime(path, mtime).unwrap();
+        }
+        fs::write(cache.entry_path("foo-bar", &key(0)), "{}").unwrap();
+        // The oldest build was used last, e.g. loaded by the latest build.
+        BuildCache::mark_used(&cache.entry_path("foo", &key(0))).unwrap();
+
+        cache.evict("foo").unwrap();
+        let mut remaining: Vec<_> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        remaining.sort();
+        let expected = [
+            format!("foo-{}.json", key(0)),
+            format!("foo-{}.json", key(3)),
+            format!("foo-{}.json", key(4)),
+            format!("foo-bar-{}.json", key(0)),
+        ];
+        assert_eq!(remaining, expected);
+    }
+}
@@ -0,0 +1,264 @@
+//! Packaging forc packages into archives, and publishing them to a registry or to IPFS.
+//!
+//! A package archive is a tar archive of the package sources under a `<name>-<version>/`
+//! directory. Archives are deterministic, so that packaging the same sources always produces the
+//! same checksum.
+
+use crate::{
+    manifest::{Dependency, PackageManifestFile},
+    source::{ipfs::IpfsClient, reg},
+};
+use anyhow::{anyhow, bail, Context, Result};
+use ipfs_api_backend_hyper::{IpfsApi, TryFromUri};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use sway_utils::constants::{LOCK_FILE_NAME, MANIFEST_FILE_NAME};
+use url::Url;
+use walkdir::WalkDir;
+
+/// Directories at the root of a package which are never packaged.
+const EXCLUDED_DIR_NAMES: &[&str] = &["out", "target"];
+
+/// The sources of a package bundled into a tar archive, ready to be published.
+#[derive(Clone, Debug)]
+pub struct PackageArchive {
+    pub name: String,
+    pub version: semver::Version,
+    /// The packaged files, relative to the package root.
+    pub files: Vec<PathBuf>,
+    pub bytes: Vec<u8>,
+    /// The hex encoded SHA-256 checksum of the archive.
+    pub checksum: String,
+}
+
+impl PackageArchive {
+    /// The name of the directory containing the package within the archive.
+    pub fn dir_name(&self) -> String {
+        format!("{}-{}", self.name, self.version)
+    }
+
+    /// The file name of the archive.
+    pub fn file_name(&self) -> String {
+        format!("{}.tar", self.dir_name())
+    }
+}
+
+/// Check that the package can be published, returning the version to publish.
+///
+/// A published package must specify its `version`, `license`, `authors` and the `forc-version`
+/// required to build it, and may only depend on packages which are published themselves.
+pub fn validate(manifest: &PackageManifestFile) -> Result<semver::Version> {
+    let project = &manifest.project;
+    let Some(version) = &project.version else {
+        bail!("`version` must be specified in the `[project]` section to publish a package");
+    };
+    if project.license.trim().is_empty() {
+        bail!("`license` must not be empty to publish a package");
+    }
+    match &project.authors {
+        Some(authors) if !authors.is_empty() && authors.iter().all(|a| !a.trim().is_empty()) => {}
+        _ => bail!("`authors` must be specified to publish a package"),
+    }
+    let Some(forc_version) = &project.forc_version else {
+        bail!("`forc-version` must be specified to publish a package");
+    };
+    let current_version = semver::Version::parse(env!("CARGO_PKG_VERSION"))?;
+    if *forc_version > current_version {
+        bail!("`forc-version` {forc_version} is newer than the current forc version {current_version}");
+    }
+    let deps = manifest
+        .deps()
+        .chain(manifest.contract_deps().map(|(n, d)| (n, &d.dependency)));
+    for (dep_name, dep) in deps {
+        if let Dependency::Detailed(det) = dep {
+            if det.path.is_some() {
+                bail!("dependency `{dep_name}` is a path dependency, which can't be published");
+            }
+        }
+    }
+    Ok(version.clone())
+}
+
+/// Validate the package and bundle its sources into an archive.
+///
+/// Hidden files, the `Forc.lock` file, the `out` and `target` directories and nested packages are
+/// left out.
+pub fn package(manifest: &PackageManifestFile) -> Result<PackageArchive> {
+    let version = validate(manifest)?;
+    let dir = manifest.dir();
+    let files = package_files(dir)?;
+    let mut archive = PackageArchive {
+        name: manifest.project.name.clone(),
+        version,
+        files,
+        bytes: vec![],
+        checksum: String::new(),
+    };
+    let mut builder = tar::Builder::new(vec![]);
+    for file in &archive.files {
+        let contents = fs::read(dir.join(file))
+            .with_context(|| format!("failed to read {}", dir.join(file).display()))?;
+        // Only the path, size and contents of the files are recorded, so that archives are
+        // reproducible.
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(0);
+        header.set_uid(0);
+        header.set_gid(0);
+        header.set_cksum();
+        let path = Path::new(&archive.dir_name()).join(file);
+        builder.append_data(&mut header, path, contents.as_slice())?;
+    }
+    archive.bytes = builder.into_inner()?;
+    archive.checksum = reg::checksum(&archive.bytes);
+    Ok(archive)
+}
+
+/// The files to package within the package directory, sorted by path.
+pub(crate) fn package_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let walker = WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            if entry.depth() == 0 {
+                return true;
+            }
+            let name = entry.file_name().to_string_lossy();
+            let is_dir = entry.file_type().is_dir();
+            let is_excluded_dir = entry.depth() == 1 && EXCLUDED_DIR_NAMES.contains(&&*name);
+            let is_nested_pkg = entry.path().join(MANIFEST_FILE_NAME).exists();
+            !name.starts_with('.') && !(is_dir && (is_excluded_dir || is_nested_pkg))
+        });
+    let mut files = vec![];
+    for entry in walker {
+        let entry = entry?;
+        if entry.file_type().is_file() && entry.file_name() != LOCK_FILE_NAME {
+            files.push(entry.path().strip_prefix(dir)?.to_path_buf());
+        }
+    }
+    Ok(files)
+}
+
+/// Publish the archive to the registry at the given location.
+///
+/// The archive is written to the packages of the registry before the new version is added to its
+/// index. Versions which were already published can't be published again.
+pub fn publish_to_registry(
+    archive: &PackageArchive,
+    index: &Url,
+    token: Option<&str>,
+) -> Result<()> {
+    let index_path = reg::index_entry_path(&archive.name);
+    let mut index_file = reg::try_read_registry_file(index, &index_path)?.unwrap_or_default();
+    let entries = reg::parse_index_entries(&index_file)
+        .with_context(|| format!("invalid registry index {index_path} in {index}"))?;
+    if entries.iter().any(|entry| entry.version == archive.version) {
+        bail!(
+            "{} {} has already been published to {}",
+            archive.name,
+            archive.version,
+            index
+        );
+    }
+
+    let archive_path = reg::archive_path(&archive.name, &archive.version);
+    reg::write_registry_file(index, &archive_path, &archive.bytes, token)?;
+
+    let entry = reg::IndexEntry {
+        name: archive.name.clone(),
+        version: archive.version.clone(),
+        checksum: archive.checksum.clone(),
+        yanked: false,
+    };
+    if !index_file.is_empty() && !index_file.ends_with(b"\n") {
+        index_file.push(b'\n');
+    }
+    serde_json::to_writer(&mut index_file, &entry)?;
+    index_file.push(b'\n');
+    reg::write_registry_file(index, &index_path, &index_file, token)
+}
+
+/// Publish the package to IPFS through the API of the node at the given URL, or of the local node
+/// by default, returning the CID of the package.
+///
+/// The CID may be used as the `ipfs` source of a dependency on the package.
+pub fn publish_to_ipfs(archive: &PackageArchive, api_url: Option<&str>) -> Result<String> {
+    let client = match api_url {
+        Some(url) => {
+            IpfsClient::from_str(url).map_err(|e| anyhow!("invalid IPFS API URL {url:?}: {e}"))?
+        }
+        None => IpfsClient::default(),
+    };
+    // IPFS stores the package as a directory, so unpack it first.
+    let tmp_dir = tempfile::tempdir()?;
+    tar::Archive::new(archive.bytes.as_slice()).unpack(tmp_dir.path())?;
+    let pkg_dir = tmp_dir.path().join(archive.dir_name());
+    let res = futures::executor::block_on(client.add_path(&pkg_dir));
+    let responses = res.map_err(|e| anyhow!("failed to add package to IPFS: {e}"))?;
+    responses
+        .into_iter()
+        .find(|res| res.name == archive.dir_name())
+        .map(|res| res.hash)
+        .ok_or_else(|| anyhow!("IPFS did not return the CID of the package directory"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBLISH_PACKAGE_DIR: &str = "./tests/publish_package";
+
+    #[test]
+    fn test_package_archive_deterministic() {
+        let manifest = PackageManifestFile::from_dir(PUBLISH_PACKAGE_DIR).unwrap();
+        let archive = package(&manifest).unwrap();
+        assert_eq!(archive.file_name(), "publish_package-0.1.0.tar");
+        let files: Vec<_> = archive.files.iter().map(|f| f.to_str().unwrap()).collect();
+        assert_eq!(files, ["Forc.toml", "README.md", "src/lib.sw"]);
+        assert_eq!(archive.checksum, reg::checksum(&archive.bytes));
+        assert_eq!(package(&manifest).unwrap().bytes, archive.bytes);
+
+        let mut tar = tar::Archive::new(archive.bytes.as_slice());
+        let paths: Vec<_> = tar
+            .entries()
+            .unwrap()
+            .map(|entry| entry.unwrap().path().unwrap().into_owned())
+            .collect();
+        assert_eq!(
+            paths[2],
+            Path::new("publish_package-0.1.0").join("src/lib.sw")
+        );
+    }
+
+    #[test]
+    fn test_package_validation() {
+        let manifest = PackageManifestFile::from_dir("./tests/test_package").unwrap();
+        let err = validate(&manifest).unwrap_err();
+        assert!(err.to_string().contains("`version`"));
+    }
+
+    #[test]
+    fn test_publish_to_local_registry() {
+        let manifest = PackageManifestFile::from_dir(PUBLISH_PACKAGE_DIR).unwrap();
+        let archive = package(&manifest).unwrap();
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let dir = tmp_dir.path();
+        let index = Url::from_directory_path(dir.canonicalize().unwrap()).unwrap();
+
+        publish_to_registry(&archive, &index, None).unwrap();
+        assert!(publish_to_registry(&archive, &index, None).is_err());
+
+        let source = reg::Source {
+            index,
+            version: semver::VersionReq::parse("^0.1").unwrap(),
+        };
+        let entries = source.index_entries(&archive.name).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].checksum, archive.checksum);
+        let published = fs::read(dir.join("packages/publish_package/publish_package-0.1.0.tar"));
+        assert_eq!(published.unwrap(), archive.bytes);
+    }
+}
@@ -11,7 +11,7 @@ pub mod git;
 pub(crate) mod ipfs;
 mod member;
 pub mod path;
-mod reg;
+pub mod reg;
 
 use self::git::Url;
 use crate::{
@@ -122,6 +122,8 @@ pub(crate) struct PinCtx<'a> {
     pub(crate) name: &'a str,
     /// The IPFS node to use for fetching IPFS sources.
     pub(crate) ipfs_node: &'a IPFSNode,
+    /// The requirements on registry packages found throughout the package graph.
+    pub(crate) registry_requirements: &'a reg::Requirements,
 }
 
 pub(crate) enum DependencyPath {
@@ -152,12 +154,7 @@ impl Source {
     ) -> Result<Self> {
         let source = match dep {
             manifest::Dependency::Simple(ref ver_str) => {
-                bail!(
-                    "Unsupported dependency declaration in \"{}\": `{}` - \
-                    currently only `git` and `path` dependencies are supported",
-                    manifest_dir.display(),
-                    ver_str
-                )
+                Source::Registry(reg_source(manifest_dir, ver_str, None)?)
             }
             manifest::Dependency::Detailed(ref det) => {
                 match (&det.path, &det.version, &det.git, &det.ipfs) {
@@ -196,6 +193,10 @@ impl Source {
                         let source = ipfs::Source(cid);
                         Source::Ipfs(source)
                     }
+                    (_, Some(version), _, _) => {
+                        let registry = det.registry.as_deref();
+                        Source::Registry(reg_source(manifest_dir, version, registry)?)
+                    }
                     _ => {
                         bail!("unsupported set of fields for dependency: {:?}", dep);
                     }
@@ -300,7 +301,7 @@ impl Pinned {
     /// Used soley for the package lock file.
     pub fn semver(&self) -> Option<semver::Version> {
         match self {
-            Self::Registry(reg) => Some(reg.source.version.clone()),
+            Self::Registry(reg) => Some(reg.version.clone()),
             _ => None,
         }
     }
@@ -355,7 +356,7 @@ impl fmt::Display for Pinned {
             Self::Path(src) => src.fmt(f),
             Self::Git(src) => src.fmt(f),
             Self::Ipfs(src) => src.fmt(f),
-            Self::Registry(_reg) => todo!("pkg registries not yet implemented"),
+            Self::Registry(src) => src.fmt(f),
         }
     }
 }
@@ -367,7 +368,7 @@ impl<'a> fmt::Display for DisplayCompiling<'a, Pinned> {
             Pinned::Path(_src) => self.manifest_dir.display().fmt(f),
             Pinned::Git(src) => src.fmt(f),
             Pinned::Ipfs(src) => src.fmt(f),
-            Pinned::Registry(_src) => todo!("registry dependencies not yet implemented"),
+            Pinned::Registry(src) => write!(f, "{} {}", src.version, src.source.index),
         }
     }
 }
@@ -385,14 +386,37 @@ impl FromStr for Pinned {
             Self::Git(src)
         } else if let Ok(src) = ipfs::Pinned::from_str(s) {
             Self::Ipfs(src)
+        } else if let Ok(src) = reg::Pinned::from_str(s) {
+            Self::Registry(src)
         } else {
-            // TODO: Try parse registry source.
             return Err(PinnedParseError);
         };
         Ok(source)
     }
 }
 
+/// Construct a registry source from a dependency's version requirement and optional registry.
+///
+/// Falls back to the registry specified by the `FORC_REGISTRY` environment variable.
+fn reg_source(manifest_dir: &Path, version: &str, registry: Option<&str>) -> Result<reg::Source> {
+    let version = semver::VersionReq::parse(version)
+        .map_err(|e| anyhow!("invalid version requirement {:?}: {}", version, e))?;
+    let registry = match registry {
+        Some(registry) => registry.to_string(),
+        None => std::env::var(reg::DEFAULT_REGISTRY_ENV_VAR).map_err(|_| {
+            anyhow!(
+                "no registry specified for dependency with version `{}` in \"{}\" - \
+                specify a `registry` or set the `{}` environment variable",
+                version,
+                manifest_dir.display(),
+                reg::DEFAULT_REGISTRY_ENV_VAR
+            )
+        })?,
+    };
+    let index = reg::Source::parse_index(&registry, manifest_dir)?;
+    Ok(reg::Source { index, version })
+}
+
 /// Produce a unique ID for a particular fetch pass.
 ///
 /// This is used in the temporary git directory and allows for avoiding contention over the git
@@ -1,40 +1,257 @@
-use crate::{manifest::PackageManifestFile, source};
-use anyhow::bail;
+//! Packages published to a registry.
+//!
+//! A registry is a directory, either local or served over HTTP, with the following layout:
+//!
+//! ```text
+//! <registry>/
+//! ├── index/
+//! │   └── <name>                          # One JSON `IndexEntry` per line, per published version.
+//! └── packages/
+//!     └── <name>/
+//!         └── <name>-<version>.tar        # The published package.
+//! ```
+//!
+//! A registry dependency is pinned to the newest published version matching its requirement, and
+//! preferably the requirements of all other dependents on the package within the package graph,
+//! so that they share a single version. The archive of the package is checked against the
+//! checksum in the index before it is unpacked into the local cache.
+
+use crate::{
+    manifest::{self, PackageManifestFile},
+    source,
+};
+use anyhow::{anyhow, bail, Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::{Path, PathBuf};
+use std::{
+    cmp::Ordering,
+    collections::{hash_map, HashMap, HashSet},
+    fmt, fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use sway_core::fuel_prelude::fuel_crypto;
+use tar::Archive;
+use tracing::info;
+use url::Url;
+
+/// The environment variable specifying the registry used by dependencies that don't specify one.
+pub const DEFAULT_REGISTRY_ENV_VAR: &str = "FORC_REGISTRY";
+
+const REGISTRY_DIR_NAME: &str = "registry";
+const REGISTRY_CACHE_DIR_NAME: &str = "cache";
+const INDEX_DIR_NAME: &str = "index";
+const PACKAGES_DIR_NAME: &str = "packages";
 
-/// A package from the official registry.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+/// A package published to a registry, along with the versions acceptable to the dependent.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub struct Source {
-    /// The base version specified for the package.
-    pub version: semver::Version,
+    /// The location of the registry. Always ends with a `/`.
+    pub index: Url,
+    /// The versions of the package that satisfy the dependency.
+    pub version: semver::VersionReq,
 }
 
+/// The version requirements of the dependents on each registry package within a package graph,
+/// keyed by the registry and the name of the package.
+pub(crate) type Requirements = HashMap<(Url, String), HashSet<semver::VersionReq>>;
+
 /// A pinned instance of the registry source.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub struct Pinned {
-    /// The registry package with base version.
+    /// The registry package with its version requirement.
     pub source: Source,
     /// The pinned version.
     pub version: semver::Version,
+    /// The hex encoded SHA-256 checksum of the package archive.
+    pub checksum: String,
+}
+
+/// A single published version of a package, as recorded in the registry index.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct IndexEntry {
+    pub name: String,
+    pub version: semver::Version,
+    /// The hex encoded SHA-256 checksum of the package archive.
+    pub checksum: String,
+    /// Yanked versions are only used by dependencies that are already pinned to them.
+    #[serde(default)]
+    pub yanked: bool,
+}
+
+/// Error returned upon failed parsing of `Pinned::from_str`.
+#[derive(Clone, Debug)]
+pub enum PinnedParseError {
+    Prefix,
+    Url,
+    VersionReq,
+    Version,
+    Checksum,
+}
+
+impl Pinned {
+    pub const PREFIX: &'static str = "registry";
+}
+
+impl Source {
+    /// Parse the location of a registry, either a URL or a path relative to `manifest_dir`.
+    pub fn parse_index(index: &str, manifest_dir: &Path) -> Result<Url> {
+        // Single letter schemes are Windows drive letters.
+        let mut url = match Url::parse(index) {
+            Ok(url) if url.scheme().len() > 1 => url,
+            _ => {
+                let path = manifest_dir.join(index);
+                let canonical_path = path.canonicalize().map_err(|e| {
+                    anyhow!("Failed to canonicalize registry path {:?}: {}", path, e)
+                })?;
+                Url::from_directory_path(&canonical_path)
+                    .map_err(|_| anyhow!("invalid registry path {:?}", canonical_path))?
+            }
+        };
+        if !url.path().ends_with('/') {
+            let path = format!("{}/", url.path());
+            url.set_path(&path);
+        }
+        Ok(url)
+    }
+
+    /// The published versions of the package with the given name.
+    pub fn index_entries(&self, name: &str) -> Result<Vec<IndexEntry>> {
+        let path = index_entry_path(name);
+        let bytes = read_registry_file(&self.index, &path).with_context(|| {
+            format!("failed to find package `{name}` in registry {}", self.index)
+        })?;
+        parse_index_entries(&bytes)
+            .with_context(|| format!("invalid registry index {path} in {}", self.index))
+    }
+
+    /// Select the newest version of the package that is not yanked and matches the requirement.
+    ///
+    /// Where one exists, the selected version also matches the `shared` requirements of the other
+    /// dependents on the package, so that all of them may use it.
+    fn select<'a>(
+        &self,
+        name: &str,
+        entries: Vec<IndexEntry>,
+        shared: impl IntoIterator<Item = &'a semver::VersionReq>,
+    ) -> Result<Pinned> {
+        let shared: Vec<_> = shared.into_iter().collect();
+        let candidates: Vec<_> = entries
+            .into_iter()
+            .filter(|entry| entry.name == name && !entry.yanked)
+            .filter(|entry| self.version.matches(&entry.version))
+            .collect();
+        let entry = candidates
+            .iter()
+            .filter(|entry| shared.iter().all(|req| req.matches(&entry.version)))
+            .max_by_key(|entry| &entry.version)
+            .or_else(|| candidates.iter().max_by_key(|entry| &entry.version))
+            .cloned()
+            .ok_or_else(|| {
+                anyhow!(
+                    "no version of `{name}` matching `{}` found in registry {}",
+                    self.version,
+                    self.index
+                )
+            })?;
+        Ok(Pinned {
+            source: self.clone(),
+            version: entry.version,
+            checksum: entry.checksum,
+        })
+    }
 }
 
 impl source::Pin for Source {
     type Pinned = Pinned;
-    fn pin(&self, _ctx: source::PinCtx) -> anyhow::Result<(Self::Pinned, PathBuf)> {
-        bail!("registry dependencies are not yet supported");
+    fn pin(&self, ctx: source::PinCtx) -> Result<(Self::Pinned, PathBuf)> {
+        if ctx.offline() && !is_local(&self.index) {
+            bail!(
+                "Unable to fetch pkg {:?} from registry {} in offline mode",
+                ctx.name(),
+                self.index
+            );
+        }
+        let entries = self.index_entries(ctx.name())?;
+        let shared = ctx
+            .registry_requirements
+            .get(&(self.index.clone(), ctx.name().to_string()))
+            .into_iter()
+            .flatten();
+        let pinned = self.select(ctx.name(), entries, shared)?;
+        let path = pkg_cache_dir(&self.index, ctx.name(), &pinned.version);
+        Ok((pinned, path))
     }
 }
 
 impl source::Fetch for Pinned {
-    fn fetch(&self, _ctx: source::PinCtx, _local: &Path) -> anyhow::Result<PackageManifestFile> {
-        bail!("registry dependencies are not yet supported");
+    fn fetch(&self, ctx: source::PinCtx, repo_path: &Path) -> Result<PackageManifestFile> {
+        let mut lock = forc_util::path_lock(repo_path)?;
+        {
+            let _guard = lock.write()?;
+            if !repo_path.exists() {
+                if ctx.offline() && !is_local(&self.source.index) {
+                    bail!(
+                        "Unable to fetch pkg {:?} from registry {} in offline mode",
+                        ctx.name(),
+                        self.source.index
+                    );
+                }
+                info!(
+                    "  {} {} {}",
+                    ansi_term::Color::Green.bold().paint("Fetching"),
+                    ansi_term::Style::new().bold().paint(ctx.name),
+                    self
+                );
+                let path = archive_path(ctx.name(), &self.version);
+                let bytes = read_registry_file(&self.source.index, &path)?;
+                if let Err(e) = self.unpack(&bytes, repo_path) {
+                    let _ = fs::remove_dir_all(repo_path);
+                    return Err(e);
+                }
+            }
+        }
+        let path = {
+            let _guard = lock.read()?;
+            manifest::find_within(repo_path, ctx.name())
+                .ok_or_else(|| anyhow!("failed to find package `{}` in {}", ctx.name(), self))?
+        };
+        PackageManifestFile::from_file(path)
     }
 }
 
 impl source::DepPath for Pinned {
-    fn dep_path(&self, _name: &str) -> anyhow::Result<source::DependencyPath> {
-        bail!("registry dependencies are not yet supported");
+    fn dep_path(&self, name: &str) -> Result<source::DependencyPath> {
+        let repo_path = pkg_cache_dir(&self.source.index, name, &self.version);
+        // Co-ordinate access to the registry cache directory using an advisory file lock.
+        let lock = forc_util::path_lock(&repo_path)?;
+        let _guard = lock.read()?;
+        let path = manifest::find_within(&repo_path, name)
+            .ok_or_else(|| anyhow!("failed to find package `{}` in {}", name, self))?;
+        Ok(source::DependencyPath::ManifestPath(path))
+    }
+}
+
+impl Pinned {
+    /// Whether this pinned package may be used for a dependency on the given source, i.e. it is
+    /// from the same registry and its version satisfies the requirement.
+    pub fn satisfies(&self, source: &Source) -> bool {
+        self.source.index == source.index && source.version.matches(&self.version)
+    }
+
+    /// Verify the checksum of the given package archive and unpack it to `dst`.
+    fn unpack(&self, archive: &[u8], dst: &Path) -> Result<()> {
+        let checksum = checksum(archive);
+        if checksum != self.checksum {
+            bail!(
+                "checksum mismatch for {}: expected {}, found {}",
+                self,
+                self.checksum,
+                checksum
+            );
+        }
+        Archive::new(archive).unpack(dst)?;
+        Ok(())
     }
 }
 
@@ -43,3 +260,293 @@ impl From<Pinned> for source::Pinned {
         Self::Registry(p)
     }
 }
+
+impl Ord for Source {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index
+            .cmp(&other.index)
+            .then_with(|| self.version.to_string().cmp(&other.version.to_string()))
+    }
+}
+
+impl PartialOrd for Source {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Pinned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // registry+<url/to/registry>?version=<requirement>#<version>:<checksum>
+        // Lock file entries are whitespace separated, so the requirement is written without spaces.
+        let requirement = self.source.version.to_string().replace(' ', "");
+        write!(
+            f,
+            "{}+{}?version={}#{}:{}",
+            Self::PREFIX,
+            self.source.index,
+            requirement,
+            self.version,
+            self.checksum
+        )
+    }
+}
+
+impl FromStr for Pinned {
+    type Err = PinnedParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // registry+<url/to/registry>?version=<requirement>#<version>:<checksum>
+        let s = s.trim();
+
+        // Check for "registry+" at the start.
+        let prefix_plus = format!("{}+", Self::PREFIX);
+        if s.find(&prefix_plus) != Some(0) {
+            return Err(PinnedParseError::Prefix);
+        }
+        let s = &s[prefix_plus.len()..];
+
+        // Parse the pinned version and checksum from the end.
+        let (s, pinned) = s.rsplit_once('#').ok_or(PinnedParseError::Version)?;
+        let (version, checksum) = pinned.split_once(':').ok_or(PinnedParseError::Checksum)?;
+        let version = semver::Version::parse(version).map_err(|_| PinnedParseError::Version)?;
+        validate_checksum(checksum).map_err(|_| PinnedParseError::Checksum)?;
+
+        // Then the registry URL and the version requirement.
+        let (index, requirement) = s
+            .rsplit_once("?version=")
+            .ok_or(PinnedParseError::VersionReq)?;
+        let index = Url::parse(index).map_err(|_| PinnedParseError::Url)?;
+        let requirement =
+            semver::VersionReq::parse(requirement).map_err(|_| PinnedParseError::VersionReq)?;
+
+        let source = Source {
+            index,
+            version: requirement,
+        };
+        Ok(Self {
+            source,
+            version,
+            checksum: checksum.to_string(),
+        })
+    }
+}
+
+/// Parse the entries of an index file, one JSON `IndexEntry` per line.
+pub fn parse_index_entries(bytes: &[u8]) -> Result<Vec<IndexEntry>> {
+    std::str::from_utf8(bytes)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).with_context(|| format!("invalid index entry: {line}"))
+        })
+        .collect()
+}
+
+/// The path of the index file of the package with the given name, relative to the registry.
+pub fn index_entry_path(name: &str) -> String {
+    format!("{INDEX_DIR_NAME}/{name}")
+}
+
+/// The path of the archive of the given package version, relative to the registry.
+pub fn archive_path(name: &str, version: &semver::Version) -> String {
+    format!("{PACKAGES_DIR_NAME}/{name}/{name}-{version}.tar")
+}
+
+/// The hex encoded SHA-256 checksum of a package archive.
+pub fn checksum(archive: &[u8]) -> String {
+    fuel_crypto::Hasher::hash(archive).to_string()
+}
+
+fn validate_checksum(checksum: &str) -> Result<()> {
+    if checksum.len() != 64 || !checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+        bail!("invalid checksum {checksum:?}");
+    }
+    Ok(())
+}
+
+/// Whether the registry is on the local file system, and can therefore be used offline.
+fn is_local(index: &Url) -> bool {
+    index.scheme() == "file"
+}
+
+/// Read the file at the given path relative to the registry.
+fn read_registry_file(index: &Url, path: &str) -> Result<Vec<u8>> {
+    try_read_registry_file(index, path)?
+        .ok_or_else(|| anyhow!("{path} not found in registry {index}"))
+}
+
+/// Read the file at the given path relative to the registry, or `None` if there is no such file.
+pub(crate) fn try_read_registry_file(index: &Url, path: &str) -> Result<Option<Vec<u8>>> {
+    let url = index.join(path)?;
+    match url.scheme() {
+        "file" => {
+            let path = registry_file_path(&url)?;
+            match fs::read(&path) {
+                Ok(bytes) => Ok(Some(bytes)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(e).with_context(|| format!("failed to read {}", path.display())),
+            }
+        }
+        "http" | "https" => futures::executor::block_on(async {
+            let res = reqwest::get(url.clone()).await?;
+            if res.status() == reqwest::StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
+            if !res.status().is_success() {
+                bail!("Failed to fetch from {url}: {}", res.status());
+            }
+            Ok(Some(res.bytes().await?.to_vec()))
+        }),
+        scheme => bail!("unsupported registry URL scheme {scheme:?} in {index}"),
+    }
+}
+
+/// Write the file at the given path relative to the registry.
+///
+/// Files are uploaded to remote registries with an HTTP `PUT` request, authorized by the given
+/// bearer token if any.
+pub(crate) fn write_registry_file(
+    index: &Url,
+    path: &str,
+    bytes: &[u8],
+    token: Option<&str>,
+) -> Result<()> {
+    let url = index.join(path)?;
+    match url.scheme() {
+        "file" => {
+            let path = registry_file_path(&url)?;
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&path, bytes).with_context(|| format!("failed to write {}", path.display()))
+        }
+        "http" | "https" => futures::executor::block_on(async {
+            let mut req = reqwest::Client::new().put(url.clone()).body(bytes.to_vec());
+            if let Some(token) = token {
+                req = req.bearer_auth(token);
+            }
+            let res = req.send().await?;
+            if !res.status().is_success() {
+                bail!("Failed to upload to {url}: {}", res.status());
+            }
+            Ok(())
+        }),
+        scheme => bail!("unsupported registry URL scheme {scheme:?} in {index}"),
+    }
+}
+
+fn registry_file_path(url: &Url) -> Result<PathBuf> {
+    url.to_file_path()
+        .map_err(|_| anyhow!("invalid registry file URL {url}"))
+}
+
+fn cache_dir() -> PathBuf {
+    forc_util::user_forc_directory()
+        .join(REGISTRY_DIR_NAME)
+        .join(REGISTRY_CACHE_DIR_NAME)
+}
+
+/// The cache directory of the given package version, distinguished by registry.
+fn pkg_cache_dir(index: &Url, name: &str, version: &semver::Version) -> PathBuf {
+    let mut hasher = hash_map::DefaultHasher::new();
+    index.hash(&mut hasher);
+    let index_hash = hasher.finish();
+    cache_dir()
+        .join(format!("{index_hash:x}"))
+        .join(format!("{name}-{version}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(version: &str, yanked: bool) -> IndexEntry {
+        IndexEntry {
+            name: "foo".to_string(),
+            version: semver::Version::parse(version).unwrap(),
+            checksum: checksum(version.as_bytes()),
+            yanked,
+        }
+    }
+
+    fn source(requirement: &str) -> Source {
+        Source {
+            index: Url::parse("file:///tmp/registry/").unwrap(),
+            version: semver::VersionReq::parse(requirement).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_source_reg_pinned_parsing() {
+        let pinned = Pinned {
+            source: source(">=1.2, <1.4"),
+            version: semver::Version::new(1, 3, 0),
+            checksum: checksum(b"foo"),
+        };
+        let string = pinned.to_string();
+        assert_eq!(
+            string,
+            format!(
+                "registry+file:///tmp/registry/?version=>=1.2,<1.4#1.3.0:{}",
+                pinned.checksum
+            )
+        );
+        assert!(!string.contains(char::is_whitespace));
+        let parsed = Pinned::from_str(&string).unwrap();
+        assert_eq!(parsed, pinned);
+        assert!(Pinned::from_str("registry+file:///tmp/registry/?version=^1#1.0.0:00").is_err());
+    }
+
+    #[test]
+    fn test_source_reg_select() {
+        let entries = || {
+            vec![
+                entry("1.0.0", false),
+                entry("1.2.0", false),
+                entry("1.3.0", true),
+                entry("2.0.0", false),
+            ]
+        };
+        let pinned = source("^1").select("foo", entries(), []).unwrap();
+        assert_eq!(pinned.version, semver::Version::new(1, 2, 0));
+        assert_eq!(pinned.checksum, checksum(b"1.2.0"));
+        let pinned = source("*").select("foo", entries(), []).unwrap();
+        assert_eq!(pinned.version, semver::Version::new(2, 0, 0));
+        assert!(source("^1.3").select("foo", entries(), []).is_err());
+        assert!(source("^1").select("bar", entries(), []).is_err());
+
+        // The requirements of other dependents are only honoured when they can be.
+        let shared = [semver::VersionReq::parse("<1.2").unwrap()];
+        let pinned = source("*").select("foo", entries(), &shared).unwrap();
+        assert_eq!(pinned.version, semver::Version::new(1, 0, 0));
+        let shared = [semver::VersionReq::parse("^3").unwrap()];
+        let pinned = source("*").select("foo", entries(), &shared).unwrap();
+        assert_eq!(pinned.version, semver::Version::new(2, 0, 0));
+    }
+
+    #[test]
+    fn test_source_reg_unpack() {
+        let mut builder = tar::Builder::new(vec![]);
+        let manifest = b"[project]\nname = \"foo\"\n";
+        let mut header = tar::Header::new_gnu();
+        header.set_size(manifest.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, "foo/Forc.toml", &manifest[..])
+            .unwrap();
+        let archive = builder.into_inner().unwrap();
+
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let dst = tmp_dir.path().join("unpacked");
+        let mut pinned = source("^1")
+            .select("foo", vec![entry("1.0.0", false)], [])
+            .unwrap();
+        assert!(pinned.unpack(&archive, &dst).is_err());
+        assert!(!dst.exists());
+
+        pinned.checksum = checksum(&archive);
+        pinned.unpack(&archive, &dst).unwrap();
+        assert_eq!(fs::read(dst.join("foo/Forc.toml")).unwrap(), manifest);
+    }
+}
@@ -0,0 +1,5 @@
+library;
+
+pub fn answer() -> u64 {
+    42
+}
@@ -105,6 +105,19 @@ pub(crate) fn render_type_anchor(
                 : uint;
             })
         }
+        TypeInfo::SignedInteger(int_bits) => {
+            use sway_types::integer_bits::IntegerBits;
+            let int = match int_bits {
+                IntegerBits::Eight => "i8",
+                IntegerBits::Sixteen => "i16",
+                IntegerBits::ThirtyTwo => "i32",
+                IntegerBits::SixtyFour => "i64",
+                IntegerBits::V256 => "i256",
+            };
+            Ok(box_html! {
+                : int;
+            })
+        }
         TypeInfo::Boolean => Ok(box_html! {
             : "bool";
         }),
@@ -127,6 +140,20 @@ pub(crate) fn render_type_anchor(
         TypeInfo::B256 => Ok(box_html! {
             : "b256";
         }),
+        TypeInfo::Ref {
+            to_mutable_value,
+            referenced_type,
+        } => {
+            let inner = render_type_anchor(
+                render_plan.engines.te().get(referenced_type.type_id),
+                render_plan,
+                current_module_info,
+            )?;
+            Ok(box_html! {
+                : if to_mutable_value { "&mut " } else { "&" };
+                : inner;
+            })
+        }
         _ => Err(anyhow!("Undetermined or unusable TypeInfo")),
     }
 }
@@ -0,0 +1,529 @@
+//! Sway types and values described by a program ABI, along with their memory representation.
+//!
+//! Types are resolved from the type declarations of an ABI into a [ParamType]. Values of a type are
+//! laid out in memory the same way the compiler lays out the corresponding IR type, i.e. how they
+//! are passed to test functions and how they are logged.
+
+use anyhow::{anyhow, bail, Result};
+use forc_pkg::program_abi;
+use std::{collections::HashMap, fmt};
+
+/// A Sway type, resolved from a program ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamType {
+    Unit,
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    U256,
+    B256,
+    StringArray(usize),
+    RawPtr,
+    Array(Box<ParamType>, usize),
+    Tuple(Vec<ParamType>),
+    Struct {
+        name: String,
+        fields: Vec<(String, ParamType)>,
+    },
+    Enum {
+        name: String,
+        variants: Vec<(String, ParamType)>,
+    },
+}
+
+/// A value of a Sway type.
+///
+/// Big integers and hashes are represented by their big-endian bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Unit,
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    U256([u8; 32]),
+    B256([u8; 32]),
+    StringArray(String),
+    RawPtr(u64),
+    Array(Vec<Value>),
+    Tuple(Vec<Value>),
+    Struct(Vec<Value>),
+    Enum(usize, Box<Value>),
+}
+
+impl ParamType {
+    /// Resolve the type of the given type application, substituting the generic type parameters
+    /// in scope with their arguments.
+    pub(crate) fn resolve(
+        application: &program_abi::TypeApplication,
+        types: &HashMap<usize, &program_abi::TypeDeclaration>,
+        generics: &HashMap<usize, ParamType>,
+    ) -> Result<Self> {
+        let type_decl = types
+            .get(&application.type_id)
+            .ok_or_else(|| anyhow!("missing ABI type declaration {}", application.type_id))?;
+        let type_field = type_decl.type_field.as_str();
+        if type_field.starts_with("generic ") {
+            return generics
+                .get(&type_decl.type_id)
+                .cloned()
+                .ok_or_else(|| anyhow!("unresolved type parameter `{type_field}`"));
+        }
+
+        // Bind the type parameters of the declaration to the arguments of this application.
+        let type_arguments = application
+            .type_arguments
+            .iter()
+            .flatten()
+            .map(|argument| Self::resolve(argument, types, generics))
+            .collect::<Result<Vec<_>>>()?;
+        let generics = type_decl
+            .type_parameters
+            .iter()
+            .flatten()
+            .copied()
+            .zip(type_arguments)
+            .collect();
+        let components = || {
+            type_decl
+                .components
+                .iter()
+                .flatten()
+                .map(|component| {
+                    Ok((
+                        component.name.clone(),
+                        Self::resolve(component, types, &generics)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+
+        let param_type = match type_field {
+            "()" => ParamType::Unit,
+            "bool" => ParamType::Bool,
+            "u8" => ParamType::U8,
+            "u16" => ParamType::U16,
+            "u32" => ParamType::U32,
+            "u64" => ParamType::U64,
+            "i8" => ParamType::I8,
+            "i16" => ParamType::I16,
+            "i32" => ParamType::I32,
+            "i64" => ParamType::I64,
+            "u256" => ParamType::U256,
+            "b256" => ParamType::B256,
+            "raw untyped ptr" => ParamType::RawPtr,
+            _ if type_field.starts_with("str[") => {
+                ParamType::StringArray(parse_length(type_field)?)
+            }
+            _ if type_field.starts_with('[') => {
+                let (_, elem_type) = components()?
+                    .pop()
+                    .ok_or_else(|| anyhow!("array type `{type_field}` has no element type"))?;
+                ParamType::Array(Box::new(elem_type), parse_length(type_field)?)
+            }
+            _ if type_field.starts_with('(') => {
+                ParamType::Tuple(components()?.into_iter().map(|(_, ty)| ty).collect())
+            }
+            _ if type_field.starts_with("struct ") => ParamType::Struct {
+                name: type_field["struct ".len()..].to_string(),
+                fields: components()?,
+            },
+            _ if type_field.starts_with("enum ") => {
+                let variants = components()?;
+                if variants.is_empty() {
+                    bail!("enum type `{type_field}` has no variants");
+                }
+                ParamType::Enum {
+                    name: type_field["enum ".len()..].to_string(),
+                    variants,
+                }
+            }
+            _ => bail!("type `{type_field}` is not supported"),
+        };
+        Ok(param_type)
+    }
+
+    /// Whether values of this type are held in a register rather than in memory.
+    pub(crate) fn is_copy_type(&self) -> bool {
+        matches!(
+            self,
+            ParamType::Unit
+                | ParamType::Bool
+                | ParamType::U8
+                | ParamType::U16
+                | ParamType::U32
+                | ParamType::U64
+                | ParamType::I8
+                | ParamType::I16
+                | ParamType::I32
+                | ParamType::I64
+                | ParamType::RawPtr
+        )
+    }
+
+    /// The size of values of this type in memory, mirroring the layout of the IR types.
+    pub(crate) fn size_in_bytes(&self) -> usize {
+        match self {
+            ParamType::Unit | ParamType::Bool | ParamType::U8 => 1,
+            // Signed integers of all widths are sign extended to a word.
+            ParamType::U16
+            | ParamType::U32
+            | ParamType::U64
+            | ParamType::I8
+            | ParamType::I16
+            | ParamType::I32
+            | ParamType::I64
+            | ParamType::RawPtr => 8,
+            ParamType::U256 | ParamType::B256 => 32,
+            ParamType::StringArray(len) => round_up_to_word(*len),
+            ParamType::Array(elem_type, len) => elem_type.size_in_bytes() * len,
+            ParamType::Tuple(fields) => fields
+                .iter()
+                .map(|field| round_up_to_word(field.size_in_bytes()))
+                .sum(),
+            ParamType::Struct { fields, .. } => fields
+                .iter()
+                .map(|(_, field)| round_up_to_word(field.size_in_bytes()))
+                .sum(),
+            ParamType::Enum { .. } => 8 + self.union_size_in_bytes(),
+        }
+    }
+
+    /// The size of the union holding the variant values of an enum, or zero if only the tag is
+    /// stored because all the variants are unit.
+    fn union_size_in_bytes(&self) -> usize {
+        match self {
+            ParamType::Enum { variants, .. }
+                if variants.iter().any(|(_, ty)| *ty != Self::Unit) =>
+            {
+                variants
+                    .iter()
+                    .map(|(_, variant)| round_up_to_word(variant.size_in_bytes()))
+                    .max()
+                    .unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
+    /// Append the memory representation of `value` to `bytes`.
+    pub(crate) fn encode(&self, value: &Value, bytes: &mut Vec<u8>) {
+        let start = bytes.len();
+        match (self, value) {
+            (ParamType::Unit, Value::Unit) => bytes.push(0),
+            (ParamType::Bool, Value::Bool(b)) => bytes.push(u8::from(*b)),
+            (ParamType::U8, Value::U8(n)) => bytes.push(*n),
+            (ParamType::U16, Value::U16(n)) => bytes.extend(u64::from(*n).to_be_bytes()),
+            (ParamType::U32, Value::U32(n)) => bytes.extend(u64::from(*n).to_be_bytes()),
+            (ParamType::U64, Value::U64(n)) | (ParamType::RawPtr, Value::RawPtr(n)) => {
+                bytes.extend(n.to_be_bytes())
+            }
+            (ParamType::I8, Value::I8(n)) => bytes.extend(i64::from(*n).to_be_bytes()),
+            (ParamType::I16, Value::I16(n)) => bytes.extend(i64::from(*n).to_be_bytes()),
+            (ParamType::I32, Value::I32(n)) => bytes.extend(i64::from(*n).to_be_bytes()),
+            (ParamType::I64, Value::I64(n)) => bytes.extend(n.to_be_bytes()),
+            (ParamType::U256, Value::U256(word)) | (ParamType::B256, Value::B256(word)) => {
+                bytes.extend(word)
+            }
+            (ParamType::StringArray(_), Value::StringArray(s)) => bytes.extend(s.as_bytes()),
+            (ParamType::Array(elem_type, _), Value::Array(elems)) => {
+                for elem in elems {
+                    elem_type.encode(elem, bytes);
+                }
+            }
+            (ParamType::Tuple(field_types), Value::Tuple(fields)) => {
+                for (field_type, field) in field_types.iter().zip(fields) {
+                    field_type.encode(field, bytes);
+                    pad_to_word(bytes);
+                }
+            }
+            (
+                ParamType::Struct {
+                    fields: field_types,
+                    ..
+                },
+                Value::Struct(fields),
+            ) => {
+                for ((_, field_type), field) in field_types.iter().zip(fields) {
+                    field_type.encode(field, bytes);
+                    pad_to_word(bytes);
+                }
+            }
+            (ParamType::Enum { variants, .. }, Value::Enum(tag, variant)) => {
+                bytes.extend((*tag as u64).to_be_bytes());
+                let union_size = self.union_size_in_bytes();
+                if union_size > 0 {
+                    // Variants are right aligned within the union.
+                    let variant_type = &variants[*tag].1;
+                    let padding = union_size - variant_type.size_in_bytes();
+                    bytes.resize(bytes.len() + padding, 0);
+                    variant_type.encode(variant, bytes);
+                }
+            }
+            _ => unreachable!("value {value:?} does not have type {self:?}"),
+        }
+        bytes.resize(start + self.size_in_bytes(), 0);
+    }
+
+    /// Decode a value of this type from its memory representation.
+    pub(crate) fn decode(&self, bytes: &[u8]) -> Result<Value> {
+        let size = self.size_in_bytes();
+        let bytes = bytes
+            .get(..size)
+            .ok_or_else(|| anyhow!("expected {size} bytes, found {}", bytes.len()))?;
+        let word = || u64::from_be_bytes(bytes[..8].try_into().expect("word sized"));
+        let value = match self {
+            ParamType::Unit => Value::Unit,
+            ParamType::Bool => Value::Bool(bytes[0] != 0),
+            ParamType::U8 => Value::U8(bytes[0]),
+            ParamType::U16 => Value::U16(word() as u16),
+            ParamType::U32 => Value::U32(word() as u32),
+            ParamType::U64 => Value::U64(word()),
+            ParamType::I8 => Value::I8(word() as i8),
+            ParamType::I16 => Value::I16(word() as i16),
+            ParamType::I32 => Value::I32(word() as i32),
+            ParamType::I64 => Value::I64(word() as i64),
+            ParamType::RawPtr => Value::RawPtr(word()),
+            ParamType::U256 => Value::U256(bytes.try_into().expect("word sized")),
+            ParamType::B256 => Value::B256(bytes.try_into().expect("word sized")),
+            ParamType::StringArray(len) => {
+                Value::StringArray(String::from_utf8_lossy(&bytes[..*len]).into_owned())
+            }
+            ParamType::Array(elem_type, len) => {
+                let elem_size = elem_type.size_in_bytes();
+                Value::Array(
+                    (0..*len)
+                        .map(|ix| elem_type.decode(&bytes[ix * elem_size..]))
+                        .collect::<Result<_>>()?,
+                )
+            }
+            ParamType::Tuple(field_types) => {
+                Value::Tuple(decode_fields(field_types.iter(), bytes)?)
+            }
+            ParamType::Struct { fields, .. } => {
+                Value::Struct(decode_fields(fields.iter().map(|(_, ty)| ty), bytes)?)
+            }
+            ParamType::Enum { variants, .. } => {
+                let tag = word() as usize;
+                let (_, variant_type) = variants
+                    .get(tag)
+                    .ok_or_else(|| anyhow!("invalid enum tag {tag}"))?;
+                let variant = match self.union_size_in_bytes() {
+                    0 => Value::Unit,
+                    union_size => {
+                        // Variants are right aligned within the union.
+                        let offset = 8 + union_size - variant_type.size_in_bytes();
+                        variant_type.decode(&bytes[offset..])?
+                    }
+                };
+                Value::Enum(tag, Box::new(variant))
+            }
+        };
+        Ok(value)
+    }
+
+    /// Decode a value of this type held in a register.
+    pub(crate) fn decode_register(&self, word: u64) -> Result<Value> {
+        let value = match self {
+            ParamType::Unit => Value::Unit,
+            ParamType::Bool => Value::Bool(word != 0),
+            ParamType::U8 => Value::U8(word as u8),
+            ParamType::U16 => Value::U16(word as u16),
+            ParamType::U32 => Value::U32(word as u32),
+            ParamType::U64 => Value::U64(word),
+            ParamType::I8 => Value::I8(word as i8),
+            ParamType::I16 => Value::I16(word as i16),
+            ParamType::I32 => Value::I32(word as i32),
+            ParamType::I64 => Value::I64(word as i64),
+            ParamType::RawPtr => Value::RawPtr(word),
+            _ => bail!("values of type {self:?} are not held in registers"),
+        };
+        Ok(value)
+    }
+
+    /// Write `value` as it would be written in Sway.
+    pub(crate) fn fmt_value(&self, value: &Value, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self, value) {
+            (_, Value::Unit) => write!(f, "()"),
+            (_, Value::Bool(b)) => write!(f, "{b}"),
+            (_, Value::U8(n)) => write!(f, "{n}"),
+            (_, Value::U16(n)) => write!(f, "{n}"),
+            (_, Value::U32(n)) => write!(f, "{n}"),
+            (_, Value::U64(n)) => write!(f, "{n}"),
+            (_, Value::I8(n)) => write!(f, "{n}"),
+            (_, Value::I16(n)) => write!(f, "{n}"),
+            (_, Value::I32(n)) => write!(f, "{n}"),
+            (_, Value::I64(n)) => write!(f, "{n}"),
+            (_, Value::RawPtr(ptr)) => write!(f, "{ptr:#x}"),
+            (_, Value::U256(word)) => write!(f, "0x{}u256", hex_string(word)),
+            (_, Value::B256(word)) => write!(f, "0x{}", hex_string(word)),
+            (_, Value::StringArray(s)) => write!(f, "{s:?}"),
+            (ParamType::Array(elem_type, _), Value::Array(elems)) => {
+                write!(f, "[")?;
+                for (ix, elem) in elems.iter().enumerate() {
+                    if ix > 0 {
+                        write!(f, ", ")?;
+                    }
+                    elem_type.fmt_value(elem, f)?;
+                }
+                write!(f, "]")
+            }
+            (ParamType::Tuple(field_types), Value::Tuple(fields)) => {
+                write!(f, "(")?;
+                for (ix, (field_type, field)) in field_types.iter().zip(fields).enumerate() {
+                    if ix > 0 {
+                        write!(f, ", ")?;
+                    }
+                    field_type.fmt_value(field, f)?;
+                }
+                if fields.len() == 1 {
+                    write!(f, ",")?;
+                }
+                write!(f, ")")
+            }
+            (
+                ParamType::Struct {
+                    name,
+                    fields: field_types,
+                },
+                Value::Struct(fields),
+            ) => {
+                write!(f, "{name} {{")?;
+                for (ix, ((field_name, field_type), field)) in
+                    field_types.iter().zip(fields).enumerate()
+                {
+                    let separator = if ix > 0 { "," } else { "" };
+                    write!(f, "{separator} {field_name}: ")?;
+                    field_type.fmt_value(field, f)?;
+                }
+                write!(f, " }}")
+            }
+            (ParamType::Enum { name, variants }, Value::Enum(tag, variant)) => {
+                let (variant_name, variant_type) = &variants[*tag];
+                write!(f, "{name}::{variant_name}")?;
+                if *variant_type != ParamType::Unit {
+                    write!(f, "(")?;
+                    variant_type.fmt_value(variant, f)?;
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+            _ => write!(f, "{value:?}"),
+        }
+    }
+}
+
+pub(crate) fn round_up_to_word(size: usize) -> usize {
+    (size + 7) & !7
+}
+
+pub(crate) fn pad_to_word(bytes: &mut Vec<u8>) {
+    bytes.resize(round_up_to_word(bytes.len()), 0);
+}
+
+/// Parse the length `N` out of `str[N]` or `[T; N]`.
+fn parse_length(type_field: &str) -> Result<usize> {
+    type_field
+        .trim_end_matches(']')
+        .rsplit(['[', ';'])
+        .next()
+        .and_then(|len| len.trim().parse().ok())
+        .ok_or_else(|| anyhow!("invalid length in type `{type_field}`"))
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Decode a sequence of values laid out as the fields of a struct.
+fn decode_fields<'a>(
+    field_types: impl Iterator<Item = &'a ParamType>,
+    bytes: &[u8],
+) -> Result<Vec<Value>> {
+    let mut offset = 0;
+    field_types
+        .map(|field_type| {
+            let field = field_type.decode(&bytes[offset..])?;
+            offset += round_up_to_word(field_type.size_in_bytes());
+            Ok(field)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::DecodedLog;
+
+    #[test]
+    fn decode_roundtrip() {
+        let point = ParamType::Struct {
+            name: "Point".to_string(),
+            fields: vec![
+                ("x".to_string(), ParamType::U8),
+                ("y".to_string(), ParamType::StringArray(3)),
+            ],
+        };
+        let shape = ParamType::Enum {
+            name: "Shape".to_string(),
+            variants: vec![
+                ("Empty".to_string(), ParamType::Unit),
+                ("Square".to_string(), point.clone()),
+                ("Line".to_string(), ParamType::U64),
+            ],
+        };
+        let param_type =
+            ParamType::Tuple(vec![shape, ParamType::Array(Box::new(ParamType::Bool), 2)]);
+        let value = Value::Tuple(vec![
+            Value::Enum(
+                1,
+                Box::new(Value::Struct(vec![
+                    Value::U8(7),
+                    Value::StringArray("abc".to_string()),
+                ])),
+            ),
+            Value::Array(vec![Value::Bool(true), Value::Bool(false)]),
+        ]);
+
+        let mut bytes = vec![];
+        param_type.encode(&value, &mut bytes);
+        assert_eq!(bytes.len(), param_type.size_in_bytes());
+        assert_eq!(param_type.decode(&bytes).unwrap(), value);
+        assert!(param_type.decode(&bytes[1..]).is_err());
+
+        let decoded = DecodedLog { param_type, value };
+        assert_eq!(
+            decoded.to_string(),
+            r#"(Shape::Square(Point { x: 7, y: "abc" }), [true, false])"#
+        );
+    }
+
+    #[test]
+    fn signed_integers() {
+        let param_type = ParamType::Tuple(vec![ParamType::I8, ParamType::I64]);
+        let value = Value::Tuple(vec![Value::I8(-1), Value::I64(i64::MIN)]);
+
+        let mut bytes = vec![];
+        param_type.encode(&value, &mut bytes);
+        assert_eq!(bytes[..8], [0xFF; 8]);
+        assert_eq!(param_type.decode(&bytes).unwrap(), value);
+        assert_eq!(
+            ParamType::I16.decode_register(-300i64 as u64).unwrap(),
+            Value::I16(-300)
+        );
+
+        let decoded = DecodedLog { param_type, value };
+        assert_eq!(decoded.to_string(), "(-1, -9223372036854775808)");
+    }
+}
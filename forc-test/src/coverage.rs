@@ -0,0 +1,555 @@
+//! Line and branch coverage for executed tests.
+//!
+//! The VM records the location of every instruction executed by a test. These locations are folded
+//! through the source maps of the bytecode they belong to, i.e. the test script itself or one of
+//! the contracts deployed for the test. A source line is instrumented when at least one instruction
+//! maps to it and is hit by a test when at least one of those instructions was executed.
+//!
+//! Branches are the conditional jumps with a statically known target. As the VM only records
+//! which instructions were executed, an outcome of a branch is considered taken by a test when both
+//! the jump and the first instruction of that outcome were executed by the test.
+
+use crate::{deployed_contract_id, TestedPackage};
+use fuel_tx as tx;
+use fuel_vm::fuel_asm::{Instruction, RegId};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use sway_core::source_map::SourceMap;
+
+/// The line and branch coverage of a set of source files.
+#[derive(Debug, Default, Clone)]
+pub struct Coverage {
+    pub files: BTreeMap<PathBuf, FileCoverage>,
+}
+
+/// The line and branch coverage of a single source file.
+#[derive(Debug, Default, Clone)]
+pub struct FileCoverage {
+    /// The number of tests that hit each instrumented line, keyed by 1-based line number.
+    pub lines: BTreeMap<usize, u64>,
+    /// The branches starting on each line, keyed by 1-based line number and then by the byte
+    /// range of the branching expression.
+    pub branches: BTreeMap<usize, BTreeMap<(usize, usize), BranchCoverage>>,
+}
+
+/// The number of tests that took each outcome of a single branch.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BranchCoverage {
+    /// The number of tests that performed the jump.
+    pub taken: u64,
+    /// The number of tests that fell through to the next instruction.
+    pub not_taken: u64,
+}
+
+/// Totals of instrumented and hit lines and branch outcomes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CoverageSummary {
+    pub lines_found: usize,
+    pub lines_hit: usize,
+    pub branches_found: usize,
+    pub branches_hit: usize,
+}
+
+/// A bytecode whose instructions may be executed by a test.
+pub(crate) struct InstrumentedProgram<'a> {
+    /// `None` for the test script, otherwise the id of the deployed contract.
+    pub(crate) context: Option<tx::ContractId>,
+    pub(crate) bytes: &'a [u8],
+    pub(crate) source_map: &'a SourceMap,
+}
+
+/// The source location of a single instruction.
+struct MappedInstruction {
+    path: Arc<PathBuf>,
+    line: usize,
+    span: (usize, usize),
+}
+
+/// A conditional jump within a program.
+struct BranchSite {
+    pc: usize,
+    /// The instruction executed when the jump is performed, followed by the one executed when it
+    /// is not.
+    targets: [usize; 2],
+}
+
+/// An instrumented program with all of its instructions resolved to source lines.
+struct ResolvedProgram {
+    context: Option<tx::ContractId>,
+    instructions: HashMap<usize, MappedInstruction>,
+    branches: Vec<BranchSite>,
+}
+
+/// Lazily loaded line offsets of source files.
+#[derive(Default)]
+struct SourceLines {
+    line_starts: HashMap<PathBuf, Option<Vec<usize>>>,
+}
+
+impl Coverage {
+    /// Collect the coverage of all tests executed for the given package.
+    ///
+    /// Source files that can no longer be read are left out of the result.
+    pub fn from_tested_pkg(tested_pkg: &TestedPackage) -> Coverage {
+        let programs = instrumented_programs(tested_pkg);
+        let mut source_lines = SourceLines::default();
+        let resolved: Vec<ResolvedProgram> = programs
+            .iter()
+            .map(|program| program.resolve(&mut source_lines))
+            .collect();
+
+        // Every instrumented line and branch is reported, including those no test hits.
+        let mut coverage = Coverage::default();
+        for program in &resolved {
+            for mapped in program.instructions.values() {
+                coverage
+                    .file_mut(&mapped.path)
+                    .lines
+                    .entry(mapped.line)
+                    .or_default();
+            }
+            for branch in &program.branches {
+                let mapped = &program.instructions[&branch.pc];
+                coverage
+                    .file_mut(&mapped.path)
+                    .branches
+                    .entry(mapped.line)
+                    .or_default()
+                    .entry(mapped.span)
+                    .or_default();
+            }
+        }
+
+        for test in &tested_pkg.tests {
+            let executed: HashSet<(Option<tx::ContractId>, usize)> = test
+                .executed_instructions
+                .iter()
+                .map(|location| {
+                    let pc = location.offset() as usize / Instruction::SIZE;
+                    (location.context(), pc)
+                })
+                .collect();
+
+            // Collect into sets first so that each test counts at most once per line or outcome,
+            // regardless of how many instructions or programs it hit.
+            let mut lines_hit = HashSet::new();
+            let mut outcomes_hit = HashSet::new();
+            for program in &resolved {
+                let is_executed = |pc| executed.contains(&(program.context, pc));
+                for (pc, mapped) in &program.instructions {
+                    if is_executed(*pc) {
+                        lines_hit.insert((mapped.path.clone(), mapped.line));
+                    }
+                }
+                for branch in program.branches.iter().filter(|b| is_executed(b.pc)) {
+                    let mapped = &program.instructions[&branch.pc];
+                    for (outcome, target) in branch.targets.iter().enumerate() {
+                        if is_executed(*target) {
+                            let key = (mapped.path.clone(), mapped.line, mapped.span);
+                            outcomes_hit.insert((key, outcome == 0));
+                        }
+                    }
+                }
+            }
+
+            for (path, line) in lines_hit {
+                *coverage.file_mut(&path).lines.entry(line).or_default() += 1;
+            }
+            for ((path, line, span), taken) in outcomes_hit {
+                let branch = coverage
+                    .file_mut(&path)
+                    .branches
+                    .entry(line)
+                    .or_default()
+                    .entry(span)
+                    .or_default();
+                match taken {
+                    true => branch.taken += 1,
+                    false => branch.not_taken += 1,
+                }
+            }
+        }
+
+        coverage
+    }
+
+    /// Add the coverage of `other` into this one, summing the number of hits.
+    pub fn merge(&mut self, other: Coverage) {
+        for (path, other_file) in other.files {
+            let file = self.file_mut(&path);
+            for (line, hits) in other_file.lines {
+                *file.lines.entry(line).or_default() += hits;
+            }
+            for (line, other_branches) in other_file.branches {
+                let branches = file.branches.entry(line).or_default();
+                for (span, other_branch) in other_branches {
+                    let branch = branches.entry(span).or_default();
+                    branch.taken += other_branch.taken;
+                    branch.not_taken += other_branch.not_taken;
+                }
+            }
+        }
+    }
+
+    /// Only retain the source files located within the given directory.
+    pub fn retain_files_within(&mut self, dir: &Path) {
+        self.files.retain(|path, _| path.starts_with(dir));
+    }
+
+    /// The totals over all source files.
+    pub fn summary(&self) -> CoverageSummary {
+        self.files.values().map(FileCoverage::summary).fold(
+            CoverageSummary::default(),
+            |acc, file| CoverageSummary {
+                lines_found: acc.lines_found + file.lines_found,
+                lines_hit: acc.lines_hit + file.lines_hit,
+                branches_found: acc.branches_found + file.branches_found,
+                branches_hit: acc.branches_hit + file.branches_hit,
+            },
+        )
+    }
+
+    /// Render this coverage as an lcov tracefile.
+    pub fn to_lcov(&self) -> String {
+        let mut lcov = String::new();
+        for (path, file) in &self.files {
+            let summary = file.summary();
+            writeln!(lcov, "TN:").unwrap();
+            writeln!(lcov, "SF:{}", path.display()).unwrap();
+            for (line, branches) in &file.branches {
+                for (block, branch) in branches.values().enumerate() {
+                    writeln!(lcov, "BRDA:{line},{block},0,{}", branch.taken).unwrap();
+                    writeln!(lcov, "BRDA:{line},{block},1,{}", branch.not_taken).unwrap();
+                }
+            }
+            writeln!(lcov, "BRF:{}", summary.branches_found).unwrap();
+            writeln!(lcov, "BRH:{}", summary.branches_hit).unwrap();
+            for (line, hits) in &file.lines {
+                writeln!(lcov, "DA:{line},{hits}").unwrap();
+            }
+            writeln!(lcov, "LF:{}", summary.lines_found).unwrap();
+            writeln!(lcov, "LH:{}", summary.lines_hit).unwrap();
+            writeln!(lcov, "end_of_record").unwrap();
+        }
+        lcov
+    }
+
+    /// Render this coverage as a standalone HTML page, listing the annotated source of every file.
+    ///
+    /// Source files are read again from disk, those that can no longer be read are only listed in
+    /// the summary table.
+    pub fn to_html(&self, title: &str) -> String {
+        let mut html = String::new();
+        let summary = self.summary();
+        writeln!(
+            html,
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>",
+            escape_html(title),
+            HTML_STYLE
+        )
+        .unwrap();
+        writeln!(html, "<h1>{}</h1>", escape_html(title)).unwrap();
+        writeln!(
+            html,
+            "<table>\n<tr><th>File</th><th>Lines</th><th>Branches</th></tr>"
+        )
+        .unwrap();
+        for (ix, (path, file)) in self.files.iter().enumerate() {
+            let file_summary = file.summary();
+            writeln!(
+                html,
+                "<tr><td><a href=\"#file-{ix}\">{}</a></td><td>{}</td><td>{}</td></tr>",
+                escape_html(&path.display().to_string()),
+                ratio(file_summary.lines_hit, file_summary.lines_found),
+                ratio(file_summary.branches_hit, file_summary.branches_found),
+            )
+            .unwrap();
+        }
+        writeln!(
+            html,
+            "<tr><th>Total</th><th>{}</th><th>{}</th></tr>\n</table>",
+            ratio(summary.lines_hit, summary.lines_found),
+            ratio(summary.branches_hit, summary.branches_found),
+        )
+        .unwrap();
+
+        for (ix, (path, file)) in self.files.iter().enumerate() {
+            let Ok(src) = fs::read_to_string(path) else {
+                continue;
+            };
+            writeln!(
+                html,
+                "<h2 id=\"file-{ix}\">{}</h2>\n<pre>",
+                escape_html(&path.display().to_string())
+            )
+            .unwrap();
+            for (line_ix, line_src) in src.lines().enumerate() {
+                let line = line_ix + 1;
+                let class = match file.lines.get(&line) {
+                    Some(0) => "miss",
+                    Some(_) => "hit",
+                    None => "none",
+                };
+                let hits = file
+                    .lines
+                    .get(&line)
+                    .map(|hits| hits.to_string())
+                    .unwrap_or_default();
+                let branches = file
+                    .branches
+                    .get(&line)
+                    .map(|branches| {
+                        branches
+                            .values()
+                            .map(|branch| {
+                                let mark = |count| if count > 0 { '+' } else { '-' };
+                                format!("[{}{}]", mark(branch.taken), mark(branch.not_taken))
+                            })
+                            .collect::<String>()
+                    })
+                    .unwrap_or_default();
+                writeln!(
+                    html,
+                    "<span class=\"{class}\"><span class=\"num\">{line:>5}</span><span class=\"hits\">{hits:>5}</span><span class=\"br\">{branches:<8}</span>{}</span>",
+                    escape_html(line_src)
+                )
+                .unwrap();
+            }
+            writeln!(html, "</pre>").unwrap();
+        }
+        writeln!(html, "</body>\n</html>").unwrap();
+        html
+    }
+
+    fn file_mut(&mut self, path: &Path) -> &mut FileCoverage {
+        self.files.entry(path.to_path_buf()).or_default()
+    }
+}
+
+impl FileCoverage {
+    /// The totals over this source file.
+    pub fn summary(&self) -> CoverageSummary {
+        let branches = self
+            .branches
+            .values()
+            .flat_map(|branches| branches.values());
+        CoverageSummary {
+            lines_found: self.lines.len(),
+            lines_hit: self.lines.values().filter(|hits| **hits > 0).count(),
+            branches_found: branches.clone().count() * 2,
+            branches_hit: branches
+                .map(|branch| usize::from(branch.taken > 0) + usize::from(branch.not_taken > 0))
+                .sum(),
+        }
+    }
+}
+
+impl<'a> InstrumentedProgram<'a> {
+    /// Map every instruction of this program to its source line and find its branches.
+    fn resolve(&self, source_lines: &mut SourceLines) -> ResolvedProgram {
+        let mut paths: HashMap<PathBuf, Arc<PathBuf>> = HashMap::new();
+        let instructions: HashMap<usize, MappedInstruction> = self
+            .source_map
+            .iter()
+            .filter_map(|(pc, (path, range))| {
+                let line = source_lines.line(&path, range.start)?;
+                let path = paths
+                    .entry(path)
+                    .or_insert_with_key(|path| Arc::new(path.clone()))
+                    .clone();
+                let span = (range.start, range.end);
+                Some((pc, MappedInstruction { path, line, span }))
+            })
+            .collect();
+        let branches = instructions
+            .keys()
+            .filter_map(|&pc| {
+                let start = pc * Instruction::SIZE;
+                let bytes = self.bytes.get(start..start + Instruction::SIZE)?;
+                let instruction = Instruction::try_from(<[u8; 4]>::try_from(bytes).ok()?).ok()?;
+                let taken = jump_target(pc, instruction)?;
+                Some(BranchSite {
+                    pc,
+                    targets: [taken, pc + 1],
+                })
+            })
+            .collect();
+        ResolvedProgram {
+            context: self.context,
+            instructions,
+            branches,
+        }
+    }
+}
+
+impl SourceLines {
+    /// The 1-based line number of the given byte offset within the file at `path`.
+    fn line(&mut self, path: &Path, offset: usize) -> Option<usize> {
+        let line_starts = self
+            .line_starts
+            .entry(path.to_path_buf())
+            .or_insert_with(|| {
+                let src = fs::read_to_string(path).ok()?;
+                let newlines = src.match_indices('\n').map(|(ix, _)| ix + 1);
+                Some(std::iter::once(0).chain(newlines).collect())
+            })
+            .as_ref()?;
+        Some(line_starts.partition_point(|&start| start <= offset))
+    }
+}
+
+/// The programs that may be executed by the tests of the given package.
+pub(crate) fn instrumented_programs(tested_pkg: &TestedPackage) -> Vec<InstrumentedProgram<'_>> {
+    let built = &tested_pkg.built;
+    let script = InstrumentedProgram {
+        context: None,
+        bytes: &built.bytecode.bytes,
+        source_map: built.source_map(),
+    };
+    // The contract under test is deployed without its tests.
+    let root_contract = built
+        .bytecode_without_tests
+        .as_ref()
+        .zip(built.source_map_without_tests())
+        .map(|(bytecode, source_map)| InstrumentedProgram {
+            context: Some(deployed_contract_id(built, &bytecode.bytes)),
+            bytes: &bytecode.bytes,
+            source_map,
+        });
+    let contract_dependencies =
+        tested_pkg
+            .contract_dependencies
+            .iter()
+            .map(|dep| InstrumentedProgram {
+                context: Some(deployed_contract_id(dep, &dep.bytecode.bytes)),
+                bytes: &dep.bytecode.bytes,
+                source_map: dep.source_map(),
+            });
+    std::iter::once(script)
+        .chain(root_contract)
+        .chain(contract_dependencies)
+        .collect()
+}
+
+/// The instruction executed when the conditional jump at `pc` is performed.
+///
+/// Returns `None` for any other instruction, or when the target depends on a register.
+fn jump_target(pc: usize, instruction: Instruction) -> Option<usize> {
+    let to_usize = |imm: u32| imm as usize;
+    match instruction {
+        Instruction::JNEI(op) => Some(to_usize(op.unpack().2.into())),
+        Instruction::JNZI(op) => Some(to_usize(op.unpack().1.into())),
+        Instruction::JNZF(op) => match op.unpack() {
+            (_, RegId::ZERO, imm) => Some(pc + to_usize(imm.into()) + 1),
+            _ => None,
+        },
+        Instruction::JNZB(op) => match op.unpack() {
+            (_, RegId::ZERO, imm) => pc.checked_sub(to_usize(imm.into()) + 1),
+            _ => None,
+        },
+        Instruction::JNEF(op) => match op.unpack() {
+            (_, _, RegId::ZERO, imm) => Some(pc + to_usize(imm.into()) + 1),
+            _ => None,
+        },
+        Instruction::JNEB(op) => match op.unpack() {
+            (_, _, RegId::ZERO, imm) => pc.checked_sub(to_usize(imm.into()) + 1),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Format a hit ratio along with its percentage.
+fn ratio(hit: usize, found: usize) -> String {
+    if found == 0 {
+        return "-".to_string();
+    }
+    let percentage = hit as f64 * 100.0 / found as f64;
+    format!("{hit}/{found} ({percentage:.1}%)")
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const HTML_STYLE: &str = "body{font-family:sans-serif}\
+table{border-collapse:collapse}\
+td,th{border:1px solid #ccc;padding:2px 8px;text-align:left}\
+pre{font-family:monospace;line-height:1.3}\
+pre>span{display:block}\
+.hit{background:#dfd}\
+.miss{background:#fdd}\
+.num,.hits{color:#888;padding-right:1em}\
+.br{color:#a60;white-space:pre}";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn branch_targets() {
+        use fuel_vm::fuel_asm::op;
+        assert_eq!(jump_target(10, op::jnei(0x10, 0x11, 42)), Some(42));
+        assert_eq!(jump_target(10, op::jnzi(0x10, 3)), Some(3));
+        assert_eq!(jump_target(10, op::jnzf(0x10, RegId::ZERO, 2)), Some(13));
+        assert_eq!(jump_target(10, op::jnzb(0x10, RegId::ZERO, 2)), Some(7));
+        assert_eq!(jump_target(10, op::jnzf(0x10, 0x11, 2)), None);
+        assert_eq!(jump_target(10, op::ji(3)), None);
+    }
+
+    #[test]
+    fn merge_and_summary() {
+        let path = PathBuf::from("/src/main.sw");
+        let mut coverage = Coverage::default();
+        let file = coverage.file_mut(&path);
+        file.lines.insert(1, 1);
+        file.lines.insert(2, 0);
+        file.branches.entry(2).or_default().insert(
+            (10, 20),
+            BranchCoverage {
+                taken: 1,
+                not_taken: 0,
+            },
+        );
+
+        let mut other = Coverage::default();
+        let other_file = other.file_mut(&path);
+        other_file.lines.insert(2, 1);
+        other_file.lines.insert(3, 0);
+        other_file.branches.entry(2).or_default().insert(
+            (10, 20),
+            BranchCoverage {
+                taken: 0,
+                not_taken: 2,
+            },
+        );
+        coverage.merge(other);
+
+        let summary = coverage.summary();
+        assert_eq!(
+            summary,
+            CoverageSummary {
+                lines_found: 3,
+                lines_hit: 2,
+                branches_found: 2,
+                branches_hit: 2,
+            }
+        );
+        let lcov = coverage.to_lcov();
+        assert!(lcov.contains("SF:/src/main.sw\n"));
+        assert!(lcov.contains("BRDA:2,0,0,1\nBRDA:2,0,1,2\n"));
+        assert!(lcov.contains("DA:2,1\n"));
+        assert!(lcov.contains("LF:3\nLH:2\nend_of_record\n"));
+
+        coverage.retain_files_within(Path::new("/other"));
+        assert!(coverage.files.is_empty());
+    }
+}
@@ -0,0 +1,656 @@
+//! Input generation and shrinking for fuzz tests, i.e. tests declared with `#[test(fuzz)]`.
+//!
+//! The parameters of a fuzz test are described by the ABI generated for the test function. Each
+//! parameter type is resolved into a [ParamType], for which random [Value]s are generated and then
+//! encoded as the script data the test entry point reads its arguments from. Once an input makes
+//! the test fail, it is repeatedly replaced by a simpler input that still fails, so that the
+//! reported counterexample is as small as possible.
+
+use crate::abi::{pad_to_word, ParamType, Value};
+use anyhow::{anyhow, bail, Result};
+use forc_pkg::program_abi;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use std::{collections::HashMap, fmt};
+
+/// The number of generated inputs each fuzz test is executed with by default.
+pub const DEFAULT_FUZZ_RUNS: usize = 256;
+
+/// The maximum number of candidate inputs executed while shrinking a failing input.
+const MAX_SHRINK_ATTEMPTS: usize = 1024;
+
+/// The set of options controlling the execution of fuzz tests.
+#[derive(Debug, Clone)]
+pub struct FuzzOpts {
+    /// The number of generated inputs each fuzz test is executed with. Every fuzz test is executed
+    /// at least once, so this should be at least 1.
+    pub runs: usize,
+    /// The seed of the input generator. Runs with the same seed generate the same inputs.
+    pub seed: u64,
+}
+
+/// A named fuzz test parameter along with its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub param_type: ParamType,
+}
+
+/// The value a fuzz test was executed with for one of its parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzInput {
+    pub param: Param,
+    pub value: Value,
+}
+
+/// The smallest failing input found for a fuzz test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    /// The value of each parameter of the test function, in declaration order.
+    pub inputs: Vec<FuzzInput>,
+    /// The number of times the originally generated failing input was successfully shrunk.
+    pub shrinks: usize,
+}
+
+/// The results of executing a fuzz test with generated inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzReport {
+    /// The number of generated inputs the test was executed with, not counting shrinking.
+    pub runs: usize,
+    /// The minimal failing input, if the test failed for any of the generated inputs.
+    pub counterexample: Option<Counterexample>,
+}
+
+/// The outcome of fuzzing a test, along with the execution that is reported for it.
+pub(crate) struct Fuzzed<T> {
+    pub report: FuzzReport,
+    /// The execution of the counterexample if there is one, otherwise of the last input.
+    pub execution: T,
+}
+
+impl Default for FuzzOpts {
+    fn default() -> Self {
+        Self {
+            runs: DEFAULT_FUZZ_RUNS,
+            seed: crate::TEST_METADATA_SEED,
+        }
+    }
+}
+
+impl Param {
+    /// Resolve the parameters of the test function described by the given fuzz test ABI.
+    pub fn from_fuzz_abi(fuzz_abi: &program_abi::ProgramABI) -> Result<Vec<Self>> {
+        let function = fuzz_abi
+            .functions
+            .first()
+            .ok_or_else(|| anyhow!("fuzz test ABI is missing the test function"))?;
+        let types = fuzz_abi
+            .types
+            .iter()
+            .map(|type_decl| (type_decl.type_id, type_decl))
+            .collect();
+        function
+            .inputs
+            .iter()
+            .map(|input| {
+                let param_type = ParamType::resolve(input, &types, &HashMap::new())
+                    .and_then(|param_type| {
+                        if param_type.contains_raw_ptr() {
+                            bail!("raw pointers are not supported in fuzz tests");
+                        }
+                        if !param_type.is_inhabited() {
+                            bail!(
+                                "types without values, such as enums without variants, are not \
+                                 supported in fuzz tests"
+                            );
+                        }
+                        Ok(param_type)
+                    })
+                    .map_err(|e| anyhow!("parameter `{}` of {}: {e}", input.name, function.name))?;
+                Ok(Param {
+                    name: input.name.clone(),
+                    param_type,
+                })
+            })
+            .collect()
+    }
+}
+
+impl ParamType {
+    /// Whether values of this type contain raw pointers, for which no meaningful values can be
+    /// generated.
+    fn contains_raw_ptr(&self) -> bool {
+        match self {
+            ParamType::RawPtr => true,
+            ParamType::Array(elem_type, _) => elem_type.contains_raw_ptr(),
+            ParamType::Tuple(fields) => fields.iter().any(Self::contains_raw_ptr),
+            ParamType::Struct { fields, .. }
+            | ParamType::Enum {
+                variants: fields, ..
+            } => fields.iter().any(|(_, ty)| ty.contains_raw_ptr()),
+            _ => false,
+        }
+    }
+
+    /// Whether any value of this type exists. An enum without variants has no values, and
+    /// neither does a type containing one, unless it is in an enum variant or an empty array.
+    fn is_inhabited(&self) -> bool {
+        match self {
+            ParamType::Array(elem_type, len) => *len == 0 || elem_type.is_inhabited(),
+            ParamType::Tuple(fields) => fields.iter().all(Self::is_inhabited),
+            ParamType::Struct { fields, .. } => fields.iter().all(|(_, ty)| ty.is_inhabited()),
+            ParamType::Enum { variants, .. } => variants.iter().any(|(_, ty)| ty.is_inhabited()),
+            _ => true,
+        }
+    }
+
+    /// Generate a random value of this type.
+    fn generate(&self, rng: &mut impl Rng) -> Value {
+        match self {
+            ParamType::Unit => Value::Unit,
+            ParamType::Bool => Value::Bool(rng.gen()),
+            ParamType::U8 => Value::U8(generate_uint(rng, u8::MAX.into()) as u8),
+            ParamType::U16 => Value::U16(generate_uint(rng, u16::MAX.into()) as u16),
+            ParamType::U32 => Value::U32(generate_uint(rng, u32::MAX.into()) as u32),
+            ParamType::U64 => Value::U64(generate_uint(rng, u64::MAX)),
+            // Reinterpreting the bits gives the boundaries of the signed ranges too.
+            ParamType::I8 => Value::I8(generate_uint(rng, u8::MAX.into()) as u8 as i8),
+            ParamType::I16 => Value::I16(generate_uint(rng, u16::MAX.into()) as u16 as i16),
+            ParamType::I32 => Value::I32(generate_uint(rng, u32::MAX.into()) as u32 as i32),
+            ParamType::I64 => Value::I64(generate_uint(rng, u64::MAX) as i64),
+            ParamType::U256 => Value::U256(generate_word(rng)),
+            ParamType::B256 => Value::B256(generate_word(rng)),
+            ParamType::RawPtr => unreachable!("raw pointers are rejected for fuzz tests"),
+            ParamType::StringArray(len) => Value::StringArray(
+                (0..*len)
+                    .map(|_| char::from(rng.gen_range(b' '..=b'~')))
+                    .collect(),
+            ),
+            ParamType::Array(elem_type, len) => {
+                Value::Array((0..*len).map(|_| elem_type.generate(rng)).collect())
+            }
+            ParamType::Tuple(fields) => {
+                Value::Tuple(fields.iter().map(|field| field.generate(rng)).collect())
+            }
+            ParamType::Struct { fields, .. } => Value::Struct(
+                fields
+                    .iter()
+                    .map(|(_, field)| field.generate(rng))
+                    .collect(),
+            ),
+            ParamType::Enum { variants, .. } => {
+                let inhabited = inhabited_variants(variants).collect::<Vec<_>>();
+                let (tag, variant_type) = inhabited[rng.gen_range(0..inhabited.len())];
+                Value::Enum(tag, Box::new(variant_type.generate(rng)))
+            }
+        }
+    }
+
+    /// The simplest value of this type, which every value is eventually shrunk towards.
+    fn simplest(&self) -> Value {
+        match self {
+            ParamType::Unit => Value::Unit,
+            ParamType::Bool => Value::Bool(false),
+            ParamType::U8 => Value::U8(0),
+            ParamType::U16 => Value::U16(0),
+            ParamType::U32 => Value::U32(0),
+            ParamType::U64 => Value::U64(0),
+            ParamType::I8 => Value::I8(0),
+            ParamType::I16 => Value::I16(0),
+            ParamType::I32 => Value::I32(0),
+            ParamType::I64 => Value::I64(0),
+            ParamType::U256 => Value::U256([0; 32]),
+            ParamType::B256 => Value::B256([0; 32]),
+            ParamType::RawPtr => unreachable!("raw pointers are rejected for fuzz tests"),
+            ParamType::StringArray(len) => {
+                Value::StringArray(SIMPLEST_CHAR.to_string().repeat(*len))
+            }
+            ParamType::Array(elem_type, len) => Value::Array(vec![elem_type.simplest(); *len]),
+            ParamType::Tuple(fields) => {
+                Value::Tuple(fields.iter().map(|field| field.simplest()).collect())
+            }
+            ParamType::Struct { fields, .. } => {
+                Value::Struct(fields.iter().map(|(_, field)| field.simplest()).collect())
+            }
+            ParamType::Enum { variants, .. } => {
+                let (tag, variant_type) = inhabited_variants(variants)
+                    .next()
+                    .expect("uninhabited types are rejected for fuzz tests");
+                Value::Enum(tag, Box::new(variant_type.simplest()))
+            }
+        }
+    }
+
+    /// Values of this type that are simpler than `value`, the simplest first.
+    fn shrink(&self, value: &Value) -> Vec<Value> {
+        match (self, value) {
+            (ParamType::Bool, Value::Bool(true)) => vec![Value::Bool(false)],
+            (ParamType::U8, Value::U8(n)) => shrink_uint(u64::from(*n))
+                .map(|n| Value::U8(n as u8))
+                .collect(),
+            (ParamType::U16, Value::U16(n)) => shrink_uint(u64::from(*n))
+                .map(|n| Value::U16(n as u16))
+                .collect(),
+            (ParamType::U32, Value::U32(n)) => shrink_uint(u64::from(*n))
+                .map(|n| Value::U32(n as u32))
+                .collect(),
+            (ParamType::U64, Value::U64(n)) => shrink_uint(*n).map(Value::U64).collect(),
+            (ParamType::I8, Value::I8(n)) => shrink_int(i64::from(*n))
+                .map(|n| Value::I8(n as i8))
+                .collect(),
+            (ParamType::I16, Value::I16(n)) => shrink_int(i64::from(*n))
+                .map(|n| Value::I16(n as i16))
+                .collect(),
+            (ParamType::I32, Value::I32(n)) => shrink_int(i64::from(*n))
+                .map(|n| Value::I32(n as i32))
+                .collect(),
+            (ParamType::I64, Value::I64(n)) => shrink_int(*n).map(Value::I64).collect(),
+            (ParamType::U256, Value::U256(word)) => {
+                shrink_word(word).into_iter().map(Value::U256).collect()
+            }
+            (ParamType::B256, Value::B256(word)) => {
+                shrink_word(word).into_iter().map(Value::B256).collect()
+            }
+            (ParamType::StringArray(_), Value::StringArray(s)) => {
+                let mut candidates = vec![];
+                let simplest = SIMPLEST_CHAR.to_string().repeat(s.len());
+                if *s != simplest {
+                    candidates.push(simplest);
+                }
+                for (ix, c) in s.char_indices() {
+                    if c != SIMPLEST_CHAR {
+                        let mut candidate = s.clone();
+                        candidate.replace_range(
+                            ix..ix + c.len_utf8(),
+                            SIMPLEST_CHAR.encode_utf8(&mut [0; 4]),
+                        );
+                        candidates.push(candidate);
+                    }
+                }
+                candidates.dedup();
+                candidates.into_iter().map(Value::StringArray).collect()
+            }
+            (ParamType::Array(elem_type, _), Value::Array(elems)) => {
+                let elem_types = vec![&**elem_type; elems.len()];
+                shrink_fields(&elem_types, elems)
+                    .into_iter()
+                    .map(Value::Array)
+                    .collect()
+            }
+            (ParamType::Tuple(field_types), Value::Tuple(fields)) => {
+                let field_types = field_types.iter().collect::<Vec<_>>();
+                shrink_fields(&field_types, fields)
+                    .into_iter()
+                    .map(Value::Tuple)
+                    .collect()
+            }
+            (
+                ParamType::Struct {
+                    fields: field_types,
+                    ..
+                },
+                Value::Struct(fields),
+            ) => {
+                let field_types = field_types.iter().map(|(_, ty)| ty).collect::<Vec<_>>();
+                shrink_fields(&field_types, fields)
+                    .into_iter()
+                    .map(Value::Struct)
+                    .collect()
+            }
+            (ParamType::Enum { variants, .. }, Value::Enum(tag, variant)) => {
+                // Prefer earlier variants, then simpler values of the same variant.
+                let earlier_variants = inhabited_variants(&variants[..*tag])
+                    .map(|(tag, ty)| Value::Enum(tag, Box::new(ty.simplest())));
+                let simpler_values = variants[*tag]
+                    .1
+                    .shrink(variant)
+                    .into_iter()
+                    .map(|variant| Value::Enum(*tag, Box::new(variant)));
+                earlier_variants.chain(simpler_values).collect()
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl fmt::Display for FuzzInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = ", self.param.name)?;
+        self.param.param_type.fmt_value(&self.value, f)
+    }
+}
+
+/// Encode the given arguments as the script data a test entry point reads its arguments from.
+///
+/// A single argument held in a register is read as a whole word. Otherwise the arguments are laid
+/// out as the fields of a struct, and those not held in registers are read by reference.
+pub fn encode_script_data(params: &[Param], values: &[Value]) -> Vec<u8> {
+    let mut bytes = vec![];
+    match (params, values) {
+        ([param], [value]) if param.param_type.is_copy_type() => {
+            let word = match value {
+                Value::Unit => 0,
+                Value::Bool(b) => u64::from(*b),
+                Value::U8(n) => u64::from(*n),
+                Value::U16(n) => u64::from(*n),
+                Value::U32(n) => u64::from(*n),
+                Value::U64(n) => *n,
+                Value::I8(n) => i64::from(*n) as u64,
+                Value::I16(n) => i64::from(*n) as u64,
+                Value::I32(n) => i64::from(*n) as u64,
+                Value::I64(n) => *n as u64,
+                _ => unreachable!("value {value:?} is not held in a register"),
+            };
+            bytes.extend(word.to_be_bytes());
+        }
+        _ => {
+            for (param, value) in params.iter().zip(values) {
+                param.param_type.encode(value, &mut bytes);
+                pad_to_word(&mut bytes);
+            }
+        }
+    }
+    bytes
+}
+
+/// Execute a test with `opts.runs` generated inputs for `params`, and shrink the first input the
+/// test fails with.
+///
+/// `exec` executes the test with the given script data, and returns whether the test passed along
+/// with the result of the execution.
+pub(crate) fn fuzz<T>(
+    params: &[Param],
+    opts: &FuzzOpts,
+    mut exec: impl FnMut(&[u8]) -> (bool, T),
+) -> Fuzzed<T> {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(opts.seed);
+    let mut runs = 0;
+    loop {
+        let inputs: Vec<Value> = params
+            .iter()
+            .map(|param| param.param_type.generate(&mut rng))
+            .collect();
+        let (passed, execution) = exec(&encode_script_data(params, &inputs));
+        runs += 1;
+        if !passed {
+            let (counterexample, execution) = shrink(params, inputs, execution, &mut exec);
+            let report = FuzzReport {
+                runs,
+                counterexample: Some(counterexample),
+            };
+            return Fuzzed { report, execution };
+        }
+        if runs >= opts.runs {
+            let report = FuzzReport {
+                runs,
+                counterexample: None,
+            };
+            return Fuzzed { report, execution };
+        }
+    }
+}
+
+/// Repeatedly replace the failing `inputs` with the first simpler input the test still fails
+/// with, until no simpler input fails or the shrinking budget is exhausted.
+fn shrink<T>(
+    params: &[Param],
+    mut inputs: Vec<Value>,
+    mut execution: T,
+    exec: &mut impl FnMut(&[u8]) -> (bool, T),
+) -> (Counterexample, T) {
+    let mut shrinks = 0;
+    let mut attempts = 0;
+    'shrinking: while attempts < MAX_SHRINK_ATTEMPTS {
+        let param_types: Vec<&ParamType> = params.iter().map(|param| &param.param_type).collect();
+        for candidate in shrink_fields(&param_types, &inputs) {
+            attempts += 1;
+            let (passed, candidate_execution) = exec(&encode_script_data(params, &candidate));
+            if !passed {
+                inputs = candidate;
+                execution = candidate_execution;
+                shrinks += 1;
+                continue 'shrinking;
+            }
+            if attempts >= MAX_SHRINK_ATTEMPTS {
+                break;
+            }
+        }
+        break;
+    }
+    let inputs = params
+        .iter()
+        .cloned()
+        .zip(inputs)
+        .map(|(param, value)| FuzzInput { param, value })
+        .collect();
+    (Counterexample { inputs, shrinks }, execution)
+}
+
+/// The tag and type of each variant of an enum which has values.
+fn inhabited_variants(
+    variants: &[(String, ParamType)],
+) -> impl Iterator<Item = (usize, &ParamType)> {
+    variants
+        .iter()
+        .map(|(_, ty)| ty)
+        .enumerate()
+        .filter(|(_, ty)| ty.is_inhabited())
+}
+
+/// The character every character of a string is shrunk towards.
+const SIMPLEST_CHAR: char = 'a';
+
+/// Candidates for a sequence of values where a single value is replaced by a simpler one.
+fn shrink_fields(types: &[&ParamType], values: &[Value]) -> Vec<Vec<Value>> {
+    types
+        .iter()
+        .zip(values)
+        .enumerate()
+        .flat_map(|(ix, (ty, value))| {
+            ty.shrink(value).into_iter().map(move |simpler| {
+                let mut candidate = values.to_vec();
+                candidate[ix] = simpler;
+                candidate
+            })
+        })
+        .collect()
+}
+
+/// Generate an integer no greater than `max`, biased towards the boundary values around which
+/// overflows and off-by-one errors tend to occur.
+fn generate_uint(rng: &mut impl Rng, max: u64) -> u64 {
+    match rng.gen_range(0..8) {
+        0 => *[0, 1, max / 2, max / 2 + 1, max - 1, max]
+            .choose(rng)
+            .expect("non-empty"),
+        1 => {
+            let bits = u64::BITS - max.leading_zeros();
+            let power_of_two = 1u64 << rng.gen_range(0..bits);
+            power_of_two - rng.gen_range(0..=1)
+        }
+        _ => rng.gen_range(0..=max),
+    }
+}
+
+/// Generate a 256-bit word, biased towards boundary values like [generate_uint].
+fn generate_word(rng: &mut impl Rng) -> [u8; 32] {
+    match rng.gen_range(0..8) {
+        0 => *[[0; 32], [0xFF; 32]].choose(rng).expect("non-empty"),
+        1 => {
+            let mut word = [0; 32];
+            word[24..].copy_from_slice(&generate_uint(rng, u64::MAX).to_be_bytes());
+            word
+        }
+        _ => rng.gen(),
+    }
+}
+
+/// Integers smaller than `n` to try when shrinking it.
+fn shrink_uint(n: u64) -> impl Iterator<Item = u64> {
+    let mut candidates = vec![0, n / 2, n.saturating_sub(1)];
+    candidates.dedup();
+    candidates
+        .into_iter()
+        .filter(move |candidate| *candidate < n)
+}
+
+/// Integers closer to zero than `n` to try when shrinking it.
+fn shrink_int(n: i64) -> impl Iterator<Item = i64> {
+    let mut candidates = vec![0, n / 2, n - n.signum()];
+    candidates.dedup();
+    candidates
+        .into_iter()
+        .filter(move |candidate| candidate.unsigned_abs() < n.unsigned_abs())
+}
+
+/// 256-bit words smaller than `word` to try when shrinking it.
+fn shrink_word(word: &[u8; 32]) -> Vec<[u8; 32]> {
+    if *word == [0; 32] {
+        return vec![];
+    }
+    let mut halved = [0; 32];
+    let mut carry = 0;
+    for (halved_byte, byte) in halved.iter_mut().zip(word) {
+        *halved_byte = (byte >> 1) | carry;
+        carry = byte << 7;
+    }
+    let mut decremented = *word;
+    for byte in decremented.iter_mut().rev() {
+        let (value, borrow) = byte.overflowing_sub(1);
+        *byte = value;
+        if !borrow {
+            break;
+        }
+    }
+    let mut candidates = vec![[0; 32], halved, decremented];
+    candidates.dedup();
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn param(name: &str, param_type: ParamType) -> Param {
+        Param {
+            name: name.to_string(),
+            param_type,
+        }
+    }
+
+    #[test]
+    fn encode_layout() {
+        // A single argument held in a register is a whole word.
+        let params = [param("a", ParamType::U8)];
+        assert_eq!(
+            encode_script_data(&params, &[Value::U8(7)]),
+            [0, 0, 0, 0, 0, 0, 0, 7]
+        );
+
+        // Otherwise small values are left aligned within their word, and enum variants are right
+        // aligned within the union.
+        let option = ParamType::Enum {
+            name: "Option".to_string(),
+            variants: vec![
+                ("None".to_string(), ParamType::Unit),
+                ("Some".to_string(), ParamType::Bool),
+            ],
+        };
+        let params = [param("a", ParamType::U8), param("b", option)];
+        let values = [Value::U8(7), Value::Enum(1, Box::new(Value::Bool(true)))];
+        assert_eq!(
+            encode_script_data(&params, &values),
+            [
+                [7, 0, 0, 0, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0, 0, 0, 1],
+                [0, 0, 0, 0, 0, 0, 0, 1]
+            ]
+            .concat()
+        );
+
+        // Arrays of bytes are packed.
+        let params = [param("a", ParamType::Array(Box::new(ParamType::U8), 3))];
+        let values = [Value::Array(vec![Value::U8(1), Value::U8(2), Value::U8(3)])];
+        assert_eq!(
+            encode_script_data(&params, &values),
+            [1, 2, 3, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn shrink_to_threshold() {
+        let params = [param("x", ParamType::U64)];
+        let opts = FuzzOpts::default();
+        let fuzzed = fuzz(&params, &opts, |script_data| {
+            let x = u64::from_be_bytes(script_data.try_into().unwrap());
+            (x <= 100, ())
+        });
+        let counterexample = fuzzed.report.counterexample.unwrap();
+        assert_eq!(counterexample.inputs[0].value, Value::U64(101));
+        assert_eq!(counterexample.inputs[0].to_string(), "x = 101");
+    }
+
+    #[test]
+    fn passing_runs() {
+        let params = [param("b", ParamType::B256)];
+        let opts = FuzzOpts {
+            runs: 10,
+            ..Default::default()
+        };
+        let mut executions = 0;
+        let fuzzed = fuzz(&params, &opts, |_| {
+            executions += 1;
+            (true, ())
+        });
+        assert_eq!(fuzzed.report.runs, 10);
+        assert_eq!(fuzzed.report.counterexample, None);
+        assert_eq!(executions, 10);
+    }
+
+    #[test]
+    fn shrink_words() {
+        let mut word = [0; 32];
+        word[31] = 3;
+        word[30] = 1;
+        let mut halved = [0; 32];
+        halved[31] = 0x81;
+        let mut decremented = word;
+        decremented[31] = 2;
+        assert_eq!(shrink_word(&word), vec![[0; 32], halved, decremented]);
+    }
+
+    #[test]
+    fn shrink_ints() {
+        assert_eq!(shrink_int(-7).collect::<Vec<_>>(), vec![0, -3, -6]);
+        assert_eq!(
+            shrink_int(i64::MIN).collect::<Vec<_>>(),
+            vec![0, i64::MIN / 2, i64::MIN + 1]
+        );
+        assert_eq!(shrink_int(0).count(), 0);
+    }
+
+    #[test]
+    fn uninhabited_variants() {
+        let never = ParamType::Enum {
+            name: "Never".to_string(),
+            variants: vec![],
+        };
+        let result = ParamType::Enum {
+            name: "Result".to_string(),
+            variants: vec![
+                ("Ok".to_string(), ParamType::U8),
+                ("Err".to_string(), never.clone()),
+            ],
+        };
+        assert!(!never.is_inhabited());
+        assert!(!ParamType::Tuple(vec![ParamType::U8, never.clone()]).is_inhabited());
+        assert!(ParamType::Array(Box::new(never), 0).is_inhabited());
+        assert!(result.is_inhabited());
+
+        // Only the inhabited variant is ever generated.
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        for _ in 0..32 {
+            assert!(matches!(result.generate(&mut rng), Value::Enum(0, _)));
+        }
+        assert_eq!(result.simplest(), Value::Enum(0, Box::new(Value::U8(0))));
+    }
+}
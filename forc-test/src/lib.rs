@@ -4,13 +4,26 @@ use fuel_tx as tx;
 use fuel_vm::checked_transaction::builder::TransactionBuilderExt;
 use fuel_vm::gas::GasCosts;
 use fuel_vm::{self as vm, fuel_asm, prelude::Instruction};
+use fuzz::{FuzzOpts, FuzzReport};
+use logs::{DecodedLog, LogDecoder};
 use pkg::TestPassCondition;
 use pkg::{Built, BuiltPackage};
 use rand::{Rng, SeedableRng};
 use rayon::prelude::*;
 use std::{collections::HashMap, fs, path::PathBuf, sync::Arc};
+use storage::{StorageChange, TestFixtures};
 use sway_core::BuildTarget;
 use sway_types::Span;
+use vm::constraints::InstructionLocation;
+
+pub mod abi;
+pub mod coverage;
+pub mod fuzz;
+pub mod logs;
+pub mod profile;
+pub mod report;
+pub mod snapshot;
+pub mod storage;
 
 /// The result of a `forc test` invocation.
 #[derive(Debug)]
@@ -25,6 +38,8 @@ pub struct TestedPackage {
     pub built: Box<pkg::BuiltPackage>,
     /// The resulting `ProgramState` after executing the test.
     pub tests: Vec<TestResult>,
+    /// The contract dependencies deployed before executing the tests, in deployment order.
+    pub contract_dependencies: Vec<Arc<pkg::BuiltPackage>>,
 }
 
 #[derive(Debug)]
@@ -62,8 +77,26 @@ pub struct TestResult {
     pub condition: pkg::TestPassCondition,
     /// Emitted `Recipt`s during the execution of the test.
     pub logs: Vec<fuel_tx::Receipt>,
+    /// The value logged by each of the `logs`, decoded using the ABI of the program which emitted
+    /// it, or `None` if it could not be decoded.
+    pub decoded_logs: Vec<Option<DecodedLog>>,
     /// Gas used while executing this test.
     pub gas_used: u64,
+    /// The location of every instruction executed by this test, across all contexts.
+    ///
+    /// Only recorded when `forc-test` is built with the `profile` feature, empty otherwise.
+    pub executed_instructions: Vec<InstructionLocation>,
+    /// The gas spent by the instructions at each location, across all contexts.
+    ///
+    /// Only recorded when `forc-test` is built with the `profile` feature, empty otherwise.
+    pub gas_per_instruction: Vec<(InstructionLocation, u64)>,
+    /// The storage slots written by this test, across all contracts.
+    pub storage_diff: Vec<StorageChange>,
+    /// For fuzz tests, the results of executing the test with generated inputs.
+    ///
+    /// The state, logs and gas of a fuzz test are those of the minimal failing input if there is
+    /// one, and of the last generated input otherwise.
+    pub fuzz: Option<FuzzReport>,
 }
 
 const TEST_METADATA_SEED: u64 = 0x7E57u64;
@@ -190,6 +223,19 @@ impl TestSetup {
         }
     }
 
+    /// Returns the mutable storage for this test setup
+    fn storage_mut(&mut self) -> &mut vm::storage::MemoryStorage {
+        match self {
+            TestSetup::WithDeployment(DeploymentSetup::Script(script_setup)) => {
+                &mut script_setup.storage
+            }
+            TestSetup::WithDeployment(DeploymentSetup::Contract(contract_setup)) => {
+                &mut contract_setup.storage
+            }
+            TestSetup::WithoutDeployment(storage) => storage,
+        }
+    }
+
     /// Produces an iterator yielding contract ids of contract dependencies for this test setup.
     fn contract_dependency_ids(&self) -> impl Iterator<Item = &tx::ContractId> + '_ {
         match self {
@@ -360,6 +406,16 @@ impl<'a> PackageTests {
         }
     }
 
+    /// Returns an iterator over the contract dependencies deployed before running the tests.
+    fn contract_dependencies(&'a self) -> impl Iterator<Item = &'a Arc<BuiltPackage>> + 'a {
+        let deps = match self {
+            PackageTests::Contract(contract) => Some(contract.contract_dependencies()),
+            PackageTests::Script(script) => Some(script.contract_dependencies()),
+            PackageTests::Predicate(_) | PackageTests::Library(_) => None,
+        };
+        deps.into_iter().flatten()
+    }
+
     /// Construct a `PackageTests` from `BuiltPackage`.
     fn from_built_pkg(
         built_pkg: Arc<BuiltPackage>,
@@ -401,8 +457,20 @@ impl<'a> PackageTests {
         &self,
         test_runners: &rayon::ThreadPool,
         test_filter: Option<&TestFilter>,
+        fuzz_opts: &FuzzOpts,
     ) -> anyhow::Result<TestedPackage> {
         let pkg_with_tests = self.built_pkg_with_tests();
+        let log_decoder = LogDecoder::new(pkg_with_tests, self.contract_dependencies());
+        let fixtures = TestFixtures::from_dir(pkg_with_tests.descriptor.manifest_file.dir())?;
+        if let Some(fixtures) = &fixtures {
+            let test_names = pkg_with_tests
+                .bytecode
+                .entries
+                .iter()
+                .filter(|entry| entry.kind.test().is_some())
+                .map(|entry| entry.finalized.fn_name.as_str());
+            fixtures.validate(test_names)?;
+        }
         let tests = test_runners.install(|| {
             pkg_with_tests
                 .bytecode
@@ -421,9 +489,45 @@ impl<'a> PackageTests {
                     let offset = u32::try_from(entry.finalized.imm)
                         .expect("test instruction offset out of range");
                     let name = entry.finalized.fn_name.clone();
-                    let test_setup = self.setup()?;
-                    let (state, duration, receipts) =
-                        exec_test(&pkg_with_tests.bytecode.bytes, offset, test_setup);
+                    let mut test_setup = self.setup()?;
+                    if let Some(fixtures) = &fixtures {
+                        let contract_ids = self.deployed_contract_ids(&test_setup);
+                        fixtures.apply(&name, test_setup.storage_mut(), &contract_ids)?;
+                    }
+                    let bytecode = &pkg_with_tests.bytecode.bytes;
+                    let condition = test_entry.pass_condition.clone();
+                    let (execution, fuzz) = match &test_entry.fuzz_abi {
+                        None => (exec_test(bytecode, offset, &test_setup, vec![]), None),
+                        Some(fuzz_abi) => {
+                            let params = fuzz::Param::from_fuzz_abi(fuzz_abi)?;
+                            let mut total_duration = std::time::Duration::ZERO;
+                            let mut all_executed_instructions = vec![];
+                            let fuzzed = fuzz::fuzz(&params, fuzz_opts, |script_data| {
+                                let execution =
+                                    exec_test(bytecode, offset, &test_setup, script_data.to_vec());
+                                total_duration += execution.duration;
+                                all_executed_instructions
+                                    .extend_from_slice(&execution.executed_instructions);
+                                (condition_is_met(&condition, &execution.state), execution)
+                            });
+                            all_executed_instructions.sort_unstable();
+                            all_executed_instructions.dedup();
+                            let execution = TestExecution {
+                                duration: total_duration,
+                                executed_instructions: all_executed_instructions,
+                                ..fuzzed.execution
+                            };
+                            (execution, Some(fuzzed.report))
+                        }
+                    };
+                    let TestExecution {
+                        state,
+                        duration,
+                        receipts,
+                        executed_instructions,
+                        gas_per_instruction,
+                        storage_diff,
+                    } = execution;
 
                     let gas_used = *receipts
                         .iter()
@@ -436,17 +540,17 @@ impl<'a> PackageTests {
                         })?;
 
                     // Only retain `Log` and `LogData` receipts.
-                    let logs = receipts
+                    let logs: Vec<_> = receipts
                         .into_iter()
                         .filter(|receipt| {
                             matches!(receipt, fuel_tx::Receipt::Log { .. })
                                 || matches!(receipt, fuel_tx::Receipt::LogData { .. })
                         })
                         .collect();
+                    let decoded_logs = logs.iter().map(|log| log_decoder.decode(log)).collect();
 
                     let span = test_entry.span.clone();
                     let file_path = test_entry.file_path.clone();
-                    let condition = test_entry.pass_condition.clone();
                     Ok(TestResult {
                         name,
                         file_path,
@@ -455,7 +559,12 @@ impl<'a> PackageTests {
                         state,
                         condition,
                         logs,
+                        decoded_logs,
                         gas_used,
+                        executed_instructions,
+                        gas_per_instruction,
+                        storage_diff,
+                        fuzz,
                     })
                 })
                 .collect::<anyhow::Result<_>>()
@@ -464,10 +573,23 @@ impl<'a> PackageTests {
         let tested_pkg = TestedPackage {
             built: Box::new(pkg_with_tests.clone()),
             tests,
+            contract_dependencies: self.contract_dependencies().cloned().collect(),
         };
         Ok(tested_pkg)
     }
 
+    /// The ids of the contracts deployed for the tests, keyed by package name.
+    fn deployed_contract_ids(&self, test_setup: &TestSetup) -> HashMap<&str, tx::ContractId> {
+        let contract_dependencies = self
+            .contract_dependencies()
+            .map(|dep| dep.descriptor.name.as_str())
+            .zip(test_setup.contract_dependency_ids().copied());
+        let root_contract = test_setup
+            .root_contract_id()
+            .map(|id| (self.built_pkg_with_tests().descriptor.name.as_str(), id));
+        contract_dependencies.chain(root_contract).collect()
+    }
+
     /// Setup the storage for a test and returns a contract id for testing contracts.
     ///
     /// For testing contracts, storage returned from this function contains the deployed contract.
@@ -506,6 +628,7 @@ impl Opts {
             metrics_outfile: self.metrics_outfile,
             tests: true,
             member_filter: Default::default(),
+            build_cache: true,
         }
     }
 }
@@ -513,15 +636,7 @@ impl Opts {
 impl TestResult {
     /// Whether or not the test passed.
     pub fn passed(&self) -> bool {
-        match &self.condition {
-            TestPassCondition::ShouldRevert(revert_code) => match revert_code {
-                Some(revert_code) => self.state == vm::state::ProgramState::Revert(*revert_code),
-                None => matches!(self.state, vm::state::ProgramState::Revert(_)),
-            },
-            TestPassCondition::ShouldNotRevert => {
-                !matches!(self.state, vm::state::ProgramState::Revert(_))
-            }
-        }
+        condition_is_met(&self.condition, &self.state)
     }
 
     /// Return the revert code for this `TestResult` if the test is reverted.
@@ -555,6 +670,17 @@ impl TestResult {
     }
 }
 
+/// Whether the given resulting state of a test execution satisfies the test's pass condition.
+fn condition_is_met(condition: &TestPassCondition, state: &vm::state::ProgramState) -> bool {
+    match condition {
+        TestPassCondition::ShouldRevert(revert_code) => match revert_code {
+            Some(revert_code) => *state == vm::state::ProgramState::Revert(*revert_code),
+            None => matches!(state, vm::state::ProgramState::Revert(_)),
+        },
+        TestPassCondition::ShouldNotRevert => !matches!(state, vm::state::ProgramState::Revert(_)),
+    }
+}
+
 /// Used to control test runner count for forc-test. Number of runners to use can be specified using
 /// `Manual` or can be left forc-test to decide by using `Auto`.
 pub enum TestRunnerCount {
@@ -616,6 +742,7 @@ impl BuiltTests {
         self,
         test_runner_count: TestRunnerCount,
         test_filter: Option<TestFilter>,
+        fuzz_opts: FuzzOpts,
     ) -> anyhow::Result<Tested> {
         let test_runners = match test_runner_count {
             TestRunnerCount::Manual(runner_count) => rayon::ThreadPoolBuilder::new()
@@ -623,7 +750,7 @@ impl BuiltTests {
                 .build(),
             TestRunnerCount::Auto => rayon::ThreadPoolBuilder::new().build(),
         }?;
-        run_tests(self, &test_runners, test_filter)
+        run_tests(self, &test_runners, test_filter, &fuzz_opts)
     }
 }
 
@@ -654,6 +781,18 @@ pub fn build(opts: Opts) -> anyhow::Result<BuiltTests> {
     BuiltTests::from_built(built, &member_contract_dependencies)
 }
 
+/// The id of the given contract bytecode when deployed for testing, i.e. with a zeroed salt.
+pub(crate) fn deployed_contract_id(
+    built_pkg: &pkg::BuiltPackage,
+    bytecode: &[u8],
+) -> tx::ContractId {
+    pkg::contract_id(
+        bytecode.to_vec(),
+        built_pkg.storage_slots.clone(),
+        &tx::Salt::zeroed(),
+    )
+}
+
 /// Result of preparing a deployment transaction setup for a contract.
 type ContractDeploymentSetup = (tx::ContractId, vm::checked_transaction::Checked<tx::Create>);
 
@@ -702,16 +841,17 @@ fn run_tests(
     built: BuiltTests,
     test_runners: &rayon::ThreadPool,
     test_filter: Option<TestFilter>,
+    fuzz_opts: &FuzzOpts,
 ) -> anyhow::Result<Tested> {
     match built {
         BuiltTests::Package(pkg) => {
-            let tested_pkg = pkg.run_tests(test_runners, test_filter.as_ref())?;
+            let tested_pkg = pkg.run_tests(test_runners, test_filter.as_ref(), fuzz_opts)?;
             Ok(Tested::Package(Box::new(tested_pkg)))
         }
         BuiltTests::Workspace(workspace) => {
             let tested_pkgs = workspace
                 .into_iter()
-                .map(|pkg| pkg.run_tests(test_runners, test_filter.as_ref()))
+                .map(|pkg| pkg.run_tests(test_runners, test_filter.as_ref(), fuzz_opts))
                 .collect::<anyhow::Result<Vec<TestedPackage>>>()?;
             Ok(Tested::Workspace(tested_pkgs))
         }
@@ -755,23 +895,30 @@ fn patch_test_bytecode(bytecode: &[u8], test_offset: u32) -> std::borrow::Cow<[u
     std::borrow::Cow::Owned(patched)
 }
 
-// Execute the test whose entry point is at the given instruction offset as if it were a script.
+/// The outcome of a single execution of a test.
+struct TestExecution {
+    state: vm::state::ProgramState,
+    duration: std::time::Duration,
+    receipts: Vec<fuel_tx::Receipt>,
+    executed_instructions: Vec<InstructionLocation>,
+    gas_per_instruction: Vec<(InstructionLocation, u64)>,
+    storage_diff: Vec<StorageChange>,
+}
+
+// Execute the test whose entry point is at the given instruction offset as if it were a script,
+// passing the given script data as the arguments of the test function.
 fn exec_test(
     bytecode: &[u8],
     test_offset: u32,
-    test_setup: TestSetup,
-) -> (
-    vm::state::ProgramState,
-    std::time::Duration,
-    Vec<fuel_tx::Receipt>,
-) {
+    test_setup: &TestSetup,
+    script_input_data: Vec<u8>,
+) -> TestExecution {
     let storage = test_setup.storage().clone();
 
     // Patch the bytecode to jump to the relevant test.
     let bytecode = patch_test_bytecode(bytecode, test_offset).into_owned();
 
     // Create a transaction to execute the test function.
-    let script_input_data = vec![];
     let mut rng = rand::rngs::StdRng::seed_from_u64(TEST_METADATA_SEED);
 
     // Prepare the transaction metadata.
@@ -824,8 +971,29 @@ fn exec_test(
     let duration = start.elapsed();
     let state = *transition.state();
     let receipts = transition.receipts().to_vec();
-
-    (state, duration, receipts)
+    #[cfg(feature = "profile")]
+    let (executed_instructions, gas_per_instruction) = {
+        let data = interpreter.profiler().data();
+        let executed_instructions = data.coverage().iter().copied().collect();
+        let gas_per_instruction = data
+            .gas()
+            .iter()
+            .map(|(location, gas)| (*location, *gas))
+            .collect();
+        (executed_instructions, gas_per_instruction)
+    };
+    #[cfg(not(feature = "profile"))]
+    let (executed_instructions, gas_per_instruction) = (vec![], vec![]);
+    let storage_diff = storage::storage_diff(test_setup.storage(), interpreter.as_ref());
+
+    TestExecution {
+        state,
+        duration,
+        receipts,
+        executed_instructions,
+        gas_per_instruction,
+        storage_diff,
+    }
 }
 
 #[cfg(test)]
@@ -833,6 +1001,8 @@ mod tests {
     use std::path::PathBuf;
 
     use crate::{build, BuiltTests, Opts, TestFilter, TestResult};
+    #[cfg(feature = "profile")]
+    use crate::{coverage::Coverage, profile::GasProfile};
 
     /// Name of the folder containing required data for tests to run, such as an example forc
     /// project.
@@ -845,6 +1015,14 @@ mod tests {
     const TEST_PREDICATE_PACKAGE_NAME: &str = "test_predicate";
     /// Name of the script package in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
     const TEST_SCRIPT_PACKAGE_NAME: &str = "test_script";
+    /// Name of the library package with fuzz tests in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_FUZZ_PACKAGE_NAME: &str = "test_fuzz";
+    /// Name of the library package used for gas profiling in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_PROFILE_PACKAGE_NAME: &str = "test_profile";
+    /// Name of the contract package with logging tests in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_LOGS_PACKAGE_NAME: &str = "test_logs";
+    /// Name of the contract package with storage fixtures in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_STORAGE_PACKAGE_NAME: &str = "test_storage";
 
     /// Build the tests in the test package with the given name located at
     /// "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME/TEST_LIBRARY_PACKAGE_NAME".
@@ -870,7 +1048,7 @@ mod tests {
     ) -> anyhow::Result<Vec<TestResult>> {
         let built_tests = test_package_built_tests(package_name)?;
         let test_runner_count = crate::TestRunnerCount::Auto;
-        let tested = built_tests.run(test_runner_count, test_filter)?;
+        let tested = built_tests.run(test_runner_count, test_filter, Default::default())?;
         match tested {
             crate::Tested::Package(tested_pkg) => Ok(tested_pkg.tests),
             crate::Tested::Workspace(_) => {
@@ -1069,4 +1247,156 @@ mod tests {
         assert_eq!(tested_predicate_test_count, 2);
         assert_eq!(tested_script_test_count, 2);
     }
+
+    #[cfg(feature = "profile")]
+    #[test]
+    fn test_contract_coverage() {
+        let built_tests = test_package_built_tests(TEST_CONTRACT_PACKAGE_NAME).unwrap();
+        let tested = built_tests
+            .run(crate::TestRunnerCount::Auto, None, Default::default())
+            .unwrap();
+        let tested_pkg = match tested {
+            crate::Tested::Package(tested_pkg) => tested_pkg,
+            crate::Tested::Workspace(_) => {
+                unreachable!("test_contract is a package, not a workspace.")
+            }
+        };
+        let coverage = Coverage::from_tested_pkg(&tested_pkg);
+        let main_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join(TEST_DATA_FOLDER_NAME)
+            .join(TEST_CONTRACT_PACKAGE_NAME)
+            .join("src")
+            .join("main.sw");
+        let main_coverage = coverage.files.get(&main_path).unwrap();
+
+        // The body of the contract method is instrumented through the deployed contract, but it
+        // is never called by the tests.
+        assert_eq!(main_coverage.lines.get(&9), Some(&0));
+    }
+
+    #[test]
+    fn test_fuzz_counterexamples() {
+        let test_results = test_package_test_results(TEST_FUZZ_PACKAGE_NAME, None).unwrap();
+        let counterexample = |name: &str| {
+            let test = test_results.iter().find(|test| test.name == name).unwrap();
+            let fuzz = test.fuzz.as_ref().unwrap();
+            fuzz.counterexample.as_ref().map(|counterexample| {
+                assert!(!test.passed());
+                counterexample
+                    .inputs
+                    .iter()
+                    .map(|input| input.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+        };
+
+        assert_eq!(counterexample("test_fuzz_pass"), None);
+        assert_eq!(
+            counterexample("test_fuzz_single_u8").as_deref(),
+            Some("a = 200")
+        );
+        assert_eq!(
+            counterexample("test_fuzz_struct").as_deref(),
+            Some("flag = true, p = Point { x: 1000, y: 0 }")
+        );
+        assert_eq!(
+            counterexample("test_fuzz_enum").as_deref(),
+            Some("opt = Option::Some(10)")
+        );
+        assert_eq!(
+            counterexample("test_fuzz_should_revert").as_deref(),
+            Some("x = 0")
+        );
+    }
+
+    #[cfg(feature = "profile")]
+    #[test]
+    fn test_gas_profile() {
+        let built_tests = test_package_built_tests(TEST_PROFILE_PACKAGE_NAME).unwrap();
+        let tested = built_tests
+            .run(crate::TestRunnerCount::Auto, None, Default::default())
+            .unwrap();
+        let tested_pkg = match tested {
+            crate::Tested::Package(tested_pkg) => tested_pkg,
+            crate::Tested::Workspace(_) => {
+                unreachable!("test_profile is a package, not a workspace.")
+            }
+        };
+        let profile = GasProfile::from_tested_pkg(&tested_pkg);
+
+        // Every unit of gas used by the tests is attributed to some stack.
+        let gas_used: u64 = tested_pkg.tests.iter().map(|test| test.gas_used).sum();
+        assert_eq!(profile.total_gas(), gas_used);
+
+        // `double` is inlined into the loop of `sum_doubled`, which isn't inlined into the test.
+        let stack = ["test_sum", "sum_doubled", "double", "multiply"].map(String::from);
+        assert!(profile.stacks.get(&stack[..]).is_some_and(|gas| *gas > 0));
+
+        let sum_doubled = profile
+            .functions()
+            .into_iter()
+            .find(|function| function.name == "sum_doubled")
+            .unwrap();
+        let double = profile
+            .functions()
+            .into_iter()
+            .find(|function| function.name == "double")
+            .unwrap();
+        assert!(sum_doubled.total_gas > double.total_gas);
+        assert!(sum_doubled.total_gas > sum_doubled.self_gas);
+    }
+
+    #[test]
+    fn test_decoded_logs() {
+        let test_results = test_package_test_results(TEST_LOGS_PACKAGE_NAME, None).unwrap();
+        let decoded_logs = |name: &str| {
+            let test = test_results.iter().find(|test| test.name == name).unwrap();
+            assert_eq!(test.decoded_logs.len(), test.logs.len());
+            test.decoded_logs
+                .iter()
+                .map(|decoded| decoded.as_ref().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            decoded_logs("test_log_values"),
+            vec![
+                "42",
+                "true",
+                "\"hello\"",
+                "Point { x: 1, y: 2 }",
+                "Shape::Circle((Point { x: 3, y: 4 }, 5))",
+                "Shape::Empty",
+                "(7, [8, 9])",
+            ]
+        );
+        // Logs of the contract under test are decoded using the ABI of the contract built without
+        // tests.
+        assert_eq!(
+            decoded_logs("test_log_contract"),
+            vec!["Shape::Square(Point { x: 10, y: 11 })"]
+        );
+    }
+
+    #[test]
+    fn test_storage_fixtures() {
+        let test_results = test_package_test_results(TEST_STORAGE_PACKAGE_NAME, None).unwrap();
+        assert_eq!(test_results.len(), 4);
+        assert!(test_results.iter().all(|test| test.passed()));
+
+        // Only `test_increment` writes to storage, over the value seeded for all tests.
+        let test_increment = test_results
+            .iter()
+            .find(|test| test.name == "test_increment")
+            .unwrap();
+        assert_eq!(test_increment.storage_diff.len(), 1);
+        let change = &test_increment.storage_diff[0];
+        assert_eq!(change.previous.unwrap()[7], 42);
+        assert_eq!(change.current.unwrap()[7], 43);
+        assert!(test_results
+            .iter()
+            .filter(|test| test.name != "test_increment")
+            .all(|test| test.storage_diff.is_empty()));
+    }
 }
@@ -0,0 +1,109 @@
+//! Decoding of the values logged by tests.
+//!
+//! Each `Log` and `LogData` receipt carries the id of the logged type in its `rb` register. The
+//! program ABI of the context emitting the receipt maps that id to the type of the logged value,
+//! which is held in the `ra` register for copy types and in the receipt data otherwise.
+
+use crate::{
+    abi::{ParamType, Value},
+    deployed_contract_id,
+};
+use forc_pkg::{self as pkg, program_abi};
+use fuel_tx as tx;
+use std::{collections::HashMap, fmt, sync::Arc};
+use sway_core::asm_generation::ProgramABI;
+
+/// A value logged by a test, decoded using the program ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedLog {
+    pub param_type: ParamType,
+    pub value: Value,
+}
+
+/// The types logged by each program executed by the tests of a package, keyed by context.
+#[derive(Debug, Default)]
+pub(crate) struct LogDecoder {
+    logged_types: HashMap<tx::ContractId, HashMap<u64, ParamType>>,
+}
+
+impl fmt::Display for DecodedLog {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.param_type.fmt_value(&self.value, f)
+    }
+}
+
+impl LogDecoder {
+    /// Collect the logged types of the programs executed by the tests of the given package.
+    ///
+    /// The tests themselves run as scripts, while the contract under test and the contract
+    /// dependencies are deployed without tests.
+    pub(crate) fn new<'a>(
+        pkg_with_tests: &pkg::BuiltPackage,
+        contract_dependencies: impl Iterator<Item = &'a Arc<pkg::BuiltPackage>>,
+    ) -> LogDecoder {
+        let mut decoder = LogDecoder::default();
+        decoder.insert(tx::ContractId::zeroed(), &pkg_with_tests.program_abi);
+        if let Some((bytecode, program_abi)) = pkg_with_tests
+            .bytecode_without_tests
+            .as_ref()
+            .zip(pkg_with_tests.program_abi_without_tests())
+        {
+            let contract_id = deployed_contract_id(pkg_with_tests, &bytecode.bytes);
+            decoder.insert(contract_id, program_abi);
+        }
+        for dep in contract_dependencies {
+            let contract_id = deployed_contract_id(dep, &dep.bytecode.bytes);
+            decoder.insert(contract_id, &dep.program_abi);
+        }
+        decoder
+    }
+
+    /// Insert the logged types of the program executed in the given context. Types which cannot
+    /// be resolved are left out, so that their logs are not decoded.
+    fn insert(&mut self, context: tx::ContractId, program_abi: &ProgramABI) {
+        let ProgramABI::Fuel(program_abi) = program_abi else {
+            return;
+        };
+        let types: HashMap<usize, &program_abi::TypeDeclaration> = program_abi
+            .types
+            .iter()
+            .map(|type_decl| (type_decl.type_id, type_decl))
+            .collect();
+        let logged_types = program_abi
+            .logged_types
+            .iter()
+            .flatten()
+            .filter_map(|logged_type| {
+                let param_type =
+                    ParamType::resolve(&logged_type.application, &types, &HashMap::new()).ok()?;
+                Some((logged_type.log_id, param_type))
+            })
+            .collect();
+        self.logged_types.insert(context, logged_types);
+    }
+
+    /// Decode the value logged by the given receipt.
+    ///
+    /// Returns `None` for receipts other than `Log` and `LogData`, and for values whose type is
+    /// unknown or which do not match their type.
+    pub(crate) fn decode(&self, receipt: &tx::Receipt) -> Option<DecodedLog> {
+        let (id, log_id) = match receipt {
+            tx::Receipt::Log { id, rb, .. } | tx::Receipt::LogData { id, rb, .. } => (id, rb),
+            _ => return None,
+        };
+        let param_type = self.logged_types.get(id)?.get(log_id)?;
+        let value = match receipt {
+            tx::Receipt::Log { ra, .. } if param_type.is_copy_type() => {
+                param_type.decode_register(*ra)
+            }
+            tx::Receipt::LogData {
+                data: Some(data), ..
+            } if !param_type.is_copy_type() => param_type.decode(data),
+            _ => return None,
+        };
+        Some(DecodedLog {
+            param_type: param_type.clone(),
+            value: value.ok()?,
+        })
+    }
+}
@@ -0,0 +1,137 @@
+//! Gas profiles of executed tests.
+//!
+//! The VM records the gas spent by the instructions at each location. Every instruction is
+//! attributed through the source map of the bytecode it belongs to, to the function it was
+//! compiled from, followed by the functions that were inlined into that function at the
+//! instruction's source location.
+//!
+//! The VM does not record call stacks, so a call which was not inlined shows up directly under the
+//! test rather than under its caller. Gas spent by instructions without a known function, such as
+//! the entry of the test script, is attributed to the test itself.
+
+use crate::{coverage::instrumented_programs, TestResult, TestedPackage};
+use fuel_tx as tx;
+use fuel_vm::fuel_asm::Instruction;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Write,
+};
+use sway_core::source_map::SourceMap;
+
+/// The gas spent by a set of tests, keyed by stack of function names.
+///
+/// Each stack starts with the name of the test, followed by the functions the gas was spent in,
+/// outermost first.
+#[derive(Debug, Default, Clone)]
+pub struct GasProfile {
+    pub stacks: BTreeMap<Vec<String>, u64>,
+}
+
+/// The gas spent in a single function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionGas {
+    pub name: String,
+    /// The gas spent by the instructions of the function itself.
+    pub self_gas: u64,
+    /// The gas spent by the instructions of the function and of the functions inlined into it.
+    pub total_gas: u64,
+}
+
+impl GasProfile {
+    /// Collect the gas profile of all tests executed for the given package.
+    pub fn from_tested_pkg(tested_pkg: &TestedPackage) -> GasProfile {
+        let source_maps: HashMap<Option<tx::ContractId>, &SourceMap> =
+            instrumented_programs(tested_pkg)
+                .into_iter()
+                .map(|program| (program.context, program.source_map))
+                .collect();
+        let mut profile = GasProfile::default();
+        for test in &tested_pkg.tests {
+            profile.add_test(test, &source_maps);
+        }
+        profile
+    }
+
+    fn add_test(
+        &mut self,
+        test: &TestResult,
+        source_maps: &HashMap<Option<tx::ContractId>, &SourceMap>,
+    ) {
+        for (location, gas) in &test.gas_per_instruction {
+            let pc = location.offset() as usize / Instruction::SIZE;
+            let functions = source_maps
+                .get(&location.context())
+                .and_then(|source_map| source_map.addr_to_functions(pc))
+                .unwrap_or_default();
+            let mut stack = vec![test.name.clone()];
+            // Instructions of the test function itself are attributed to the test.
+            let functions = match functions.split_first() {
+                Some((first, rest)) if *first == test.name => rest,
+                _ => &functions[..],
+            };
+            stack.extend(functions.iter().map(|name| name.to_string()));
+            *self.stacks.entry(stack).or_default() += gas;
+        }
+    }
+
+    /// Add the stacks of `other` into this profile, summing their gas.
+    pub fn merge(&mut self, other: GasProfile) {
+        for (stack, gas) in other.stacks {
+            *self.stacks.entry(stack).or_default() += gas;
+        }
+    }
+
+    /// The gas spent over all stacks.
+    pub fn total_gas(&self) -> u64 {
+        self.stacks.values().sum()
+    }
+
+    /// The gas spent in each function, excluding the tests themselves, in order of decreasing self
+    /// gas.
+    pub fn functions(&self) -> Vec<FunctionGas> {
+        let mut functions: HashMap<&str, FunctionGas> = HashMap::new();
+        for (stack, gas) in &self.stacks {
+            let Some((leaf, callers)) = stack[1..].split_last() else {
+                continue;
+            };
+            let function = |name: &str| FunctionGas {
+                name: name.to_owned(),
+                self_gas: 0,
+                total_gas: 0,
+            };
+            functions
+                .entry(leaf)
+                .or_insert_with(|| function(leaf))
+                .self_gas += gas;
+            // Names may repeat within a stack, e.g. for methods of different types sharing a
+            // name, so each function is counted once per stack.
+            let mut seen = HashSet::new();
+            for name in callers.iter().chain(std::iter::once(leaf)) {
+                if seen.insert(name) {
+                    functions
+                        .entry(name)
+                        .or_insert_with(|| function(name))
+                        .total_gas += gas;
+                }
+            }
+        }
+        let mut functions: Vec<FunctionGas> = functions.into_values().collect();
+        functions.sort_by(|a, b| {
+            b.self_gas
+                .cmp(&a.self_gas)
+                .then_with(|| b.total_gas.cmp(&a.total_gas))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        functions
+    }
+
+    /// Render this profile in the folded stack format read by flamegraph tools, i.e. one line per
+    /// stack of `;` separated function names followed by the gas spent.
+    pub fn to_folded(&self) -> String {
+        let mut folded = String::new();
+        for (stack, gas) in self.stacks.iter().filter(|(_, gas)| **gas > 0) {
+            writeln!(folded, "{} {gas}", stack.join(";")).unwrap();
+        }
+        folded
+    }
+}
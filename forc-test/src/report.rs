@@ -0,0 +1,272 @@
+//! Machine-readable reports of executed tests, as JSON or as JUnit XML.
+
+use crate::{storage::StorageChange, TestResult, Tested, TestedPackage};
+use forc_util::tx_utils::log_receipts_to_json;
+use serde::Serialize;
+use std::{fmt::Write, path::PathBuf};
+
+/// The results of all tested packages.
+#[derive(Debug, Serialize)]
+pub struct TestReport {
+    pub packages: Vec<PackageReport>,
+}
+
+/// The results of the tests of a single package.
+#[derive(Debug, Serialize)]
+pub struct PackageReport {
+    pub name: String,
+    pub passed: usize,
+    pub failed: usize,
+    /// The time taken by all tests of the package, in seconds.
+    pub duration: f64,
+    pub tests: Vec<TestCaseReport>,
+}
+
+/// The result of a single test.
+#[derive(Debug, Serialize)]
+pub struct TestCaseReport {
+    pub name: String,
+    pub file: PathBuf,
+    /// The line of the test declaration, if the source file could still be read.
+    pub line: Option<usize>,
+    pub passed: bool,
+    /// The time taken by the test, in seconds.
+    pub duration: f64,
+    pub gas_used: u64,
+    pub revert_code: Option<u64>,
+    /// The known error signal of the revert code, if any.
+    pub error_signal: Option<String>,
+    /// The `Log` and `LogData` receipts emitted by the test.
+    pub logs: serde_json::Value,
+    /// The value logged by each of the `logs` as it would be written in Sway, if it was decoded.
+    pub decoded_logs: Vec<Option<String>>,
+    /// The storage slots written by the test.
+    pub storage_diff: Vec<StorageChange>,
+    pub fuzz: Option<FuzzCaseReport>,
+}
+
+/// The outcome of fuzzing a test.
+#[derive(Debug, Serialize)]
+pub struct FuzzCaseReport {
+    pub runs: usize,
+    /// The smallest failing inputs found, formatted as `name = value` pairs.
+    pub counterexample: Option<String>,
+    /// The number of times the counterexample was shrunk.
+    pub shrinks: usize,
+}
+
+impl TestReport {
+    /// Collect the report of the given tested package or workspace.
+    pub fn from_tested(tested: &Tested) -> anyhow::Result<TestReport> {
+        let pkgs = match tested {
+            Tested::Package(pkg) => std::slice::from_ref(&**pkg),
+            Tested::Workspace(pkgs) => &pkgs[..],
+        };
+        let packages = pkgs
+            .iter()
+            .map(PackageReport::from_tested_pkg)
+            .collect::<anyhow::Result<_>>()?;
+        Ok(TestReport { packages })
+    }
+
+    /// Render this report as pretty-printed JSON.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Render this report as JUnit XML, with a test suite per package.
+    pub fn to_junit(&self) -> anyhow::Result<String> {
+        let mut xml = String::new();
+        let tests: usize = self.packages.iter().map(|pkg| pkg.tests.len()).sum();
+        let failures: usize = self.packages.iter().map(|pkg| pkg.failed).sum();
+        let time: f64 = self.packages.iter().map(|pkg| pkg.duration).sum();
+        writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            xml,
+            r#"<testsuites name="forc test" tests="{tests}" failures="{failures}" time="{time}">"#
+        )?;
+        for pkg in &self.packages {
+            writeln!(
+                xml,
+                r#"  <testsuite name="{}" tests="{}" failures="{}" time="{}">"#,
+                escape_xml(&pkg.name),
+                pkg.tests.len(),
+                pkg.failed,
+                pkg.duration
+            )?;
+            for test in &pkg.tests {
+                let line = test
+                    .line
+                    .map(|line| format!(r#" line="{line}""#))
+                    .unwrap_or_default();
+                writeln!(
+                    xml,
+                    r#"    <testcase name="{}" classname="{}" file="{}"{line} time="{}">"#,
+                    escape_xml(&test.name),
+                    escape_xml(&pkg.name),
+                    escape_xml(&test.file.display().to_string()),
+                    test.duration
+                )?;
+                if !test.passed {
+                    let mut message = match test.revert_code {
+                        Some(revert_code) => format!("revert code: {revert_code:x}"),
+                        None => "test did not meet its pass condition".to_string(),
+                    };
+                    if let Some(error_signal) = &test.error_signal {
+                        write!(message, " -- {error_signal}")?;
+                    }
+                    if let Some(counterexample) = test
+                        .fuzz
+                        .as_ref()
+                        .and_then(|fuzz| fuzz.counterexample.as_ref())
+                    {
+                        write!(message, " -- counterexample: {counterexample}")?;
+                    }
+                    writeln!(
+                        xml,
+                        r#"      <failure message="{}"/>"#,
+                        escape_xml(&message)
+                    )?;
+                }
+                if test.logs.as_array().is_some_and(|logs| !logs.is_empty()) {
+                    writeln!(
+                        xml,
+                        "      <system-out>{}</system-out>",
+                        escape_xml(&serde_json::to_string(&test.logs)?)
+                    )?;
+                }
+                writeln!(xml, "    </testcase>")?;
+            }
+            writeln!(xml, "  </testsuite>")?;
+        }
+        writeln!(xml, "</testsuites>")?;
+        Ok(xml)
+    }
+}
+
+impl PackageReport {
+    fn from_tested_pkg(tested_pkg: &TestedPackage) -> anyhow::Result<PackageReport> {
+        let tests = tested_pkg
+            .tests
+            .iter()
+            .map(TestCaseReport::from_test_result)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let passed = tests.iter().filter(|test| test.passed).count();
+        Ok(PackageReport {
+            name: tested_pkg.built.descriptor.name.clone(),
+            passed,
+            failed: tests.len() - passed,
+            duration: tests.iter().map(|test| test.duration).sum(),
+            tests,
+        })
+    }
+}
+
+impl TestCaseReport {
+    fn from_test_result(test: &TestResult) -> anyhow::Result<TestCaseReport> {
+        let fuzz = test.fuzz.as_ref().map(|fuzz| FuzzCaseReport {
+            runs: fuzz.runs,
+            counterexample: fuzz.counterexample.as_ref().map(|counterexample| {
+                counterexample
+                    .inputs
+                    .iter()
+                    .map(|input| input.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }),
+            shrinks: fuzz
+                .counterexample
+                .as_ref()
+                .map(|counterexample| counterexample.shrinks)
+                .unwrap_or_default(),
+        });
+        Ok(TestCaseReport {
+            name: test.name.clone(),
+            file: test.file_path.to_path_buf(),
+            line: test.details().ok().map(|details| details.line_number),
+            passed: test.passed(),
+            duration: test.duration.as_secs_f64(),
+            gas_used: test.gas_used,
+            revert_code: test.revert_code(),
+            error_signal: test.error_signal().ok().map(|signal| signal.to_string()),
+            logs: log_receipts_to_json(&test.logs)?,
+            decoded_logs: test
+                .decoded_logs
+                .iter()
+                .map(|decoded| decoded.as_ref().map(|decoded| decoded.to_string()))
+                .collect(),
+            storage_diff: test.storage_diff.clone(),
+            fuzz,
+        })
+    }
+}
+
+/// Escape the characters with a special meaning in XML attributes and text.
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_case(name: &str, passed: bool, revert_code: Option<u64>) -> TestCaseReport {
+        TestCaseReport {
+            name: name.to_string(),
+            file: PathBuf::from("src/lib.sw"),
+            line: Some(3),
+            passed,
+            duration: 0.5,
+            gas_used: 18,
+            revert_code,
+            error_signal: None,
+            logs: serde_json::Value::Array(vec![]),
+            decoded_logs: vec![],
+            storage_diff: vec![],
+            fuzz: None,
+        }
+    }
+
+    #[test]
+    fn junit() {
+        let report = TestReport {
+            packages: vec![PackageReport {
+                name: "pkg<1>".to_string(),
+                passed: 1,
+                failed: 1,
+                duration: 1.0,
+                tests: vec![
+                    test_case("test_ok", true, None),
+                    test_case("test_revert", false, Some(42)),
+                ],
+            }],
+        };
+        let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="forc test" tests="2" failures="1" time="1">
+  <testsuite name="pkg&lt;1&gt;" tests="2" failures="1" time="1">
+    <testcase name="test_ok" classname="pkg&lt;1&gt;" file="src/lib.sw" line="3" time="0.5">
+    </testcase>
+    <testcase name="test_revert" classname="pkg&lt;1&gt;" file="src/lib.sw" line="3" time="0.5">
+      <failure message="revert code: 2a"/>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#;
+        assert_eq!(report.to_junit().unwrap(), expected);
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["packages"][0]["tests"][1]["revert_code"], 42);
+        assert_eq!(json["packages"][0]["failed"], 1);
+    }
+}
@@ -0,0 +1,189 @@
+//! Gas snapshots of executed tests.
+//!
+//! A snapshot records the gas used by each test of a package, one test per line, so that changes
+//! in gas usage show up in the diff of the checked-in snapshot file. Comparing the gas used by a
+//! later run against a snapshot reports the tests whose gas usage changed.
+//!
+//! Fuzz tests are left out, as the gas they use depends on their generated inputs.
+
+use crate::TestedPackage;
+use anyhow::{bail, Context};
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+/// The name of the snapshot file, located next to the manifest of the package.
+pub const GAS_SNAPSHOT_FILE_NAME: &str = ".gas-snapshot";
+
+/// The gas used by each test of a package, keyed by test name.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GasSnapshot {
+    pub tests: BTreeMap<String, u64>,
+}
+
+/// The differences between a snapshot and the gas used by a later run.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GasSnapshotDiff {
+    /// The tests whose gas usage changed, by name.
+    pub changed: Vec<GasChange>,
+    /// The tests which are not in the snapshot, with the gas they used.
+    pub added: Vec<(String, u64)>,
+    /// The tests in the snapshot which were not run, with the gas they used in the snapshot.
+    pub removed: Vec<(String, u64)>,
+}
+
+/// A change in the gas used by a single test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasChange {
+    pub name: String,
+    pub previous: u64,
+    pub current: u64,
+}
+
+impl GasSnapshot {
+    /// The gas used by each test executed for the given package, except for fuzz tests.
+    pub fn from_tested_pkg(tested_pkg: &TestedPackage) -> GasSnapshot {
+        let tests = tested_pkg
+            .tests
+            .iter()
+            .filter(|test| test.fuzz.is_none())
+            .map(|test| (test.name.clone(), test.gas_used))
+            .collect();
+        GasSnapshot { tests }
+    }
+
+    /// Parse a snapshot in the format written by its `Display` implementation, i.e. lines of
+    /// `<test name> (gas: <gas used>)`.
+    pub fn parse(src: &str) -> anyhow::Result<GasSnapshot> {
+        let mut tests = BTreeMap::new();
+        for (ix, line) in src.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let parsed = line
+                .strip_suffix(')')
+                .and_then(|line| line.split_once(" (gas: "))
+                .and_then(|(name, gas)| Some((name.trim(), gas.parse::<u64>().ok()?)));
+            let Some((name, gas)) = parsed else {
+                bail!("Invalid gas snapshot entry on line {}: {line}", ix + 1);
+            };
+            if tests.insert(name.to_owned(), gas).is_some() {
+                bail!("Duplicate gas snapshot entry for test {name}");
+            }
+        }
+        Ok(GasSnapshot { tests })
+    }
+
+    /// Read and parse the snapshot file at the given path.
+    pub fn from_file(path: &Path) -> anyhow::Result<GasSnapshot> {
+        let src = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read gas snapshot {}", path.display()))?;
+        Self::parse(&src).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Compare the gas used by `current` against this snapshot.
+    pub fn diff(&self, current: &GasSnapshot) -> GasSnapshotDiff {
+        let mut diff = GasSnapshotDiff::default();
+        for (name, &gas) in &current.tests {
+            match self.tests.get(name) {
+                Some(&previous) if previous != gas => diff.changed.push(GasChange {
+                    name: name.clone(),
+                    previous,
+                    current: gas,
+                }),
+                Some(_) => (),
+                None => diff.added.push((name.clone(), gas)),
+            }
+        }
+        diff.removed = self
+            .tests
+            .iter()
+            .filter(|(name, _)| !current.tests.contains_key(*name))
+            .map(|(name, gas)| (name.clone(), *gas))
+            .collect();
+        diff
+    }
+}
+
+impl fmt::Display for GasSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, gas) in &self.tests {
+            writeln!(f, "{name} (gas: {gas})")?;
+        }
+        Ok(())
+    }
+}
+
+impl GasSnapshotDiff {
+    /// The changes where gas used rose by more than `threshold` percent of the snapshot.
+    pub fn regressions(&self, threshold: f64) -> impl Iterator<Item = &GasChange> {
+        self.changed
+            .iter()
+            .filter(move |change| change.percentage() > threshold)
+    }
+}
+
+impl GasChange {
+    /// The change in gas used, as a percentage of the gas used in the snapshot.
+    pub fn percentage(&self) -> f64 {
+        let delta = self.current as f64 - self.previous as f64;
+        match self.previous {
+            0 => f64::INFINITY.copysign(delta),
+            previous => delta * 100.0 / previous as f64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(tests: &[(&str, u64)]) -> GasSnapshot {
+        GasSnapshot {
+            tests: tests
+                .iter()
+                .map(|(name, gas)| (name.to_string(), *gas))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parse_roundtrip() {
+        let snapshot = snapshot(&[("test_bam", 18), ("test_bum", 1234)]);
+        let src = snapshot.to_string();
+        assert_eq!(src, "test_bam (gas: 18)\ntest_bum (gas: 1234)\n");
+        assert_eq!(GasSnapshot::parse(&src).unwrap(), snapshot);
+
+        assert!(GasSnapshot::parse("test_bam 18\n").is_err());
+        assert!(GasSnapshot::parse("test_bam (gas: 1)\ntest_bam (gas: 2)\n").is_err());
+    }
+
+    #[test]
+    fn diff_and_regressions() {
+        let previous = snapshot(&[("a", 100), ("b", 100), ("c", 100), ("d", 100)]);
+        let current = snapshot(&[("a", 100), ("b", 105), ("c", 120), ("e", 7)]);
+        let diff = previous.diff(&current);
+
+        assert_eq!(diff.added, vec![("e".to_string(), 7)]);
+        assert_eq!(diff.removed, vec![("d".to_string(), 100)]);
+        let names = |changes: Vec<&GasChange>| {
+            changes
+                .into_iter()
+                .map(|change| change.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(diff.changed.iter().collect()), vec!["b", "c"]);
+        assert_eq!(names(diff.regressions(0.0).collect()), vec!["b", "c"]);
+        assert_eq!(names(diff.regressions(10.0).collect()), vec!["c"]);
+        assert_eq!(
+            names(diff.regressions(20.0).collect()),
+            Vec::<String>::new()
+        );
+
+        let decrease = GasChange {
+            name: "f".to_string(),
+            previous: 200,
+            current: 150,
+        };
+        assert_eq!(decrease.percentage(), -25.0);
+    }
+}
@@ -0,0 +1,257 @@
+//! Storage fixtures applied before tests, and the storage changes made by tests.
+//!
+//! A fixture file next to the manifest of a package seeds the storage slots and balances of the
+//! contracts deployed for its tests, i.e. the contract under test and its contract dependencies.
+//! Contracts are referred to by package name. The state under `all` is applied before every test,
+//! followed by the state under the name of the test, if any:
+//!
+//! ```json
+//! {
+//!   "all": {
+//!     "my_contract": {
+//!       "storage": [{ "key": "<slot key>", "value": "<slot value>" }],
+//!       "balances": { "<asset id>": 100 }
+//!     }
+//!   },
+//!   "tests": {
+//!     "test_withdraw": { "my_contract": { "balances": { "<asset id>": 0 } } }
+//!   }
+//! }
+//! ```
+//!
+//! Storage slots are in the format of the storage slots file written by `forc build`.
+
+use anyhow::{bail, Context};
+use fuel_tx as tx;
+use fuel_vm::storage::{ContractsAssetsStorage, InterpreterStorage, MemoryStorage};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+/// The name of the fixture file, located next to the manifest of the package.
+pub const TEST_FIXTURES_FILE_NAME: &str = "test-fixtures.json";
+
+/// The state to apply before the tests of a package.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestFixtures {
+    /// The state applied before every test.
+    #[serde(default)]
+    pub all: Fixture,
+    /// The state applied before the test with the given name, after `all`.
+    #[serde(default)]
+    pub tests: BTreeMap<String, Fixture>,
+}
+
+/// The state of the deployed contracts, keyed by package name.
+pub type Fixture = BTreeMap<String, ContractFixture>;
+
+/// The state of a single deployed contract.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContractFixture {
+    /// Storage slots written over those of the deployed contract.
+    #[serde(default)]
+    pub storage: Vec<tx::StorageSlot>,
+    /// The balance of the contract for each asset.
+    #[serde(default)]
+    pub balances: BTreeMap<tx::AssetId, u64>,
+}
+
+/// A storage slot written by a test.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StorageChange {
+    pub contract_id: tx::ContractId,
+    pub key: tx::Bytes32,
+    /// The value of the slot before the test, or `None` if it was unset.
+    pub previous: Option<tx::Bytes32>,
+    /// The value of the slot after the test, or `None` if it was cleared.
+    pub current: Option<tx::Bytes32>,
+}
+
+impl TestFixtures {
+    /// Parse fixtures from their JSON representation.
+    pub fn parse(src: &str) -> anyhow::Result<TestFixtures> {
+        Ok(serde_json::from_str(src)?)
+    }
+
+    /// Read the fixture file of the package in the given directory, if there is one.
+    pub fn from_dir(dir: &Path) -> anyhow::Result<Option<TestFixtures>> {
+        let path = dir.join(TEST_FIXTURES_FILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let src = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read test fixtures {}", path.display()))?;
+        let fixtures =
+            Self::parse(&src).with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Some(fixtures))
+    }
+
+    /// Check that the fixtures only refer to the given tests.
+    pub(crate) fn validate<'a>(
+        &self,
+        test_names: impl Iterator<Item = &'a str>,
+    ) -> anyhow::Result<()> {
+        let test_names: HashSet<&str> = test_names.collect();
+        if let Some(name) = self
+            .tests
+            .keys()
+            .find(|name| !test_names.contains(name.as_str()))
+        {
+            bail!("test fixture for unknown test `{name}`");
+        }
+        Ok(())
+    }
+
+    /// Apply the state for the test with the given name to `storage`.
+    pub(crate) fn apply(
+        &self,
+        test_name: &str,
+        storage: &mut MemoryStorage,
+        contract_ids: &HashMap<&str, tx::ContractId>,
+    ) -> anyhow::Result<()> {
+        let fixtures = std::iter::once(&self.all).chain(self.tests.get(test_name));
+        for fixture in fixtures {
+            for (contract_name, contract_fixture) in fixture {
+                let Some(contract_id) = contract_ids.get(contract_name.as_str()) else {
+                    bail!(
+                        "test fixture for `{contract_name}`, which is not deployed for the tests"
+                    );
+                };
+                contract_fixture.apply(contract_id, storage)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ContractFixture {
+    fn apply(
+        &self,
+        contract_id: &tx::ContractId,
+        storage: &mut MemoryStorage,
+    ) -> anyhow::Result<()> {
+        for slot in &self.storage {
+            storage.merkle_contract_state_insert(contract_id, slot.key(), slot.value())?;
+        }
+        for (asset_id, amount) in &self.balances {
+            storage.merkle_contract_asset_id_balance_insert(contract_id, asset_id, *amount)?;
+        }
+        Ok(())
+    }
+}
+
+/// The storage slots whose values differ between `previous` and `current`, ordered by contract
+/// and key.
+pub(crate) fn storage_diff(
+    previous: &MemoryStorage,
+    current: &MemoryStorage,
+) -> Vec<StorageChange> {
+    let previous: BTreeMap<_, _> = previous.all_contract_state().collect();
+    let current: BTreeMap<_, _> = current.all_contract_state().collect();
+    let mut keys: Vec<_> = previous.keys().chain(current.keys()).copied().collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter_map(|key| {
+            let previous = previous.get(key).map(|value| **value);
+            let current = current.get(key).map(|value| **value);
+            (previous != current).then(|| StorageChange {
+                contract_id: *key.contract_id(),
+                key: *key.state_key(),
+                previous,
+                current,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_and_diff() {
+        let fixtures = TestFixtures::parse(
+            r#"{
+                "all": {
+                    "a": { "storage": [{ "key": "0x01", "value": "0x02" }] }
+                },
+                "tests": {
+                    "test_b": {
+                        "b": { "balances": { "0x03": 7 } },
+                        "a": { "storage": [{ "key": "0x01", "value": "0x04" }] }
+                    }
+                }
+            }"#,
+        );
+        assert!(fixtures.is_err(), "keys and values are full words");
+
+        let word = |byte: u8| {
+            let mut word = [0u8; 32];
+            word[31] = byte;
+            tx::Bytes32::from(word)
+        };
+        let fixtures = TestFixtures::parse(&format!(
+            r#"{{
+                "all": {{
+                    "a": {{ "storage": [{{ "key": "{one:x}", "value": "{two:x}" }}] }}
+                }},
+                "tests": {{
+                    "test_b": {{
+                        "b": {{ "balances": {{ "{three:x}": 7 }} }},
+                        "a": {{ "storage": [{{ "key": "{one:x}", "value": "{four:x}" }}] }}
+                    }}
+                }}
+            }}"#,
+            one = word(1),
+            two = word(2),
+            three = word(3),
+            four = word(4),
+        ))
+        .unwrap();
+        assert!(fixtures.validate(["test_a", "test_b"].into_iter()).is_ok());
+        assert!(fixtures.validate(["test_a"].into_iter()).is_err());
+
+        let a = tx::ContractId::from(*word(10));
+        let b = tx::ContractId::from(*word(11));
+        let contract_ids = HashMap::from([("a", a), ("b", b)]);
+        let initial = MemoryStorage::default();
+
+        let mut storage = initial.clone();
+        fixtures
+            .apply("test_a", &mut storage, &contract_ids)
+            .unwrap();
+        assert_eq!(*storage.contract_state(&a, &word(1)), word(2));
+
+        let mut storage = initial.clone();
+        fixtures
+            .apply("test_b", &mut storage, &contract_ids)
+            .unwrap();
+        assert_eq!(*storage.contract_state(&a, &word(1)), word(4));
+        let balance = storage
+            .merkle_contract_asset_id_balance(&b, &tx::AssetId::from(*word(3)))
+            .unwrap();
+        assert_eq!(balance, Some(7));
+
+        let diff = storage_diff(&initial, &storage);
+        assert_eq!(
+            diff,
+            vec![StorageChange {
+                contract_id: a,
+                key: word(1),
+                previous: None,
+                current: Some(word(4)),
+            }]
+        );
+
+        let contract_ids = HashMap::from([("a", a)]);
+        assert!(fixtures
+            .apply("test_b", &mut storage, &contract_ids)
+            .is_err());
+    }
+}
@@ -0,0 +1,34 @@
+library;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+#[test(fuzz)]
+fn test_fuzz_pass(x: u64, y: u64) {
+    assert(x / 2 <= x);
+    assert(y % 2 < 2);
+}
+
+#[test(fuzz)]
+fn test_fuzz_single_u8(a: u8) {
+    assert(a < 200);
+}
+
+#[test(fuzz)]
+fn test_fuzz_struct(flag: bool, p: Point) {
+    assert(!flag || p.x < 1000);
+}
+
+#[test(fuzz)]
+fn test_fuzz_enum(opt: Option<u64>) {
+    if let Some(v) = opt {
+        assert(v < 10);
+    }
+}
+
+#[test(fuzz, should_revert)]
+fn test_fuzz_should_revert(x: u64) {
+    assert(x < 5);
+}
@@ -0,0 +1,42 @@
+contract;
+
+use std::logging::log;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+enum Shape {
+    Circle: (Point, u64),
+    Square: Point,
+    Empty: (),
+}
+
+abi Logger {
+    fn log_shape(x: u64);
+}
+
+impl Logger for Contract {
+    fn log_shape(x: u64) {
+        log(Shape::Square(Point { x, y: x + 1 }));
+    }
+}
+
+#[test]
+fn test_log_values() {
+    log(42u8);
+    log(true);
+    let greeting: str[5] = __to_str_array("hello");
+    log(greeting);
+    log(Point { x: 1, y: 2 });
+    log(Shape::Circle((Point { x: 3, y: 4 }, 5)));
+    log(Shape::Empty);
+    log((7u32, [8u16, 9u16]));
+}
+
+#[test]
+fn test_log_contract() {
+    let caller = abi(Logger, CONTRACT_ID);
+    caller.log_shape(10);
+}
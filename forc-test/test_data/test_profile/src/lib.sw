@@ -0,0 +1,22 @@
+library;
+
+#[inline(always)]
+fn double(x: u64) -> u64 {
+    x * 2
+}
+
+#[inline(never)]
+fn sum_doubled(n: u64) -> u64 {
+    let mut i = 0;
+    let mut sum = 0;
+    while i < n {
+        sum += double(i);
+        i += 1;
+    }
+    sum
+}
+
+#[test]
+fn test_sum() {
+    assert(sum_doubled(10) == 90);
+}
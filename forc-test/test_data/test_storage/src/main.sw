@@ -0,0 +1,57 @@
+contract;
+
+use std::{constants::BASE_ASSET_ID, context::this_balance};
+
+storage {
+    counter: u64 = 0,
+}
+
+abi Counter {
+    #[storage(read)]
+    fn get() -> u64;
+    #[storage(read, write)]
+    fn increment() -> u64;
+    fn balance() -> u64;
+}
+
+impl Counter for Contract {
+    #[storage(read)]
+    fn get() -> u64 {
+        storage.counter.read()
+    }
+
+    #[storage(read, write)]
+    fn increment() -> u64 {
+        let incremented = storage.counter.read() + 1;
+        storage.counter.write(incremented);
+        incremented
+    }
+
+    fn balance() -> u64 {
+        this_balance(BASE_ASSET_ID)
+    }
+}
+
+#[test]
+fn test_seeded_counter() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.get() == 42);
+}
+
+#[test]
+fn test_overridden_counter() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.get() == 7);
+}
+
+#[test]
+fn test_increment() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.increment() == 43);
+}
+
+#[test]
+fn test_seeded_balance() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.balance() == 500);
+}
@@ -158,6 +158,17 @@ pub mod tx_utils {
         receipts: &[fuel_tx::Receipt],
         pretty_print: bool,
     ) -> Result<String> {
+        let receipt_to_json_array = log_receipts_to_json(receipts)?;
+        if pretty_print {
+            Ok(serde_json::to_string_pretty(&receipt_to_json_array)?)
+        } else {
+            Ok(serde_json::to_string(&receipt_to_json_array)?)
+        }
+    }
+
+    /// Convert receipts to a JSON array, with the data of `LogData` and `ReturnData` receipts
+    /// encoded as hex.
+    pub fn log_receipts_to_json(receipts: &[fuel_tx::Receipt]) -> Result<serde_json::Value> {
         let mut receipt_to_json_array = serde_json::to_value(receipts)?;
         for (rec_index, receipt) in receipts.iter().enumerate() {
             let rec_value = receipt_to_json_array.get_mut(rec_index).ok_or_else(|| {
@@ -184,11 +195,7 @@ pub mod tx_utils {
                 _ => {}
             }
         }
-        if pretty_print {
-            Ok(serde_json::to_string_pretty(&receipt_to_json_array)?)
-        } else {
-            Ok(serde_json::to_string(&receipt_to_json_array)?)
-        }
+        Ok(receipt_to_json_array)
     }
 }
 
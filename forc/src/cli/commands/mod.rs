@@ -9,6 +9,7 @@ pub mod new;
 pub mod parse_bytecode;
 pub mod plugins;
 pub mod predicate_root;
+pub mod publish;
 pub mod template;
 pub mod test;
 pub mod update;
@@ -0,0 +1,38 @@
+use crate::ops::forc_publish;
+use clap::Parser;
+use forc_util::ForcResult;
+
+/// Package the project and publish it to a registry or to IPFS.
+///
+/// The package archive is written to `<project-name>/out/package` before it is published.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+
+    /// The registry to publish to, either a URL or the path to a local registry directory.
+    #[clap(long, env = "FORC_REGISTRY")]
+    pub registry: Option<String>,
+
+    /// The token authorizing uploads to a remote registry.
+    #[clap(long, env = "FORC_REGISTRY_TOKEN", hide_env_values = true)]
+    pub token: Option<String>,
+
+    /// Publish the package to IPFS rather than to a registry.
+    #[clap(long)]
+    pub ipfs: bool,
+
+    /// The URL of the API of the IPFS node to publish to. Defaults to the local IPFS node.
+    #[clap(long, requires = "ipfs")]
+    pub ipfs_api_url: Option<String>,
+
+    /// Validate and package the project without publishing it.
+    #[clap(long)]
+    pub dry_run: bool,
+}
+
+pub fn exec(command: Command) -> ForcResult<()> {
+    forc_publish::publish(command)?;
+    Ok(())
+}
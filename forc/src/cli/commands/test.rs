@@ -2,10 +2,24 @@ use crate::cli;
 use ansi_term::Colour;
 use clap::Parser;
 use forc_pkg as pkg;
-use forc_test::{TestFilter, TestRunnerCount, TestedPackage};
-use forc_util::{tx_utils::format_log_receipts, ForcError, ForcResult};
+use forc_test::{
+    coverage::Coverage,
+    fuzz::{FuzzOpts, DEFAULT_FUZZ_RUNS},
+    profile::GasProfile,
+    report::TestReport,
+    snapshot::{GasSnapshot, GAS_SNAPSHOT_FILE_NAME},
+    TestFilter, TestResult, TestRunnerCount, TestedPackage,
+};
+use forc_util::{
+    default_output_directory, forc_result_bail, tx_utils::format_log_receipts, ForcError,
+    ForcResult,
+};
+use std::{fs, path::Path, path::PathBuf};
 use tracing::info;
 
+/// The exit code of `forc test` when a test failed or the gas usage doesn't match the snapshots.
+const FAILING_UNIT_TESTS_EXIT_CODE: u8 = 101;
+
 /// Run the Sway unit tests for the current project.
 ///
 /// NOTE: Previously this command was used to support Rust integration testing, however the
@@ -20,6 +34,9 @@ use tracing::info;
 /// Unit tests decorated with the `#[test(script)]` attribute that are declared within `contract`
 /// projects may also call directly into their associated contract's ABI.
 ///
+/// Unit tests decorated with the `#[test(fuzz)]` attribute take parameters, and are executed with
+/// many randomly generated arguments. The smallest arguments found to fail the test are reported.
+///
 /// Upon successful compilation, test scripts are executed to their completion. A test is
 /// considered a failure in the case that a revert (`rvrt`) instruction is encountered during
 /// execution. Otherwise, it is considered a success.
@@ -38,6 +55,73 @@ pub struct Command {
     /// Number of threads to utilize when running the tests. By default, this is the number of
     /// threads available in your system.
     pub test_threads: Option<usize>,
+    #[clap(long)]
+    /// Collect line and branch coverage of the executed tests. An lcov tracefile and an HTML report
+    /// are written to the `out/coverage` directory of each tested package, as well as of the
+    /// workspace when testing a workspace. Requires forc to be built with the `profile` feature.
+    pub coverage: bool,
+    #[clap(long)]
+    /// Profile the gas spent by the executed tests. A folded stack file for flamegraph tools is
+    /// written to the `out/profile` directory of each tested package, and the functions spending
+    /// the most gas are listed. Requires forc to be built with the `profile` feature.
+    pub profile: bool,
+    #[clap(long, value_enum, default_value_t = TestReportFormat::Human)]
+    /// The format of the test results. `json` and `junit` print a report of every tested package
+    /// to stdout once all tests are done, in place of the human readable results. All other output
+    /// is then written to stderr.
+    pub format: TestReportFormat,
+    #[clap(flatten)]
+    pub fuzz: FuzzCmdOpts,
+    #[clap(flatten)]
+    pub gas_snapshot: GasSnapshotCmdOpts,
+}
+
+/// The formats test results can be printed in.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TestReportFormat {
+    /// Human readable results, printed as the tests of each package are done.
+    Human,
+    /// A JSON report of every tested package.
+    Json,
+    /// A JUnit XML report, with a test suite for every tested package.
+    Junit,
+}
+
+/// The set of options provided for controlling the execution of fuzz tests.
+#[derive(Parser, Debug, Clone)]
+pub struct FuzzCmdOpts {
+    /// Number of generated inputs each fuzz test is executed with. Must be at least 1.
+    #[clap(
+        long = "fuzz-runs",
+        default_value_t = DEFAULT_FUZZ_RUNS,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    pub runs: usize,
+    /// Seed of the fuzz test input generator, to reproduce or vary the generated inputs.
+    #[clap(long = "fuzz-seed")]
+    pub seed: Option<u64>,
+}
+
+/// The set of options provided for comparing the gas used by tests against snapshots.
+#[derive(Parser, Debug, Clone)]
+pub struct GasSnapshotCmdOpts {
+    /// Compare the gas used by each test against the `.gas-snapshot` file of its package, then
+    /// update that file with the gas used by this run. The file is created if it doesn't exist.
+    /// The files are only written when all tests passed and the gas used by no test rose above
+    /// the threshold, otherwise the run fails.
+    #[clap(long = "gas-snapshot")]
+    pub enabled: bool,
+    /// Percentage by which the gas used by a test may rise above its snapshot.
+    #[clap(long = "gas-snapshot-threshold", default_value_t = 0.0)]
+    pub threshold: f64,
+    /// Only warn when the gas used by a test rose above the threshold, rather than failing the
+    /// run. The snapshot is still left unchanged.
+    #[clap(long = "gas-snapshot-warn")]
+    pub warn: bool,
+    /// Compare the gas used by each test against the `.gas-snapshot` file of its package without
+    /// ever writing it, failing the run if the file is missing. Implies `--gas-snapshot`.
+    #[clap(long = "gas-snapshot-check")]
+    pub check: bool,
 }
 
 /// The set of options provided for controlling output of a test.
@@ -46,9 +130,15 @@ pub struct TestPrintOpts {
     #[clap(long = "pretty-print", short = 'r')]
     /// Pretty-print the logs emiited from tests.
     pub pretty_print: bool,
-    /// Print `Log` and `LogData` receipts for tests.
+    /// Print the values logged by tests, decoded using the ABI of the package.
     #[clap(long = "logs", short = 'l')]
     pub print_logs: bool,
+    /// Print the `Log` and `LogData` receipts of tests rather than their decoded values.
+    #[clap(long = "raw-logs")]
+    pub raw_logs: bool,
+    /// Print the storage slots written by each test.
+    #[clap(long = "storage-diff")]
+    pub storage_diff: bool,
 }
 
 pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
@@ -63,6 +153,31 @@ pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
         filter_phrase,
         exact_match: cmd.filter_exact,
     });
+    let coverage = cmd.coverage;
+    let profile = cmd.profile;
+    if (coverage || profile) && !cfg!(feature = "profile") {
+        forc_result_bail!(
+            "`--coverage` and `--profile` require forc to be built with the `profile` feature, \
+             e.g. `cargo install forc --features profile`"
+        );
+    }
+    let format = cmd.format;
+    let gas_snapshot_opts = cmd.gas_snapshot.clone();
+    let filtered = test_filter.is_some();
+    let mut fuzz_opts = FuzzOpts {
+        runs: cmd.fuzz.runs,
+        ..Default::default()
+    };
+    if let Some(seed) = cmd.fuzz.seed {
+        fuzz_opts.seed = seed;
+    }
+    let manifest_dir = cmd
+        .build
+        .pkg
+        .path
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or(std::env::current_dir()?);
     let opts = opts_from_cmd(cmd);
     let built_tests = forc_test::build(opts)?;
     let start = std::time::Instant::now();
@@ -76,13 +191,13 @@ pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
         num_tests_ignored,
         formatted_test_count_string(&num_tests_ignored)
     );
-    let tested = built_tests.run(test_runner_count, test_filter)?;
+    let tested = built_tests.run(test_runner_count, test_filter, fuzz_opts)?;
     let duration = start.elapsed();
 
     // Eventually we'll print this in a fancy manner, but this will do for testing.
-    let all_tests_passed = match tested {
-        forc_test::Tested::Workspace(pkgs) => {
-            for pkg in &pkgs {
+    let all_tests_passed = match (&tested, format) {
+        (forc_test::Tested::Workspace(pkgs), TestReportFormat::Human) => {
+            for pkg in pkgs {
                 let built = &pkg.built.descriptor.name;
                 info!("\n   tested -- {built}\n");
                 print_tested_pkg(pkg, &test_print_opts)?;
@@ -90,18 +205,43 @@ pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
             info!("\n   Finished in {:?}", duration);
             pkgs.iter().all(|pkg| pkg.tests_passed())
         }
-        forc_test::Tested::Package(pkg) => {
-            print_tested_pkg(&pkg, &test_print_opts)?;
+        (forc_test::Tested::Package(pkg), TestReportFormat::Human) => {
+            print_tested_pkg(pkg, &test_print_opts)?;
             pkg.tests_passed()
         }
+        (_, TestReportFormat::Json | TestReportFormat::Junit) => {
+            let report = TestReport::from_tested(&tested)?;
+            // The report is printed regardless of the log level. All other output is written to
+            // stderr when a report format is selected, so the report is alone on stdout.
+            match format {
+                TestReportFormat::Json => println!("{}", report.to_json()?),
+                _ => print!("{}", report.to_junit()?),
+            }
+            report.packages.iter().all(|pkg| pkg.failed == 0)
+        }
     };
 
-    if all_tests_passed {
-        Ok(())
-    } else {
+    if coverage {
+        write_coverage_reports(&tested, &manifest_dir)?;
+    }
+
+    if profile {
+        write_gas_profiles(&tested)?;
+    }
+
+    let gas_snapshots_passed = match gas_snapshot_opts.enabled || gas_snapshot_opts.check {
+        true => check_gas_snapshots(&tested, &gas_snapshot_opts, filtered, all_tests_passed)?,
+        false => true,
+    };
+
+    if !all_tests_passed {
         let forc_error: ForcError = "Some tests failed.".into();
-        const FAILING_UNIT_TESTS_EXIT_CODE: u8 = 101;
         Err(forc_error.exit_code(FAILING_UNIT_TESTS_EXIT_CODE))
+    } else if !gas_snapshots_passed {
+        let forc_error: ForcError = "Gas usage does not match the snapshot.".into();
+        Err(forc_error.exit_code(FAILING_UNIT_TESTS_EXIT_CODE))
+    } else {
+        Ok(())
     }
 }
 
@@ -115,19 +255,29 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
             true => ("ok", Colour::Green),
             false => ("FAILED", Colour::Red),
         };
+        let fuzz_runs = match &test.fuzz {
+            Some(fuzz) => format!(", {} runs", fuzz.runs),
+            None => String::new(),
+        };
         info!(
-            "      test {} ... {} ({:?}, {} gas)",
+            "      test {} ... {} ({:?}, {} gas{})",
             test.name,
             color.paint(state),
             test.duration,
-            test.gas_used
+            test.gas_used,
+            fuzz_runs
         );
 
         // If logs are enabled, print them.
-        if test_print_opts.print_logs {
+        if test_print_opts.raw_logs {
             let logs = &test.logs;
             let formatted_logs = format_log_receipts(logs, test_print_opts.pretty_print)?;
             info!("{}", formatted_logs);
+        } else if test_print_opts.print_logs {
+            print_decoded_logs(test)?;
+        }
+        if test_print_opts.storage_diff {
+            print_storage_diff(test);
         }
 
         // If the test is failing, save the test result for printing the details later on.
@@ -146,8 +296,6 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
             let failed_test_details = failed_test.details()?;
             let path = &*failed_test_details.file_path;
             let line_number = failed_test_details.line_number;
-            let logs = &failed_test.logs;
-            let formatted_logs = format_log_receipts(logs, test_print_opts.pretty_print)?;
             info!(
                 "      - test {}, {:?}:{} ",
                 failed_test_name, path, line_number
@@ -162,7 +310,29 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
                 }
                 info!("{failed_info_str}");
             }
-            info!("        Logs: {}", formatted_logs);
+            if let Some(counterexample) = failed_test
+                .fuzz
+                .as_ref()
+                .and_then(|fuzz| fuzz.counterexample.as_ref())
+            {
+                let inputs = counterexample
+                    .inputs
+                    .iter()
+                    .map(|input| input.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                info!(
+                    "        counterexample: {inputs} (shrunk {} times)",
+                    counterexample.shrinks
+                );
+            }
+            if test_print_opts.raw_logs {
+                let logs = &failed_test.logs;
+                let formatted_logs = format_log_receipts(logs, test_print_opts.pretty_print)?;
+                info!("        Logs: {}", formatted_logs);
+            } else {
+                print_decoded_logs(failed_test)?;
+            }
         }
         info!("\n");
     }
@@ -183,6 +353,221 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
     Ok(())
 }
 
+/// Print the storage slots written by the test, grouped by contract.
+fn print_storage_diff(test: &TestResult) {
+    let mut contract_id = None;
+    for change in &test.storage_diff {
+        if contract_id != Some(change.contract_id) {
+            contract_id = Some(change.contract_id);
+            info!("        storage of contract {:#x}:", change.contract_id);
+        }
+        let [previous, current] = [change.previous, change.current].map(|value| {
+            value
+                .map(|value| format!("{value:#x}"))
+                .unwrap_or_else(|| "unset".to_string())
+        });
+        info!("          {:#x}: {previous} -> {current}", change.key);
+    }
+}
+
+/// Print the values logged by the test, falling back to the receipt for logs which could not be
+/// decoded.
+fn print_decoded_logs(test: &TestResult) -> ForcResult<()> {
+    for (log, decoded) in test.logs.iter().zip(&test.decoded_logs) {
+        let formatted_log = match decoded {
+            Some(decoded) => decoded.to_string(),
+            None => format_log_receipts(std::slice::from_ref(log), false)?,
+        };
+        info!("        log: {}", formatted_log);
+    }
+    Ok(())
+}
+
+/// Write the coverage reports of each tested package, and of the workspace if one was tested.
+fn write_coverage_reports(tested: &forc_test::Tested, manifest_dir: &Path) -> ForcResult<()> {
+    let pkgs = match tested {
+        forc_test::Tested::Package(pkg) => std::slice::from_ref(&**pkg),
+        forc_test::Tested::Workspace(pkgs) => &pkgs[..],
+    };
+    let mut workspace_coverage = Coverage::default();
+    for pkg in pkgs {
+        let pkg_coverage = Coverage::from_tested_pkg(pkg);
+        let pkg_dir = pkg.built.descriptor.manifest_file.dir();
+        let mut report = pkg_coverage.clone();
+        report.retain_files_within(pkg_dir);
+        write_coverage_report(&report, &pkg.built.descriptor.name, pkg_dir)?;
+        workspace_coverage.merge(pkg_coverage);
+    }
+    if let forc_test::Tested::Workspace(_) = tested {
+        let manifest_file = pkg::manifest::ManifestFile::from_dir(manifest_dir)?;
+        let workspace_dir = manifest_file.dir();
+        workspace_coverage.retain_files_within(workspace_dir);
+        write_coverage_report(&workspace_coverage, "workspace", workspace_dir)?;
+    }
+    Ok(())
+}
+
+/// Write the lcov and HTML coverage reports into the `out/coverage` directory of `dir`.
+fn write_coverage_report(coverage: &Coverage, name: &str, dir: &Path) -> ForcResult<()> {
+    let coverage_dir = default_output_directory(dir).join("coverage");
+    fs::create_dir_all(&coverage_dir)?;
+    fs::write(coverage_dir.join("lcov.info"), coverage.to_lcov())?;
+    let title = format!("Coverage report for {name}");
+    fs::write(coverage_dir.join("index.html"), coverage.to_html(&title))?;
+
+    let summary = coverage.summary();
+    let percentage = |hit, found| match found {
+        0 => 100.0,
+        _ => hit as f64 * 100.0 / found as f64,
+    };
+    info!(
+        "   Coverage of {name}: {:.1}% of {} lines, {:.1}% of {} branches, report in {}",
+        percentage(summary.lines_hit, summary.lines_found),
+        summary.lines_found,
+        percentage(summary.branches_hit, summary.branches_found),
+        summary.branches_found,
+        coverage_dir.display()
+    );
+    Ok(())
+}
+
+/// Write the folded stack gas profile of each tested package into its `out/profile` directory and
+/// print the functions spending the most gas.
+fn write_gas_profiles(tested: &forc_test::Tested) -> ForcResult<()> {
+    const SUMMARY_LEN: usize = 10;
+    let pkgs = match tested {
+        forc_test::Tested::Package(pkg) => std::slice::from_ref(&**pkg),
+        forc_test::Tested::Workspace(pkgs) => &pkgs[..],
+    };
+    for pkg in pkgs {
+        let profile = GasProfile::from_tested_pkg(pkg);
+        let pkg_dir = pkg.built.descriptor.manifest_file.dir();
+        let profile_dir = default_output_directory(pkg_dir).join("profile");
+        fs::create_dir_all(&profile_dir)?;
+        let folded_path = profile_dir.join("gas.folded");
+        fs::write(&folded_path, profile.to_folded())?;
+
+        info!(
+            "\n   Gas profile of {}: {} gas, folded stacks in {}",
+            pkg.built.descriptor.name,
+            profile.total_gas(),
+            folded_path.display()
+        );
+        let functions = profile.functions();
+        if functions.is_empty() {
+            continue;
+        }
+        info!("      {:>12} {:>12}  function", "self gas", "total gas");
+        for function in functions.iter().take(SUMMARY_LEN) {
+            info!(
+                "      {:>12} {:>12}  {}",
+                function.self_gas, function.total_gas, function.name
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Compare the gas used by the tests of each tested package against the snapshot next to its
+/// manifest, and update the snapshots if all tests passed and the gas usage of none regressed.
+/// Snapshots are never written with `--gas-snapshot-check`.
+///
+/// Returns `false` if the gas used by any test rose above the threshold and regressions aren't
+/// only warned about, or if a snapshot is missing with `--gas-snapshot-check`. When only some of
+/// the tests were run, the snapshot entries of the others are kept.
+fn check_gas_snapshots(
+    tested: &forc_test::Tested,
+    opts: &GasSnapshotCmdOpts,
+    filtered: bool,
+    all_tests_passed: bool,
+) -> ForcResult<bool> {
+    let pkgs = match tested {
+        forc_test::Tested::Package(pkg) => std::slice::from_ref(&**pkg),
+        forc_test::Tested::Workspace(pkgs) => &pkgs[..],
+    };
+    let mut passed = true;
+    let mut regressed = false;
+    let mut updates = vec![];
+    for pkg in pkgs {
+        let current = GasSnapshot::from_tested_pkg(pkg);
+        let path = pkg
+            .built
+            .descriptor
+            .manifest_file
+            .dir()
+            .join(GAS_SNAPSHOT_FILE_NAME);
+        let name = &pkg.built.descriptor.name;
+        if !path.exists() {
+            if opts.check {
+                info!("\n   Gas snapshot of {name} is missing: {}", path.display());
+                passed = false;
+            } else {
+                updates.push((path, current));
+            }
+            continue;
+        }
+
+        let previous = GasSnapshot::from_file(&path)?;
+        let diff = previous.diff(&current);
+        let regressions: Vec<_> = diff.regressions(opts.threshold).collect();
+        info!(
+            "\n   Gas snapshot of {name}: {} changed, {} new, {} regressed",
+            diff.changed.len(),
+            diff.added.len(),
+            regressions.len()
+        );
+        for change in &diff.changed {
+            let line = format!(
+                "      {}: {} -> {} gas ({:+.2}%)",
+                change.name,
+                change.previous,
+                change.current,
+                change.percentage()
+            );
+            let regressed = regressions.iter().any(|regression| regression == &change);
+            match (regressed, opts.warn) {
+                (true, true) => info!("{}", Colour::Yellow.paint(line)),
+                (true, false) => info!("{}", Colour::Red.paint(line)),
+                (false, _) => info!("{line}"),
+            }
+        }
+        for (test, gas) in &diff.added {
+            info!("      {test}: {gas} gas (new)");
+        }
+        if !filtered {
+            for (test, gas) in &diff.removed {
+                info!("      {test}: {gas} gas (removed)");
+            }
+        }
+
+        if !regressions.is_empty() {
+            regressed = true;
+            passed &= opts.warn;
+        }
+        let mut updated = match filtered {
+            true => previous,
+            false => GasSnapshot::default(),
+        };
+        updated.tests.extend(current.tests);
+        updates.push((path, updated));
+    }
+
+    if opts.check || !all_tests_passed || regressed {
+        if !opts.check && !updates.is_empty() {
+            info!("\n   Gas snapshots left unchanged");
+        }
+        return Ok(passed);
+    }
+    for (path, snapshot) in updates {
+        let contents = snapshot.to_string();
+        if fs::read_to_string(&path).ok().as_deref() != Some(contents.as_str()) {
+            fs::write(&path, contents)?;
+            info!("\n   Gas snapshot written to {}", path.display());
+        }
+    }
+    Ok(passed)
+}
+
 fn opts_from_cmd(cmd: Command) -> forc_test::Opts {
     forc_test::Opts {
         pkg: pkg::PkgOpts {
@@ -225,3 +610,17 @@ fn formatted_test_count_string(count: &usize) -> &str {
         "tests"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzz_runs_at_least_one() {
+        let runs = |arg: &str| {
+            FuzzCmdOpts::try_parse_from(["forc-test", "--fuzz-runs", arg]).map(|opts| opts.runs)
+        };
+        assert!(runs("0").is_err());
+        assert_eq!(runs("1").unwrap(), 1);
+    }
+}
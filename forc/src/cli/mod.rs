@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use self::commands::{
     addr2line, build, check, clean, completions, contract_id, init, new, parse_bytecode, plugins,
-    predicate_root, template, test, update,
+    predicate_root, publish, template, test, update,
 };
 use addr2line::Command as Addr2LineCommand;
 use anyhow::anyhow;
@@ -12,13 +12,14 @@ use clap::{Parser, Subcommand};
 pub use clean::Command as CleanCommand;
 pub use completions::Command as CompletionsCommand;
 pub(crate) use contract_id::Command as ContractIdCommand;
-use forc_tracing::{init_tracing_subscriber, TracingSubscriberOptions};
+use forc_tracing::{init_tracing_subscriber, TracingSubscriberOptions, TracingWriterMode};
 use forc_util::ForcResult;
 pub use init::Command as InitCommand;
 pub use new::Command as NewCommand;
 use parse_bytecode::Command as ParseBytecodeCommand;
 pub use plugins::Command as PluginsCommand;
 pub(crate) use predicate_root::Command as PredicateRootCommand;
+pub use publish::Command as PublishCommand;
 pub use template::Command as TemplateCommand;
 pub use test::Command as TestCommand;
 use tracing::metadata::LevelFilter;
@@ -64,6 +65,7 @@ enum Forc {
     Test(TestCommand),
     Update(UpdateCommand),
     Plugins(PluginsCommand),
+    Publish(PublishCommand),
     Template(TemplateCommand),
     ContractId(ContractIdCommand),
     PredicateRoot(PredicateRootCommand),
@@ -81,11 +83,18 @@ enum Forc {
 
 pub async fn run_cli() -> ForcResult<()> {
     let opt = Opt::parse();
+    // A machine readable test report must be the only output on stdout.
+    let writer_mode = match &opt.command {
+        Forc::Test(command) if command.format != test::TestReportFormat::Human => {
+            Some(TracingWriterMode::Stderr)
+        }
+        _ => None,
+    };
     let tracing_options = TracingSubscriberOptions {
         verbosity: Some(opt.verbose),
         silent: Some(opt.silent),
         log_level: opt.log_level,
-        ..Default::default()
+        writer_mode,
     };
 
     init_tracing_subscriber(tracing_options);
@@ -100,6 +109,7 @@ pub async fn run_cli() -> ForcResult<()> {
         Forc::New(command) => new::exec(command),
         Forc::ParseBytecode(command) => parse_bytecode::exec(command),
         Forc::Plugins(command) => plugins::exec(command),
+        Forc::Publish(command) => publish::exec(command),
         Forc::Test(command) => test::exec(command),
         Forc::Update(command) => update::exec(command).await,
         Forc::Template(command) => template::exec(command),
@@ -0,0 +1,88 @@
+use crate::cli::PublishCommand;
+use anyhow::{anyhow, bail, Result};
+use forc_pkg::{manifest::ManifestFile, publish, source::reg};
+use forc_util::default_output_directory;
+use std::{fs, path::PathBuf};
+use sway_utils::{find_parent_manifest_dir, MANIFEST_FILE_NAME};
+use tracing::info;
+
+/// The directory within the output directory to which package archives are written.
+const PACKAGE_DIR_NAME: &str = "package";
+
+pub fn publish(command: PublishCommand) -> Result<()> {
+    let PublishCommand {
+        path,
+        registry,
+        token,
+        ipfs,
+        ipfs_api_url,
+        dry_run,
+    } = command;
+
+    let current_dir = std::env::current_dir().map_err(|e| anyhow!("{:?}", e))?;
+    let this_dir = match path {
+        Some(path) => PathBuf::from(path),
+        None => current_dir.clone(),
+    };
+    let manifest_dir = match find_parent_manifest_dir(&this_dir) {
+        Some(dir) => dir,
+        None => bail!(
+            "could not find `{}` in `{}` or any parent directory",
+            MANIFEST_FILE_NAME,
+            this_dir.display(),
+        ),
+    };
+    let manifest = match ManifestFile::from_dir(&manifest_dir)? {
+        ManifestFile::Package(manifest) => manifest,
+        ManifestFile::Workspace(_) => {
+            bail!("workspaces can't be published, publish each of their members instead")
+        }
+    };
+
+    let archive = publish::package(&manifest)?;
+    let package_dir = default_output_directory(manifest.dir()).join(PACKAGE_DIR_NAME);
+    fs::create_dir_all(&package_dir)?;
+    let archive_path = package_dir.join(archive.file_name());
+    fs::write(&archive_path, &archive.bytes)?;
+    info!(
+        "  {} {} {} ({} files)",
+        ansi_term::Colour::Green.bold().paint("Packaged"),
+        archive.name,
+        archive.version,
+        archive.files.len()
+    );
+    for file in &archive.files {
+        info!("    {}", file.display());
+    }
+    info!("    archive: {}", archive_path.display());
+    info!("    checksum: {}", archive.checksum);
+
+    if dry_run {
+        info!(" `--dry-run` enabled: the package was not published");
+        return Ok(());
+    }
+
+    let published = ansi_term::Colour::Green.bold().paint("Published");
+    if ipfs {
+        let cid = publish::publish_to_ipfs(&archive, ipfs_api_url.as_deref())?;
+        info!(
+            "  {published} {} {} to IPFS: {cid}",
+            archive.name, archive.version
+        );
+        info!(
+            "    depend on it with `{} = {{ ipfs = \"{cid}\" }}`",
+            archive.name
+        );
+    } else {
+        let Some(registry) = registry else {
+            bail!("no registry to publish to - specify `--registry`, set the `FORC_REGISTRY` environment variable or use `--ipfs`")
+        };
+        let index = reg::Source::parse_index(&registry, &current_dir)?;
+        publish::publish_to_registry(&archive, &index, token.as_deref())?;
+        info!(
+            "  {published} {} {} to {index}",
+            archive.name, archive.version
+        );
+    }
+    Ok(())
+}
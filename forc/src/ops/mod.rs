@@ -4,5 +4,6 @@ pub mod forc_clean;
 pub mod forc_contract_id;
 pub mod forc_init;
 pub mod forc_predicate_root;
+pub mod forc_publish;
 pub mod forc_template;
 pub mod forc_update;
@@ -18,6 +18,10 @@ pub enum Assignable {
         field: BigUint,
         field_span: Span,
     },
+    Deref {
+        star_token: StarToken,
+        expr: Box<Expr>,
+    },
 }
 
 impl Spanned for Assignable {
@@ -31,6 +35,7 @@ impl Spanned for Assignable {
             Assignable::TupleFieldProjection {
                 target, field_span, ..
             } => Span::join(target.span(), field_span.clone()),
+            Assignable::Deref { star_token, expr } => Span::join(star_token.span(), expr.span()),
         }
     }
 }
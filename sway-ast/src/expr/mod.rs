@@ -41,6 +41,13 @@ pub enum Expr {
         condition: Box<Expr>,
         block: Braces<CodeBlockContents>,
     },
+    For {
+        for_token: ForToken,
+        value_pattern: Pattern,
+        in_token: InToken,
+        iterator: Box<Expr>,
+        block: Braces<CodeBlockContents>,
+    },
     FuncApp {
         func: Box<Expr>,
         args: Parens<Punctuated<Expr, CommaToken>>,
@@ -68,17 +75,22 @@ pub enum Expr {
         field_span: Span,
     },
     Ref {
-        ref_token: RefToken,
+        ampersand_token: AmpersandToken,
+        mut_token: Option<MutToken>,
         expr: Box<Expr>,
     },
     Deref {
-        deref_token: DerefToken,
+        star_token: StarToken,
         expr: Box<Expr>,
     },
     Not {
         bang_token: BangToken,
         expr: Box<Expr>,
     },
+    Neg {
+        sub_token: SubToken,
+        expr: Box<Expr>,
+    },
     Mul {
         lhs: Box<Expr>,
         star_token: StarToken,
@@ -174,6 +186,12 @@ pub enum Expr {
         double_pipe_token: DoublePipeToken,
         rhs: Box<Expr>,
     },
+    /// A range expression, e.g. `0..len` or `1..=10`.
+    Range {
+        start: Box<Expr>,
+        limits: RangeLimits,
+        end: Box<Expr>,
+    },
     Reassignment {
         assignable: Assignable,
         reassignment_op: ReassignmentOp,
@@ -220,6 +238,9 @@ impl Spanned for Expr {
             Expr::While {
                 while_token, block, ..
             } => Span::join(while_token.span(), block.span()),
+            Expr::For {
+                for_token, block, ..
+            } => Span::join(for_token.span(), block.span()),
             Expr::FuncApp { func, args } => Span::join(func.span(), args.span()),
             Expr::Index { target, arg } => Span::join(target.span(), arg.span()),
             Expr::MethodCall { target, args, .. } => Span::join(target.span(), args.span()),
@@ -227,9 +248,14 @@ impl Spanned for Expr {
             Expr::TupleFieldProjection {
                 target, field_span, ..
             } => Span::join(target.span(), field_span.clone()),
-            Expr::Ref { ref_token, expr } => Span::join(ref_token.span(), expr.span()),
-            Expr::Deref { deref_token, expr } => Span::join(deref_token.span(), expr.span()),
+            Expr::Ref {
+                ampersand_token,
+                expr,
+                ..
+            } => Span::join(ampersand_token.span(), expr.span()),
+            Expr::Deref { star_token, expr } => Span::join(star_token.span(), expr.span()),
             Expr::Not { bang_token, expr } => Span::join(bang_token.span(), expr.span()),
+            Expr::Neg { sub_token, expr } => Span::join(sub_token.span(), expr.span()),
             Expr::Pow { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
             Expr::Mul { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
             Expr::Div { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
@@ -249,6 +275,7 @@ impl Spanned for Expr {
             Expr::GreaterThanEq { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
             Expr::LogicalAnd { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
             Expr::LogicalOr { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
+            Expr::Range { start, end, .. } => Span::join(start.span(), end.span()),
             Expr::Reassignment {
                 assignable, expr, ..
             } => Span::join(assignable.span(), expr.span()),
@@ -289,6 +316,23 @@ impl ReassignmentOpVariant {
     }
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub enum RangeLimits {
+    /// `..`, which excludes the end of the range.
+    HalfOpen(DoubleDotToken),
+    /// `..=`, which includes the end of the range.
+    Closed(DoubleDotEqToken),
+}
+
+impl Spanned for RangeLimits {
+    fn span(&self) -> Span {
+        match self {
+            RangeLimits::HalfOpen(token) => token.span(),
+            RangeLimits::Closed(token) => token.span(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct AbiCastArgs {
     pub name: PathType,
@@ -362,6 +406,7 @@ pub enum ExprArrayDescriptor {
 #[derive(Clone, Debug, Serialize)]
 pub struct MatchBranch {
     pub pattern: Pattern,
+    pub guard_opt: Option<(IfToken, Box<Expr>)>,
     pub fat_right_arrow_token: FatRightArrowToken,
     pub kind: MatchBranchKind,
 }
@@ -482,6 +527,7 @@ impl Expr {
                     field_span,
                 }),
             },
+            Expr::Deref { star_token, expr } => Ok(Assignable::Deref { star_token, expr }),
             expr => Err(expr),
         }
     }
@@ -493,7 +539,8 @@ impl Expr {
                 | Expr::Asm(..)
                 | Expr::If(..)
                 | Expr::Match { .. }
-                | Expr::While { .. },
+                | Expr::While { .. }
+                | Expr::For { .. },
         )
     }
 }
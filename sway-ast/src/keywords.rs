@@ -73,6 +73,7 @@ define_keyword!(MatchToken, "match");
 define_keyword!(MutToken, "mut");
 define_keyword!(LetToken, "let");
 define_keyword!(WhileToken, "while");
+define_keyword!(InToken, "in");
 define_keyword!(WhereToken, "where");
 define_keyword!(RefToken, "ref");
 define_keyword!(DerefToken, "deref");
@@ -187,8 +188,14 @@ define_token!(
     [Equals, GreaterThan],
     [GreaterThan, Equals]
 );
-define_token!(DotToken, "`.`", [Dot], []);
-define_token!(DoubleDotToken, "`..`", [Dot, Dot], [Dot]);
+define_token!(DotToken, "`.`", [Dot], [Dot]);
+define_token!(DoubleDotToken, "`..`", [Dot, Dot], [Dot, Equals]);
+define_token!(
+    DoubleDotEqToken,
+    "`..=`",
+    [Dot, Dot, Equals],
+    [Equals, GreaterThan]
+);
 define_token!(BangToken, "`!`", [Bang], [Equals]);
 define_token!(PercentToken, "`%`", [Percent], []);
 define_token!(AddToken, "`+`", [Add], [Equals]);
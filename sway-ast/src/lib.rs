@@ -26,7 +26,7 @@ pub use crate::{
         asm::{AsmBlock, AsmRegisterDeclaration},
         op_code::Instruction,
         AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
+        ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind, RangeLimits,
     },
     generics::{GenericArgs, GenericParams},
     intrinsics::*,
@@ -20,6 +20,17 @@ pub enum Pattern {
         name: Ident,
     },
     Literal(Literal),
+    /// A negated integer literal, e.g. `-1`.
+    NegativeLiteral {
+        sub_token: SubToken,
+        literal: LitInt,
+    },
+    /// An integer range pattern, e.g. `1..=5` or `-8..0`.
+    Range {
+        start: Box<Pattern>,
+        limits: RangeLimits,
+        end: Box<Pattern>,
+    },
     Constant(PathExpr),
     Constructor {
         path: PathExpr,
@@ -57,6 +68,10 @@ impl Spanned for Pattern {
             },
             Pattern::AmbiguousSingleIdent(ident) => ident.span(),
             Pattern::Literal(literal) => literal.span(),
+            Pattern::NegativeLiteral { sub_token, literal } => {
+                Span::join(sub_token.span(), literal.span())
+            }
+            Pattern::Range { start, end, .. } => Span::join(start.span(), end.span()),
             Pattern::Constant(path_expr) => path_expr.span(),
             Pattern::Constructor { path, args } => Span::join(path.span(), args.span()),
             Pattern::Struct { path, fields } => Span::join(path.span(), fields.span()),
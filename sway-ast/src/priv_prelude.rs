@@ -6,7 +6,7 @@ pub use {
         expr::{
             asm::{AsmBlock, AsmImmediate},
             op_code::Instruction,
-            CodeBlockContents, Expr,
+            CodeBlockContents, Expr, RangeLimits,
         },
         generics::{GenericArgs, GenericParams},
         intrinsics::*,
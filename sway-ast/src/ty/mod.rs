@@ -22,6 +22,11 @@ pub enum Ty {
         slice_token: SliceToken,
         ty: SquareBrackets<Box<Ty>>,
     },
+    Ref {
+        ampersand_token: AmpersandToken,
+        mut_token: Option<MutToken>,
+        ty: Box<Ty>,
+    },
 }
 
 impl Spanned for Ty {
@@ -35,6 +40,11 @@ impl Spanned for Ty {
             Ty::Infer { underscore_token } => underscore_token.span(),
             Ty::Ptr { ptr_token, ty } => Span::join(ptr_token.span(), ty.span()),
             Ty::Slice { slice_token, ty } => Span::join(slice_token.span(), ty.span()),
+            Ty::Ref {
+                ampersand_token,
+                ty,
+                ..
+            } => Span::join(ampersand_token.span(), ty.span()),
         }
     }
 }
@@ -93,6 +93,14 @@ pub fn abi_str(type_info: &TypeInfo, type_engine: &TypeEngine, decl_engine: &Dec
             IntegerBits::V256 => "uint256",
         }
         .into(),
+        SignedInteger(x) => match x {
+            IntegerBits::Eight => "int8",
+            IntegerBits::Sixteen => "int16",
+            IntegerBits::ThirtyTwo => "int32",
+            IntegerBits::SixtyFour => "int64",
+            IntegerBits::V256 => "int256",
+        }
+        .into(),
         Boolean => "bool".into(),
         Custom {
             qualified_call_path: call_path,
@@ -136,6 +144,16 @@ pub fn abi_str(type_info: &TypeInfo, type_engine: &TypeEngine, decl_engine: &Dec
         Slice(ty) => {
             format!("__slice {}", abi_str_type_arg(ty, type_engine, decl_engine))
         }
+        Ref {
+            to_mutable_value,
+            referenced_type,
+        } => {
+            format!(
+                "__ref {}{}",
+                if *to_mutable_value { "mut " } else { "" },
+                abi_str_type_arg(referenced_type, type_engine, decl_engine)
+            )
+        }
         Alias { ty, .. } => abi_str_type_arg(ty, type_engine, decl_engine),
         TraitType {
             name,
@@ -161,6 +179,13 @@ pub fn abi_param_type(
             IntegerBits::SixtyFour => ethabi::ParamType::Uint(64),
             IntegerBits::V256 => ethabi::ParamType::Uint(256),
         },
+        SignedInteger(x) => match x {
+            IntegerBits::Eight => ethabi::ParamType::Int(8),
+            IntegerBits::Sixteen => ethabi::ParamType::Int(16),
+            IntegerBits::ThirtyTwo => ethabi::ParamType::Int(32),
+            IntegerBits::SixtyFour => ethabi::ParamType::Int(64),
+            IntegerBits::V256 => ethabi::ParamType::Int(256),
+        },
         Boolean => ethabi::ParamType::Bool,
         B256 => ethabi::ParamType::Uint(256),
         Contract => ethabi::ParamType::Address,
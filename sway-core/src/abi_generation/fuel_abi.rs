@@ -798,6 +798,14 @@ impl TypeInfo {
                 IntegerBits::V256 => "u256",
             }
             .into(),
+            SignedInteger(x) => match x {
+                IntegerBits::Eight => "i8",
+                IntegerBits::Sixteen => "i16",
+                IntegerBits::ThirtyTwo => "i32",
+                IntegerBits::SixtyFour => "i64",
+                IntegerBits::V256 => "i256",
+            }
+            .into(),
             Boolean => "bool".into(),
             Custom {
                 qualified_call_path: call_path,
@@ -841,6 +849,16 @@ impl TypeInfo {
             Slice(ty) => {
                 format!("__slice {}", ty.abi_str(ctx, type_engine, decl_engine))
             }
+            Ref {
+                to_mutable_value,
+                referenced_type,
+            } => {
+                format!(
+                    "__ref {}{}",
+                    if *to_mutable_value { "mut " } else { "" },
+                    referenced_type.abi_str(ctx, type_engine, decl_engine)
+                )
+            }
             Alias { ty, .. } => ty.abi_str(ctx, type_engine, decl_engine),
             TraitType {
                 name,
@@ -877,7 +895,7 @@ fn call_path_display(ctx: &mut AbiContext, call_path: &CallPath) -> String {
 }
 
 impl TyFunctionDecl {
-    pub(self) fn generate_abi_function(
+    pub fn generate_abi_function(
         &self,
         ctx: &mut AbiContext,
         type_engine: &TypeEngine,
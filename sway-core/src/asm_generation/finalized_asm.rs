@@ -26,6 +26,8 @@ pub struct FinalizedAsm {
     pub program_kind: ProgramKind,
     pub entries: Vec<FinalizedEntry>,
     pub abi: Option<ProgramABI>,
+    /// The functions the program was compiled from, used to map instructions back to them.
+    pub functions: FunctionsInfo,
 }
 
 #[derive(Clone, Debug)]
@@ -41,6 +43,16 @@ pub struct FinalizedEntry {
     pub test_decl_ref: Option<DeclRefFunction>,
 }
 
+/// The names and spans of the functions a program was compiled from.
+#[derive(Clone, Debug, Default)]
+pub struct FunctionsInfo {
+    /// The name and declaration span of every function which has code in the program.
+    pub functions: Vec<(String, Span)>,
+    /// For every call site which had functions inlined into it, the names of the inlined
+    /// functions, outermost first.
+    pub inlined_calls: Vec<(Span, Vec<String>)>,
+}
+
 /// The bytecode for a sway program as well as the byte offsets of configuration-time constants in
 /// the bytecode.
 pub struct CompiledBytecode {
@@ -56,13 +68,21 @@ impl FinalizedAsm {
         source_engine: &SourceEngine,
     ) -> Result<CompiledBytecode, ErrorEmitted> {
         match &self.program_section {
-            InstructionSet::Fuel { ops } => to_bytecode_mut(
-                handler,
-                ops,
-                &mut self.data_section,
-                source_map,
-                source_engine,
-            ),
+            InstructionSet::Fuel { ops } => {
+                for (name, span) in &self.functions.functions {
+                    source_map.insert_function(source_engine, name, span);
+                }
+                for (span, inlined) in &self.functions.inlined_calls {
+                    source_map.insert_inlined_call(source_engine, span, inlined);
+                }
+                to_bytecode_mut(
+                    handler,
+                    ops,
+                    &mut self.data_section,
+                    source_map,
+                    source_engine,
+                )
+            }
             InstructionSet::Evm { ops } => {
                 let mut assembler = Assembler::new();
                 if let Err(e) = assembler.push_all(ops.clone()) {
@@ -1,20 +1,24 @@
 use super::{
     asm_builder::{AsmBuilder, AsmBuilderResult},
     evm::EvmAsmBuilder,
-    finalized_asm::{check_invalid_opcodes, FinalizedAsm},
+    finalized_asm::{check_invalid_opcodes, FinalizedAsm, FunctionsInfo},
     fuel::{
         data_section::{DataId, DataSection},
         fuel_asm_builder::FuelAsmBuilder,
         register_sequencer::RegisterSequencer,
     },
+    instruction_set::InstructionSet,
     programs::{AbstractEntry, AbstractProgram, FinalProgram, ProgramKind},
     MidenVMAsmBuilder,
 };
 
-use crate::{BuildConfig, BuildTarget};
+use crate::{ir_generation::source_fn_name, metadata::MetadataManager, BuildConfig, BuildTarget};
 
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_ir::*;
+use sway_types::Span;
+
+use std::collections::HashMap;
 
 pub fn compile_ir_to_asm(
     handler: &Handler,
@@ -39,13 +43,79 @@ pub fn compile_ir_to_asm(
         println!("{final_program}");
     }
 
-    let final_asm = final_program.finalize();
+    let mut final_asm = final_program.finalize();
+    if matches!(final_asm.program_section, InstructionSet::Fuel { .. }) {
+        final_asm.functions = collect_functions_info(ir, module);
+    }
 
     check_invalid_opcodes(handler, &final_asm)?;
 
     Ok(final_asm)
 }
 
+/// Collect the names and spans of the functions in `module`, and the names of the functions
+/// inlined into them at each call site.
+///
+/// Instructions take their span from the outermost call site they were inlined through.  When the
+/// instructions of a call site were inlined through different chains of functions, only the common
+/// outermost part of those chains is kept.
+fn collect_functions_info(context: &Context, module: Module) -> FunctionsInfo {
+    let mut md_mgr = MetadataManager::default();
+    let mut functions = Vec::new();
+    let mut inlined_calls: HashMap<Span, Vec<String>> = HashMap::new();
+    let mut call_order = Vec::new();
+    for function in module.function_iter(context) {
+        if let Some(span) = md_mgr.md_to_span(context, function.get_metadata(context)) {
+            // Entry functions are compiled directly and keep their names.
+            let name = function.get_name(context);
+            let name = match function.is_entry(context) {
+                true => name,
+                false => source_fn_name(name),
+            };
+            functions.push((name.to_owned(), span));
+        }
+        for (_, ins) in function.instruction_iter(context) {
+            let md_idx = ins.get_metadata(context);
+            let inlined: Vec<String> = md_mgr
+                .md_to_inlined_fns(context, md_idx)
+                .iter()
+                .map(|name| source_fn_name(name).to_owned())
+                .collect();
+            if inlined.is_empty() {
+                continue;
+            }
+            let Some(span) = md_mgr.md_to_span(context, md_idx) else {
+                continue;
+            };
+            match inlined_calls.get_mut(&span) {
+                Some(chain) => {
+                    let common = chain
+                        .iter()
+                        .zip(inlined.iter())
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    chain.truncate(common);
+                }
+                None => {
+                    call_order.push(span.clone());
+                    inlined_calls.insert(span, inlined);
+                }
+            }
+        }
+    }
+    let inlined_calls = call_order
+        .into_iter()
+        .filter_map(|span| {
+            let chain = inlined_calls.remove(&span)?;
+            (!chain.is_empty()).then_some((span, chain))
+        })
+        .collect();
+    FunctionsInfo {
+        functions,
+        inlined_calls,
+    }
+}
+
 fn compile_module_to_asm(
     handler: &Handler,
     reg_seqr: RegisterSequencer,
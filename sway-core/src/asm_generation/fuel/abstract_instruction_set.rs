@@ -1,6 +1,7 @@
 use crate::{
     asm_generation::fuel::{
         allocated_abstract_instruction_set::AllocatedAbstractInstructionSet, register_allocator,
+        register_sequencer::RegisterSequencer,
     },
     asm_lang::{
         allocated_ops::{AllocatedOp, AllocatedOpcode},
@@ -23,8 +24,9 @@ pub struct AbstractInstructionSet {
 }
 
 impl AbstractInstructionSet {
-    pub(crate) fn optimize(self) -> AbstractInstructionSet {
-        self.remove_sequential_jumps()
+    pub(crate) fn optimize(self, reg_seqr: &mut RegisterSequencer) -> AbstractInstructionSet {
+        self.peephole_optimize(reg_seqr)
+            .remove_sequential_jumps()
             .remove_redundant_moves()
             .remove_unused_ops()
     }
@@ -36,5 +36,8 @@ pub(crate) const EIGHTEEN_BITS: u64 = 0b11_1111_1111_1111_1111;
 pub(crate) const TWELVE_BITS: u64 = 0b1111_1111_1111;
 pub(crate) const SIX_BITS: u64 = 0b11_1111;
 
+/// The bit in `$flag` which makes overflowing arithmetic wrap around instead of panicking.
+pub(crate) const FLAG_WRAPPING: u16 = 0b10;
+
 /// Some arbitrary values used for error codes.
 pub(crate) const MISMATCHED_SELECTOR_REVERT_CODE: u32 = 123;
@@ -222,7 +222,9 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
                     cond_value,
                     true_block,
                     false_block,
-                } => self.compile_conditional_branch(cond_value, true_block, false_block),
+                } => {
+                    self.compile_conditional_branch(instr_val, cond_value, true_block, false_block)
+                }
                 InstOp::ContractCall {
                     params,
                     coins,
@@ -698,6 +700,9 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
                 val2_reg,
                 VirtualImmediate06::wide_cmp(WideCmp::GreaterThan, true),
             ),
+            Predicate::SLessThan | Predicate::SGreaterThan => {
+                unreachable!("signed comparisons are not wide")
+            }
         };
 
         self.cur_bytecode.push(Op {
@@ -732,6 +737,18 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
             BinaryOpKind::Mod => Either::Left(VirtualOp::MOD(res_reg.clone(), val1_reg, val2_reg)),
             BinaryOpKind::Rsh => Either::Left(VirtualOp::SRL(res_reg.clone(), val1_reg, val2_reg)),
             BinaryOpKind::Lsh => Either::Left(VirtualOp::SLL(res_reg.clone(), val1_reg, val2_reg)),
+            BinaryOpKind::SAdd
+            | BinaryOpKind::SSub
+            | BinaryOpKind::SMul
+            | BinaryOpKind::SDiv
+            | BinaryOpKind::SMod
+            | BinaryOpKind::SRsh => {
+                let ops = self.signed_binary_op(op, res_reg.clone(), val1_reg, val2_reg);
+                let owning_span = self.md_mgr.val_to_span(self.context, *instr_val);
+                self.push_wrapping(ops, owning_span);
+                self.reg_map.insert(*instr_val, res_reg);
+                return Ok(());
+            }
         };
         self.cur_bytecode.push(Op {
             opcode,
@@ -743,6 +760,112 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         Ok(())
     }
 
+    /// Expands a signed operation on two's complement `u64` values into unsigned VM
+    /// instructions. The expansion must run with the wrapping flag set, see `push_wrapping()`.
+    fn signed_binary_op(
+        &mut self,
+        op: &BinaryOpKind,
+        res_reg: VirtualRegister,
+        val1_reg: VirtualRegister,
+        val2_reg: VirtualRegister,
+    ) -> Vec<VirtualOp> {
+        let zero = VirtualRegister::Constant(ConstantRegister::Zero);
+        let imm_63 = VirtualImmediate12 { value: 63 };
+        match op {
+            BinaryOpKind::SAdd => vec![VirtualOp::ADD(res_reg, val1_reg, val2_reg)],
+            BinaryOpKind::SSub => vec![VirtualOp::SUB(res_reg, val1_reg, val2_reg)],
+            BinaryOpKind::SMul => vec![VirtualOp::MUL(res_reg, val1_reg, val2_reg)],
+            BinaryOpKind::SDiv | BinaryOpKind::SMod => {
+                // Divide the absolute values and then restore the sign, which for the remainder
+                // is the sign of the dividend. A sign mask is all ones for negative values, and
+                // `(x ^ mask) - mask` negates `x` when the mask is set.
+                let sign1 = self.reg_seqr.next();
+                let mask1 = self.reg_seqr.next();
+                let sign2 = self.reg_seqr.next();
+                let mask2 = self.reg_seqr.next();
+                let flipped1 = self.reg_seqr.next();
+                let abs1 = self.reg_seqr.next();
+                let flipped2 = self.reg_seqr.next();
+                let abs2 = self.reg_seqr.next();
+                let abs_res = self.reg_seqr.next();
+                let flipped_res = self.reg_seqr.next();
+                let mut ops = vec![
+                    VirtualOp::SRLI(sign1.clone(), val1_reg.clone(), imm_63.clone()),
+                    VirtualOp::SUB(mask1.clone(), zero.clone(), sign1),
+                    VirtualOp::SRLI(sign2.clone(), val2_reg.clone(), imm_63),
+                    VirtualOp::SUB(mask2.clone(), zero, sign2),
+                    VirtualOp::XOR(flipped1.clone(), val1_reg, mask1.clone()),
+                    VirtualOp::SUB(abs1.clone(), flipped1, mask1.clone()),
+                    VirtualOp::XOR(flipped2.clone(), val2_reg, mask2.clone()),
+                    VirtualOp::SUB(abs2.clone(), flipped2, mask2.clone()),
+                ];
+                let res_mask = if matches!(op, BinaryOpKind::SDiv) {
+                    let res_mask = self.reg_seqr.next();
+                    ops.push(VirtualOp::DIV(abs_res.clone(), abs1, abs2));
+                    ops.push(VirtualOp::XOR(res_mask.clone(), mask1, mask2));
+                    res_mask
+                } else {
+                    ops.push(VirtualOp::MOD(abs_res.clone(), abs1, abs2));
+                    mask1
+                };
+                ops.push(VirtualOp::XOR(
+                    flipped_res.clone(),
+                    abs_res,
+                    res_mask.clone(),
+                ));
+                ops.push(VirtualOp::SUB(res_reg, flipped_res, res_mask));
+                ops
+            }
+            BinaryOpKind::SRsh => {
+                // Shift in ones for negative values by flipping the bits around a logical shift.
+                let sign = self.reg_seqr.next();
+                let mask = self.reg_seqr.next();
+                let flipped = self.reg_seqr.next();
+                let shifted = self.reg_seqr.next();
+                vec![
+                    VirtualOp::SRLI(sign.clone(), val1_reg.clone(), imm_63),
+                    VirtualOp::SUB(mask.clone(), zero, sign),
+                    VirtualOp::XOR(flipped.clone(), val1_reg, mask.clone()),
+                    VirtualOp::SRL(shifted.clone(), flipped, val2_reg),
+                    VirtualOp::XOR(res_reg, shifted, mask),
+                ]
+            }
+            _ => unreachable!("only signed operations are expanded"),
+        }
+    }
+
+    /// Pushes `ops` with the wrapping flag set, so that overflowing unsigned arithmetic behaves
+    /// like two's complement arithmetic instead of panicking, and restores the flags after.
+    fn push_wrapping(&mut self, ops: Vec<VirtualOp>, owning_span: Option<Span>) {
+        let saved_flags = self.reg_seqr.next();
+        let wrapping_flags = self.reg_seqr.next();
+        let prologue = [
+            VirtualOp::MOVE(
+                saved_flags.clone(),
+                VirtualRegister::Constant(ConstantRegister::Flags),
+            ),
+            VirtualOp::ORI(
+                wrapping_flags.clone(),
+                saved_flags.clone(),
+                VirtualImmediate12 {
+                    value: compiler_constants::FLAG_WRAPPING,
+                },
+            ),
+            VirtualOp::FLAG(wrapping_flags),
+        ];
+        for opcode in prologue
+            .into_iter()
+            .chain(ops)
+            .chain([VirtualOp::FLAG(saved_flags)])
+        {
+            self.cur_bytecode.push(Op {
+                opcode: Either::Left(opcode),
+                comment: String::new(),
+                owning_span: owning_span.clone(),
+            });
+        }
+    }
+
     fn compile_branch(&mut self, to_block: &BranchToWithArgs) -> Result<(), CompileError> {
         self.compile_branch_to_phi_value(to_block)?;
 
@@ -786,6 +909,34 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
                     owning_span,
                 });
             }
+            Predicate::SLessThan | Predicate::SGreaterThan => {
+                // Flipping the sign bits maps two's complement order onto unsigned order.
+                let sign_bit = self.reg_seqr.next();
+                let lhs_flipped = self.reg_seqr.next();
+                let rhs_flipped = self.reg_seqr.next();
+                let cmp = if matches!(pred, Predicate::SLessThan) {
+                    VirtualOp::LT(res_reg.clone(), lhs_flipped.clone(), rhs_flipped.clone())
+                } else {
+                    VirtualOp::GT(res_reg.clone(), lhs_flipped.clone(), rhs_flipped.clone())
+                };
+                for opcode in [
+                    VirtualOp::MOVI(sign_bit.clone(), VirtualImmediate18 { value: 1 }),
+                    VirtualOp::SLLI(
+                        sign_bit.clone(),
+                        sign_bit.clone(),
+                        VirtualImmediate12 { value: 63 },
+                    ),
+                    VirtualOp::XOR(lhs_flipped, lhs_reg, sign_bit.clone()),
+                    VirtualOp::XOR(rhs_flipped, rhs_reg, sign_bit),
+                    cmp,
+                ] {
+                    self.cur_bytecode.push(Op {
+                        opcode: Either::Left(opcode),
+                        comment: comment.clone(),
+                        owning_span: owning_span.clone(),
+                    });
+                }
+            }
         }
         self.reg_map.insert(*instr_val, res_reg);
         Ok(())
@@ -793,6 +944,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
 
     fn compile_conditional_branch(
         &mut self,
+        instr_val: &Value,
         cond_value: &Value,
         true_block: &BranchToWithArgs,
         false_block: &BranchToWithArgs,
@@ -811,8 +963,9 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         let cond_reg = self.value_to_register(cond_value)?;
 
         let true_label = self.block_to_label(&true_block.block);
+        let owning_span = self.md_mgr.val_to_span(self.context, *instr_val);
         self.cur_bytecode
-            .push(Op::jump_if_not_zero(cond_reg, true_label));
+            .push(Op::jump_if_not_zero(cond_reg, true_label, owning_span));
 
         let false_label = self.block_to_label(&false_block.block);
         self.cur_bytecode.push(Op::jump_to_label(false_label));
@@ -1825,7 +1978,6 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         // - If we're given a base to add to and the immediate is small enough we can use ADDI.
         // - If the immediate is too big for that then we need to MOVI and ADD.
         // - If the immediate is very big then we LW and ADD.
-        // XXX This can be done with peephole optimisations when we get them.
         if imm <= compiler_constants::TWELVE_BITS && base.is_some() {
             self.cur_bytecode.push(Op {
                 opcode: Either::Left(VirtualOp::ADDI(
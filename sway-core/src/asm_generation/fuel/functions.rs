@@ -259,7 +259,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         let locals_alloc_result = self.alloc_locals(function);
 
         if func_is_entry {
-            self.compile_external_args(function)
+            self.compile_external_args(function, test_decl_ref.is_some())
                 .map_err(|e| handler.emit_err(e))?
         } else {
             // Make copies of the arg registers.
@@ -407,8 +407,18 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         }
     }
 
-    // Handle loading the arguments of a contract call
-    fn compile_external_args(&mut self, function: Function) -> Result<(), CompileError> {
+    // Handle loading the arguments of a contract call.  Tests are always executed as scripts, so
+    // their arguments are read from the script data regardless of the kind of program.
+    fn compile_external_args(
+        &mut self,
+        function: Function,
+        is_test: bool,
+    ) -> Result<(), CompileError> {
+        let program_kind = if is_test {
+            ProgramKind::Script
+        } else {
+            self.program_kind
+        };
         match function.args_iter(self.context).count() {
             // Nothing to do if there are no arguments
             0 => Ok(()),
@@ -418,13 +428,13 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
             1 => {
                 let (_, val) = function.args_iter(self.context).next().unwrap();
                 let single_arg_reg = self.reg_seqr.next();
-                match self.program_kind {
+                match program_kind {
                     ProgramKind::Contract => {
                         self.read_args_base_from_frame(&single_arg_reg);
                     }
                     ProgramKind::Library => {} // Nothing to do here
                     ProgramKind::Script | ProgramKind::Predicate => {
-                        if let ProgramKind::Predicate = self.program_kind {
+                        if let ProgramKind::Predicate = program_kind {
                             self.read_args_base_from_predicate_data(&single_arg_reg);
                         } else {
                             self.read_args_base_from_script_data(&single_arg_reg);
@@ -455,7 +465,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
             // Otherwise, the args are bundled together and pointed to by the base register.
             _ => {
                 let args_base_reg = self.reg_seqr.next();
-                match self.program_kind {
+                match program_kind {
                     ProgramKind::Contract => self.read_args_base_from_frame(&args_base_reg),
                     ProgramKind::Library => return Ok(()), // Nothing to do here
                     ProgramKind::Predicate => {
@@ -626,6 +636,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         self.cur_bytecode.push(Op::jump_if_not_zero(
             input_type.clone(),
             input_type_not_coin_label,
+            None,
         ));
 
         // If the input is indeed a "coin", then use `GTF` to get the "input coin predicate data
@@ -690,6 +701,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         self.cur_bytecode.push(Op::jump_if_not_zero(
             input_type_not_message,
             input_type_not_message_label,
+            None,
         ));
 
         // If the input is indeed a "message", then use `GTF` to get the "input message predicate
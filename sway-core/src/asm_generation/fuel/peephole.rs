@@ -0,0 +1,509 @@
+//! Peephole optimisations over the abstract instructions of a function.
+//!
+//! These are small pattern based rewrites of adjacent instructions which are run before register
+//! allocation, while the registers are still virtual.  Rewritten instructions are replaced by
+//! NOOPs with a comment, which are removed later by `remove_unused_ops()`, or are expanded in place
+//! into cheaper instructions using new virtual registers.
+
+use crate::{
+    asm_generation::fuel::{
+        abstract_instruction_set::AbstractInstructionSet, compiler_constants,
+        register_sequencer::RegisterSequencer,
+    },
+    asm_lang::{Label, Op, OrganizationalOp, VirtualImmediate12, VirtualOp, VirtualRegister},
+};
+
+use std::collections::{HashMap, HashSet};
+
+use either::Either;
+
+impl AbstractInstructionSet {
+    pub(super) fn peephole_optimize(
+        self,
+        reg_seqr: &mut RegisterSequencer,
+    ) -> AbstractInstructionSet {
+        self.fold_immediates()
+            .collapse_word_copies(reg_seqr)
+            .forward_stored_words()
+            .shorten_jump_chains()
+            .remove_empty_copies()
+    }
+
+    /// Folds a `MOVI` of a 12 bit value into the next instruction when it has an immediate form
+    /// and is the only reader of the value.
+    fn fold_immediates(mut self) -> AbstractInstructionSet {
+        // E.g.:
+        //     MOVI t, 8
+        //     MCP  a, b, t   =>   MCPI a, b, 8
+        let mut use_counts: HashMap<VirtualRegister, usize> = HashMap::new();
+        for op in &self.ops {
+            for reg in op.use_registers() {
+                *use_counts.entry(reg.clone()).or_default() += 1;
+            }
+        }
+
+        for idx in 1..self.ops.len() {
+            let Either::Left(VirtualOp::MOVI(tmp_reg @ VirtualRegister::Virtual(_), imm)) =
+                &self.ops[idx - 1].opcode
+            else {
+                continue;
+            };
+            if u64::from(imm.value) > compiler_constants::TWELVE_BITS {
+                continue;
+            }
+            let Either::Left(op) = &self.ops[idx].opcode else {
+                continue;
+            };
+
+            // The MOVI may only be removed if nothing else reads the temporary, or if it's
+            // overwritten by the folded instruction itself.
+            let tmp_is_dead = use_counts.get(tmp_reg) == Some(&1)
+                || op.def_registers().into_iter().any(|reg| reg == tmp_reg);
+            if !tmp_is_dead {
+                continue;
+            }
+
+            let imm = VirtualImmediate12 {
+                value: imm.value as u16,
+            };
+            if let Some(folded_op) = fold_immediate(op, tmp_reg, imm) {
+                self.ops[idx].opcode = Either::Left(folded_op);
+                self.ops[idx - 1] = Op {
+                    opcode: Either::Left(VirtualOp::NOOP),
+                    comment: "folded MOVI into immediate".into(),
+                    owning_span: None,
+                };
+            }
+        }
+
+        self
+    }
+
+    /// Replaces each `MCPI` of a single word with a `LW` and a `SW` through a new register, which
+    /// use far less gas.  A following load of the copied word may then be forwarded.
+    fn collapse_word_copies(mut self, reg_seqr: &mut RegisterSequencer) -> AbstractInstructionSet {
+        // E.g.:
+        //     MCPI a, b, 8   =>   LW t, b, 0
+        //                         SW a, t, 0
+        let mut ops = Vec::with_capacity(self.ops.len());
+        for op in self.ops {
+            match &op.opcode {
+                Either::Left(VirtualOp::MCPI(dst, src, len)) if len.value == 8 => {
+                    let tmp_reg = reg_seqr.next();
+                    ops.push(Op {
+                        opcode: Either::Left(VirtualOp::LW(
+                            tmp_reg.clone(),
+                            src.clone(),
+                            VirtualImmediate12 { value: 0 },
+                        )),
+                        comment: "collapsed word MCPI".into(),
+                        owning_span: op.owning_span.clone(),
+                    });
+                    ops.push(Op {
+                        opcode: Either::Left(VirtualOp::SW(
+                            dst.clone(),
+                            tmp_reg,
+                            VirtualImmediate12 { value: 0 },
+                        )),
+                        comment: "collapsed word MCPI".into(),
+                        owning_span: op.owning_span,
+                    });
+                }
+                _otherwise => ops.push(op),
+            }
+        }
+        self.ops = ops;
+
+        self
+    }
+
+    /// Replaces a `LW` directly after a `SW` to the same address with a `MOVE` of the stored
+    /// register, which the register allocator can then usually coalesce away.
+    fn forward_stored_words(mut self) -> AbstractInstructionSet {
+        for idx in 1..self.ops.len() {
+            let (
+                Either::Left(VirtualOp::SW(st_base, st_src, st_offs)),
+                Either::Left(VirtualOp::LW(ld_dst, ld_base, ld_offs)),
+            ) = (&self.ops[idx - 1].opcode, &self.ops[idx].opcode)
+            else {
+                continue;
+            };
+            if st_base != ld_base || st_offs.value != ld_offs.value {
+                continue;
+            }
+
+            self.ops[idx] = if ld_dst == st_src {
+                Op {
+                    opcode: Either::Left(VirtualOp::NOOP),
+                    comment: "removed redundant LW".into(),
+                    owning_span: None,
+                }
+            } else {
+                Op {
+                    opcode: Either::Left(VirtualOp::MOVE(ld_dst.clone(), st_src.clone())),
+                    comment: "forward stored word".into(),
+                    owning_span: self.ops[idx].owning_span.clone(),
+                }
+            };
+        }
+
+        self
+    }
+
+    /// Retargets jumps to a label which is followed by another unconditional jump, so they go
+    /// straight to the final destination.
+    fn shorten_jump_chains(mut self) -> AbstractInstructionSet {
+        // Find the jump, if any, which is the first instruction after each label.
+        let mut label_jumps: HashMap<Label, Label> = HashMap::new();
+        for (idx, op) in self.ops.iter().enumerate() {
+            if let Either::Right(OrganizationalOp::Label(label)) = &op.opcode {
+                let next_op = self.ops[idx + 1..].iter().find(|op| {
+                    !matches!(
+                        op.opcode,
+                        Either::Right(OrganizationalOp::Label(_) | OrganizationalOp::Comment)
+                            | Either::Left(VirtualOp::NOOP)
+                    )
+                });
+                if let Some(Op {
+                    opcode: Either::Right(OrganizationalOp::Jump(dst_label)),
+                    ..
+                }) = next_op
+                {
+                    label_jumps.insert(*label, *dst_label);
+                }
+            }
+        }
+
+        let final_dst = |label: &Label| {
+            // Guard against infinite loops, which are chains which lead back to themselves.
+            let mut visited = HashSet::from([*label]);
+            let mut dst = *label;
+            while let Some(next_dst) = label_jumps.get(&dst) {
+                if !visited.insert(*next_dst) {
+                    break;
+                }
+                dst = *next_dst;
+            }
+            dst
+        };
+
+        for op in &mut self.ops {
+            match &mut op.opcode {
+                Either::Right(
+                    OrganizationalOp::Jump(label) | OrganizationalOp::JumpIfNotZero(_, label),
+                ) => *label = final_dst(label),
+                _otherwise => (),
+            }
+        }
+
+        self
+    }
+
+    /// Removes memory copies and clears of zero bytes.
+    fn remove_empty_copies(mut self) -> AbstractInstructionSet {
+        for op in &mut self.ops {
+            if matches!(
+                &op.opcode,
+                Either::Left(VirtualOp::MCPI(_, _, VirtualImmediate12 { value: 0 }))
+            ) || matches!(&op.opcode, Either::Left(VirtualOp::MCLI(_, imm)) if imm.value == 0)
+            {
+                *op = Op {
+                    opcode: Either::Left(VirtualOp::NOOP),
+                    comment: "removed empty memory copy".into(),
+                    owning_span: None,
+                };
+            }
+        }
+
+        self
+    }
+}
+
+/// Returns `op` with its `tmp_reg` argument replaced by `imm`, if it has such a form.
+fn fold_immediate(
+    op: &VirtualOp,
+    tmp_reg: &VirtualRegister,
+    imm: VirtualImmediate12,
+) -> Option<VirtualOp> {
+    use VirtualOp::*;
+
+    // For commutative ops the temporary may be either argument, but not both.
+    macro_rules! commutative {
+        ($op_i: ident, $dst: expr, $lhs: expr, $rhs: expr) => {
+            if $rhs == tmp_reg && $lhs != tmp_reg {
+                Some($op_i($dst.clone(), $lhs.clone(), imm))
+            } else if $lhs == tmp_reg && $rhs != tmp_reg {
+                Some($op_i($dst.clone(), $rhs.clone(), imm))
+            } else {
+                None
+            }
+        };
+    }
+    macro_rules! rhs_only {
+        ($op_i: ident, $dst: expr, $lhs: expr, $rhs: expr) => {
+            if $rhs == tmp_reg && $lhs != tmp_reg {
+                Some($op_i($dst.clone(), $lhs.clone(), imm))
+            } else {
+                None
+            }
+        };
+    }
+
+    match op {
+        ADD(d, l, r) => commutative!(ADDI, d, l, r),
+        AND(d, l, r) => commutative!(ANDI, d, l, r),
+        MUL(d, l, r) => commutative!(MULI, d, l, r),
+        OR(d, l, r) => commutative!(ORI, d, l, r),
+        XOR(d, l, r) => commutative!(XORI, d, l, r),
+        DIV(d, l, r) => rhs_only!(DIVI, d, l, r),
+        EXP(d, l, r) => rhs_only!(EXPI, d, l, r),
+        MOD(d, l, r) => rhs_only!(MODI, d, l, r),
+        SLL(d, l, r) => rhs_only!(SLLI, d, l, r),
+        SRL(d, l, r) => rhs_only!(SRLI, d, l, r),
+        SUB(d, l, r) => rhs_only!(SUBI, d, l, r),
+        MCP(dst, src, len) if dst != tmp_reg => rhs_only!(MCPI, dst, src, len),
+        _otherwise => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm_lang::ConstantRegister;
+    use sway_error::handler::Handler;
+    use sway_types::{Ident, Span};
+
+    /// Parses one instruction per line in the format they're printed in, with `.N` for labels,
+    /// `ji .N` and `jnzi $rX .N` for jumps and `$rN` for virtual registers.
+    fn parse(asm: &str) -> AbstractInstructionSet {
+        let parse_label = |label: &str| Label(label[1..].parse().unwrap());
+        let parse_reg = |reg: &str| {
+            let name = reg.trim_start_matches('$');
+            match ConstantRegister::parse_register_name(name) {
+                Some(const_reg) => VirtualRegister::Constant(const_reg),
+                None if name == "locbase" => {
+                    VirtualRegister::Constant(ConstantRegister::LocalsBase)
+                }
+                None => VirtualRegister::Virtual(name[1..].to_owned()),
+            }
+        };
+
+        let ops = asm
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let words = line.split_whitespace().collect::<Vec<_>>();
+                let opcode = match words.as_slice() {
+                    [label] if label.starts_with('.') => {
+                        Either::Right(OrganizationalOp::Label(parse_label(label)))
+                    }
+                    ["ji", label] => Either::Right(OrganizationalOp::Jump(parse_label(label))),
+                    ["jnzi", reg, label] => Either::Right(OrganizationalOp::JumpIfNotZero(
+                        parse_reg(reg),
+                        parse_label(label),
+                    )),
+                    [name, args @ ..] => {
+                        let (regs, imm) = match args.last() {
+                            Some(imm) if imm.starts_with('i') => (
+                                &args[..args.len() - 1],
+                                Some(Ident::new_no_span(imm.to_string())),
+                            ),
+                            _otherwise => (args, None),
+                        };
+                        let regs = regs.iter().map(|reg| parse_reg(reg)).collect::<Vec<_>>();
+                        Either::Left(
+                            Op::parse_opcode(
+                                &Handler::default(),
+                                &Ident::new_no_span(name.to_string()),
+                                &regs,
+                                &imm,
+                                Span::dummy(),
+                            )
+                            .unwrap(),
+                        )
+                    }
+                    [] => unreachable!(),
+                };
+                Op {
+                    opcode,
+                    comment: String::new(),
+                    owning_span: None,
+                }
+            })
+            .collect();
+
+        AbstractInstructionSet { ops }
+    }
+
+    /// Checks that the peephole optimisations rewrite `before` into `after`, ignoring NOOPs.
+    ///
+    /// New registers are numbered from `$r100`, after those used by the tests.
+    fn check(before: &str, after: &str) {
+        let mut reg_seqr = RegisterSequencer::new();
+        for _ in 0..100 {
+            reg_seqr.next();
+        }
+        let optimized = parse(before)
+            .peephole_optimize(&mut reg_seqr)
+            .ops
+            .iter()
+            .filter(|op| !matches!(op.opcode, Either::Left(VirtualOp::NOOP)))
+            .map(|op| op.opcode.to_string())
+            .collect::<Vec<_>>();
+        let expected = parse(after)
+            .ops
+            .iter()
+            .map(|op| op.opcode.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(optimized, expected);
+    }
+
+    #[test]
+    fn fold_movi_add() {
+        check(
+            "movi $r0 i24
+             add $r1 $r2 $r0
+             add $r3 $r0 $r1
+             movi $r4 i40
+             add $r4 $locbase $r4",
+            "movi $r0 i24
+             add $r1 $r2 $r0
+             add $r3 $r0 $r1
+             addi $r4 $locbase i40",
+        );
+    }
+
+    #[test]
+    fn fold_movi_commutative() {
+        check(
+            "movi $r0 i3
+             mul $r1 $r0 $r2
+             movi $r3 i3
+             sub $r4 $r3 $r2
+             movi $r5 i7
+             and $r6 $r5 $r5",
+            "muli $r1 $r2 i3
+             movi $r3 i3
+             sub $r4 $r3 $r2
+             movi $r5 i7
+             and $r6 $r5 $r5",
+        );
+    }
+
+    #[test]
+    fn fold_movi_too_large() {
+        check(
+            "movi $r0 i4096
+             add $r1 $r2 $r0
+             movi $r3 i4095
+             srl $r4 $r2 $r3",
+            "movi $r0 i4096
+             add $r1 $r2 $r0
+             srli $r4 $r2 i4095",
+        );
+    }
+
+    #[test]
+    fn fold_movi_mcp() {
+        check(
+            "movi $r0 i16
+             mcp $r1 $r2 $r0
+             movi $r3 i0
+             mcp $r1 $r2 $r3
+             movi $r4 i8
+             mcp $r4 $r2 $r4",
+            "mcpi $r1 $r2 i16
+             movi $r4 i8
+             mcp $r4 $r2 $r4",
+        );
+    }
+
+    #[test]
+    fn remove_empty_copies() {
+        check(
+            "mcpi $r0 $r1 i0
+             mcli $r0 i0
+             mcli $r0 i8",
+            "mcli $r0 i8",
+        );
+    }
+
+    #[test]
+    fn collapse_word_copies() {
+        check(
+            "mcpi $r0 $r1 i8
+             mcpi $r0 $r1 i16
+             movi $r2 i8
+             mcp $r3 $locbase $r2
+             lw $r4 $r3 i0",
+            "lw $r100 $r1 i0
+             sw $r0 $r100 i0
+             mcpi $r0 $r1 i16
+             lw $r101 $locbase i0
+             sw $r3 $r101 i0
+             move $r4 $r101",
+        );
+    }
+
+    #[test]
+    fn forward_stored_words() {
+        check(
+            "sw $locbase $r0 i2
+             lw $r1 $locbase i2
+             sw $r2 $r0 i0
+             lw $r0 $r2 i0
+             sw $r2 $r0 i0
+             lw $r3 $r2 i1
+             sw $r2 $r0 i0
+             lw $r3 $r4 i0",
+            "sw $locbase $r0 i2
+             move $r1 $r0
+             sw $r2 $r0 i0
+             sw $r2 $r0 i0
+             lw $r3 $r2 i1
+             sw $r2 $r0 i0
+             lw $r3 $r4 i0",
+        );
+    }
+
+    #[test]
+    fn shorten_jump_chains() {
+        check(
+            "jnzi $r0 .1
+             ji .2
+             .1
+             .3
+             ji .4
+             .2
+             ji .1
+             .4
+             ret $zero",
+            "jnzi $r0 .4
+             ji .4
+             .1
+             .3
+             ji .4
+             .2
+             ji .4
+             .4
+             ret $zero",
+        );
+    }
+
+    #[test]
+    fn shorten_jump_chains_cycle() {
+        check(
+            "ji .1
+             .1
+             ji .2
+             .2
+             ji .1",
+            "ji .2
+             .1
+             ji .1
+             .2
+             ji .2",
+        );
+    }
+}
@@ -11,6 +11,6 @@ mod instruction_set;
 mod programs;
 
 mod finalized_asm;
-pub use finalized_asm::{CompiledBytecode, FinalizedAsm, FinalizedEntry};
+pub use finalized_asm::{CompiledBytecode, FinalizedAsm, FinalizedEntry, FunctionsInfo};
 
 pub(crate) use programs::ProgramKind;
@@ -29,6 +29,7 @@ impl FinalProgram {
                     })
                     .collect(),
                 abi: None,
+                functions: Default::default(),
             },
             FinalProgram::Evm { ops, abi } => FinalizedAsm {
                 data_section: DataSection {
@@ -38,6 +39,7 @@ impl FinalProgram {
                 program_kind: super::ProgramKind::Script,
                 entries: vec![],
                 abi: Some(ProgramABI::Evm(abi)),
+                functions: Default::default(),
             },
             FinalProgram::MidenVM { ops } => FinalizedAsm {
                 data_section: DataSection {
@@ -48,6 +50,7 @@ impl FinalProgram {
                 program_kind: super::ProgramKind::Script,
                 entries: vec![],
                 abi: None, /* TODO? */
+                functions: Default::default(),
             },
         }
     }
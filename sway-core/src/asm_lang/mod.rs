@@ -211,11 +211,15 @@ impl Op {
     }
 
     /// Jumps to [Label] `label`  if the given [VirtualRegister] `reg0` is not equal to zero.
-    pub(crate) fn jump_if_not_zero(reg0: VirtualRegister, label: Label) -> Self {
+    pub(crate) fn jump_if_not_zero(
+        reg0: VirtualRegister,
+        label: Label,
+        owning_span: Option<Span>,
+    ) -> Self {
         Op {
             opcode: Either::Right(OrganizationalOp::JumpIfNotZero(reg0, label)),
             comment: String::new(),
-            owning_span: None,
+            owning_span,
         }
     }
 
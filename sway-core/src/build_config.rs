@@ -1,4 +1,4 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{ops::Range, path::PathBuf, sync::Arc};
 
 use serde::{Deserialize, Serialize};
 use strum::EnumString;
@@ -32,6 +32,85 @@ pub enum BuildTarget {
     MidenVM,
 }
 
+/// The level of optimization applied to the IR before code generation.
+///
+/// Levels are written as `0`, `1` and `2`, or as `"s"` to optimize for bytecode size.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum OptLevel {
+    /// No optimizations. Functions are never inlined, which keeps the generated code close to
+    /// the source for debugging and gas attribution.
+    O0,
+    #[default]
+    O1,
+    /// More aggressive inlining and simplification than `O1`.
+    O2,
+    /// Optimize for bytecode size.
+    Os,
+}
+
+impl std::fmt::Display for OptLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            OptLevel::O0 => "0",
+            OptLevel::O1 => "1",
+            OptLevel::O2 => "2",
+            OptLevel::Os => "s",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl std::str::FromStr for OptLevel {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "0" => Ok(OptLevel::O0),
+            "1" => Ok(OptLevel::O1),
+            "2" => Ok(OptLevel::O2),
+            "s" => Ok(OptLevel::Os),
+            _ => Err(format!(
+                "invalid optimization level `{s}`, expected one of 0, 1, 2 or \"s\""
+            )),
+        }
+    }
+}
+
+impl Serialize for OptLevel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            OptLevel::O0 => serializer.serialize_u8(0),
+            OptLevel::O1 => serializer.serialize_u8(1),
+            OptLevel::O2 => serializer.serialize_u8(2),
+            OptLevel::Os => serializer.serialize_str("s"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OptLevel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Level(u64),
+            Name(String),
+        }
+        let s = match Repr::deserialize(deserializer)? {
+            Repr::Level(level) => level.to_string(),
+            Repr::Name(name) => name,
+        };
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The name of an IR pass to run, as listed in a build profile.
+#[derive(Clone, Debug)]
+pub struct PassEntry {
+    pub name: String,
+    /// The path to the manifest listing the pass and the byte range of the entry within it, used
+    /// to point errors about the pass at the manifest.
+    pub manifest_span: Option<(Arc<PathBuf>, Range<usize>)>,
+}
+
 /// Configuration for the overall build and compilation process.
 #[derive(Clone)]
 pub struct BuildConfig {
@@ -46,6 +125,9 @@ pub struct BuildConfig {
     pub(crate) print_finalized_asm: bool,
     pub(crate) print_ir: bool,
     pub(crate) include_tests: bool,
+    pub(crate) optimization_level: OptLevel,
+    pub(crate) passes: Option<Vec<PassEntry>>,
+    pub(crate) size_budget: Option<u64>,
     pub time_phases: bool,
     pub metrics_outfile: Option<String>,
 }
@@ -90,6 +172,9 @@ impl BuildConfig {
             print_finalized_asm: false,
             print_ir: false,
             include_tests: false,
+            optimization_level: OptLevel::default(),
+            passes: None,
+            size_budget: None,
             time_phases: false,
             metrics_outfile: None,
         }
@@ -156,6 +241,35 @@ impl BuildConfig {
         }
     }
 
+    /// The level of optimization applied to the IR.
+    ///
+    /// Default: `OptLevel::O1`
+    pub fn optimization_level(self, optimization_level: OptLevel) -> Self {
+        Self {
+            optimization_level,
+            ..self
+        }
+    }
+
+    /// The names of the IR passes to run in order, in place of the passes of the optimization
+    /// level. Target specific lowering passes are always run afterwards.
+    ///
+    /// Default: `None`
+    pub fn passes(self, passes: Option<Vec<PassEntry>>) -> Self {
+        Self { passes, ..self }
+    }
+
+    /// The size in bytes which inlining mustn't grow the estimated bytecode of the program
+    /// beyond, which is useful for predicates and scripts as their size is limited.
+    ///
+    /// Default: `None`
+    pub fn size_budget(self, size_budget: Option<u64>) -> Self {
+        Self {
+            size_budget,
+            ..self
+        }
+    }
+
     pub fn canonical_root_module(&self) -> Arc<PathBuf> {
         self.canonical_root_module.clone()
     }
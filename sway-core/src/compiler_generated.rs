@@ -27,6 +27,48 @@ pub fn is_generated_destructured_struct_var_name(name: &str) -> bool {
     name.starts_with(DESTRUCTURED_STRUCT_VAR_NAME_PREFIX)
 }
 
+/// The prefix for the compiler generated names of function parameters
+/// that are destructured into a pattern at the start of the function body.
+const DESTRUCTURED_PARAM_VAR_NAME_PREFIX: &str = "__destructured_param_";
+
+pub(crate) fn generate_destructured_param_var_name(suffix: usize) -> String {
+    format!("{DESTRUCTURED_PARAM_VAR_NAME_PREFIX}{suffix}")
+}
+
+pub fn is_generated_destructured_param_var_name(name: &str) -> bool {
+    name.starts_with(DESTRUCTURED_PARAM_VAR_NAME_PREFIX)
+}
+
+/// The prefix for the compiler generated names of
+/// variables that store the values iterated over in `for` loops.
+const FOR_LOOP_ITERABLE_VAR_NAME_PREFIX: &str = "__for_iterable_";
+
+pub(crate) fn generate_for_loop_iterable_var_name(suffix: usize) -> String {
+    format!("{FOR_LOOP_ITERABLE_VAR_NAME_PREFIX}{suffix}")
+}
+
+/// The prefix for the compiler generated names of
+/// variables that store the iterators of `for` loops.
+const FOR_LOOP_ITERATOR_VAR_NAME_PREFIX: &str = "__for_iterator_";
+
+pub(crate) fn generate_for_loop_iterator_var_name(suffix: usize) -> String {
+    format!("{FOR_LOOP_ITERATOR_VAR_NAME_PREFIX}{suffix}")
+}
+
+/// The prefix for the compiler generated names of variables that
+/// store the optional values returned by the iterators of `for` loops.
+const FOR_LOOP_NEXT_VALUE_VAR_NAME_PREFIX: &str = "__for_next_value_";
+
+pub(crate) fn generate_for_loop_next_value_var_name(suffix: usize) -> String {
+    format!("{FOR_LOOP_NEXT_VALUE_VAR_NAME_PREFIX}{suffix}")
+}
+
+pub fn is_generated_any_for_loop_var_name(name: &str) -> bool {
+    name.starts_with(FOR_LOOP_ITERABLE_VAR_NAME_PREFIX)
+        || name.starts_with(FOR_LOOP_ITERATOR_VAR_NAME_PREFIX)
+        || name.starts_with(FOR_LOOP_NEXT_VALUE_VAR_NAME_PREFIX)
+}
+
 /// The prefix for the compiler generated names of
 /// variables that store values matched in match expressions.
 const MATCHED_VALUE_VAR_NAME_PREFIX: &str = "__matched_value_";
@@ -1810,19 +1810,32 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             Ok(vec![])
         }
         Reassignment(typed_reassignment) => {
-            if let Some(variable_entry) = graph
-                .namespace
-                .get_variable(&typed_reassignment.lhs_base_name)
-            {
-                for leaf in leaves {
-                    graph.add_edge(*leaf, variable_entry.variable_decl_ix, "".into());
+            let leaves = match &typed_reassignment.lhs {
+                ty::TyReassignmentTarget::ElementAccess { base_name, .. } => {
+                    if let Some(variable_entry) = graph.namespace.get_variable(base_name) {
+                        for leaf in leaves {
+                            graph.add_edge(*leaf, variable_entry.variable_decl_ix, "".into());
+                        }
+                    }
+                    leaves.to_vec()
                 }
-            }
+                ty::TyReassignmentTarget::Deref(exp) => connect_expression(
+                    engines,
+                    &exp.expression,
+                    graph,
+                    leaves,
+                    exit_node,
+                    "dereferenced reassignment target",
+                    tree_type,
+                    exp.span.clone(),
+                    options,
+                )?,
+            };
             connect_expression(
                 engines,
                 &typed_reassignment.rhs.expression,
                 graph,
-                leaves,
+                &leaves,
                 exit_node,
                 "variable reassignment",
                 tree_type,
@@ -1830,6 +1843,17 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
                 options,
             )
         }
+        Ref(exp) | Deref(exp) => connect_expression(
+            engines,
+            &exp.expression,
+            graph,
+            leaves,
+            exit_node,
+            "",
+            tree_type,
+            exp.span.clone(),
+            options,
+        ),
         Return(exp) => {
             let this_index = graph.add_node("return entry".into());
             for leaf in leaves {
@@ -11,6 +11,7 @@ use sway_error::error::CompileError;
 use sway_ir::{Context, Kind};
 use sway_types::span::Span;
 
+pub(crate) use function::source_fn_name;
 pub(crate) use purity::{check_function_purity, PurityEnv};
 
 use crate::{language::ty, Engines};
@@ -13,7 +13,7 @@ use crate::{
 };
 
 use super::{
-    convert::{convert_literal_to_constant, convert_resolved_typeid},
+    convert::{convert_literal_to_constant, convert_numeric_literal, convert_resolved_typeid},
     function::FnCompiler,
     types::*,
 };
@@ -28,7 +28,7 @@ use sway_ir::{
     value::Value,
     InstOp, Instruction, Type, TypeContent,
 };
-use sway_types::{ident::Ident, integer_bits::IntegerBits, span::Spanned, Span};
+use sway_types::{ident::Ident, span::Spanned, Span};
 use sway_utils::mapped_stack::MappedStack;
 
 enum ConstEvalError {
@@ -267,11 +267,12 @@ fn const_eval_typed_expr(
     expr: &ty::TyExpression,
 ) -> Result<Option<Constant>, ConstEvalError> {
     Ok(match &expr.expression {
-        ty::TyExpressionVariant::Literal(Literal::Numeric(n)) => {
-            let implied_lit = match lookup.engines.te().get(expr.return_type) {
-                TypeInfo::UnsignedInteger(IntegerBits::Eight) => Literal::U8(*n as u8),
-                _ => Literal::U64(*n),
-            };
+        ty::TyExpressionVariant::Literal(
+            lit @ (Literal::Numeric(_) | Literal::NegativeNumeric(_)),
+        ) => {
+            let implied_lit =
+                convert_numeric_literal(lookup.engines, expr.return_type, lit, &expr.span)
+                    .map_err(ConstEvalError::CompileError)?;
             Some(convert_literal_to_constant(lookup.context, &implied_lit))
         }
         ty::TyExpressionVariant::Literal(l) => Some(convert_literal_to_constant(lookup.context, l)),
@@ -634,7 +635,9 @@ fn const_eval_typed_expr(
         | ty::TyExpressionVariant::UnsafeDowncast { .. }
         | ty::TyExpressionVariant::Break
         | ty::TyExpressionVariant::Continue
-        | ty::TyExpressionVariant::WhileLoop { .. } => {
+        | ty::TyExpressionVariant::WhileLoop { .. }
+        | ty::TyExpressionVariant::Ref(_)
+        | ty::TyExpressionVariant::Deref(_) => {
             return Err(ConstEvalError::CannotBeEvaluatedToConst {
                 span: expr.span.clone(),
             })
@@ -742,6 +745,20 @@ fn const_eval_intrinsic(
 
     assert!(args.len() == intrinsic.arguments.len());
 
+    // Signed integers are sign extended `u64` constants which need signed arithmetic.
+    // Untyped literal operands stay numeric, so the signedness may come from either operand,
+    // except for the amount of a shift.
+    let typed_operands = match intrinsic.kind {
+        Intrinsic::Lsh | Intrinsic::Rsh => &intrinsic.arguments[..1],
+        _ => &intrinsic.arguments[..],
+    };
+    let is_signed = typed_operands.iter().any(|arg| {
+        matches!(
+            lookup.engines.te().get(arg.return_type),
+            TypeInfo::SignedInteger(_)
+        )
+    });
+
     match intrinsic.kind {
         Intrinsic::Add | Intrinsic::Sub | Intrinsic::Mul | Intrinsic::Div | Intrinsic::Mod => {
             let ty = args[0].ty;
@@ -749,6 +766,28 @@ fn const_eval_intrinsic(
 
             use ConstantValue::*;
             match (&args[0].value, &args[1].value) {
+                (Uint(arg1), Uint(ref arg2)) if is_signed => {
+                    // Signed arithmetic wraps, just like the code generated for it.
+                    let (arg1, arg2) = (*arg1 as i64, *arg2 as i64);
+                    let result = match intrinsic.kind {
+                        Intrinsic::Add => Some(arg1.wrapping_add(arg2)),
+                        Intrinsic::Sub => Some(arg1.wrapping_sub(arg2)),
+                        Intrinsic::Mul => Some(arg1.wrapping_mul(arg2)),
+                        Intrinsic::Div => (arg2 != 0).then(|| arg1.wrapping_div(arg2)),
+                        Intrinsic::Mod => (arg2 != 0).then(|| arg1.wrapping_rem(arg2)),
+                        _ => unreachable!(),
+                    };
+
+                    match result {
+                        Some(result) => Ok(Some(Constant {
+                            ty,
+                            value: ConstantValue::Uint(result as u64),
+                        })),
+                        None => Err(ConstEvalError::CannotBeEvaluatedToConst {
+                            span: intrinsic.span.clone(),
+                        }),
+                    }
+                }
                 (Uint(arg1), Uint(ref arg2)) => {
                     // All arithmetic is done as if it were u64
                     let result = match intrinsic.kind {
@@ -870,6 +909,14 @@ fn const_eval_intrinsic(
 
             use ConstantValue::*;
             match (&args[0].value, &args[1].value) {
+                (Uint(arg1), Uint(ref arg2))
+                    if is_signed && matches!(intrinsic.kind, Intrinsic::Rsh) =>
+                {
+                    Ok(Some(Constant {
+                        ty,
+                        value: ConstantValue::Uint(((*arg1 as i64) >> (*arg2).min(63)) as u64),
+                    }))
+                }
                 (Uint(arg1), Uint(ref arg2)) => {
                     let result = match intrinsic.kind {
                         Intrinsic::Lsh => u32::try_from(*arg2)
@@ -1017,6 +1064,12 @@ fn const_eval_intrinsic(
             }))
         }
         Intrinsic::Gt => match (&args[0].value, &args[1].value) {
+            (ConstantValue::Uint(val1), ConstantValue::Uint(val2)) if is_signed => {
+                Ok(Some(Constant {
+                    ty: Type::get_bool(lookup.context),
+                    value: ConstantValue::Bool((*val1 as i64) > (*val2 as i64)),
+                }))
+            }
             (ConstantValue::Uint(val1), ConstantValue::Uint(val2)) => Ok(Some(Constant {
                 ty: Type::get_bool(lookup.context),
                 value: ConstantValue::Bool(val1 > val2),
@@ -1030,6 +1083,12 @@ fn const_eval_intrinsic(
             }
         },
         Intrinsic::Lt => match (&args[0].value, &args[1].value) {
+            (ConstantValue::Uint(val1), ConstantValue::Uint(val2)) if is_signed => {
+                Ok(Some(Constant {
+                    ty: Type::get_bool(lookup.context),
+                    value: ConstantValue::Bool((*val1 as i64) < (*val2 as i64)),
+                }))
+            }
             (ConstantValue::Uint(val1), ConstantValue::Uint(val2)) => Ok(Some(Constant {
                 ty: Type::get_bool(lookup.context),
                 value: ConstantValue::Bool(val1 < val2),
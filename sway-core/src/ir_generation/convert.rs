@@ -2,7 +2,7 @@ use crate::{
     decl_engine::DeclEngine,
     language::Literal,
     type_system::{TypeId, TypeInfo},
-    TypeEngine,
+    Engines, TypeEngine,
 };
 
 use super::types::{create_tagged_union_type, create_tuple_aggregate};
@@ -27,7 +27,12 @@ pub(super) fn convert_literal_to_value(context: &mut Context, ast_literal: &Lite
         Literal::U32(n) => Constant::get_uint(context, 64, *n as u64),
         Literal::U64(n) => Constant::get_uint(context, 64, *n),
         Literal::U256(n) => Constant::get_uint256(context, n.clone()),
-        Literal::Numeric(_) => unreachable!(),
+        // Signed integers are sign extended to 64 bits.
+        Literal::I8(n) => Constant::get_uint(context, 64, *n as i64 as u64),
+        Literal::I16(n) => Constant::get_uint(context, 64, *n as i64 as u64),
+        Literal::I32(n) => Constant::get_uint(context, 64, *n as i64 as u64),
+        Literal::I64(n) => Constant::get_uint(context, 64, *n as u64),
+        Literal::Numeric(_) | Literal::NegativeNumeric(_) => unreachable!(),
         Literal::String(s) => Constant::get_string(context, s.as_str().as_bytes().to_vec()),
         Literal::Boolean(b) => Constant::get_bool(context, *b),
         Literal::B256(bs) => Constant::get_b256(context, *bs),
@@ -45,13 +50,41 @@ pub(super) fn convert_literal_to_constant(
         Literal::U32(n) => Constant::new_uint(context, 64, *n as u64),
         Literal::U64(n) => Constant::new_uint(context, 64, *n),
         Literal::U256(n) => Constant::new_uint256(context, n.clone()),
-        Literal::Numeric(_) => unreachable!(),
+        Literal::I8(n) => Constant::new_uint(context, 64, *n as i64 as u64),
+        Literal::I16(n) => Constant::new_uint(context, 64, *n as i64 as u64),
+        Literal::I32(n) => Constant::new_uint(context, 64, *n as i64 as u64),
+        Literal::I64(n) => Constant::new_uint(context, 64, *n as u64),
+        Literal::Numeric(_) | Literal::NegativeNumeric(_) => unreachable!(),
         Literal::String(s) => Constant::new_string(context, s.as_str().as_bytes().to_vec()),
         Literal::Boolean(b) => Constant::new_bool(context, *b),
         Literal::B256(bs) => Constant::new_b256(context, *bs),
     }
 }
 
+/// Given the type an untyped integer literal was inferred to have, return the typed literal
+/// that it stands for.
+pub(super) fn convert_numeric_literal(
+    engines: &Engines,
+    ast_type: TypeId,
+    ast_literal: &Literal,
+    span: &Span,
+) -> Result<Literal, CompileError> {
+    let type_info = engines.te().get(ast_type);
+    match (ast_literal, &type_info) {
+        (Literal::Numeric(n), TypeInfo::UnsignedInteger(IntegerBits::Eight)) => {
+            Ok(Literal::U8(*n as u8))
+        }
+        (Literal::Numeric(n), TypeInfo::SignedInteger(_)) => Ok(Literal::I64(*n as i64)),
+        (Literal::Numeric(n), _) => Ok(Literal::U64(*n)),
+        (Literal::NegativeNumeric(n), TypeInfo::SignedInteger(_)) => Ok(Literal::I64(*n)),
+        (Literal::NegativeNumeric(_), _) => Err(CompileError::IntegerTooSmall {
+            ty: engines.help_out(type_info).to_string(),
+            span: span.clone(),
+        }),
+        _ => Ok(ast_literal.clone()),
+    }
+}
+
 pub(super) fn convert_resolved_typeid(
     type_engine: &TypeEngine,
     decl_engine: &DeclEngine,
@@ -108,6 +141,8 @@ fn convert_resolved_type(
         | TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo)
         | TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)
         | TypeInfo::Numeric => Type::get_uint64(context),
+        // Signed integers of all widths are sign extended 64-bit values.
+        TypeInfo::SignedInteger(_) => Type::get_uint64(context),
         TypeInfo::Boolean => Type::get_bool(context),
         TypeInfo::B256 => Type::get_b256(context),
         TypeInfo::StringSlice => Type::get_slice(context),
@@ -154,6 +189,7 @@ fn convert_resolved_type(
         TypeInfo::RawUntypedPtr => Type::get_uint64(context),
         TypeInfo::RawUntypedSlice => Type::get_slice(context),
         TypeInfo::Ptr(_) => Type::get_uint64(context),
+        TypeInfo::Ref { .. } => Type::get_uint64(context),
         TypeInfo::Slice(_) => Type::get_slice(context),
         TypeInfo::Alias { ty, .. } => {
             convert_resolved_typeid(type_engine, decl_engine, context, &ty.type_id, span)?
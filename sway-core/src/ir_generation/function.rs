@@ -25,7 +25,6 @@ use sway_ir::{Context, *};
 use sway_types::{
     constants,
     ident::Ident,
-    integer_bits::IntegerBits,
     span::{Span, Spanned},
     state::StateIndex,
     Named,
@@ -363,11 +362,15 @@ impl<'eng> FnCompiler<'eng> {
                 let string_len = s.as_str().len() as u64;
                 self.compile_string_slice(context, span_md_idx, string_data, string_len)
             }
-            ty::TyExpressionVariant::Literal(Literal::Numeric(n)) => {
-                let implied_lit = match self.engines.te().get(ast_expr.return_type) {
-                    TypeInfo::UnsignedInteger(IntegerBits::Eight) => Literal::U8(*n as u8),
-                    _ => Literal::U64(*n),
-                };
+            ty::TyExpressionVariant::Literal(
+                lit @ (Literal::Numeric(_) | Literal::NegativeNumeric(_)),
+            ) => {
+                let implied_lit = convert_numeric_literal(
+                    self.engines,
+                    ast_expr.return_type,
+                    lit,
+                    &ast_expr.span,
+                )?;
                 Ok(convert_literal_to_value(context, &implied_lit)
                     .add_metadatum(context, span_md_idx))
             }
@@ -561,6 +564,12 @@ impl<'eng> FnCompiler<'eng> {
             ty::TyExpressionVariant::Return(exp) => {
                 self.compile_return_statement(context, md_mgr, exp)
             }
+            ty::TyExpressionVariant::Ref(exp) => {
+                self.compile_ref(context, md_mgr, exp, span_md_idx)
+            }
+            ty::TyExpressionVariant::Deref(exp) => {
+                self.compile_deref(context, md_mgr, exp, span_md_idx)
+            }
         }
     }
 
@@ -701,10 +710,18 @@ impl<'eng> FnCompiler<'eng> {
                 let rhs = &arguments[1];
                 let lhs_value = self.compile_expression_to_value(context, md_mgr, lhs)?;
                 let rhs_value = self.compile_expression_to_value(context, md_mgr, rhs)?;
-                let pred = match kind {
-                    Intrinsic::Eq => Predicate::Equal,
-                    Intrinsic::Gt => Predicate::GreaterThan,
-                    Intrinsic::Lt => Predicate::LessThan,
+                let is_signed = [lhs, rhs].iter().any(|arg| {
+                    matches!(
+                        engines.te().get(arg.return_type),
+                        TypeInfo::SignedInteger(_)
+                    )
+                });
+                let pred = match (kind, is_signed) {
+                    (Intrinsic::Eq, _) => Predicate::Equal,
+                    (Intrinsic::Gt, false) => Predicate::GreaterThan,
+                    (Intrinsic::Gt, true) => Predicate::SGreaterThan,
+                    (Intrinsic::Lt, false) => Predicate::LessThan,
+                    (Intrinsic::Lt, true) => Predicate::SLessThan,
                     _ => unreachable!(),
                 };
                 Ok(self
@@ -928,21 +945,41 @@ impl<'eng> FnCompiler<'eng> {
             | Intrinsic::Mod
             | Intrinsic::Rsh
             | Intrinsic::Lsh => {
-                let op = match kind {
-                    Intrinsic::Add => BinaryOpKind::Add,
-                    Intrinsic::Sub => BinaryOpKind::Sub,
-                    Intrinsic::Mul => BinaryOpKind::Mul,
-                    Intrinsic::Div => BinaryOpKind::Div,
-                    Intrinsic::And => BinaryOpKind::And,
-                    Intrinsic::Or => BinaryOpKind::Or,
-                    Intrinsic::Xor => BinaryOpKind::Xor,
-                    Intrinsic::Mod => BinaryOpKind::Mod,
-                    Intrinsic::Rsh => BinaryOpKind::Rsh,
-                    Intrinsic::Lsh => BinaryOpKind::Lsh,
-                    _ => unreachable!(),
-                };
                 let lhs = &arguments[0];
                 let rhs = &arguments[1];
+                // Signed integers are sign extended to 64 bits and need their own operations,
+                // bitwise operations and left shifts are the same for both.
+                // An untyped literal operand, like the `0` in `__sub(0, x)`, stays numeric, so
+                // the signedness may come from either operand, except for the shift amount.
+                let typed_operands = match kind {
+                    Intrinsic::Lsh | Intrinsic::Rsh => &arguments[..1],
+                    _ => &arguments[..],
+                };
+                let is_signed = typed_operands.iter().any(|arg| {
+                    matches!(
+                        engines.te().get(arg.return_type),
+                        TypeInfo::SignedInteger(_)
+                    )
+                });
+                let op = match (kind, is_signed) {
+                    (Intrinsic::Add, false) => BinaryOpKind::Add,
+                    (Intrinsic::Add, true) => BinaryOpKind::SAdd,
+                    (Intrinsic::Sub, false) => BinaryOpKind::Sub,
+                    (Intrinsic::Sub, true) => BinaryOpKind::SSub,
+                    (Intrinsic::Mul, false) => BinaryOpKind::Mul,
+                    (Intrinsic::Mul, true) => BinaryOpKind::SMul,
+                    (Intrinsic::Div, false) => BinaryOpKind::Div,
+                    (Intrinsic::Div, true) => BinaryOpKind::SDiv,
+                    (Intrinsic::Mod, false) => BinaryOpKind::Mod,
+                    (Intrinsic::Mod, true) => BinaryOpKind::SMod,
+                    (Intrinsic::Rsh, false) => BinaryOpKind::Rsh,
+                    (Intrinsic::Rsh, true) => BinaryOpKind::SRsh,
+                    (Intrinsic::And, _) => BinaryOpKind::And,
+                    (Intrinsic::Or, _) => BinaryOpKind::Or,
+                    (Intrinsic::Xor, _) => BinaryOpKind::Xor,
+                    (Intrinsic::Lsh, _) => BinaryOpKind::Lsh,
+                    _ => unreachable!(),
+                };
                 let lhs_value = self.compile_expression_to_value(context, md_mgr, lhs)?;
                 let rhs_value = self.compile_expression_to_value(context, md_mgr, rhs)?;
                 Ok(self
@@ -1767,13 +1804,11 @@ impl<'eng> FnCompiler<'eng> {
         self.current_block = cond_block;
         let cond_value = self.compile_expression_to_value(context, md_mgr, condition)?;
         if !self.current_block.is_terminated(context) {
-            self.current_block.ins(context).conditional_branch(
-                cond_value,
-                body_block,
-                final_block,
-                vec![],
-                vec![],
-            );
+            let cond_span_md_idx = md_mgr.span_to_md(context, &condition.span);
+            self.current_block
+                .ins(context)
+                .conditional_branch(cond_value, body_block, final_block, vec![], vec![])
+                .add_metadatum(context, cond_span_md_idx);
         }
 
         self.current_block = final_block;
@@ -2006,9 +2041,26 @@ impl<'eng> FnCompiler<'eng> {
         ast_reassignment: &ty::TyReassignment,
         span_md_idx: Option<MetadataIndex>,
     ) -> Result<Value, CompileError> {
+        let (base_name, base_type, indices) = match &ast_reassignment.lhs {
+            ty::TyReassignmentTarget::ElementAccess {
+                base_name,
+                base_type,
+                indices,
+            } => (base_name, *base_type, indices),
+            ty::TyReassignmentTarget::Deref(reference) => {
+                return self.compile_deref_reassignment(
+                    context,
+                    md_mgr,
+                    reference,
+                    &ast_reassignment.rhs,
+                    span_md_idx,
+                )
+            }
+        };
+
         let name = self
             .lexical_map
-            .get(ast_reassignment.lhs_base_name.as_str())
+            .get(base_name.as_str())
             .expect("All local symbols must be in the lexical symbol map.");
 
         // First look for a local variable with the required name
@@ -2027,10 +2079,7 @@ impl<'eng> FnCompiler<'eng> {
                     .args_iter(context)
                     .find_map(|(arg_name, arg_val)| (arg_name == name).then_some(*arg_val)))
             .ok_or_else(|| {
-                CompileError::InternalOwned(
-                    format!("variable not found: {name}"),
-                    ast_reassignment.lhs_base_name.span(),
-                )
+                CompileError::InternalOwned(format!("variable not found: {name}"), base_name.span())
             })?;
 
         let reassign_val =
@@ -2039,16 +2088,15 @@ impl<'eng> FnCompiler<'eng> {
             return Ok(reassign_val);
         }
 
-        let lhs_ptr = if ast_reassignment.lhs_indices.is_empty() {
+        let lhs_ptr = if indices.is_empty() {
             // A non-aggregate; use a direct `store`.
             lhs_val
         } else {
             // Create a GEP by following the chain of LHS indices.  We use a scan which is
             // essentially a map with context, which is the parent type id for the current field.
-            let gep_indices = ast_reassignment
-                .lhs_indices
+            let gep_indices = indices
                 .iter()
-                .scan(ast_reassignment.lhs_type, |cur_type_id, idx_kind| {
+                .scan(base_type, |cur_type_id, idx_kind| {
                     let cur_type_info = self.engines.te().get_unaliased(*cur_type_id);
                     Some(match (idx_kind, cur_type_info) {
                         (
@@ -2076,7 +2124,7 @@ impl<'eng> FnCompiler<'eng> {
                                                 in reassignment.",
                                             struct_call_path.suffix.as_str(),
                                         ),
-                                        ast_reassignment.lhs_base_name.span(),
+                                        base_name.span(),
                                     )
                                 })
                                 .map(|(field_idx, field_type_id)| {
@@ -2106,7 +2154,7 @@ impl<'eng> FnCompiler<'eng> {
             let field_type = reassign_val.get_type(context).ok_or_else(|| {
                 CompileError::Internal(
                     "Failed to determine type of reassignment.",
-                    ast_reassignment.lhs_base_name.span(),
+                    base_name.span(),
                 )
             })?;
 
@@ -2125,6 +2173,95 @@ impl<'eng> FnCompiler<'eng> {
         Ok(Constant::get_unit(context).add_metadatum(context, span_md_idx))
     }
 
+    fn compile_deref_reassignment(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        ast_reference: &ty::TyExpression,
+        ast_rhs: &ty::TyExpression,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        // As with the element access above, the RHS is evaluated before the assignee.
+        let reassign_val = self.compile_expression_to_value(context, md_mgr, ast_rhs)?;
+        if reassign_val.is_diverging(context) {
+            return Ok(reassign_val);
+        }
+
+        let lhs_ptr = self.compile_deref(context, md_mgr, ast_reference, span_md_idx)?;
+        if lhs_ptr.is_diverging(context) {
+            return Ok(lhs_ptr);
+        }
+
+        self.current_block
+            .ins(context)
+            .store(lhs_ptr, reassign_val)
+            .add_metadatum(context, span_md_idx);
+
+        Ok(Constant::get_unit(context).add_metadatum(context, span_md_idx))
+    }
+
+    fn compile_ref(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        ast_expr: &ty::TyExpression,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        // References are plain `u64` addresses in the IR.  Places (variables, fields, elements)
+        // are referenced directly, while any other value is first spilled to a temporary.
+        let value = self.compile_expression_to_ptr(context, md_mgr, ast_expr)?;
+        if value.is_diverging(context) {
+            return Ok(value);
+        }
+
+        let int_ty = Type::get_uint64(context);
+        Ok(self
+            .current_block
+            .ins(context)
+            .ptr_to_int(value, int_ty)
+            .add_metadatum(context, span_md_idx))
+    }
+
+    fn compile_deref(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        ast_expr: &ty::TyExpression,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let TypeInfo::Ref {
+            referenced_type, ..
+        } = self.engines.te().get_unaliased(ast_expr.return_type)
+        else {
+            return Err(CompileError::Internal(
+                "Cannot dereference a non-reference expression.",
+                ast_expr.span.clone(),
+            ));
+        };
+
+        let reference = self.compile_expression_to_value(context, md_mgr, ast_expr)?;
+        if reference.is_diverging(context) {
+            return Ok(reference);
+        }
+
+        let referenced_ir_type = convert_resolved_typeid(
+            self.engines.te(),
+            self.engines.de(),
+            context,
+            &referenced_type.type_id,
+            &referenced_type.span,
+        )?;
+        let ptr_ty = Type::new_ptr(context, referenced_ir_type);
+
+        // The resulting pointer is loaded by `compile_expression_to_value()` where a value is
+        // needed, and used directly as the place when assigning through the reference.
+        Ok(self
+            .current_block
+            .ins(context)
+            .int_to_ptr(reference, ptr_ty)
+            .add_metadatum(context, span_md_idx))
+    }
+
     fn compile_array_expr(
         &mut self,
         context: &mut Context,
@@ -2872,3 +3009,18 @@ impl<'eng> FnCompiler<'eng> {
         Ok(storage_key)
     }
 }
+
+/// The name of the Sway function which an IR function was compiled from.
+///
+/// Called functions are compiled as single-use instantiations, with their names suffixed by a
+/// unique id to tell them apart (see `FnCompiler::compile_fn_call()`).  This strips that suffix.
+pub(crate) fn source_fn_name(ir_name: &str) -> &str {
+    match ir_name.rsplit_once('_') {
+        Some((name, id))
+            if !name.is_empty() && !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            name
+        }
+        _ => ir_name,
+    }
+}
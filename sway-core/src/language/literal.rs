@@ -16,8 +16,16 @@ pub enum Literal {
     U32(u32),
     U64(u64),
     U256(U256),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
     String(span::Span),
     Numeric(u64),
+    /// A negative integer literal without a type suffix, e.g. `-5`.
+    /// Like [Literal::Numeric], its type is determined by inference,
+    /// but it can only resolve to a signed integer type.
+    NegativeNumeric(i64),
     Boolean(bool),
     B256([u8; 32]),
 }
@@ -62,6 +70,26 @@ impl Hash for Literal {
                 state.write_u8(8);
                 x.hash(state);
             }
+            I8(x) => {
+                state.write_u8(9);
+                x.hash(state);
+            }
+            I16(x) => {
+                state.write_u8(10);
+                x.hash(state);
+            }
+            I32(x) => {
+                state.write_u8(11);
+                x.hash(state);
+            }
+            I64(x) => {
+                state.write_u8(12);
+                x.hash(state);
+            }
+            NegativeNumeric(x) => {
+                state.write_u8(13);
+                x.hash(state);
+            }
         }
     }
 }
@@ -74,6 +102,11 @@ impl PartialEq for Literal {
             (Self::U32(l0), Self::U32(r0)) => l0 == r0,
             (Self::U64(l0), Self::U64(r0)) => l0 == r0,
             (Self::U256(l0), Self::U256(r0)) => l0 == r0,
+            (Self::I8(l0), Self::I8(r0)) => l0 == r0,
+            (Self::I16(l0), Self::I16(r0)) => l0 == r0,
+            (Self::I32(l0), Self::I32(r0)) => l0 == r0,
+            (Self::I64(l0), Self::I64(r0)) => l0 == r0,
+            (Self::NegativeNumeric(l0), Self::NegativeNumeric(r0)) => l0 == r0,
             (Self::String(l0), Self::String(r0)) => *l0.as_str() == *r0.as_str(),
             (Self::Numeric(l0), Self::Numeric(r0)) => l0 == r0,
             (Self::Boolean(l0), Self::Boolean(r0)) => l0 == r0,
@@ -91,7 +124,12 @@ impl fmt::Display for Literal {
             Literal::U32(content) => content.to_string(),
             Literal::U64(content) => content.to_string(),
             Literal::U256(content) => content.to_string(),
+            Literal::I8(content) => content.to_string(),
+            Literal::I16(content) => content.to_string(),
+            Literal::I32(content) => content.to_string(),
+            Literal::I64(content) => content.to_string(),
             Literal::Numeric(content) => content.to_string(),
+            Literal::NegativeNumeric(content) => content.to_string(),
             Literal::String(content) => content.as_str().to_string(),
             Literal::Boolean(content) => content.to_string(),
             Literal::B256(content) => content
@@ -134,14 +172,35 @@ impl Literal {
     pub(crate) fn to_typeinfo(&self) -> TypeInfo {
         match self {
             Literal::String(_) => TypeInfo::StringSlice,
-            Literal::Numeric(_) => TypeInfo::Numeric,
+            Literal::Numeric(_) | Literal::NegativeNumeric(_) => TypeInfo::Numeric,
             Literal::U8(_) => TypeInfo::UnsignedInteger(IntegerBits::Eight),
             Literal::U16(_) => TypeInfo::UnsignedInteger(IntegerBits::Sixteen),
             Literal::U32(_) => TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo),
             Literal::U64(_) => TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
             Literal::U256(_) => TypeInfo::UnsignedInteger(IntegerBits::V256),
+            Literal::I8(_) => TypeInfo::SignedInteger(IntegerBits::Eight),
+            Literal::I16(_) => TypeInfo::SignedInteger(IntegerBits::Sixteen),
+            Literal::I32(_) => TypeInfo::SignedInteger(IntegerBits::ThirtyTwo),
+            Literal::I64(_) => TypeInfo::SignedInteger(IntegerBits::SixtyFour),
             Literal::Boolean(_) => TypeInfo::Boolean,
             Literal::B256(_) => TypeInfo::B256,
         }
     }
+
+    /// Returns the value of an integer literal, or `None` if the literal is
+    /// not an integer or is a `u256` that does not fit into 64 bits.
+    pub(crate) fn as_integer(&self) -> Option<i128> {
+        match self {
+            Literal::U8(x) => Some((*x).into()),
+            Literal::U16(x) => Some((*x).into()),
+            Literal::U32(x) => Some((*x).into()),
+            Literal::U64(x) | Literal::Numeric(x) => Some((*x).into()),
+            Literal::U256(x) => u64::try_from(x.clone()).ok().map(i128::from),
+            Literal::I8(x) => Some((*x).into()),
+            Literal::I16(x) => Some((*x).into()),
+            Literal::I32(x) => Some((*x).into()),
+            Literal::I64(x) | Literal::NegativeNumeric(x) => Some((*x).into()),
+            Literal::String(_) | Literal::Boolean(_) | Literal::B256(_) => None,
+        }
+    }
 }
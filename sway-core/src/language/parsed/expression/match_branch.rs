@@ -5,6 +5,8 @@ use super::{Expression, Scrutinee};
 #[derive(Debug, Clone)]
 pub struct MatchBranch {
     pub scrutinee: Scrutinee,
+    /// The optional `if` condition that must also hold for the branch to match.
+    pub guard: Option<Expression>,
     pub result: Expression,
     pub(crate) span: span::Span,
 }
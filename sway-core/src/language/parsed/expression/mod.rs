@@ -72,6 +72,18 @@ pub struct IfExpression {
 pub struct MatchExpression {
     pub value: Box<Expression>,
     pub branches: Vec<MatchBranch>,
+    /// Tells if the match expression is desugared from a destructuring pattern,
+    /// which must be irrefutable, and where the pattern is written.
+    pub destructuring: Option<DestructuringKind>,
+}
+
+/// The place of a destructuring pattern, which must be irrefutable.
+#[derive(Debug, Clone, Copy)]
+pub enum DestructuringKind {
+    /// The pattern of a `let` statement or of a function parameter.
+    LetOrParam,
+    /// The pattern of a `for` loop.
+    ForLoop,
 }
 
 #[derive(Debug, Clone)]
@@ -235,6 +247,41 @@ pub struct WhileLoopExpression {
     pub body: CodeBlock,
 }
 
+/// A `for` loop, which is desugared to a `while` loop over an iterator:
+///
+/// ```ignore
+/// let __for_iterable_1 = <iterable>;
+/// let mut __for_iterator_1 = <iterator over __for_iterable_1>;
+/// while true {
+///     let __for_next_value_1 = __for_iterator_1.next();
+///     if __for_next_value_1.is_none() {
+///         break;
+///     }
+///     let <pattern> = __for_next_value_1.unwrap();
+///     <body>
+/// }
+/// ```
+///
+/// The declarations of the iterable and the iterator are created during type
+/// checking, because the iterator depends on the type of the iterable. An array
+/// is iterated over with a `std::iterator::SliceIter`, while any other iterable
+/// must implement `Iterator` and is its own iterator.
+#[derive(Debug, Clone)]
+pub struct ForLoopExpression {
+    pub iterable: Box<Expression>,
+    pub iterable_name: Ident,
+    pub iterator_name: Ident,
+    /// The body of the `while` loop, ending with the body of the `for` loop.
+    pub body: CodeBlock,
+}
+
+#[derive(Debug, Clone)]
+pub struct RefExpression {
+    /// True if the reference is a reference to a mutable `value`.
+    pub to_mutable_value: bool,
+    pub value: Box<Expression>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ReassignmentExpression {
     pub lhs: ReassignmentTarget,
@@ -304,15 +351,23 @@ pub enum ExpressionKind {
     /// A control flow element which loops continually until some boolean expression evaluates as
     /// `false`.
     WhileLoop(WhileLoopExpression),
+    /// A control flow element which loops over the values returned by an iterator.
+    ForLoop(ForLoopExpression),
     Break,
     Continue,
     Reassignment(ReassignmentExpression),
     Return(Box<Expression>),
+    /// A reference to a value, `&value` or `&mut value`.
+    Ref(RefExpression),
+    /// A dereference of a reference, `*reference`.
+    Deref(Box<Expression>),
 }
 
 #[derive(Debug, Clone)]
 pub enum ReassignmentTarget {
     VariableExpression(Box<Expression>),
+    /// An assignment through a reference, `*reference = ...`, holding the reference expression.
+    Deref(Box<Expression>),
 }
 
 #[derive(Debug, Clone)]
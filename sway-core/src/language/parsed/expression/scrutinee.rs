@@ -23,6 +23,14 @@ pub enum Scrutinee {
         value: Literal,
         span: Span,
     },
+    /// An integer range, e.g. `1..=5`. `end` is excluded from the range
+    /// unless `is_inclusive` is set.
+    Range {
+        start: Literal,
+        end: Literal,
+        is_inclusive: bool,
+        span: Span,
+    },
     Variable {
         name: Ident,
         span: Span,
@@ -68,6 +76,7 @@ impl Spanned for Scrutinee {
             Scrutinee::Or { span, .. } => span.clone(),
             Scrutinee::CatchAll { span } => span.clone(),
             Scrutinee::Literal { span, .. } => span.clone(),
+            Scrutinee::Range { span, .. } => span.clone(),
             Scrutinee::Variable { span, .. } => span.clone(),
             Scrutinee::AmbiguousSingleIdent(ident) => ident.span(),
             Scrutinee::StructScrutinee { span, .. } => span.clone(),
@@ -173,6 +182,7 @@ impl Scrutinee {
                 .flat_map(|scrutinee| scrutinee.gather_approximate_typeinfo_dependencies())
                 .collect::<Vec<TypeInfo>>(),
             Scrutinee::Literal { .. }
+            | Scrutinee::Range { .. }
             | Scrutinee::CatchAll { .. }
             | Scrutinee::AmbiguousSingleIdent(..)
             | Scrutinee::Variable { .. }
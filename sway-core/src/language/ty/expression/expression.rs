@@ -304,8 +304,14 @@ impl CollectTypesMetadata for TyExpression {
             | Continue
             | FunctionParameter => {}
             Reassignment(reassignment) => {
+                if let TyReassignmentTarget::Deref(exp) = &reassignment.lhs {
+                    res.append(&mut exp.collect_types_metadata(handler, ctx)?);
+                }
                 res.append(&mut reassignment.rhs.collect_types_metadata(handler, ctx)?);
             }
+            Ref(exp) | Deref(exp) => {
+                res.append(&mut exp.collect_types_metadata(handler, ctx)?);
+            }
         }
         Ok(res)
     }
@@ -393,9 +399,17 @@ impl DeterministicallyAborts for TyExpression {
             }
             Break => false,
             Continue => false,
-            Reassignment(reassignment) => reassignment
-                .rhs
-                .deterministically_aborts(decl_engine, check_call_body),
+            Reassignment(reassignment) => {
+                if let TyReassignmentTarget::Deref(exp) = &reassignment.lhs {
+                    if exp.deterministically_aborts(decl_engine, check_call_body) {
+                        return true;
+                    }
+                }
+                reassignment
+                    .rhs
+                    .deterministically_aborts(decl_engine, check_call_body)
+            }
+            Ref(exp) | Deref(exp) => exp.deterministically_aborts(decl_engine, check_call_body),
             // TODO: Is this correct?
             // I'm not sure what this function is supposed to do exactly. It's called
             // "deterministically_aborts" which I thought meant it checks for an abort/panic, but
@@ -143,6 +143,8 @@ pub enum TyExpressionVariant {
     Continue,
     Reassignment(Box<TyReassignment>),
     Return(Box<TyExpression>),
+    Ref(Box<TyExpression>),
+    Deref(Box<TyExpression>),
 }
 
 impl EqWithEngines for TyExpressionVariant {}
@@ -401,6 +403,8 @@ impl PartialEqWithEngines for TyExpressionVariant {
                     condition: r_condition,
                 },
             ) => l_body.eq(r_body, engines) && l_condition.eq(r_condition, engines),
+            (Self::Ref(l_exp), Self::Ref(r_exp)) => l_exp.eq(r_exp, engines),
+            (Self::Deref(l_exp), Self::Deref(r_exp)) => l_exp.eq(r_exp, engines),
             (l, r) => std::mem::discriminant(l) == std::mem::discriminant(r),
         }
     }
@@ -601,6 +605,9 @@ impl HashWithEngines for TyExpressionVariant {
             Self::Return(exp) => {
                 exp.hash(state, engines);
             }
+            Self::Ref(exp) | Self::Deref(exp) => {
+                exp.hash(state, engines);
+            }
         }
     }
 }
@@ -745,6 +752,7 @@ impl SubstTypes for TyExpressionVariant {
             Continue => (),
             Reassignment(reassignment) => reassignment.subst(type_mapping, engines),
             Return(stmt) => stmt.subst(type_mapping, engines),
+            Ref(exp) | Deref(exp) => exp.subst(type_mapping, engines),
         }
     }
 }
@@ -891,6 +899,7 @@ impl ReplaceDecls for TyExpressionVariant {
                     reassignment.replace_decls(decl_mapping, handler, ctx)?
                 }
                 Return(stmt) => stmt.replace_decls(decl_mapping, handler, ctx)?,
+                Ref(exp) | Deref(exp) => exp.replace_decls(decl_mapping, handler, ctx)?,
             }
 
             Ok(())
@@ -999,6 +1008,9 @@ impl TypeCheckAnalysis for TyExpressionVariant {
             TyExpressionVariant::Return(node) => {
                 node.type_check_analyze(handler, ctx)?;
             }
+            TyExpressionVariant::Ref(exp) | TyExpressionVariant::Deref(exp) => {
+                exp.type_check_analyze(handler, ctx)?;
+            }
         }
         Ok(())
     }
@@ -1116,17 +1128,19 @@ impl TypeCheckFinalization for TyExpressionVariant {
                 TyExpressionVariant::Break => {}
                 TyExpressionVariant::Continue => {}
                 TyExpressionVariant::Reassignment(node) => {
-                    for lhs_index in node.lhs_indices.iter_mut() {
-                        match lhs_index {
-                            ProjectionKind::StructField { name: _ } => {}
-                            ProjectionKind::TupleField {
-                                index: _,
-                                index_span: _,
-                            } => {}
-                            ProjectionKind::ArrayIndex {
-                                index,
-                                index_span: _,
-                            } => index.expression.type_check_finalize(handler, ctx)?,
+                    if let TyReassignmentTarget::ElementAccess { indices, .. } = &mut node.lhs {
+                        for lhs_index in indices.iter_mut() {
+                            match lhs_index {
+                                ProjectionKind::StructField { name: _ } => {}
+                                ProjectionKind::TupleField {
+                                    index: _,
+                                    index_span: _,
+                                } => {}
+                                ProjectionKind::ArrayIndex {
+                                    index,
+                                    index_span: _,
+                                } => index.expression.type_check_finalize(handler, ctx)?,
+                            }
                         }
                     }
                     node.type_check_finalize(handler, ctx)?;
@@ -1134,6 +1148,9 @@ impl TypeCheckFinalization for TyExpressionVariant {
                 TyExpressionVariant::Return(node) => {
                     node.type_check_finalize(handler, ctx)?;
                 }
+                TyExpressionVariant::Ref(exp) | TyExpressionVariant::Deref(exp) => {
+                    exp.type_check_finalize(handler, ctx)?;
+                }
             }
             Ok(())
         })
@@ -1236,6 +1253,7 @@ impl UpdateConstantExpression for TyExpressionVariant {
                 reassignment.update_constant_expression(engines, implementing_type)
             }
             Return(stmt) => stmt.update_constant_expression(engines, implementing_type),
+            Ref(exp) | Deref(exp) => exp.update_constant_expression(engines, implementing_type),
         }
     }
 }
@@ -1378,25 +1396,38 @@ impl DebugWithEngines for TyExpressionVariant {
             }
             TyExpressionVariant::Break => "break".to_string(),
             TyExpressionVariant::Continue => "continue".to_string(),
-            TyExpressionVariant::Reassignment(reassignment) => {
-                let mut place = reassignment.lhs_base_name.to_string();
-                for index in &reassignment.lhs_indices {
-                    place.push('.');
-                    match index {
-                        ProjectionKind::StructField { name } => place.push_str(name.as_str()),
-                        ProjectionKind::TupleField { index, .. } => {
-                            write!(&mut place, "{index}").unwrap();
-                        }
-                        ProjectionKind::ArrayIndex { index, .. } => {
-                            write!(&mut place, "{index:#?}").unwrap();
+            TyExpressionVariant::Reassignment(reassignment) => match &reassignment.lhs {
+                TyReassignmentTarget::ElementAccess {
+                    base_name, indices, ..
+                } => {
+                    let mut place = base_name.to_string();
+                    for index in indices {
+                        place.push('.');
+                        match index {
+                            ProjectionKind::StructField { name } => place.push_str(name.as_str()),
+                            ProjectionKind::TupleField { index, .. } => {
+                                write!(&mut place, "{index}").unwrap();
+                            }
+                            ProjectionKind::ArrayIndex { index, .. } => {
+                                write!(&mut place, "{index:#?}").unwrap();
+                            }
                         }
                     }
+                    format!("reassignment to {place}")
                 }
-                format!("reassignment to {place}")
-            }
+                TyReassignmentTarget::Deref(exp) => {
+                    format!("reassignment to *{:?}", engines.help_out(&**exp))
+                }
+            },
             TyExpressionVariant::Return(exp) => {
                 format!("return {:?}", engines.help_out(&**exp))
             }
+            TyExpressionVariant::Ref(exp) => {
+                format!("&({:?})", engines.help_out(&**exp))
+            }
+            TyExpressionVariant::Deref(exp) => {
+                format!("*({:?})", engines.help_out(&**exp))
+            }
         };
         write!(f, "{s}")
     }
@@ -1447,7 +1478,12 @@ impl TyExpressionVariant {
                 buf
             }
             TyExpressionVariant::Reassignment(reassignment) => {
-                reassignment.rhs.gather_return_statements()
+                let mut buf = match &reassignment.lhs {
+                    TyReassignmentTarget::Deref(exp) => exp.gather_return_statements(),
+                    TyReassignmentTarget::ElementAccess { .. } => vec![],
+                };
+                buf.append(&mut reassignment.rhs.gather_return_statements());
+                buf
             }
             TyExpressionVariant::LazyOperator { lhs, rhs, .. } => [lhs, rhs]
                 .into_iter()
@@ -1507,6 +1543,9 @@ impl TyExpressionVariant {
                 .collect(),
             TyExpressionVariant::EnumTag { exp } => exp.gather_return_statements(),
             TyExpressionVariant::UnsafeDowncast { exp, .. } => exp.gather_return_statements(),
+            TyExpressionVariant::Ref(exp) | TyExpressionVariant::Deref(exp) => {
+                exp.gather_return_statements()
+            }
 
             TyExpressionVariant::Return(exp) => {
                 vec![exp]
@@ -8,7 +8,10 @@ use crate::{
 };
 use itertools::Itertools;
 use sway_ast::Intrinsic;
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    error::{CompileError, TypeNotAllowedReason},
+    handler::{ErrorEmitted, Handler},
+};
 use sway_types::Span;
 
 #[derive(Debug, Clone)]
@@ -98,9 +101,26 @@ impl CollectTypesMetadata for TyIntrinsicFunctionKind {
 
         match self.kind {
             Intrinsic::Log => {
+                let logged_type_id = self.arguments[0].return_type;
+                let contains_ref = !logged_type_id
+                    .extract_any_including_self(
+                        ctx.engines,
+                        &|t| matches!(t, TypeInfo::Ref { .. }),
+                        vec![],
+                    )
+                    .is_empty();
+                if contains_ref {
+                    let span = ctx
+                        .call_site_get(&logged_type_id)
+                        .unwrap_or_else(|| self.arguments[0].span.clone());
+                    handler.emit_err(CompileError::TypeNotAllowed {
+                        reason: TypeNotAllowedReason::ReferenceInLoggedType,
+                        span,
+                    });
+                }
                 types_metadata.push(TypeMetadata::LoggedType(
                     LogId::new(ctx.log_id_counter()),
-                    self.arguments[0].return_type,
+                    logged_type_id,
                 ));
                 *ctx.log_id_counter_mut() += 1;
             }
@@ -32,7 +32,10 @@ pub(crate) struct TyMatchBranch {
     pub(crate) matched_or_variant_index_vars: MatchedOrVariantIndexVars,
     /// A boolean expression that represents the total match arm requirement,
     /// or `None` if the match arm is a catch-all arm.
+    /// If the match arm has a guard, the guard is already a part of the condition.
     pub(crate) condition: MatchBranchCondition,
+    /// True if the match arm has an `if` guard.
+    pub(crate) has_guard: bool,
     /// The resulting [crate::ty::TyCodeBlock] that includes the match arm variable declarations
     /// and the typed result from the original untyped branch result.
     pub(crate) result: TyExpression,
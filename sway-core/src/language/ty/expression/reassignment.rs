@@ -19,47 +19,128 @@ use crate::{
 
 #[derive(Clone, Debug)]
 pub struct TyReassignment {
-    // either a direct variable, so length of 1, or
-    // at series of struct fields/array indices (array syntax)
-    pub lhs_base_name: Ident,
-    pub lhs_type: TypeId,
-    pub lhs_indices: Vec<ProjectionKind>,
+    pub lhs: TyReassignmentTarget,
     pub rhs: TyExpression,
 }
 
+#[derive(Clone, Debug)]
+pub enum TyReassignmentTarget {
+    /// An [TyExpression] representing a single variable or a path
+    /// to a part of an aggregate.
+    /// E.g.:
+    ///  - `my_variable`
+    ///  - `array[0].field.x.1`
+    ElementAccess {
+        /// [Ident] of the single variable, or the starting variable
+        /// of the path to a part of an aggregate.
+        base_name: Ident,
+        /// [TypeId] of the variable behind the `base_name`.
+        base_type: TypeId,
+        /// Indices representing the path from the `base_name` to the
+        /// final part of an aggregate.
+        /// Empty if the LHS of the reassignment is a single variable.
+        indices: Vec<ProjectionKind>,
+    },
+    /// An dereferencing [TyExpression] representing dereferencing
+    /// of an arbitrary reference expression.
+    /// E.g.:
+    ///  - *my_ref
+    ///  - **if x > 0 { &mut &mut a } else { &mut &mut b }
+    Deref(Box<TyExpression>),
+}
+
+impl EqWithEngines for TyReassignmentTarget {}
+impl PartialEqWithEngines for TyReassignmentTarget {
+    fn eq(&self, other: &Self, engines: &Engines) -> bool {
+        let type_engine = engines.te();
+        match (self, other) {
+            (TyReassignmentTarget::Deref(l), TyReassignmentTarget::Deref(r)) => (*l).eq(r, engines),
+            (
+                TyReassignmentTarget::ElementAccess {
+                    base_name: l_name,
+                    base_type: l_type,
+                    indices: l_indices,
+                },
+                TyReassignmentTarget::ElementAccess {
+                    base_name: r_name,
+                    base_type: r_type,
+                    indices: r_indices,
+                },
+            ) => {
+                l_name == r_name
+                    && type_engine
+                        .get(*l_type)
+                        .eq(&type_engine.get(*r_type), engines)
+                    && l_indices.eq(r_indices, engines)
+            }
+            _ => false,
+        }
+    }
+}
+
 impl EqWithEngines for TyReassignment {}
 impl PartialEqWithEngines for TyReassignment {
     fn eq(&self, other: &Self, engines: &Engines) -> bool {
+        self.lhs.eq(&other.lhs, engines) && self.rhs.eq(&other.rhs, engines)
+    }
+}
+
+impl HashWithEngines for TyReassignmentTarget {
+    fn hash<H: Hasher>(&self, state: &mut H, engines: &Engines) {
         let type_engine = engines.te();
-        self.lhs_base_name == other.lhs_base_name
-            && type_engine
-                .get(self.lhs_type)
-                .eq(&type_engine.get(other.lhs_type), engines)
-            && self.lhs_indices.eq(&other.lhs_indices, engines)
-            && self.rhs.eq(&other.rhs, engines)
+        std::mem::discriminant(self).hash(state);
+        match self {
+            TyReassignmentTarget::Deref(exp) => exp.hash(state, engines),
+            TyReassignmentTarget::ElementAccess {
+                base_name,
+                base_type,
+                indices,
+            } => {
+                base_name.hash(state);
+                type_engine.get(*base_type).hash(state, engines);
+                indices.hash(state, engines);
+            }
+        }
     }
 }
 
 impl HashWithEngines for TyReassignment {
     fn hash<H: Hasher>(&self, state: &mut H, engines: &Engines) {
-        let TyReassignment {
-            lhs_base_name,
-            lhs_type,
-            lhs_indices,
-            rhs,
-        } = self;
-        let type_engine = engines.te();
-        lhs_base_name.hash(state);
-        type_engine.get(*lhs_type).hash(state, engines);
-        lhs_indices.hash(state, engines);
+        let TyReassignment { lhs, rhs } = self;
+        lhs.hash(state, engines);
         rhs.hash(state, engines);
     }
 }
 
+impl SubstTypes for TyReassignmentTarget {
+    fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: &Engines) {
+        match self {
+            TyReassignmentTarget::Deref(exp) => exp.subst(type_mapping, engines),
+            TyReassignmentTarget::ElementAccess { base_type, .. } => {
+                base_type.subst(type_mapping, engines)
+            }
+        }
+    }
+}
+
 impl SubstTypes for TyReassignment {
     fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: &Engines) {
+        self.lhs.subst(type_mapping, engines);
         self.rhs.subst(type_mapping, engines);
-        self.lhs_type.subst(type_mapping, engines);
+    }
+}
+
+impl ReplaceDecls for TyReassignmentTarget {
+    fn replace_decls_inner(
+        &mut self,
+        decl_mapping: &DeclMapping,
+        handler: &Handler,
+        ctx: &mut TypeCheckContext,
+    ) -> Result<(), ErrorEmitted> {
+        match self {
+            TyReassignmentTarget::Deref(exp) => exp.replace_decls(decl_mapping, handler, ctx),
+            TyReassignmentTarget::ElementAccess { .. } => Ok(()),
+        }
     }
 }
 
@@ -70,32 +151,74 @@ impl ReplaceDecls for TyReassignment {
         handler: &Handler,
         ctx: &mut TypeCheckContext,
     ) -> Result<(), ErrorEmitted> {
+        self.lhs.replace_decls(decl_mapping, handler, ctx)?;
         self.rhs.replace_decls(decl_mapping, handler, ctx)
     }
 }
 
+impl TypeCheckAnalysis for TyReassignmentTarget {
+    fn type_check_analyze(
+        &self,
+        handler: &Handler,
+        ctx: &mut TypeCheckAnalysisContext,
+    ) -> Result<(), ErrorEmitted> {
+        match self {
+            TyReassignmentTarget::Deref(exp) => exp.type_check_analyze(handler, ctx),
+            TyReassignmentTarget::ElementAccess { .. } => Ok(()),
+        }
+    }
+}
+
 impl TypeCheckAnalysis for TyReassignment {
     fn type_check_analyze(
         &self,
         handler: &Handler,
         ctx: &mut TypeCheckAnalysisContext,
     ) -> Result<(), ErrorEmitted> {
+        self.lhs.type_check_analyze(handler, ctx)?;
         self.rhs.type_check_analyze(handler, ctx)
     }
 }
 
+impl TypeCheckFinalization for TyReassignmentTarget {
+    fn type_check_finalize(
+        &mut self,
+        handler: &Handler,
+        ctx: &mut TypeCheckFinalizationContext,
+    ) -> Result<(), ErrorEmitted> {
+        match self {
+            TyReassignmentTarget::Deref(exp) => exp.type_check_finalize(handler, ctx),
+            TyReassignmentTarget::ElementAccess { .. } => Ok(()),
+        }
+    }
+}
+
 impl TypeCheckFinalization for TyReassignment {
     fn type_check_finalize(
         &mut self,
         handler: &Handler,
         ctx: &mut TypeCheckFinalizationContext,
     ) -> Result<(), ErrorEmitted> {
+        self.lhs.type_check_finalize(handler, ctx)?;
         self.rhs.type_check_finalize(handler, ctx)
     }
 }
 
+impl UpdateConstantExpression for TyReassignmentTarget {
+    fn update_constant_expression(&mut self, engines: &Engines, implementing_type: &TyDecl) {
+        match self {
+            TyReassignmentTarget::Deref(exp) => {
+                exp.update_constant_expression(engines, implementing_type)
+            }
+            TyReassignmentTarget::ElementAccess { .. } => {}
+        }
+    }
+}
+
 impl UpdateConstantExpression for TyReassignment {
     fn update_constant_expression(&mut self, engines: &Engines, implementing_type: &TyDecl) {
+        self.lhs
+            .update_constant_expression(engines, implementing_type);
         self.rhs
             .update_constant_expression(engines, implementing_type)
     }
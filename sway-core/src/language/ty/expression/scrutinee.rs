@@ -18,6 +18,11 @@ pub enum TyScrutineeVariant {
     Or(Vec<TyScrutinee>),
     CatchAll,
     Literal(Literal),
+    Range {
+        start: Literal,
+        end: Literal,
+        is_inclusive: bool,
+    },
     Variable(Ident),
     Constant(Ident, Literal, TyConstantDecl),
     StructScrutinee {
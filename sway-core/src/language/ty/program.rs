@@ -224,6 +224,41 @@ impl TyProgram {
                     }
                 }
 
+                // References point into the contract's own memory, so they can neither be
+                // passed to nor returned from its ABI methods.
+                for abi_entry in abi_entries.iter() {
+                    let method = decl_engine.get_function(abi_entry);
+                    for p in method.parameters() {
+                        if let Some(error) = get_type_not_allowed_error(
+                            engines,
+                            p.type_argument.type_id,
+                            &p.type_argument,
+                            |t| match t {
+                                TypeInfo::Ref { .. } => {
+                                    Some(TypeNotAllowedReason::ReferenceInAbiParameters)
+                                }
+                                _ => None,
+                            },
+                        ) {
+                            handler.emit_err(error);
+                        }
+                    }
+
+                    if let Some(error) = get_type_not_allowed_error(
+                        engines,
+                        method.return_type.type_id,
+                        &method.return_type,
+                        |t| match t {
+                            TypeInfo::Ref { .. } => {
+                                Some(TypeNotAllowedReason::ReferenceInAbiReturn)
+                            }
+                            _ => None,
+                        },
+                    ) {
+                        handler.emit_err(error);
+                    }
+                }
+
                 TyProgramKind::Contract { abi_entries }
             }
             parsed::TreeType::Library => {
@@ -346,12 +381,38 @@ impl TyProgram {
                             span: param.name.span(),
                         });
                     }
+
+                    if let Some(error) = get_type_not_allowed_error(
+                        engines,
+                        param.type_argument.type_id,
+                        &param.type_argument,
+                        |t| match t {
+                            TypeInfo::Ref { .. } => {
+                                Some(TypeNotAllowedReason::ReferenceInMainParameters)
+                            }
+                            _ => None,
+                        },
+                    ) {
+                        handler.emit_err(error);
+                    }
+                }
+
+                if let Some(error) = get_type_not_allowed_error(
+                    engines,
+                    main_function.return_type.type_id,
+                    &main_function.return_type,
+                    |t| match t {
+                        TypeInfo::Ref { .. } => Some(TypeNotAllowedReason::ReferenceInMainReturn),
+                        _ => None,
+                    },
+                ) {
+                    handler.emit_err(error);
                 }
             }
             _ => (),
         }
 
-        //configurables and constant cannot be str slice
+        //configurables and constant cannot be str slice, and configurables cannot be references
         for c in configurables.iter() {
             if let Some(error) = get_type_not_allowed_error(
                 engines,
@@ -359,6 +420,7 @@ impl TyProgram {
                 &c.type_ascription,
                 |t| match t {
                     TypeInfo::StringSlice => Some(TypeNotAllowedReason::StringSliceInConfigurables),
+                    TypeInfo::Ref { .. } => Some(TypeNotAllowedReason::ReferenceInConfigurables),
                     _ => None,
                 },
             ) {
@@ -27,7 +27,7 @@ use crate::source_map::SourceMap;
 pub use asm_generation::from_ir::compile_ir_to_asm;
 use asm_generation::FinalizedAsm;
 pub use asm_generation::{CompiledBytecode, FinalizedEntry};
-pub use build_config::{BuildConfig, BuildTarget};
+pub use build_config::{BuildConfig, BuildTarget, OptLevel, PassEntry};
 use control_flow_analysis::ControlFlowGraph;
 use metadata::MetadataManager;
 use query_engine::{ModuleCacheKey, ModulePath, ProgramsCacheEntry};
@@ -40,9 +40,9 @@ use std::sync::Arc;
 use sway_ast::AttributeDecl;
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_ir::{
-    create_o1_pass_group, register_known_passes, Context, Kind, Module, PassManager,
-    ARGDEMOTION_NAME, CONSTDEMOTION_NAME, DCE_NAME, MEM2REG_NAME, MEMCPYOPT_NAME,
-    MISCDEMOTION_NAME, MODULEPRINTER_NAME, RETDEMOTION_NAME, SIMPLIFYCFG_NAME, SROA_NAME,
+    create_fuel_lowering_pass_group, create_o0_pass_group, create_o1_pass_group,
+    create_o2_pass_group, create_os_pass_group, register_known_passes, Context, InlineConfig, Kind,
+    Module, PassGroup, PassManager, MODULEPRINTER_NAME,
 };
 use sway_types::constants::DOC_COMMENT_ATTRIBUTE_NAME;
 use sway_types::SourceEngine;
@@ -765,28 +765,35 @@ pub(crate) fn compile_ast_to_ir_to_asm(
     // Initialize the pass manager and register known passes.
     let mut pass_mgr = PassManager::default();
     register_known_passes(&mut pass_mgr);
-    let mut pass_group = create_o1_pass_group();
+    pass_mgr.set_config(InlineConfig {
+        size_budget: build_config.size_budget,
+    });
+    let mut pass_group = match &build_config.passes {
+        Some(passes) => {
+            let mut pass_group = PassGroup::default();
+            for entry in passes {
+                let Some(pass) = pass_mgr.lookup_registered_pass(&entry.name) else {
+                    return Err(handler.emit_err(CompileError::UnknownOptimizationPass {
+                        name: entry.name.clone(),
+                        span: manifest_span(engines, entry),
+                    }));
+                };
+                pass_group.append_pass(pass.name);
+            }
+            pass_group
+        }
+        None => match build_config.optimization_level {
+            OptLevel::O0 => create_o0_pass_group(),
+            OptLevel::O1 => create_o1_pass_group(),
+            OptLevel::O2 => create_o2_pass_group(),
+            OptLevel::Os => create_os_pass_group(),
+        },
+    };
 
     // Target specific transforms should be moved into something more configured.
     if build_config.build_target == BuildTarget::Fuel {
         // FuelVM target specific transforms.
-        //
-        // Demote large by-value constants, arguments and return values to by-reference values
-        // using temporaries.
-        pass_group.append_pass(CONSTDEMOTION_NAME);
-        pass_group.append_pass(ARGDEMOTION_NAME);
-        pass_group.append_pass(RETDEMOTION_NAME);
-        pass_group.append_pass(MISCDEMOTION_NAME);
-
-        // Convert loads and stores to mem_copys where possible.
-        pass_group.append_pass(MEMCPYOPT_NAME);
-
-        // Run a DCE and simplify-cfg to clean up any obsolete instructions.
-        pass_group.append_pass(DCE_NAME);
-        pass_group.append_pass(SIMPLIFYCFG_NAME);
-        pass_group.append_pass(SROA_NAME);
-        pass_group.append_pass(MEM2REG_NAME);
-        pass_group.append_pass(DCE_NAME);
+        pass_group.append_group(create_fuel_lowering_pass_group());
     }
 
     if build_config.print_ir {
@@ -809,6 +816,20 @@ pub(crate) fn compile_ast_to_ir_to_asm(
     Ok(final_asm)
 }
 
+/// The span of the manifest entry naming the given pass, or a dummy span if it wasn't named by a
+/// manifest or the manifest can no longer be read.
+fn manifest_span(engines: &Engines, entry: &build_config::PassEntry) -> span::Span {
+    entry
+        .manifest_span
+        .as_ref()
+        .and_then(|(path, range)| {
+            let src = std::fs::read_to_string(path.as_ref()).ok()?;
+            let source_id = engines.se().get_source_id(path);
+            span::Span::new(Arc::from(src), range.start, range.end, Some(source_id))
+        })
+        .unwrap_or_else(span::Span::dummy)
+}
+
 /// Given input Sway source code, compile to [CompiledBytecode], containing the asm in bytecode form.
 #[allow(clippy::too_many_arguments)]
 pub fn compile_to_bytecode(
@@ -156,6 +156,27 @@ impl MetadataManager {
         })
     }
 
+    /// Gets the names of the functions which were inlined to produce an instruction, outermost
+    /// first.  The list is empty for instructions which weren't inlined.
+    pub(crate) fn md_to_inlined_fns(
+        &mut self,
+        context: &Context,
+        md_idx: Option<MetadataIndex>,
+    ) -> Vec<String> {
+        let mut names = Vec::new();
+        Self::for_each_md_idx(context, md_idx, |md_idx| {
+            if let Some(name) = md_idx
+                .get_content(context)
+                .unwrap_struct("inlined", 1)
+                .and_then(|fields| fields[0].unwrap_string())
+            {
+                names.push(name.to_owned());
+            }
+            None::<()>
+        });
+        names
+    }
+
     fn md_to_file_location(
         &mut self,
         context: &Context,
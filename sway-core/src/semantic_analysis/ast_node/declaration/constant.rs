@@ -80,7 +80,7 @@ impl ty::TyConstantDecl {
         // `type_ascription` if `type_ascription` is a concrete integer type that does not
         // conflict with the type of `expression` (i.e. passes the type checking above).
         let return_type = match type_engine.get(type_ascription.type_id) {
-            TypeInfo::UnsignedInteger(_) => type_ascription.type_id,
+            TypeInfo::UnsignedInteger(_) | TypeInfo::SignedInteger(_) => type_ascription.type_id,
             _ => match &value {
                 Some(value) => value.return_type,
                 None => type_ascription.type_id,
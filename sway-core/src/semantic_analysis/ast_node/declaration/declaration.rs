@@ -59,7 +59,9 @@ impl TyDecl {
                 // `type_ascription` if `type_ascription` is a concrete integer type that does not
                 // conflict with the type of `body` (i.e. passes the type checking above).
                 let return_type = match type_engine.get(type_ascription.type_id) {
-                    TypeInfo::UnsignedInteger(_) => type_ascription.type_id,
+                    TypeInfo::UnsignedInteger(_) | TypeInfo::SignedInteger(_) => {
+                        type_ascription.type_id
+                    }
                     _ => body.return_type,
                 };
                 let typed_var_decl = ty::TyDecl::VariableDecl(Box::new(ty::TyVariableDecl {
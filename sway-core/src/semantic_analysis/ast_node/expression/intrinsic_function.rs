@@ -118,7 +118,10 @@ fn type_check_not(
 
     let t = engines.te().get(operand_expr.return_type);
     match t {
-        TypeInfo::B256 | TypeInfo::UnsignedInteger(_) | TypeInfo::Numeric => Ok((
+        TypeInfo::B256
+        | TypeInfo::UnsignedInteger(_)
+        | TypeInfo::SignedInteger(_)
+        | TypeInfo::Numeric => Ok((
             ty::TyIntrinsicFunctionKind {
                 kind,
                 arguments: vec![operand_expr],
@@ -395,15 +398,15 @@ fn type_check_to_str_array(
 
 /// Signature: `__eq<T>(lhs: T, rhs: T) -> bool`
 /// Description: Returns whether `lhs` and `rhs` are equal.
-/// Constraints: `T` is `bool`, `u8`, `u16`, `u32`, `u64`, or `raw_ptr`.
+/// Constraints: `T` is `bool`, an integer type, or `raw_ptr`.
 ///
 /// Signature: `__gt<T>(lhs: T, rhs: T) -> bool`
-/// Description: Returns whether `lhs` > `rhs`.
-/// Constraints: `T` is `u8`, `u16`, `u32`, `u64`.
+/// Description: Returns whether `lhs` > `rhs`. Signed integers are compared as signed values.
+/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`.
 ///
 /// Signature: `__lt<T>(lhs: T, rhs: T) -> bool`
-/// Description: Returns whether `lhs` < `rhs`.
-/// Constraints: `T` is `u8`, `u16`, `u32`, `u64`.
+/// Description: Returns whether `lhs` < `rhs`. Signed integers are compared as signed values.
+/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`.
 fn type_check_cmp(
     handler: &Handler,
     mut ctx: TypeCheckContext,
@@ -440,7 +443,10 @@ fn type_check_cmp(
         && matches!(arg_ty, TypeInfo::Boolean | TypeInfo::RawUntypedPtr);
     let is_valid_arg_ty = matches!(
         arg_ty,
-        TypeInfo::UnsignedInteger(_) | TypeInfo::Numeric | TypeInfo::B256
+        TypeInfo::UnsignedInteger(_)
+            | TypeInfo::SignedInteger(_)
+            | TypeInfo::Numeric
+            | TypeInfo::B256
     ) || is_eq_bool_ptr;
 
     if !is_valid_arg_ty {
@@ -913,31 +919,35 @@ fn type_check_log(
 
 /// Signature: `__add<T>(lhs: T, rhs: T) -> T`
 /// Description: Adds `lhs` and `rhs` and returns the result.
-/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`.
+///              Signed integers wrap around on overflow.
+/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`.
 ///
 /// Signature: `__sub<T>(lhs: T, rhs: T) -> T`
 /// Description: Subtracts `lhs` and `rhs` and returns the result.
-/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`.
+///              Signed integers wrap around on overflow.
+/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`.
 ///
 /// Signature: `__mul<T>(lhs: T, rhs: T) -> T`
 /// Description: Multiplies `lhs` and `rhs` and returns the result.
-/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`.
+///              Signed integers wrap around on overflow.
+/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`.
 ///
 /// Signature: `__div<T>(lhs: T, rhs: T) -> T`
 /// Description: Divides `lhs` and `rhs` and returns the result.
-/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`.
+///              Signed division rounds towards zero.
+/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`.
 ///
 /// Signature: `__and<T>(lhs: T, rhs: T) -> T`
 /// Description: Bitwise And of `lhs` and `rhs` and returns the result.
-/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`.
+/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`.
 ///
 /// Signature: `__or<T>(lhs: T, rhs: T) -> T`
 /// Description: Bitwise Or `lhs` and `rhs` and returns the result.
-/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`.
+/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`.
 ///
 /// Signature: `__xor<T>(lhs: T, rhs: T) -> T`
 /// Description: Bitwise Xor `lhs` and `rhs` and returns the result.
-/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`.
+/// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`.
 fn type_check_arith_binary_op(
     handler: &Handler,
     mut ctx: TypeCheckContext,
@@ -1025,7 +1035,10 @@ fn type_check_bitwise_binary_op(
 
     let t = engines.te().get(lhs.return_type);
     match t {
-        TypeInfo::B256 | TypeInfo::UnsignedInteger(_) | TypeInfo::Numeric => Ok((
+        TypeInfo::B256
+        | TypeInfo::UnsignedInteger(_)
+        | TypeInfo::SignedInteger(_)
+        | TypeInfo::Numeric => Ok((
             ty::TyIntrinsicFunctionKind {
                 kind,
                 arguments: vec![lhs, rhs],
@@ -1047,11 +1060,12 @@ fn type_check_bitwise_binary_op(
 
 /// Signature: `__lsh<T, U>(lhs: T, rhs: U) -> T`
 /// Description: Logical left shifts the `lhs` by the `rhs` and returns the result.
-/// Constraints: `T` and `U` are an integer type, i.e. `u8`, `u16`, `u32`, `u64`.
+/// Constraints: `T` and `U` are an integer type, i.e. `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`.
 ///
 /// Signature: `__rsh<T, U>(lhs: T, rhs: U) -> T`
-/// Description: Logical right shifts the `lhs` by the `rhs` and returns the result.
-/// Constraints: `T` and `U` are an integer type, i.e. `u8`, `u16`, `u32`, `u64`.
+/// Description: Right shifts the `lhs` by the `rhs` and returns the result.
+///              The shift is logical for unsigned and arithmetic for signed integers.
+/// Constraints: `T` and `U` are an integer type, i.e. `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`.
 fn type_check_shift_binary_op(
     handler: &Handler,
     mut ctx: TypeCheckContext,
@@ -1098,7 +1112,10 @@ fn type_check_shift_binary_op(
 
     let t = engines.te().get(lhs.return_type);
     match t {
-        TypeInfo::B256 | TypeInfo::UnsignedInteger(_) | TypeInfo::Numeric => Ok((
+        TypeInfo::B256
+        | TypeInfo::UnsignedInteger(_)
+        | TypeInfo::SignedInteger(_)
+        | TypeInfo::Numeric => Ok((
             ty::TyIntrinsicFunctionKind {
                 kind,
                 arguments: vec![lhs, rhs],
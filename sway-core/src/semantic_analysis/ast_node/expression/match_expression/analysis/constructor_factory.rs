@@ -157,6 +157,90 @@ impl ConstructorFactory {
                         .into();
                 Pattern::from_pat_stack(handler, unincluded, span)?
             }
+            Pattern::I8(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I8(range) => ranges.push(range),
+                        _ => {
+                            return Err(handler.emit_err(CompileError::Internal(
+                                "expected all patterns to be of the same type",
+                                span.clone(),
+                            )));
+                        }
+                    }
+                }
+                let unincluded: PatStack =
+                    Range::find_exclusionary_ranges(handler, ranges, Range::i8(), span)?
+                        .into_iter()
+                        .map(Pattern::I8)
+                        .collect::<Vec<_>>()
+                        .into();
+                Pattern::from_pat_stack(handler, unincluded, span)?
+            }
+            Pattern::I16(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I16(range) => ranges.push(range),
+                        _ => {
+                            return Err(handler.emit_err(CompileError::Internal(
+                                "expected all patterns to be of the same type",
+                                span.clone(),
+                            )));
+                        }
+                    }
+                }
+                let unincluded: PatStack =
+                    Range::find_exclusionary_ranges(handler, ranges, Range::i16(), span)?
+                        .into_iter()
+                        .map(Pattern::I16)
+                        .collect::<Vec<_>>()
+                        .into();
+                Pattern::from_pat_stack(handler, unincluded, span)?
+            }
+            Pattern::I32(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I32(range) => ranges.push(range),
+                        _ => {
+                            return Err(handler.emit_err(CompileError::Internal(
+                                "expected all patterns to be of the same type",
+                                span.clone(),
+                            )));
+                        }
+                    }
+                }
+                let unincluded: PatStack =
+                    Range::find_exclusionary_ranges(handler, ranges, Range::i32(), span)?
+                        .into_iter()
+                        .map(Pattern::I32)
+                        .collect::<Vec<_>>()
+                        .into();
+                Pattern::from_pat_stack(handler, unincluded, span)?
+            }
+            Pattern::I64(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I64(range) => ranges.push(range),
+                        _ => {
+                            return Err(handler.emit_err(CompileError::Internal(
+                                "expected all patterns to be of the same type",
+                                span.clone(),
+                            )));
+                        }
+                    }
+                }
+                let unincluded: PatStack =
+                    Range::find_exclusionary_ranges(handler, ranges, Range::i64(), span)?
+                        .into_iter()
+                        .map(Pattern::I64)
+                        .collect::<Vec<_>>()
+                        .into();
+                Pattern::from_pat_stack(handler, unincluded, span)?
+            }
             Pattern::Numeric(range) => {
                 let mut ranges = vec![range];
                 for pat in rest.into_iter() {
@@ -409,6 +493,66 @@ impl ConstructorFactory {
                 }
                 Range::do_ranges_equal_range(handler, ranges, Range::u64(), span)
             }
+            Pattern::I8(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I8(range) => ranges.push(range),
+                        _ => {
+                            return Err(handler.emit_err(CompileError::Internal(
+                                "expected all patterns to be of the same type",
+                                span.clone(),
+                            )));
+                        }
+                    }
+                }
+                Range::do_ranges_equal_range(handler, ranges, Range::i8(), span)
+            }
+            Pattern::I16(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I16(range) => ranges.push(range),
+                        _ => {
+                            return Err(handler.emit_err(CompileError::Internal(
+                                "expected all patterns to be of the same type",
+                                span.clone(),
+                            )));
+                        }
+                    }
+                }
+                Range::do_ranges_equal_range(handler, ranges, Range::i16(), span)
+            }
+            Pattern::I32(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I32(range) => ranges.push(range),
+                        _ => {
+                            return Err(handler.emit_err(CompileError::Internal(
+                                "expected all patterns to be of the same type",
+                                span.clone(),
+                            )));
+                        }
+                    }
+                }
+                Range::do_ranges_equal_range(handler, ranges, Range::i32(), span)
+            }
+            Pattern::I64(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I64(range) => ranges.push(range),
+                        _ => {
+                            return Err(handler.emit_err(CompileError::Internal(
+                                "expected all patterns to be of the same type",
+                                span.clone(),
+                            )));
+                        }
+                    }
+                }
+                Range::do_ranges_equal_range(handler, ranges, Range::i64(), span)
+            }
             Pattern::Numeric(range) => {
                 let mut ranges = vec![range];
                 for pat in rest.into_iter() {
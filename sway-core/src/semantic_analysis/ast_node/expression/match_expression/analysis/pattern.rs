@@ -3,10 +3,10 @@ use std::{cmp::Ordering, fmt};
 use std::fmt::Write;
 use sway_error::error::CompileError;
 use sway_error::handler::{ErrorEmitted, Handler};
-use sway_types::Span;
+use sway_types::{integer_bits::IntegerBits, Span};
 
 use crate::decl_engine::DeclEngine;
-use crate::{language::ty, language::Literal, TypeInfo};
+use crate::{language::ty, language::Literal, Engines, TypeId, TypeInfo};
 
 use super::{patstack::PatStack, range::Range};
 
@@ -102,6 +102,10 @@ pub(crate) enum Pattern {
     U16(Range<u16>),
     U32(Range<u32>),
     U64(Range<u64>),
+    I8(Range<i8>),
+    I16(Range<i16>),
+    I32(Range<i32>),
+    I64(Range<i64>),
     B256([u8; 32]),
     Boolean(bool),
     Numeric(Range<u64>),
@@ -114,12 +118,33 @@ pub(crate) enum Pattern {
 
 impl Pattern {
     /// Converts a `Scrutinee` to a `Pattern`.
-    pub(crate) fn from_scrutinee(scrutinee: ty::TyScrutinee) -> Self {
+    pub(crate) fn from_scrutinee(
+        handler: &Handler,
+        engines: &Engines,
+        scrutinee: ty::TyScrutinee,
+    ) -> Result<Self, ErrorEmitted> {
         let pat = match scrutinee.variant {
             ty::TyScrutineeVariant::CatchAll => Pattern::Wildcard,
             ty::TyScrutineeVariant::Variable(_) => Pattern::Wildcard,
-            ty::TyScrutineeVariant::Literal(value) => Pattern::from_literal(value),
-            ty::TyScrutineeVariant::Constant(_, value, _) => Pattern::from_literal(value),
+            ty::TyScrutineeVariant::Literal(value) => {
+                Pattern::from_literal(handler, engines, value, scrutinee.type_id, &scrutinee.span)?
+            }
+            ty::TyScrutineeVariant::Constant(_, value, _) => {
+                Pattern::from_literal(handler, engines, value, scrutinee.type_id, &scrutinee.span)?
+            }
+            ty::TyScrutineeVariant::Range {
+                start,
+                end,
+                is_inclusive,
+            } => Pattern::from_range(
+                handler,
+                engines,
+                start,
+                end,
+                is_inclusive,
+                scrutinee.type_id,
+                &scrutinee.span,
+            )?,
             ty::TyScrutineeVariant::StructScrutinee {
                 struct_ref,
                 fields,
@@ -128,7 +153,7 @@ impl Pattern {
                 let mut new_fields = vec![];
                 for field in fields.into_iter() {
                     let f = match field.scrutinee {
-                        Some(scrutinee) => Pattern::from_scrutinee(scrutinee),
+                        Some(scrutinee) => Pattern::from_scrutinee(handler, engines, scrutinee)?,
                         None => Pattern::Wildcard,
                     };
                     new_fields.push((field.field.as_str().to_string(), f));
@@ -141,14 +166,14 @@ impl Pattern {
             ty::TyScrutineeVariant::Or(elems) => {
                 let mut new_elems = PatStack::empty();
                 for elem in elems.into_iter() {
-                    new_elems.push(Pattern::from_scrutinee(elem));
+                    new_elems.push(Pattern::from_scrutinee(handler, engines, elem)?);
                 }
                 Pattern::Or(new_elems)
             }
             ty::TyScrutineeVariant::Tuple(elems) => {
                 let mut new_elems = PatStack::empty();
                 for elem in elems.into_iter() {
-                    new_elems.push(Pattern::from_scrutinee(elem));
+                    new_elems.push(Pattern::from_scrutinee(handler, engines, elem)?);
                 }
                 Pattern::Tuple(new_elems)
             }
@@ -160,27 +185,121 @@ impl Pattern {
             } => Pattern::Enum(EnumPattern {
                 enum_name: enum_ref.name().to_string(),
                 variant_name: variant.name.to_string(),
-                value: Box::new(Pattern::from_scrutinee(*value)),
+                value: Box::new(Pattern::from_scrutinee(handler, engines, *value)?),
             }),
         };
-        pat
+        Ok(pat)
     }
 
-    /// Convert the given literal `value` into a pattern.
-    fn from_literal(value: Literal) -> Pattern {
-        match value {
-            Literal::U8(x) => Pattern::U8(Range::from_single(x)),
-            Literal::U16(x) => Pattern::U16(Range::from_single(x)),
-            Literal::U32(x) => Pattern::U32(Range::from_single(x)),
-            Literal::U64(x) => Pattern::U64(Range::from_single(x)),
+    /// Convert the given literal `value` into a pattern. Integer literals take
+    /// the integer pattern of their resolved type `type_id`, so that `1`, `1u8`
+    /// and `-1` land in the same kind of `Range`.
+    fn from_literal(
+        handler: &Handler,
+        engines: &Engines,
+        value: Literal,
+        type_id: TypeId,
+        span: &Span,
+    ) -> Result<Pattern, ErrorEmitted> {
+        let pat = match value {
             Literal::U256(x) => Pattern::U64(Range::from_single(
                 x.try_into().expect("pattern only works with 64 bits"),
             )),
             Literal::B256(x) => Pattern::B256(x),
             Literal::Boolean(b) => Pattern::Boolean(b),
-            Literal::Numeric(x) => Pattern::Numeric(Range::from_single(x)),
             Literal::String(s) => Pattern::String(s.as_str().to_string()),
+            value => match value.as_integer() {
+                Some(x) => Pattern::from_integer_range(handler, engines, x, x, type_id, span)?,
+                None => {
+                    return Err(handler.emit_err(CompileError::Internal(
+                        "expected an integer literal",
+                        span.clone(),
+                    )));
+                }
+            },
+        };
+        Ok(pat)
+    }
+
+    /// Convert the range `start..end`, or `start..=end` if `is_inclusive`, into
+    /// a pattern of the resolved integer type `type_id`.
+    fn from_range(
+        handler: &Handler,
+        engines: &Engines,
+        start: Literal,
+        end: Literal,
+        is_inclusive: bool,
+        type_id: TypeId,
+        span: &Span,
+    ) -> Result<Pattern, ErrorEmitted> {
+        let (first, end) = match (start.as_integer(), end.as_integer()) {
+            (Some(first), Some(end)) => (first, end),
+            _ => {
+                return Err(handler.emit_err(CompileError::Internal(
+                    "expected integer range bounds",
+                    span.clone(),
+                )));
+            }
+        };
+        // Both bounds must fit into the type, even the excluded `end`.
+        Pattern::from_integer_range(handler, engines, end, end, type_id, span)?;
+        let last = if is_inclusive { end } else { end - 1 };
+        Pattern::from_integer_range(handler, engines, first, last, type_id, span)
+    }
+
+    /// Creates the pattern of the integer type `type_id` that matches the
+    /// values from `first` to `last`, inclusive. Patterns of `u64` and of
+    /// not yet decayed numeric types are both represented as
+    /// `Pattern::Numeric`, because the matched value can decay from the
+    /// one into the other while the match arms are type checked.
+    fn from_integer_range(
+        handler: &Handler,
+        engines: &Engines,
+        first: i128,
+        last: i128,
+        type_id: TypeId,
+        span: &Span,
+    ) -> Result<Pattern, ErrorEmitted> {
+        fn convert<T: TryFrom<i128>>(
+            handler: &Handler,
+            engines: &Engines,
+            x: i128,
+            type_id: TypeId,
+            span: &Span,
+        ) -> Result<T, ErrorEmitted> {
+            T::try_from(x).map_err(|_| {
+                let ty = engines.help_out(type_id).to_string();
+                let span = span.clone();
+                handler.emit_err(if x < 0 {
+                    CompileError::IntegerTooSmall { ty, span }
+                } else {
+                    CompileError::IntegerTooLarge { ty, span }
+                })
+            })
         }
+
+        macro_rules! range {
+            ($variant:ident) => {
+                Pattern::$variant(Range::from_double(
+                    handler,
+                    convert(handler, engines, first, type_id, span)?,
+                    convert(handler, engines, last, type_id, span)?,
+                    span,
+                )?)
+            };
+        }
+
+        let pat = match engines.te().get(type_id) {
+            TypeInfo::UnsignedInteger(IntegerBits::Eight) => range!(U8),
+            TypeInfo::UnsignedInteger(IntegerBits::Sixteen) => range!(U16),
+            TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo) => range!(U32),
+            TypeInfo::SignedInteger(IntegerBits::Eight) => range!(I8),
+            TypeInfo::SignedInteger(IntegerBits::Sixteen) => range!(I16),
+            TypeInfo::SignedInteger(IntegerBits::ThirtyTwo) => range!(I32),
+            TypeInfo::SignedInteger(IntegerBits::SixtyFour | IntegerBits::V256) => range!(I64),
+            _ => range!(Numeric),
+        };
+        Ok(pat)
     }
 
     /// Converts a `PatStack` to a `Pattern`. If the `PatStack` is of length 1,
@@ -352,6 +471,42 @@ impl Pattern {
                 }
                 Pattern::U64(range.clone())
             }
+            Pattern::I8(range) => {
+                if !args.is_empty() {
+                    return Err(handler.emit_err(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    )));
+                }
+                Pattern::I8(range.clone())
+            }
+            Pattern::I16(range) => {
+                if !args.is_empty() {
+                    return Err(handler.emit_err(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    )));
+                }
+                Pattern::I16(range.clone())
+            }
+            Pattern::I32(range) => {
+                if !args.is_empty() {
+                    return Err(handler.emit_err(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    )));
+                }
+                Pattern::I32(range.clone())
+            }
+            Pattern::I64(range) => {
+                if !args.is_empty() {
+                    return Err(handler.emit_err(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    )));
+                }
+                Pattern::I64(range.clone())
+            }
             Pattern::B256(b) => {
                 if !args.is_empty() {
                     return Err(handler.emit_err(CompileError::Internal(
@@ -481,6 +636,10 @@ impl Pattern {
             Pattern::U16(_) => 0,
             Pattern::U32(_) => 0,
             Pattern::U64(_) => 0,
+            Pattern::I8(_) => 0,
+            Pattern::I16(_) => 0,
+            Pattern::I32(_) => 0,
+            Pattern::I64(_) => 0,
             Pattern::B256(_) => 0,
             Pattern::Boolean(_) => 0,
             Pattern::Numeric(_) => 0,
@@ -528,6 +687,10 @@ impl Pattern {
             (Pattern::U16(a), Pattern::U16(b)) => a == b,
             (Pattern::U32(a), Pattern::U32(b)) => a == b,
             (Pattern::U64(a), Pattern::U64(b)) => a == b,
+            (Pattern::I8(a), Pattern::I8(b)) => a == b,
+            (Pattern::I16(a), Pattern::I16(b)) => a == b,
+            (Pattern::I32(a), Pattern::I32(b)) => a == b,
+            (Pattern::I64(a), Pattern::I64(b)) => a == b,
             (Pattern::B256(a), Pattern::B256(b)) => a == b,
             (Pattern::Boolean(a), Pattern::Boolean(b)) => a == b,
             (Pattern::Numeric(a), Pattern::Numeric(b)) => a == b,
@@ -560,6 +723,78 @@ impl Pattern {
         }
     }
 
+    /// Checks to see if every value matched by the constructor *c* is also
+    /// matched by the constructor of `self`. Integer constructors are compared
+    /// by range inclusion, which is exact only when *c* has been split with
+    /// `Pattern::split_constructor` against the column that `self` comes from.
+    /// All other constructors are compared with
+    /// `Pattern::has_the_same_constructor`.
+    pub(crate) fn covers_constructor(&self, c: &Pattern) -> bool {
+        match (self, c) {
+            (Pattern::U8(a), Pattern::U8(b)) => a.encompasses(b),
+            (Pattern::U16(a), Pattern::U16(b)) => a.encompasses(b),
+            (Pattern::U32(a), Pattern::U32(b)) => a.encompasses(b),
+            (Pattern::U64(a), Pattern::U64(b)) => a.encompasses(b),
+            (Pattern::I8(a), Pattern::I8(b)) => a.encompasses(b),
+            (Pattern::I16(a), Pattern::I16(b)) => a.encompasses(b),
+            (Pattern::I32(a), Pattern::I32(b)) => a.encompasses(b),
+            (Pattern::I64(a), Pattern::I64(b)) => a.encompasses(b),
+            (Pattern::Numeric(a), Pattern::Numeric(b)) => a.encompasses(b),
+            (a, b) => b.has_the_same_constructor(a),
+        }
+    }
+
+    /// Splits an integer constructor into the constructors delimited by the
+    /// boundaries of the integer ranges in *column*, so that each resulting
+    /// constructor is either fully included in or disjoint from every range in
+    /// *column*. For example, given:
+    ///
+    /// ```ignore
+    /// self:   Pattern::U64(Range { first: 0, last: 10 })
+    /// column: [
+    ///           Pattern::U64(Range { first: 5, last: 20 }),
+    ///           Pattern::Wildcard
+    ///         ]
+    /// ```
+    ///
+    /// this would result in:
+    ///
+    /// ```ignore
+    /// [
+    ///     Pattern::U64(Range { first: 0, last: 4 }),
+    ///     Pattern::U64(Range { first: 5, last: 10 })
+    /// ]
+    /// ```
+    ///
+    /// Any other pattern is returned as is.
+    pub(crate) fn split_constructor(&self, column: &PatStack) -> Vec<Pattern> {
+        let column = column.flatten();
+        macro_rules! split {
+            ($variant:ident, $range:expr) => {
+                $range
+                    .split(column.iter().filter_map(|pat| match pat {
+                        Pattern::$variant(range) => Some(range),
+                        _ => None,
+                    }))
+                    .into_iter()
+                    .map(Pattern::$variant)
+                    .collect()
+            };
+        }
+        match self {
+            Pattern::U8(range) => split!(U8, range),
+            Pattern::U16(range) => split!(U16, range),
+            Pattern::U32(range) => split!(U32, range),
+            Pattern::U64(range) => split!(U64, range),
+            Pattern::I8(range) => split!(I8, range),
+            Pattern::I16(range) => split!(I16, range),
+            Pattern::I32(range) => split!(I32, range),
+            Pattern::I64(range) => split!(I64, range),
+            Pattern::Numeric(range) => split!(Numeric, range),
+            pat => vec![pat.clone()],
+        }
+    }
+
     /// Extracts the "sub-patterns" of a `Pattern`, aka the "arguments" to the
     /// patterns "constructor". Some patterns have 0 sub-patterns and some
     /// patterns have >0 sub-patterns. For example, this pattern:
@@ -629,6 +864,10 @@ impl Pattern {
             Pattern::U16(n) => Pattern::U16(n),
             Pattern::U32(n) => Pattern::U32(n),
             Pattern::U64(n) => Pattern::U64(n),
+            Pattern::I8(n) => Pattern::I8(n),
+            Pattern::I16(n) => Pattern::I16(n),
+            Pattern::I32(n) => Pattern::I32(n),
+            Pattern::I64(n) => Pattern::I64(n),
             Pattern::B256(n) => Pattern::B256(n),
             Pattern::Boolean(b) => Pattern::Boolean(b),
             Pattern::Numeric(n) => Pattern::Numeric(n),
@@ -675,14 +914,18 @@ impl Pattern {
             Pattern::U16(_) => 2,
             Pattern::U32(_) => 3,
             Pattern::U64(_) => 4,
-            Pattern::B256(_) => 5,
-            Pattern::Boolean(_) => 6,
-            Pattern::Numeric(_) => 7,
-            Pattern::String(_) => 8,
-            Pattern::Struct(_) => 9,
-            Pattern::Enum(_) => 10,
-            Pattern::Tuple(_) => 11,
-            Pattern::Or(_) => 12,
+            Pattern::I8(_) => 5,
+            Pattern::I16(_) => 6,
+            Pattern::I32(_) => 7,
+            Pattern::I64(_) => 8,
+            Pattern::B256(_) => 9,
+            Pattern::Boolean(_) => 10,
+            Pattern::Numeric(_) => 11,
+            Pattern::String(_) => 12,
+            Pattern::Struct(_) => 13,
+            Pattern::Enum(_) => 14,
+            Pattern::Tuple(_) => 15,
+            Pattern::Or(_) => 16,
         }
     }
 }
@@ -695,6 +938,10 @@ impl fmt::Display for Pattern {
             Pattern::U16(range) => format!("{range}"),
             Pattern::U32(range) => format!("{range}"),
             Pattern::U64(range) => format!("{range}"),
+            Pattern::I8(range) => format!("{range}"),
+            Pattern::I16(range) => format!("{range}"),
+            Pattern::I32(range) => format!("{range}"),
+            Pattern::I64(range) => format!("{range}"),
             Pattern::Numeric(range) => format!("{range}"),
             Pattern::B256(n) => format!("{n:#?}"),
             Pattern::Boolean(b) => format!("{b}"),
@@ -728,6 +975,10 @@ impl std::cmp::Ord for Pattern {
             (Pattern::U16(x), Pattern::U16(y)) => x.cmp(y),
             (Pattern::U32(x), Pattern::U32(y)) => x.cmp(y),
             (Pattern::U64(x), Pattern::U64(y)) => x.cmp(y),
+            (Pattern::I8(x), Pattern::I8(y)) => x.cmp(y),
+            (Pattern::I16(x), Pattern::I16(y)) => x.cmp(y),
+            (Pattern::I32(x), Pattern::I32(y)) => x.cmp(y),
+            (Pattern::I64(x), Pattern::I64(y)) => x.cmp(y),
             (Pattern::B256(x), Pattern::B256(y)) => x.cmp(y),
             (Pattern::Boolean(x), Pattern::Boolean(y)) => x.cmp(y),
             (Pattern::Numeric(x), Pattern::Numeric(y)) => x.cmp(y),
@@ -19,10 +19,10 @@ pub(crate) trait MyMath<T> {
 
 impl MyMath<u8> for u8 {
     fn global_max() -> u8 {
-        std::u8::MAX
+        u8::MAX
     }
     fn global_min() -> u8 {
-        std::u8::MIN
+        u8::MIN
     }
 
     fn incr(&self) -> u8 {
@@ -35,10 +35,10 @@ impl MyMath<u8> for u8 {
 
 impl MyMath<u16> for u16 {
     fn global_max() -> u16 {
-        std::u16::MAX
+        u16::MAX
     }
     fn global_min() -> u16 {
-        std::u16::MIN
+        u16::MIN
     }
 
     fn incr(&self) -> u16 {
@@ -51,10 +51,10 @@ impl MyMath<u16> for u16 {
 
 impl MyMath<u32> for u32 {
     fn global_max() -> u32 {
-        std::u32::MAX
+        u32::MAX
     }
     fn global_min() -> u32 {
-        std::u32::MIN
+        u32::MIN
     }
 
     fn incr(&self) -> u32 {
@@ -67,10 +67,10 @@ impl MyMath<u32> for u32 {
 
 impl MyMath<u64> for u64 {
     fn global_max() -> u64 {
-        std::u64::MAX
+        u64::MAX
     }
     fn global_min() -> u64 {
-        std::u64::MIN
+        u64::MIN
     }
 
     fn incr(&self) -> u64 {
@@ -81,6 +81,70 @@ impl MyMath<u64> for u64 {
     }
 }
 
+impl MyMath<i8> for i8 {
+    fn global_max() -> i8 {
+        i8::MAX
+    }
+    fn global_min() -> i8 {
+        i8::MIN
+    }
+
+    fn incr(&self) -> i8 {
+        self + 1
+    }
+    fn decr(&self) -> i8 {
+        self - 1
+    }
+}
+
+impl MyMath<i16> for i16 {
+    fn global_max() -> i16 {
+        i16::MAX
+    }
+    fn global_min() -> i16 {
+        i16::MIN
+    }
+
+    fn incr(&self) -> i16 {
+        self + 1
+    }
+    fn decr(&self) -> i16 {
+        self - 1
+    }
+}
+
+impl MyMath<i32> for i32 {
+    fn global_max() -> i32 {
+        i32::MAX
+    }
+    fn global_min() -> i32 {
+        i32::MIN
+    }
+
+    fn incr(&self) -> i32 {
+        self + 1
+    }
+    fn decr(&self) -> i32 {
+        self - 1
+    }
+}
+
+impl MyMath<i64> for i64 {
+    fn global_max() -> i64 {
+        i64::MAX
+    }
+    fn global_min() -> i64 {
+        i64::MIN
+    }
+
+    fn incr(&self) -> i64 {
+        self + 1
+    }
+    fn decr(&self) -> i64 {
+        self - 1
+    }
+}
+
 /// A `Range<T>` is a range of values of type T. Given this range:
 ///
 /// ```ignore
@@ -109,8 +173,7 @@ where
         + PartialOrd
         + Clone
         + MyMath<T>
-        + Sub<Output = T>
-        + Into<u64>,
+        + Sub<Output = T>,
 {
     first: T,
     last: T,
@@ -119,8 +182,8 @@ where
 impl Range<u8> {
     pub(crate) fn u8() -> Range<u8> {
         Range {
-            first: std::u8::MIN,
-            last: std::u8::MAX,
+            first: u8::MIN,
+            last: u8::MAX,
         }
     }
 }
@@ -128,8 +191,8 @@ impl Range<u8> {
 impl Range<u16> {
     pub(crate) fn u16() -> Range<u16> {
         Range {
-            first: std::u16::MIN,
-            last: std::u16::MAX,
+            first: u16::MIN,
+            last: u16::MAX,
         }
     }
 }
@@ -137,8 +200,8 @@ impl Range<u16> {
 impl Range<u32> {
     pub(crate) fn u32() -> Range<u32> {
         Range {
-            first: std::u32::MIN,
-            last: std::u32::MAX,
+            first: u32::MIN,
+            last: u32::MAX,
         }
     }
 }
@@ -146,8 +209,44 @@ impl Range<u32> {
 impl Range<u64> {
     pub(crate) fn u64() -> Range<u64> {
         Range {
-            first: std::u64::MIN,
-            last: std::u64::MAX,
+            first: u64::MIN,
+            last: u64::MAX,
+        }
+    }
+}
+
+impl Range<i8> {
+    pub(crate) fn i8() -> Range<i8> {
+        Range {
+            first: i8::MIN,
+            last: i8::MAX,
+        }
+    }
+}
+
+impl Range<i16> {
+    pub(crate) fn i16() -> Range<i16> {
+        Range {
+            first: i16::MIN,
+            last: i16::MAX,
+        }
+    }
+}
+
+impl Range<i32> {
+    pub(crate) fn i32() -> Range<i32> {
+        Range {
+            first: i32::MIN,
+            last: i32::MAX,
+        }
+    }
+}
+
+impl Range<i64> {
+    pub(crate) fn i64() -> Range<i64> {
+        Range {
+            first: i64::MIN,
+            last: i64::MAX,
         }
     }
 }
@@ -162,8 +261,7 @@ where
         + PartialOrd
         + Clone
         + MyMath<T>
-        + Sub<Output = T>
-        + Into<u64>,
+        + Sub<Output = T>,
 {
     /// Creates a `Range<T>` from a single value of type `T`, where the value is used
     /// both as the lower inclusive contains and the upper inclusive contains.
@@ -176,7 +274,7 @@ where
 
     /// Creates a `Range<T>` and ensures that it is a "valid `Range<T>`"
     /// (i.e.) that `first` is <= to `last`
-    fn from_double(
+    pub(crate) fn from_double(
         handler: &Handler,
         first: T,
         last: T,
@@ -296,7 +394,7 @@ where
         let mut stack: Vec<Range<T>> = vec![];
 
         // 1. Sort the intervals based on increasing order of starting time.
-        ranges.sort_by(|a, b| b.first.cmp(&a.first));
+        ranges.sort_by(|a, b| a.first.cmp(&b.first));
 
         // 2. Push the first interval on to a stack.
         let (first, rest) = match ranges.split_first() {
@@ -330,7 +428,6 @@ where
                 stack.push(range.clone());
             }
         }
-        stack.reverse();
         Ok(stack)
     }
 
@@ -455,6 +552,47 @@ where
         }
     }
 
+    /// Splits this `Range<T>` at the boundaries of the overlapping *others*,
+    /// so that every resulting `Range<T>` is either encompassed by or
+    /// disjoint from each of the *others*. For example:
+    ///
+    /// ```ignore
+    /// self:   |--------------|
+    /// others:    |---|  |---------|
+    /// ->      |-||---||-||---|
+    /// ```
+    ///
+    /// Two `Range<T>`s split against the same *others* produce the same
+    /// pieces wherever they overlap.
+    pub(crate) fn split<'a>(&self, others: impl IntoIterator<Item = &'a Range<T>>) -> Vec<Range<T>>
+    where
+        T: 'a,
+    {
+        let mut starts = vec![self.first.clone()];
+        for other in others.into_iter().filter(|other| self.overlaps(other)) {
+            if other.first > self.first {
+                starts.push(other.first.clone());
+            }
+            if other.last < self.last {
+                starts.push(other.last.incr());
+            }
+        }
+        starts.sort();
+        starts.dedup();
+
+        let lasts = starts
+            .iter()
+            .skip(1)
+            .map(|start| start.decr())
+            .chain(std::iter::once(self.last.clone()));
+        starts
+            .iter()
+            .cloned()
+            .zip(lasts)
+            .map(|(first, last)| Range { first, last })
+            .collect()
+    }
+
     /// Checks to see if two ranges overlap. There are 4 ways in which this
     /// might be the case:
     ///
@@ -488,7 +626,7 @@ where
     /// A: |------------|
     /// B: |------------|
     /// ```
-    fn encompasses(&self, other: &Range<T>) -> bool {
+    pub(crate) fn encompasses(&self, other: &Range<T>) -> bool {
         self.first <= other.first && self.last >= other.last
     }
 
@@ -511,9 +649,8 @@ where
     /// ```
     fn within_one(&self, other: &Range<T>) -> bool {
         !self.overlaps(other)
-            && (other.first > self.last && (other.first.clone() - self.last.clone()).into() == 1u64
-                || self.first > other.last
-                    && (self.first.clone() - other.last.clone()).into() == 1u64)
+            && (other.first > self.last && other.first == self.last.incr()
+                || self.first > other.last && self.first == other.last.incr())
     }
 }
 
@@ -527,8 +664,7 @@ where
         + PartialOrd
         + Clone
         + MyMath<T>
-        + Sub<Output = T>
-        + Into<u64>,
+        + Sub<Output = T>,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut builder = String::new();
@@ -573,8 +709,7 @@ where
         + PartialOrd
         + Clone
         + MyMath<T>
-        + Sub<Output = T>
-        + Into<u64>,
+        + Sub<Output = T>,
 {
     fn cmp(&self, other: &Self) -> Ordering {
         use Ordering::*;
@@ -603,8 +738,7 @@ where
         + PartialOrd
         + Clone
         + MyMath<T>
-        + Sub<Output = T>
-        + Into<u64>,
+        + Sub<Output = T>,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
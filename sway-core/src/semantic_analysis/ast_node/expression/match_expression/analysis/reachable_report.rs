@@ -3,13 +3,25 @@ use crate::language::ty;
 pub(crate) struct ReachableReport {
     pub(crate) reachable: bool,
     pub(crate) scrutinee: ty::TyScrutinee,
+    pub(crate) has_guard: bool,
 }
 
 impl ReachableReport {
-    pub(super) fn new(reachable: bool, scrutinee: ty::TyScrutinee) -> ReachableReport {
+    pub(super) fn new(
+        reachable: bool,
+        scrutinee: ty::TyScrutinee,
+        has_guard: bool,
+    ) -> ReachableReport {
         ReachableReport {
             reachable,
             scrutinee,
+            has_guard,
         }
     }
+
+    /// Returns true if the arm matches all the values of its type, which is
+    /// the case if it has a catch-all pattern and no guard.
+    pub(crate) fn is_catch_all(&self) -> bool {
+        self.scrutinee.is_catch_all() && !self.has_guard
+    }
 }
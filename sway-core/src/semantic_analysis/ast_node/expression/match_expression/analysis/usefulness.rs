@@ -195,11 +195,23 @@ use super::{
 /// is an imaginary additional wildcard pattern. The match expression is
 /// exhaustive if the imaginary additional wildcard pattern has an empty
 /// `WitnessReport`.
+///
+/// Each arm is given together with a flag that tells if the arm has an `if`
+/// guard. A guarded arm is checked for its reachability, but because its guard
+/// might not hold, its pattern is not added to *P* and so it never makes
+/// later arms unreachable nor contributes to the exhaustivity of the match
+/// expression.
+///
+/// Integer constructors are ranges, which can partially overlap. Before
+/// specializing *P* to an integer constructor, the constructor is split at
+/// the boundaries of the ranges in the first column of *P*, so that every
+/// resulting constructor is either fully included in or disjoint from each of
+/// those ranges.
 pub(crate) fn check_match_expression_usefulness(
     handler: &Handler,
     engines: &Engines,
     type_id: TypeId,
-    scrutinees: Vec<ty::TyScrutinee>,
+    arms: Vec<(ty::TyScrutinee, bool)>,
     span: Span,
 ) -> Result<(WitnessReport, Vec<ReachableReport>), ErrorEmitted> {
     let mut matrix = Matrix::empty();
@@ -213,7 +225,7 @@ pub(crate) fn check_match_expression_usefulness(
         .te()
         .get(type_id)
         .has_valid_constructor(engines.de())
-        && scrutinees.is_empty()
+        && arms.is_empty()
     {
         let witness_report = WitnessReport::NoWitnesses;
         let arms_reachability = vec![];
@@ -221,15 +233,19 @@ pub(crate) fn check_match_expression_usefulness(
     }
 
     let factory = ConstructorFactory::new(engines, type_id);
-    for scrutinee in scrutinees.into_iter() {
-        let pat = Pattern::from_scrutinee(scrutinee.clone());
+    for (scrutinee, has_guard) in arms.into_iter() {
+        let pat = Pattern::from_scrutinee(handler, engines, scrutinee.clone())?;
         let v = PatStack::from_pattern(pat);
         let witness_report = is_useful(handler, engines, &factory, &matrix, &v, &span)?;
-        matrix.push(v);
+        // a guarded arm might not match the values its pattern covers
+        if !has_guard {
+            matrix.push(v);
+        }
         // if an arm has witnesses to its usefulness then it is reachable
         arms_reachability.push(ReachableReport::new(
             witness_report.has_witnesses(),
             scrutinee,
+            has_guard,
         ));
     }
     let v = PatStack::from_pattern(Pattern::wild_pattern());
@@ -325,14 +341,28 @@ fn is_useful_wildcard(
     let sigma = p.compute_sigma(handler, span)?;
 
     // 2. Determine if Σ is a complete signature.
-    let is_complete_signature = factory.is_complete_signature(handler, engines, &sigma, span)?;
+    //
+    //    Wildcard rows are kept by the specialization for every constructor,
+    //    so when the other constructors of Σ are complete on their own, e.g.
+    //    `0..=255` next to `_` for a `u8`, the wildcard does not stand for any
+    //    further values and is not specialized for by itself.
+    let constructors = sigma.filter_out_wildcards();
+    let constructors_are_complete =
+        factory.is_complete_signature(handler, engines, &constructors, span)?;
+    let is_complete_signature = constructors_are_complete
+        || factory.is_complete_signature(handler, engines, &sigma, span)?;
 
     if is_complete_signature {
         // 3. If it is a complete signature:
 
+        let sigma = if constructors_are_complete {
+            constructors
+        } else {
+            sigma
+        };
         let mut witness_report = WitnessReport::NoWitnesses;
         let mut pat_stack = PatStack::empty();
-        for c_k in sigma.iter() {
+        for c_k in split_sigma(&sigma).iter() {
             //     3.1. For every every *k* 0..*n*, compute the specialized `Matrix`
             //        *S(cₖ, P)*
             let s_c_k_p = compute_specialized_matrix(handler, c_k, p, q, span)?;
@@ -448,21 +478,26 @@ fn is_useful_constructed(
     c: Pattern,
     span: &Span,
 ) -> Result<WitnessReport, ErrorEmitted> {
-    // 1. Extract the specialized `Matrix` *S(c, P)*
-    let s_c_p = compute_specialized_matrix(handler, &c, p, q, span)?;
+    // An integer range *c* is useful if any of the pieces it splits into
+    // against the first column of *P* is useful.
+    let sigma = p.compute_sigma(handler, span)?;
+    let mut witness_report = WitnessReport::NoWitnesses;
+    for c in c.split_constructor(&sigma) {
+        // 1. Extract the specialized `Matrix` *S(c, P)*
+        let s_c_p = compute_specialized_matrix(handler, &c, p, q, span)?;
 
-    // 2. Extract the specialized `Matrix` *S(c, q)*
-    let s_c_q =
-        compute_specialized_matrix(handler, &c, &Matrix::from_pat_stack(q.clone()), q, span)?;
+        // 2. Extract the specialized `Matrix` *S(c, q)*
+        let s_c_q =
+            compute_specialized_matrix(handler, &c, &Matrix::from_pat_stack(q.clone()), q, span)?;
 
-    // *S(c, q)* may have multiple rows in the case of a or pattern
-    // in that case we define: *U(P,((r1∣r2) q2...qn)) = U(P,(r1 q2...qn)) ∨ U(P,(r2 q2...qn))*
-    let mut witness_report = WitnessReport::NoWitnesses;
-    for s_c_q in s_c_q.rows() {
-        // 3. Recursively compute *U(S(c, P), S(c, q))*
-        let wr = is_useful(handler, engines, factory, &s_c_p, s_c_q, span)?;
+        // *S(c, q)* may have multiple rows in the case of a or pattern
+        // in that case we define: *U(P,((r1∣r2) q2...qn)) = U(P,(r1 q2...qn)) ∨ U(P,(r2 q2...qn))*
+        for s_c_q in s_c_q.rows() {
+            // 3. Recursively compute *U(S(c, P), S(c, q))*
+            let wr = is_useful(handler, engines, factory, &s_c_p, s_c_q, span)?;
 
-        witness_report = WitnessReport::join_witness_reports(witness_report, wr);
+            witness_report = WitnessReport::join_witness_reports(witness_report, wr);
+        }
     }
     Ok(witness_report)
 }
@@ -505,6 +540,21 @@ fn is_useful_or(
     Ok(witness_report)
 }
 
+/// Splits the integer constructors of Σ against each other, so that each of
+/// the resulting constructors is either fully included in or disjoint from
+/// every constructor of Σ. Other constructors are kept as they are.
+fn split_sigma(sigma: &PatStack) -> PatStack {
+    let mut split = PatStack::empty();
+    for c in sigma.iter() {
+        for c_k in c.split_constructor(sigma) {
+            if !split.contains(&c_k) {
+                split.push(c_k);
+            }
+        }
+    }
+    split
+}
+
 /// Given a `Matrix` *P*, constructs the default `Matrix` *D(P). This is done by
 /// sequentially computing the rows of *D(P)*.
 ///
@@ -697,7 +747,7 @@ fn compute_specialized_matrix_row(
             rows.append(&mut s_c_p.into_rows());
         }
         other => {
-            if c.has_the_same_constructor(&other) {
+            if other.covers_constructor(c) {
                 // 1. *pⁱ₁* is a constructed pattern *c'(r₁, ..., rₐ)* where *c* == *c'*:
                 //     1.1. the resulting row equals \[*r₁ ... rₐ pⁱ₂ ... pⁱₙ*\]
                 let mut row: PatStack = other.sub_patterns(handler, span)?;
@@ -181,6 +181,31 @@ impl Instantiate {
         ty::TyExpression::core_ops_neq(handler, ctx, vec![lhs, rhs], self.dummy_span())
     }
 
+    /// Instantiates an expression equivalent to `<exp> >= <start> && <exp> <= <end>`,
+    /// or to `<exp> >= <start> && <exp> < <end>` if the range is not `is_inclusive`.
+    pub(super) fn range_result(
+        &self,
+        handler: &Handler,
+        mut ctx: TypeCheckContext,
+        exp: ty::TyExpression,
+        start: ty::TyExpression,
+        end: ty::TyExpression,
+        is_inclusive: bool,
+    ) -> Result<ty::TyExpression, ErrorEmitted> {
+        let lower = ty::TyExpression::core_ops_ge(
+            handler,
+            ctx.by_ref(),
+            vec![exp.clone(), start],
+            self.dummy_span(),
+        )?;
+        let upper = if is_inclusive {
+            ty::TyExpression::core_ops_le(handler, ctx, vec![exp, end], self.dummy_span())?
+        } else {
+            ty::TyExpression::core_ops_lt(handler, ctx, vec![exp, end], self.dummy_span())?
+        };
+        Ok(self.lazy_and(lower, upper))
+    }
+
     /// Instantiates an expression equivalent to `<lhs> == <rhs>`. The method expects that
     /// the expression can be instantiated and panics if that's not the case.
     pub(super) fn eq(
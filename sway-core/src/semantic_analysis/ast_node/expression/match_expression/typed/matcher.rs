@@ -26,6 +26,11 @@ use sway_types::{integer_bits::IntegerBits, span::Span, Named, Spanned};
 /// fulfilled for the match arm to match.
 pub(super) type MatchReq = (ty::TyExpression, ty::TyExpression);
 
+/// A single requirement in the form `<lhs> >= <start> && <lhs> <= <end>` that has
+/// to be fulfilled for the match arm to match. The last element tells if `<end>`
+/// is included in the range, and if not, the upper comparison is `<lhs> < <end>`.
+pub(super) type MatchRangeReq = (ty::TyExpression, ty::TyExpression, ty::TyExpression, bool);
+
 /// A single variable in the form `let <ident> = <expression>`
 /// that has to be extracted from the match arm.
 pub(super) type MatchVarDecl = (Ident, ty::TyExpression);
@@ -39,6 +44,7 @@ pub(super) enum ReqOrVarDecl {
     /// Means a catch-all pattern.
     Neither,
     Req(MatchReq),
+    RangeReq(MatchRangeReq),
     VarDecl(MatchVarDecl),
 }
 
@@ -64,6 +70,14 @@ impl ReqDeclTree {
         }
     }
 
+    /// Creates a new tree that contains only one leaf node with the
+    /// [MatchRangeReq] `req`.
+    fn range_req(req: MatchRangeReq) -> Self {
+        Self {
+            root: ReqDeclNode::ReqOrVarDecl(ReqOrVarDecl::RangeReq(req)),
+        }
+    }
+
     /// Creates a new tree that contains only the leaf node with the
     /// [MatchVarDecl] `decl`.
     fn decl(decl: MatchVarDecl) -> Self {
@@ -225,6 +239,11 @@ pub(super) fn matcher(
         }
         ty::TyScrutineeVariant::CatchAll => Ok(ReqDeclTree::none()),
         ty::TyScrutineeVariant::Literal(value) => Ok(match_literal(exp, value, span)),
+        ty::TyScrutineeVariant::Range {
+            start,
+            end,
+            is_inclusive,
+        } => Ok(match_range(exp, start, end, is_inclusive, span)),
         ty::TyScrutineeVariant::Variable(name) => Ok(match_variable(exp, name)),
         ty::TyScrutineeVariant::Constant(_, _, const_decl) => {
             Ok(match_constant(ctx, exp, const_decl, span))
@@ -388,6 +407,23 @@ fn match_literal(exp: &ty::TyExpression, scrutinee: Literal, span: Span) -> ReqD
     ReqDeclTree::req(req)
 }
 
+fn match_range(
+    exp: &ty::TyExpression,
+    start: Literal,
+    end: Literal,
+    is_inclusive: bool,
+    span: Span,
+) -> ReqDeclTree {
+    let bound = |value| ty::TyExpression {
+        expression: ty::TyExpressionVariant::Literal(value),
+        return_type: exp.return_type,
+        span: span.clone(),
+    };
+    let req = (exp.to_owned(), bound(start), bound(end), is_inclusive);
+
+    ReqDeclTree::range_req(req)
+}
+
 fn match_variable(exp: &ty::TyExpression, scrutinee_name: Ident) -> ReqDeclTree {
     let decl = (scrutinee_name, exp.to_owned());
 
@@ -31,6 +31,7 @@ impl ty::TyMatchBranch {
     ) -> Result<(ty::TyMatchBranch, ty::TyScrutinee), ErrorEmitted> {
         let MatchBranch {
             scrutinee,
+            guard,
             result,
             span: branch_span,
         } = branch;
@@ -103,6 +104,40 @@ impl ty::TyMatchBranch {
             });
         }
 
+        // if the branch has a guard, type check it in the scope of the match arm variables
+        // and append it to the branch condition as `<condition> && { <var decls>; <guard> }`
+        let has_guard = guard.is_some();
+        let condition = match guard {
+            Some(guard) => {
+                let typed_guard = {
+                    let ctx = branch_ctx
+                        .by_ref()
+                        .with_help_text("The guard of a match arm must be a boolean expression.")
+                        .with_type_annotation(type_engine.insert(engines, TypeInfo::Boolean, None));
+                    ty::TyExpression::type_check(handler, ctx, guard)?
+                };
+                let typed_guard_span = typed_guard.span.clone();
+                let mut guard_block_contents = code_block_contents.clone();
+                guard_block_contents.push(ty::TyAstNode {
+                    content: ty::TyAstNodeContent::ImplicitReturnExpression(typed_guard),
+                    span: typed_guard_span.clone(),
+                });
+                let guard_block = ty::TyExpression {
+                    expression: ty::TyExpressionVariant::CodeBlock(ty::TyCodeBlock {
+                        contents: guard_block_contents,
+                        whole_block_span: typed_guard_span.clone(),
+                    }),
+                    return_type: type_engine.insert(engines, TypeInfo::Boolean, None),
+                    span: typed_guard_span,
+                };
+                match condition {
+                    Some(condition) => Some(instantiate.lazy_and(condition, guard_block)),
+                    None => Some(guard_block),
+                }
+            }
+            None => condition,
+        };
+
         // type check the branch result
         let typed_result = {
             let ctx = branch_ctx.by_ref().with_type_annotation(type_engine.insert(
@@ -161,6 +196,7 @@ impl ty::TyMatchBranch {
         let typed_branch = ty::TyMatchBranch {
             matched_or_variant_index_vars: or_variant_vars,
             condition,
+            has_guard,
             result: new_result,
             span: branch_span,
         };
@@ -280,6 +316,19 @@ fn instantiate_branch_condition_result_var_declarations_and_matched_or_variant_i
                     .map(Some)?;
                 Ok((condition, vec![], vec![]))
             }
+            ReqDeclNode::ReqOrVarDecl(ReqOrVarDecl::RangeReq(req)) => {
+                let condition = instantiate
+                    .range_result(
+                        handler,
+                        ctx.by_ref(),
+                        req.0.clone(),
+                        req.1.clone(),
+                        req.2.clone(),
+                        req.3,
+                    )
+                    .map(Some)?;
+                Ok((condition, vec![], vec![]))
+            }
             ReqDeclNode::ReqOrVarDecl(ReqOrVarDecl::VarDecl(decl)) => {
                 if parent_node.is_none() {
                     // I am the root/only node. Add my declaration to the result var declarations and pass no requirements and no carry over vars.
@@ -9,7 +9,7 @@ use crate::{
     language::{
         parsed::*,
         ty::{self, TyDecl, TyScrutinee},
-        CallPath,
+        CallPath, Literal,
     },
     semantic_analysis::{
         type_check_context::EnforceTypeArguments, TypeCheckContext, TypeCheckFinalization,
@@ -74,6 +74,12 @@ impl TyScrutinee {
                 };
                 Ok(typed_scrutinee)
             }
+            Scrutinee::Range {
+                start,
+                end,
+                is_inclusive,
+                span,
+            } => type_check_range(handler, ctx, start, end, is_inclusive, span),
             Scrutinee::Variable { name, span } => type_check_variable(handler, ctx, name, span),
             Scrutinee::StructScrutinee {
                 struct_name,
@@ -141,6 +147,7 @@ impl TyScrutinee {
             ty::TyScrutineeVariant::CatchAll => true,
             ty::TyScrutineeVariant::Variable(_) => true,
             ty::TyScrutineeVariant::Literal(_) => false,
+            ty::TyScrutineeVariant::Range { .. } => false,
             ty::TyScrutineeVariant::Constant { .. } => false,
             ty::TyScrutineeVariant::StructScrutinee { fields, .. } => fields
                 .iter()
@@ -153,6 +160,49 @@ impl TyScrutinee {
     }
 }
 
+fn type_check_range(
+    handler: &Handler,
+    ctx: TypeCheckContext,
+    start: Literal,
+    end: Literal,
+    is_inclusive: bool,
+    span: Span,
+) -> Result<ty::TyScrutinee, ErrorEmitted> {
+    let engines = ctx.engines;
+    let type_engine = engines.te();
+
+    let (first, last) = match (start.as_integer(), end.as_integer()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => {
+            return Err(handler.emit_err(CompileError::Unimplemented(
+                "range patterns are only supported for integers of up to 64 bits",
+                span,
+            )));
+        }
+    };
+    if first > last || (first == last && !is_inclusive) {
+        return Err(handler.emit_err(CompileError::MatchRangePatternEmpty { span }));
+    }
+
+    // both bounds of the range must have the same type
+    let type_id = type_engine.insert(engines, start.to_typeinfo(), span.source_id());
+    let end_type_id = type_engine.insert(engines, end.to_typeinfo(), span.source_id());
+    handler.scope(|h| {
+        type_engine.unify(h, engines, end_type_id, type_id, &span, "", None);
+        Ok(())
+    })?;
+
+    Ok(ty::TyScrutinee {
+        variant: ty::TyScrutineeVariant::Range {
+            start,
+            end,
+            is_inclusive,
+        },
+        type_id,
+        span,
+    })
+}
+
 fn type_check_variable(
     handler: &Handler,
     ctx: TypeCheckContext,
@@ -25,7 +25,7 @@ use crate::{
         ty::{self, TyCodeBlock, TyImplItem},
         *,
     },
-    namespace::{IsExtendingExistingImpl, IsImplSelf},
+    namespace::{IsExtendingExistingImpl, IsImplSelf, TryInsertingTraitImplOnFailure},
     semantic_analysis::{expression::ReachableReport, type_check_context::EnforceTypeArguments, *},
     transform::to_parsed_lang::type_name_to_type_info_opt,
     type_system::*,
@@ -68,6 +68,39 @@ impl ty::TyExpression {
         Self::core_ops(handler, ctx, OpVariant::NotEquals, arguments, span)
     }
 
+    pub(crate) fn core_ops_lt(
+        handler: &Handler,
+        ctx: TypeCheckContext,
+        arguments: Vec<ty::TyExpression>,
+        span: Span,
+    ) -> Result<ty::TyExpression, ErrorEmitted> {
+        Self::core_ops(handler, ctx, OpVariant::LessThan, arguments, span)
+    }
+
+    pub(crate) fn core_ops_le(
+        handler: &Handler,
+        ctx: TypeCheckContext,
+        arguments: Vec<ty::TyExpression>,
+        span: Span,
+    ) -> Result<ty::TyExpression, ErrorEmitted> {
+        Self::core_ops(handler, ctx, OpVariant::LessThanOrEqualTo, arguments, span)
+    }
+
+    pub(crate) fn core_ops_ge(
+        handler: &Handler,
+        ctx: TypeCheckContext,
+        arguments: Vec<ty::TyExpression>,
+        span: Span,
+    ) -> Result<ty::TyExpression, ErrorEmitted> {
+        Self::core_ops(
+            handler,
+            ctx,
+            OpVariant::GreaterThanOrEqualTo,
+            arguments,
+            span,
+        )
+    }
+
     fn core_ops(
         handler: &Handler,
         mut ctx: TypeCheckContext,
@@ -226,15 +259,18 @@ impl ty::TyExpression {
                 r#else.map(|e| *e),
                 span,
             ),
-            ExpressionKind::Match(MatchExpression { value, branches }) => {
-                Self::type_check_match_expression(
-                    handler,
-                    ctx.by_ref().with_help_text(""),
-                    *value,
-                    branches,
-                    span,
-                )
-            }
+            ExpressionKind::Match(MatchExpression {
+                value,
+                branches,
+                destructuring,
+            }) => Self::type_check_match_expression(
+                handler,
+                ctx.by_ref().with_help_text(""),
+                *value,
+                branches,
+                destructuring,
+                span,
+            ),
             ExpressionKind::Asm(asm) => {
                 Self::type_check_asm_expression(handler, ctx.by_ref(), *asm, span)
             }
@@ -357,6 +393,9 @@ impl ty::TyExpression {
             ExpressionKind::WhileLoop(WhileLoopExpression { condition, body }) => {
                 Self::type_check_while_loop(handler, ctx.by_ref(), *condition, body, span)
             }
+            ExpressionKind::ForLoop(for_loop) => {
+                Self::type_check_for_loop(handler, ctx.by_ref(), for_loop, span)
+            }
             ExpressionKind::Break => {
                 let expr = ty::TyExpression {
                     expression: ty::TyExpressionVariant::Break,
@@ -376,6 +415,13 @@ impl ty::TyExpression {
             ExpressionKind::Reassignment(ReassignmentExpression { lhs, rhs }) => {
                 Self::type_check_reassignment(handler, ctx.by_ref(), lhs, *rhs, span)
             }
+            ExpressionKind::Ref(RefExpression {
+                to_mutable_value,
+                value,
+            }) => Self::type_check_ref(handler, ctx.by_ref(), to_mutable_value, *value, span),
+            ExpressionKind::Deref(expr) => {
+                Self::type_check_deref(handler, ctx.by_ref(), *expr, span)
+            }
             ExpressionKind::Return(expr) => {
                 let ctx = ctx
                     // we use "unknown" here because return statements do not
@@ -424,11 +470,13 @@ impl ty::TyExpression {
             .unwrap_or_else(|err| type_engine.insert(engines, TypeInfo::ErrorRecovery(err), None));
 
         // Literals of type Numeric can now be resolved if typed_expression.return_type is
-        // an UnsignedInteger or a Numeric
+        // an UnsignedInteger, a SignedInteger or a Numeric
         if let ty::TyExpressionVariant::Literal(lit) = typed_expression.clone().expression {
-            if let Literal::Numeric(_) = lit {
+            if let Literal::Numeric(_) | Literal::NegativeNumeric(_) = lit {
                 match type_engine.get(typed_expression.return_type) {
-                    TypeInfo::UnsignedInteger(_) | TypeInfo::Numeric => {
+                    TypeInfo::UnsignedInteger(_)
+                    | TypeInfo::SignedInteger(_)
+                    | TypeInfo::Numeric => {
                         typed_expression = Self::resolve_numeric_literal(
                             handler,
                             ctx,
@@ -449,12 +497,16 @@ impl ty::TyExpression {
         let type_engine = engines.te();
         let return_type = match &lit {
             Literal::String(_) => TypeInfo::StringSlice,
-            Literal::Numeric(_) => TypeInfo::Numeric,
+            Literal::Numeric(_) | Literal::NegativeNumeric(_) => TypeInfo::Numeric,
             Literal::U8(_) => TypeInfo::UnsignedInteger(IntegerBits::Eight),
             Literal::U16(_) => TypeInfo::UnsignedInteger(IntegerBits::Sixteen),
             Literal::U32(_) => TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo),
             Literal::U64(_) => TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
             Literal::U256(_) => TypeInfo::UnsignedInteger(IntegerBits::V256),
+            Literal::I8(_) => TypeInfo::SignedInteger(IntegerBits::Eight),
+            Literal::I16(_) => TypeInfo::SignedInteger(IntegerBits::Sixteen),
+            Literal::I32(_) => TypeInfo::SignedInteger(IntegerBits::ThirtyTwo),
+            Literal::I64(_) => TypeInfo::SignedInteger(IntegerBits::SixtyFour),
             Literal::Boolean(_) => TypeInfo::Boolean,
             Literal::B256(_) => TypeInfo::B256,
         };
@@ -668,6 +720,7 @@ impl ty::TyExpression {
         mut ctx: TypeCheckContext,
         value: Expression,
         branches: Vec<MatchBranch>,
+        destructuring: Option<DestructuringKind>,
         span: Span,
     ) -> Result<ty::TyExpression, ErrorEmitted> {
         let type_engine = ctx.engines.te();
@@ -699,13 +752,13 @@ impl ty::TyExpression {
         )?;
 
         // check to see if the match expression is exhaustive and if all match arms are reachable
-        let (witness_report, arms_reachability) = check_match_expression_usefulness(
-            handler,
-            engines,
-            type_id,
-            typed_scrutinees.clone(),
-            span.clone(),
-        )?;
+        let arms = typed_scrutinees
+            .iter()
+            .cloned()
+            .zip(typed_match_expression.branches.iter().map(|b| b.has_guard))
+            .collect();
+        let (witness_report, arms_reachability) =
+            check_match_expression_usefulness(handler, engines, type_id, arms, span.clone())?;
 
         // if there is an interior catch-all arm
         if let Some(catch_all_arm_position) = interior_catch_all_arm_position(&arms_reachability) {
@@ -765,24 +818,36 @@ impl ty::TyExpression {
                         preceding_arms: Either::Left(
                             other_arms_reachability
                                 .iter()
+                                .filter(|report| !report.has_guard)
                                 .map(|report| report.scrutinee.span.clone())
                                 .collect(),
                         ),
                         unreachable_arm: last_arm_report.scrutinee.span.clone(),
                         is_last_arm: true,
-                        is_catch_all_arm: last_arm_report.scrutinee.is_catch_all(),
+                        is_catch_all_arm: last_arm_report.is_catch_all(),
                     },
                 });
             }
         }
 
         if witness_report.has_witnesses() {
-            return Err(
-                handler.emit_err(CompileError::MatchExpressionNonExhaustive {
-                    missing_patterns: format!("{witness_report}"),
+            let missing_patterns = format!("{witness_report}");
+            return Err(handler.emit_err(match destructuring {
+                Some(DestructuringKind::LetOrParam) => {
+                    CompileError::RefutablePatternInDestructuring {
+                        missing_patterns,
+                        span,
+                    }
+                }
+                Some(DestructuringKind::ForLoop) => CompileError::RefutablePatternInForLoop {
+                    missing_patterns,
                     span,
-                }),
-            );
+                },
+                None => CompileError::MatchExpressionNonExhaustive {
+                    missing_patterns,
+                    span,
+                },
+            }));
         }
 
         // desugar the typed match expression to a typed if expression
@@ -809,7 +874,7 @@ impl ty::TyExpression {
                 .split_last()?
                 .1
                 .iter()
-                .position(|report| report.scrutinee.is_catch_all())
+                .position(|report| report.is_catch_all())
         }
 
         fn check_interior_non_catch_all_arms_for_reachability(
@@ -829,6 +894,7 @@ impl ty::TyExpression {
                             preceding_arms: Either::Left(
                                 arms_reachability[..index]
                                     .iter()
+                                    .filter(|report| !report.has_guard)
                                     .map(|report| report.scrutinee.span.clone())
                                     .collect(),
                             ),
@@ -1903,6 +1969,342 @@ impl ty::TyExpression {
         Ok(exp)
     }
 
+    /// Type checks a `for` loop, by declaring the iterable and its iterator
+    /// and type checking the `while` loop the `for` loop is desugared to.
+    /// See [ForLoopExpression] for the desugaring.
+    fn type_check_for_loop(
+        handler: &Handler,
+        mut ctx: TypeCheckContext,
+        for_loop: ForLoopExpression,
+        span: Span,
+    ) -> Result<Self, ErrorEmitted> {
+        let type_engine = ctx.engines.te();
+        let engines = ctx.engines();
+
+        let ForLoopExpression {
+            iterable,
+            iterable_name,
+            iterator_name,
+            body,
+        } = for_loop;
+        let iterable_span = iterable.span.clone();
+
+        // The iterators live in `std`, which packages built without it cannot refer to.
+        let std_iterator_path =
+            ["std", "iterator"].map(|name| Ident::new_with_override(name.into(), span.clone()));
+        if ctx.namespace.root.submodule(&std_iterator_path).is_none() {
+            return Err(handler.emit_err(CompileError::ForLoopWithoutStd { span }));
+        }
+
+        let var_decl = |name: Ident, body: Expression, is_mutable: bool| {
+            let type_id = type_engine.insert(engines, TypeInfo::Unknown, None);
+            AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    VariableDeclaration {
+                        type_ascription: TypeArgument {
+                            type_id,
+                            initial_type_id: type_id,
+                            span: name.span(),
+                            call_path_tree: None,
+                        },
+                        name,
+                        body,
+                        is_mutable,
+                    },
+                )),
+                span: iterable_span.clone(),
+            }
+        };
+        let var_exp = |name: &Ident| Expression {
+            kind: ExpressionKind::Variable(name.clone()),
+            span: iterable_span.clone(),
+        };
+
+        // The iterable and its iterator are visible only within the loop.
+        let mut for_loop_namespace = ctx.namespace.clone();
+        let mut ctx = ctx
+            .by_ref()
+            .scoped(&mut for_loop_namespace)
+            .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown, None))
+            .with_help_text("");
+
+        let typed_iterable_decl = ty::TyAstNode::type_check(
+            handler,
+            ctx.by_ref(),
+            var_decl(iterable_name.clone(), *iterable, false),
+        )?;
+        let iterable_type = match &typed_iterable_decl.content {
+            ty::TyAstNodeContent::Declaration(ty::TyDecl::VariableDecl(decl)) => decl.return_type,
+            _ => unreachable!("The iterable of a `for` loop is declared as a variable."),
+        };
+
+        let iterator = match type_engine.get(iterable_type) {
+            // Arrays are iterated over with a `std::iterator::SliceIter`:
+            // `SliceIter::<T> { ptr: __addr_of(<iterable>), len: <length>, index: 0 }`
+            TypeInfo::Array(elem_type, length) => {
+                let path_ident = |name: &str| Ident::new_with_override(name.into(), span.clone());
+                let literal = |value: u64| Expression {
+                    kind: ExpressionKind::Literal(Literal::U64(value)),
+                    span: iterable_span.clone(),
+                };
+                let field = |name: &str, value: Expression| StructExpressionField {
+                    name: path_ident(name),
+                    value,
+                    span: iterable_span.clone(),
+                };
+                let addr_of_iterable = Expression {
+                    kind: ExpressionKind::IntrinsicFunction(IntrinsicFunctionExpression {
+                        name: path_ident("__addr_of"),
+                        kind_binding: TypeBinding {
+                            inner: Intrinsic::AddrOf,
+                            type_arguments: TypeArgs::Regular(vec![]),
+                            span: iterable_span.clone(),
+                        },
+                        arguments: vec![var_exp(&iterable_name)],
+                    }),
+                    span: iterable_span.clone(),
+                };
+                Expression {
+                    kind: ExpressionKind::Struct(Box::new(StructExpression {
+                        call_path_binding: TypeBinding {
+                            inner: CallPath {
+                                prefixes: vec![path_ident("std"), path_ident("iterator")],
+                                suffix: path_ident("SliceIter"),
+                                is_absolute: true,
+                            },
+                            type_arguments: TypeArgs::Regular(vec![TypeArgument {
+                                type_id: elem_type.type_id,
+                                initial_type_id: elem_type.type_id,
+                                span: iterable_span.clone(),
+                                call_path_tree: None,
+                            }]),
+                            span: iterable_span.clone(),
+                        },
+                        fields: vec![
+                            field("ptr", addr_of_iterable),
+                            field("len", literal(length.val() as u64)),
+                            field("index", literal(0)),
+                        ],
+                    })),
+                    span: iterable_span.clone(),
+                }
+            }
+            // Don't report errors for iterables that have already failed to type check.
+            TypeInfo::ErrorRecovery(_) | TypeInfo::Unknown => var_exp(&iterable_name),
+            // Any other iterable must be an iterator itself.
+            _ => {
+                let iterator_constraint = TraitConstraint {
+                    trait_name: CallPath {
+                        prefixes: vec![
+                            Ident::new_with_override("std".into(), span.clone()),
+                            Ident::new_with_override("iterator".into(), span.clone()),
+                        ],
+                        suffix: Ident::new_with_override("Iterator".into(), span.clone()),
+                        is_absolute: true,
+                    },
+                    type_arguments: vec![],
+                };
+                if ctx
+                    .namespace
+                    .implemented_traits
+                    .check_if_trait_constraints_are_satisfied_for_type(
+                        &Handler::default(),
+                        iterable_type,
+                        &[iterator_constraint],
+                        &iterable_span,
+                        engines,
+                        TryInsertingTraitImplOnFailure::Yes,
+                    )
+                    .is_err()
+                {
+                    return Err(handler.emit_err(CompileError::ForLoopIterableNotIterator {
+                        ty: engines.help_out(iterable_type).to_string(),
+                        span: iterable_span,
+                    }));
+                }
+                var_exp(&iterable_name)
+            }
+        };
+        let typed_iterator_decl = ty::TyAstNode::type_check(
+            handler,
+            ctx.by_ref(),
+            var_decl(iterator_name, iterator, true),
+        )?;
+
+        let boolean_ty = type_engine.insert(engines, TypeInfo::Boolean, None);
+        let unit_ty = type_engine.insert(engines, TypeInfo::Tuple(Vec::new()), None);
+        let typed_loop = {
+            let mut ctx = ctx.by_ref().with_type_annotation(unit_ty).with_help_text(
+                "A for loop's loop body cannot implicitly return a value. Try \
+                     assigning it to a mutable variable declared outside of the loop \
+                     instead.",
+            );
+            let mut typed_body = ty::TyCodeBlock::type_check(handler, ctx.by_ref(), &body)?;
+
+            let mut unification_ctx = TypeCheckUnificationContext::new(engines, ctx);
+            typed_body.type_check_unify(handler, &mut unification_ctx)?;
+
+            ty::TyExpression {
+                expression: ty::TyExpressionVariant::WhileLoop {
+                    condition: Box::new(ty::TyExpression {
+                        expression: ty::TyExpressionVariant::Literal(Literal::Boolean(true)),
+                        return_type: boolean_ty,
+                        span: span.clone(),
+                    }),
+                    body: typed_body,
+                },
+                return_type: unit_ty,
+                span: span.clone(),
+            }
+        };
+
+        let exp = ty::TyExpression {
+            expression: ty::TyExpressionVariant::CodeBlock(ty::TyCodeBlock {
+                contents: vec![
+                    typed_iterable_decl,
+                    typed_iterator_decl,
+                    ty::TyAstNode {
+                        content: ty::TyAstNodeContent::ImplicitReturnExpression(typed_loop),
+                        span: span.clone(),
+                    },
+                ],
+                whole_block_span: span.clone(),
+            }),
+            return_type: unit_ty,
+            span,
+        };
+        Ok(exp)
+    }
+
+    fn type_check_ref(
+        handler: &Handler,
+        mut ctx: TypeCheckContext<'_>,
+        to_mutable_value: bool,
+        value: Expression,
+        span: Span,
+    ) -> Result<ty::TyExpression, ErrorEmitted> {
+        let engines = ctx.engines();
+        let type_engine = ctx.engines().te();
+
+        // If the type annotation is a reference, use the referenced type as the
+        // annotation of the referenced value.
+        let type_annotation = match type_engine.get_unaliased(ctx.type_annotation()) {
+            TypeInfo::Ref {
+                referenced_type, ..
+            } => referenced_type.type_id,
+            _ => type_engine.insert(engines, TypeInfo::Unknown, None),
+        };
+        let ctx = ctx
+            .by_ref()
+            .with_type_annotation(type_annotation)
+            .with_help_text("");
+
+        let expr_span = value.span();
+        let expr = ty::TyExpression::type_check(handler, ctx, value)?;
+
+        if to_mutable_value {
+            Self::check_ref_mut_target(handler, engines, &expr)?;
+        }
+
+        let expr_type_argument: TypeArgument = expr.return_type.into();
+        let typed_expr = ty::TyExpression {
+            expression: ty::TyExpressionVariant::Ref(Box::new(expr)),
+            return_type: type_engine.insert(
+                engines,
+                TypeInfo::Ref {
+                    to_mutable_value,
+                    referenced_type: TypeArgument {
+                        span: expr_span,
+                        ..expr_type_argument
+                    },
+                },
+                None,
+            ),
+            span,
+        };
+
+        Ok(typed_expr)
+    }
+
+    /// Makes sure that a `&mut` reference can be taken to the place denoted by `expr`.
+    /// Places rooted in immutable variables, or reached through immutable references,
+    /// cannot be mutably referenced. Any other value is a temporary and can be.
+    fn check_ref_mut_target(
+        handler: &Handler,
+        engines: &Engines,
+        expr: &ty::TyExpression,
+    ) -> Result<(), ErrorEmitted> {
+        match &expr.expression {
+            ty::TyExpressionVariant::VariableExpression {
+                name, mutability, ..
+            } if !mutability.is_mutable() => Err(handler.emit_err(
+                CompileError::RefMutCannotReferenceImmutableVariable {
+                    decl_name: name.clone(),
+                    span: expr.span.clone(),
+                },
+            )),
+            ty::TyExpressionVariant::StructFieldAccess { prefix, .. }
+            | ty::TyExpressionVariant::TupleElemAccess { prefix, .. }
+            | ty::TyExpressionVariant::ArrayIndex { prefix, .. } => {
+                Self::check_ref_mut_target(handler, engines, prefix)
+            }
+            ty::TyExpressionVariant::Deref(reference) => {
+                match engines.te().get_unaliased(reference.return_type) {
+                    TypeInfo::Ref {
+                        to_mutable_value: false,
+                        ..
+                    } => Err(handler.emit_err(
+                        CompileError::RefMutCannotReferenceImmutableReference {
+                            span: expr.span.clone(),
+                        },
+                    )),
+                    _ => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn type_check_deref(
+        handler: &Handler,
+        mut ctx: TypeCheckContext<'_>,
+        expr: Expression,
+        span: Span,
+    ) -> Result<ty::TyExpression, ErrorEmitted> {
+        let engines = ctx.engines();
+        let type_engine = ctx.engines().te();
+
+        let deref_ctx = ctx
+            .by_ref()
+            .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown, None))
+            .with_help_text("");
+
+        let expr_span = expr.span();
+        let expr = ty::TyExpression::type_check(handler, deref_ctx, expr)?;
+
+        let expr_type = type_engine.get_unaliased(expr.return_type);
+        let return_type = match expr_type {
+            TypeInfo::ErrorRecovery(_) => Ok(expr.return_type),
+            TypeInfo::Ref {
+                referenced_type, ..
+            } => Ok(referenced_type.type_id),
+            _ => Err(
+                handler.emit_err(CompileError::ExpressionCannotBeDereferenced {
+                    expression_type: engines.help_out(expr.return_type).to_string(),
+                    span: expr_span,
+                }),
+            ),
+        }?;
+
+        let typed_expr = ty::TyExpression {
+            expression: ty::TyExpressionVariant::Deref(Box::new(expr)),
+            return_type,
+            span,
+        };
+
+        Ok(typed_expr)
+    }
+
     fn type_check_reassignment(
         handler: &Handler,
         ctx: TypeCheckContext,
@@ -1918,6 +2320,57 @@ impl ty::TyExpression {
             .with_help_text("");
         // ensure that the lhs is a supported expression kind
         match lhs {
+            ReassignmentTarget::Deref(reference) => {
+                let reference_span = reference.span();
+                let reference_ctx = ctx
+                    .by_ref()
+                    .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown, None))
+                    .with_help_text("");
+                let reference = ty::TyExpression::type_check(handler, reference_ctx, *reference)?;
+
+                let referenced_type = match type_engine.get_unaliased(reference.return_type) {
+                    TypeInfo::Ref {
+                        to_mutable_value: true,
+                        referenced_type,
+                    } => referenced_type,
+                    TypeInfo::Ref { .. } => {
+                        return Err(handler.emit_err(
+                            CompileError::AssignmentViaNonMutableReference {
+                                span: reference_span,
+                            },
+                        ));
+                    }
+                    _ => {
+                        return Err(handler.emit_err(
+                            CompileError::ExpressionCannotBeDereferenced {
+                                expression_type: engines
+                                    .help_out(reference.return_type)
+                                    .to_string(),
+                                span: reference_span,
+                            },
+                        ));
+                    }
+                };
+
+                // type check the reassignment
+                let ctx = ctx
+                    .with_type_annotation(referenced_type.type_id)
+                    .with_help_text("");
+                let rhs_span = rhs.span();
+                let rhs = ty::TyExpression::type_check(handler, ctx, rhs)
+                    .unwrap_or_else(|err| ty::TyExpression::error(err, rhs_span, engines));
+
+                Ok(ty::TyExpression {
+                    expression: ty::TyExpressionVariant::Reassignment(Box::new(
+                        ty::TyReassignment {
+                            lhs: ty::TyReassignmentTarget::Deref(Box::new(reference)),
+                            rhs,
+                        },
+                    )),
+                    return_type: type_engine.insert(engines, TypeInfo::Tuple(Vec::new()), None),
+                    span,
+                })
+            }
             ReassignmentTarget::VariableExpression(var) => {
                 let mut expr = var;
                 let mut names_vec = Vec::new();
@@ -1994,9 +2447,11 @@ impl ty::TyExpression {
                 Ok(ty::TyExpression {
                     expression: ty::TyExpressionVariant::Reassignment(Box::new(
                         ty::TyReassignment {
-                            lhs_base_name: base_name,
-                            lhs_type: final_return_type,
-                            lhs_indices: names_vec,
+                            lhs: ty::TyReassignmentTarget::ElementAccess {
+                                base_name,
+                                base_type: final_return_type,
+                                indices: names_vec,
+                            },
                             rhs,
                         },
                     )),
@@ -2018,65 +2473,88 @@ impl ty::TyExpression {
         let engines = ctx.engines();
 
         // Parse and resolve a Numeric(span) based on new_type.
-        let (val, new_integer_type) = match lit {
-            Literal::Numeric(num) => match type_engine.get(new_type) {
-                TypeInfo::UnsignedInteger(n) => match n {
-                    IntegerBits::Eight => (
-                        num.to_string().parse().map(Literal::U8).map_err(|e| {
-                            Literal::handle_parse_int_error(
-                                engines,
-                                e,
-                                TypeInfo::UnsignedInteger(IntegerBits::Eight),
-                                span.clone(),
-                            )
-                        }),
-                        new_type,
-                    ),
-                    IntegerBits::Sixteen => (
-                        num.to_string().parse().map(Literal::U16).map_err(|e| {
-                            Literal::handle_parse_int_error(
-                                engines,
-                                e,
-                                TypeInfo::UnsignedInteger(IntegerBits::Sixteen),
-                                span.clone(),
-                            )
-                        }),
-                        new_type,
-                    ),
-                    IntegerBits::ThirtyTwo => (
-                        num.to_string().parse().map(Literal::U32).map_err(|e| {
-                            Literal::handle_parse_int_error(
-                                engines,
-                                e,
-                                TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo),
-                                span.clone(),
-                            )
-                        }),
-                        new_type,
-                    ),
-                    IntegerBits::SixtyFour => (
-                        num.to_string().parse().map(Literal::U64).map_err(|e| {
-                            Literal::handle_parse_int_error(
-                                engines,
-                                e,
-                                TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
-                                span.clone(),
-                            )
+        let num = match lit {
+            Literal::Numeric(num) => num.to_string(),
+            Literal::NegativeNumeric(num) => num.to_string(),
+            _ => unreachable!("Unexpected non-integer literals"),
+        };
+        let is_negative = matches!(lit, Literal::NegativeNumeric(_));
+        let parse_int_error = |e, ty| Literal::handle_parse_int_error(engines, e, ty, span.clone());
+        let (val, new_integer_type) = match type_engine.get(new_type) {
+            ty @ TypeInfo::UnsignedInteger(_) if is_negative => (
+                Err(CompileError::IntegerTooSmall {
+                    ty: engines.help_out(ty).to_string(),
+                    span: span.clone(),
+                }),
+                new_type,
+            ),
+            TypeInfo::UnsignedInteger(n) => match n {
+                IntegerBits::Eight => (
+                    num.parse().map(Literal::U8).map_err(|e| {
+                        parse_int_error(e, TypeInfo::UnsignedInteger(IntegerBits::Eight))
+                    }),
+                    new_type,
+                ),
+                IntegerBits::Sixteen => (
+                    num.parse().map(Literal::U16).map_err(|e| {
+                        parse_int_error(e, TypeInfo::UnsignedInteger(IntegerBits::Sixteen))
+                    }),
+                    new_type,
+                ),
+                IntegerBits::ThirtyTwo => (
+                    num.parse().map(Literal::U32).map_err(|e| {
+                        parse_int_error(e, TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo))
+                    }),
+                    new_type,
+                ),
+                IntegerBits::SixtyFour => (
+                    num.parse().map(Literal::U64).map_err(|e| {
+                        parse_int_error(e, TypeInfo::UnsignedInteger(IntegerBits::SixtyFour))
+                    }),
+                    new_type,
+                ),
+                // Numerics are limited to u64 for now
+                IntegerBits::V256 => (
+                    num.parse::<u64>()
+                        .map(|num| Literal::U256(U256::from(num)))
+                        .map_err(|e| {
+                            parse_int_error(e, TypeInfo::UnsignedInteger(IntegerBits::V256))
                         }),
-                        new_type,
-                    ),
-                    // Numerics are limited to u64 for now
-                    IntegerBits::V256 => (Ok(Literal::U256(U256::from(num))), new_type),
-                },
-                TypeInfo::Numeric => (
-                    num.to_string().parse().map(Literal::Numeric).map_err(|e| {
-                        Literal::handle_parse_int_error(engines, e, TypeInfo::Numeric, span.clone())
+                    new_type,
+                ),
+            },
+            TypeInfo::SignedInteger(n) => match n {
+                IntegerBits::Eight => (
+                    num.parse().map(Literal::I8).map_err(|e| {
+                        parse_int_error(e, TypeInfo::SignedInteger(IntegerBits::Eight))
                     }),
-                    type_engine.insert(engines, TypeInfo::Numeric, None),
+                    new_type,
                 ),
-                _ => unreachable!("Unexpected type for integer literals"),
+                IntegerBits::Sixteen => (
+                    num.parse().map(Literal::I16).map_err(|e| {
+                        parse_int_error(e, TypeInfo::SignedInteger(IntegerBits::Sixteen))
+                    }),
+                    new_type,
+                ),
+                IntegerBits::ThirtyTwo => (
+                    num.parse().map(Literal::I32).map_err(|e| {
+                        parse_int_error(e, TypeInfo::SignedInteger(IntegerBits::ThirtyTwo))
+                    }),
+                    new_type,
+                ),
+                IntegerBits::SixtyFour => (
+                    num.parse().map(Literal::I64).map_err(|e| {
+                        parse_int_error(e, TypeInfo::SignedInteger(IntegerBits::SixtyFour))
+                    }),
+                    new_type,
+                ),
+                IntegerBits::V256 => unreachable!("There is no 256-bit signed integer type"),
             },
-            _ => unreachable!("Unexpected non-integer literals"),
+            TypeInfo::Numeric => (
+                Ok(lit),
+                type_engine.insert(engines, TypeInfo::Numeric, None),
+            ),
+            _ => unreachable!("Unexpected type for integer literals"),
         };
 
         match val {
@@ -19,6 +19,7 @@ use crate::{
 use std::collections::HashSet;
 use std::fmt;
 use sway_error::warning::{CompileWarning, Warning};
+use sway_ir::{is_storage_read_opcode, is_storage_write_opcode};
 use sway_types::{Ident, Span, Spanned};
 
 #[derive(PartialEq, Eq, Hash, Clone)]
@@ -218,7 +219,15 @@ fn analyze_expression(
         | Break
         | Continue
         | AbiName(_) => effects_of_expression(engines, expr),
-        Reassignment(reassgn) => analyze_expression(engines, &reassgn.rhs, block_name, warnings),
+        Reassignment(reassgn) => match &reassgn.lhs {
+            ty::TyReassignmentTarget::Deref(exp) => {
+                // assuming left-to-right evaluation
+                analyze_expressions(engines, vec![exp, &reassgn.rhs], block_name, warnings)
+            }
+            ty::TyReassignmentTarget::ElementAccess { .. } => {
+                analyze_expression(engines, &reassgn.rhs, block_name, warnings)
+            }
+        },
         CodeBlock(codeblock) => analyze_code_block(engines, codeblock, block_name, warnings),
         LazyOperator {
             lhs: left,
@@ -306,7 +315,9 @@ fn analyze_expression(
         | Return(expr)
         | EnumTag { exp: expr }
         | UnsafeDowncast { exp: expr, .. }
-        | AbiCast { address: expr, .. } => analyze_expression(engines, expr, block_name, warnings),
+        | AbiCast { address: expr, .. }
+        | Ref(expr)
+        | Deref(expr) => analyze_expression(engines, expr, block_name, warnings),
         EnumInstantiation { contents, .. } => match contents {
             Some(expr) => analyze_expression(engines, expr, block_name, warnings),
             None => HashSet::new(),
@@ -501,7 +512,15 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
         | Continue
         | AbiName(_) => HashSet::new(),
         // this type of assignment only mutates local variables and not storage
-        Reassignment(reassgn) => effects_of_expression(engines, &reassgn.rhs),
+        Reassignment(reassgn) => match &reassgn.lhs {
+            ty::TyReassignmentTarget::Deref(exp) => set_union(
+                effects_of_expression(engines, exp),
+                effects_of_expression(engines, &reassgn.rhs),
+            ),
+            ty::TyReassignmentTarget::ElementAccess { .. } => {
+                effects_of_expression(engines, &reassgn.rhs)
+            }
+        },
         StorageAccess(_) => match type_engine.get(expr.return_type) {
             // accessing a storage map's method (or a storage vector's method),
             // which is represented using a struct with empty fields
@@ -556,7 +575,9 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
         | TupleElemAccess { prefix: expr, .. }
         | EnumTag { exp: expr }
         | UnsafeDowncast { exp: expr, .. }
-        | Return(expr) => effects_of_expression(engines, expr),
+        | Return(expr)
+        | Ref(expr)
+        | Deref(expr) => effects_of_expression(engines, expr),
         EnumInstantiation { contents, .. } => match contents {
             Some(expr) => effects_of_expression(engines, expr),
             None => HashSet::new(),
@@ -612,8 +633,8 @@ fn effects_of_intrinsic(intr: &sway_ast::Intrinsic) -> HashSet<Effect> {
 
 fn effects_of_asm_op(op: &AsmOp) -> HashSet<Effect> {
     match op.op_name.as_str().to_lowercase().as_str() {
-        "scwq" | "sww" | "swwq" => HashSet::from([Effect::StorageWrite]),
-        "srw" | "srwq" => HashSet::from([Effect::StorageRead]),
+        op_name if is_storage_write_opcode(op_name) => HashSet::from([Effect::StorageWrite]),
+        op_name if is_storage_read_opcode(op_name) => HashSet::from([Effect::StorageRead]),
         "tr" | "tro" => HashSet::from([Effect::BalanceTreeReadWrite]),
         "bal" => HashSet::from([Effect::BalanceTreeRead]),
         "smo" => HashSet::from([Effect::OutputMessage]),
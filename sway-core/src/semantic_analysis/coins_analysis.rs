@@ -64,7 +64,8 @@ pub fn possibly_nonzero_u64_expression(
         | StructFieldAccess { .. }
         | TupleElemAccess { .. }
         | StorageAccess(_)
-        | WhileLoop { .. } => true,
+        | WhileLoop { .. }
+        | Deref(_) => true,
         // The following expression variants are unreachable, because of the type system
         // but we still consider these as non-zero to be on the safe side
         LazyOperator { .. }
@@ -81,6 +82,7 @@ pub fn possibly_nonzero_u64_expression(
         | Break
         | Continue
         | Reassignment(_)
-        | Return(_) => true,
+        | Return(_)
+        | Ref(_) => true,
     }
 }
@@ -68,10 +68,12 @@ impl Root {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn resolve_call_path_and_root_type_id(
         &self,
         handler: &Handler,
         engines: &Engines,
+        mod_path: &Path,
         root_type_id: TypeId,
         mut as_trait: Option<CallPath>,
         call_path: &CallPath,
@@ -86,6 +88,7 @@ impl Root {
                 decl_opt = Some(self.resolve_associated_type_from_type_id(
                     handler,
                     engines,
+                    mod_path,
                     ident,
                     type_id,
                     as_trait.clone(),
@@ -96,6 +99,7 @@ impl Root {
                 decl_opt = Some(self.resolve_associated_type(
                     handler,
                     engines,
+                    mod_path,
                     ident,
                     decl,
                     as_trait.clone(),
@@ -108,6 +112,7 @@ impl Root {
             let decl = self.resolve_associated_type_from_type_id(
                 handler,
                 engines,
+                mod_path,
                 &call_path.suffix,
                 type_id,
                 as_trait,
@@ -119,6 +124,7 @@ impl Root {
             let decl = self.resolve_associated_item(
                 handler,
                 engines,
+                mod_path,
                 &call_path.suffix,
                 decl,
                 as_trait,
@@ -162,9 +168,15 @@ impl Root {
         let mut decl_opt = None;
         for ident in mod_path.iter() {
             if let Some(decl) = decl_opt {
-                decl_opt = Some(
-                    self.resolve_associated_type(handler, engines, ident, decl, None, self_type)?,
-                );
+                decl_opt = Some(self.resolve_associated_type(
+                    handler,
+                    engines,
+                    &current_mod_path,
+                    ident,
+                    decl,
+                    None,
+                    self_type,
+                )?);
             } else {
                 match module.submodules.get(ident.as_str()) {
                     Some(ns) => {
@@ -185,8 +197,15 @@ impl Root {
             }
         }
         if let Some(decl) = decl_opt {
-            let decl =
-                self.resolve_associated_item(handler, engines, symbol, decl, None, self_type)?;
+            let decl = self.resolve_associated_item(
+                handler,
+                engines,
+                &current_mod_path,
+                symbol,
+                decl,
+                None,
+                self_type,
+            )?;
             return Ok((decl, current_mod_path));
         }
 
@@ -197,10 +216,12 @@ impl Root {
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn resolve_associated_type(
         &self,
         handler: &Handler,
         engines: &Engines,
+        mod_path: &Path,
         symbol: &Ident,
         decl: ty::TyDecl,
         as_trait: Option<CallPath>,
@@ -211,6 +232,7 @@ impl Root {
         self.resolve_associated_type_from_type_id(
             handler,
             engines,
+            mod_path,
             symbol,
             engines
                 .te()
@@ -220,10 +242,12 @@ impl Root {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn resolve_associated_item(
         &self,
         handler: &Handler,
         engines: &Engines,
+        mod_path: &Path,
         symbol: &Ident,
         decl: ty::TyDecl,
         as_trait: Option<CallPath>,
@@ -234,6 +258,7 @@ impl Root {
         self.resolve_associated_item_from_type_id(
             handler,
             engines,
+            mod_path,
             symbol,
             engines
                 .te()
@@ -274,17 +299,19 @@ impl Root {
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn resolve_associated_type_from_type_id(
         &self,
         handler: &Handler,
         engines: &Engines,
+        mod_path: &Path,
         symbol: &Ident,
         type_id: TypeId,
         as_trait: Option<CallPath>,
         self_type: Option<TypeId>,
     ) -> Result<ty::TyDecl, ErrorEmitted> {
         let item_decl = self.resolve_associated_item_from_type_id(
-            handler, engines, symbol, type_id, as_trait, self_type,
+            handler, engines, mod_path, symbol, type_id, as_trait, self_type,
         )?;
         if !matches!(item_decl, ty::TyDecl::TraitTypeDecl(_)) {
             return Err(handler.emit_err(CompileError::Internal(
@@ -295,10 +322,12 @@ impl Root {
         Ok(item_decl)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn resolve_associated_item_from_type_id(
         &self,
         handler: &Handler,
         engines: &Engines,
+        mod_path: &Path,
         symbol: &Ident,
         type_id: TypeId,
         as_trait: Option<CallPath>,
@@ -317,6 +346,7 @@ impl Root {
             type_id
         };
         let item_ref = self
+            .check_submodule(handler, mod_path)?
             .implemented_traits
             .get_trait_item_for_type(handler, engines, symbol, type_id, as_trait)?;
         match item_ref {
@@ -610,21 +610,38 @@ impl Dependencies {
             }) => self
                 .gather_from_expr(engines, condition)
                 .gather_from_block(engines, body),
+            ExpressionKind::ForLoop(ForLoopExpression { iterable, body, .. }) => self
+                .gather_from_expr(engines, iterable)
+                .gather_from_block(engines, body),
             ExpressionKind::Reassignment(reassignment) => {
-                self.gather_from_expr(engines, &reassignment.rhs)
+                let deps = match &reassignment.lhs {
+                    ReassignmentTarget::VariableExpression(_) => self,
+                    ReassignmentTarget::Deref(reference) => {
+                        self.gather_from_expr(engines, reference)
+                    }
+                };
+                deps.gather_from_expr(engines, &reassignment.rhs)
             }
             ExpressionKind::Return(expr) => self.gather_from_expr(engines, expr),
+            ExpressionKind::Ref(RefExpression { value, .. }) => {
+                self.gather_from_expr(engines, value)
+            }
+            ExpressionKind::Deref(reference) => self.gather_from_expr(engines, reference),
         }
     }
 
     fn gather_from_match_branch(self, engines: &Engines, branch: &MatchBranch) -> Self {
         let MatchBranch {
-            scrutinee, result, ..
+            scrutinee,
+            guard,
+            result,
+            ..
         } = branch;
         self.gather_from_iter(
             scrutinee.gather_approximate_typeinfo_dependencies().iter(),
             |deps, type_info| deps.gather_from_typeinfo(engines, type_info),
         )
+        .gather_from_opt_expr(engines, guard.as_ref())
         .gather_from_expr(engines, result)
     }
 
@@ -740,6 +757,9 @@ impl Dependencies {
                 |deps, variant| deps.gather_from_type_argument(engines, &variant.type_argument),
             ),
             TypeInfo::Alias { ty, .. } => self.gather_from_type_argument(engines, ty),
+            TypeInfo::Ref {
+                referenced_type, ..
+            } => self.gather_from_type_argument(engines, referenced_type),
             _ => self,
         }
     }
@@ -879,6 +899,13 @@ fn type_info_name(type_info: &TypeInfo) -> String {
             IntegerBits::SixtyFour => "uint64",
             IntegerBits::V256 => "uint256",
         },
+        TypeInfo::SignedInteger(n) => match n {
+            IntegerBits::Eight => "int8",
+            IntegerBits::Sixteen => "int16",
+            IntegerBits::ThirtyTwo => "int32",
+            IntegerBits::SixtyFour => "int64",
+            IntegerBits::V256 => "int256",
+        },
         TypeInfo::Boolean => "bool",
         TypeInfo::Custom {
             qualified_call_path: name,
@@ -905,6 +932,7 @@ fn type_info_name(type_info: &TypeInfo) -> String {
         TypeInfo::RawUntypedSlice => "raw untyped slice",
         TypeInfo::Ptr(..) => "__ptr",
         TypeInfo::Slice(..) => "__slice",
+        TypeInfo::Ref { .. } => "reference",
         TypeInfo::Alias { .. } => "alias",
         TypeInfo::TraitType { .. } => "trait type",
     }
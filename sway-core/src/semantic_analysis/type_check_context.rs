@@ -413,6 +413,7 @@ impl<'a> TypeCheckContext<'a> {
                         .resolve_call_path_and_root_type_id(
                             handler,
                             self.engines,
+                            &self.namespace.mod_path,
                             root_type_id,
                             None,
                             &qualified_call_path.clone().to_call_path(handler)?,
@@ -459,6 +460,34 @@ impl<'a> TypeCheckContext<'a> {
                     elem_ty.span.source_id(),
                 )
             }
+            TypeInfo::Ref {
+                to_mutable_value,
+                mut referenced_type,
+            } => {
+                referenced_type.type_id = self
+                    .resolve(
+                        handler,
+                        referenced_type.type_id,
+                        span,
+                        enforce_type_arguments,
+                        None,
+                        mod_path,
+                    )
+                    .unwrap_or_else(|err| {
+                        self.engines
+                            .te()
+                            .insert(self.engines, TypeInfo::ErrorRecovery(err), None)
+                    });
+
+                self.engines.te().insert(
+                    self.engines,
+                    TypeInfo::Ref {
+                        to_mutable_value,
+                        referenced_type: referenced_type.clone(),
+                    },
+                    referenced_type.span.source_id(),
+                )
+            }
             TypeInfo::Tuple(mut type_arguments) => {
                 for type_argument in type_arguments.iter_mut() {
                     type_argument.type_id = self
@@ -686,6 +715,7 @@ impl<'a> TypeCheckContext<'a> {
             self.namespace.root.resolve_call_path_and_root_type_id(
                 handler,
                 self.engines,
+                &self.namespace.mod_path,
                 root_type_id,
                 as_trait_opt,
                 &qualified_call_path.call_path,
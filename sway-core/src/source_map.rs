@@ -21,6 +21,12 @@ pub struct SourceMap {
     paths: Vec<PathBuf>,
     /// Mapping from opcode index to source location
     map: HashMap<usize, SourceMapSpan>,
+    /// Functions the opcodes were compiled from.
+    #[serde(default)]
+    functions: Vec<SourceMapFunction>,
+    /// Call sites which had functions inlined into them.
+    #[serde(default)]
+    inlined_calls: Vec<SourceMapInlinedCall>,
 }
 impl SourceMap {
     pub fn new() -> Self {
@@ -39,29 +45,56 @@ impl SourceMap {
     }
 
     pub fn insert(&mut self, source_engine: &SourceEngine, pc: usize, span: &Span) {
-        if let Some(source_id) = span.source_id() {
-            let path = source_engine.get_path(source_id);
-            let path_index = self
-                .paths
-                .iter()
-                .position(|p| *p == *path)
-                .unwrap_or_else(|| {
-                    self.paths.push((*path).to_owned());
-                    self.paths.len() - 1
-                });
-            self.map.insert(
-                pc,
-                SourceMapSpan {
-                    path: PathIndex(path_index),
-                    range: LocationRange {
-                        start: span.start(),
-                        end: span.end(),
-                    },
-                },
-            );
+        if let Some(sms) = self.intern_span(source_engine, span) {
+            self.map.insert(pc, sms);
         }
     }
 
+    /// Inserts a function declaration, which opcodes are attributed to by their source location.
+    pub fn insert_function(&mut self, source_engine: &SourceEngine, name: &str, span: &Span) {
+        if let Some(span) = self.intern_span(source_engine, span) {
+            self.functions.push(SourceMapFunction {
+                name: name.to_owned(),
+                span,
+            });
+        }
+    }
+
+    /// Inserts the names of the functions inlined at a call site, outermost first.
+    pub fn insert_inlined_call(
+        &mut self,
+        source_engine: &SourceEngine,
+        span: &Span,
+        inlined: &[String],
+    ) {
+        if let Some(span) = self.intern_span(source_engine, span) {
+            self.inlined_calls.push(SourceMapInlinedCall {
+                span,
+                inlined: inlined.to_vec(),
+            });
+        }
+    }
+
+    fn intern_span(&mut self, source_engine: &SourceEngine, span: &Span) -> Option<SourceMapSpan> {
+        let source_id = span.source_id()?;
+        let path = source_engine.get_path(source_id);
+        let path_index = self
+            .paths
+            .iter()
+            .position(|p| *p == *path)
+            .unwrap_or_else(|| {
+                self.paths.push((*path).to_owned());
+                self.paths.len() - 1
+            });
+        Some(SourceMapSpan {
+            path: PathIndex(path_index),
+            range: LocationRange {
+                start: span.start(),
+                end: span.end(),
+            },
+        })
+    }
+
     /// Inverse source mapping
     pub fn addr_to_span(&self, pc: usize) -> Option<(PathBuf, LocationRange)> {
         self.map.get(&pc).map(|sms| {
@@ -81,16 +114,59 @@ impl SourceMap {
             (p.to_owned(), sms.range)
         })
     }
+
+    /// The names of the functions an opcode was compiled from, outermost first.
+    ///
+    /// That is the innermost function declaration containing the source location of the opcode,
+    /// followed by the functions that were inlined at that location.  Calls which weren't inlined
+    /// can't be recovered statically and aren't part of the result.
+    pub fn addr_to_functions(&self, pc: usize) -> Option<Vec<&str>> {
+        let sms = self.map.get(&pc)?;
+        let function = self
+            .functions
+            .iter()
+            .filter(|function| {
+                function.span.path == sms.path
+                    && function.span.range.start <= sms.range.start
+                    && sms.range.end <= function.span.range.end
+            })
+            .min_by_key(|function| function.span.range.end - function.span.range.start)?;
+        let mut names = vec![function.name.as_str()];
+        if let Some(call) = self.inlined_calls.iter().find(|call| call.span == *sms) {
+            names.extend(call.inlined.iter().map(String::as_str));
+        }
+        Some(names)
+    }
+
+    /// Iterates over every mapped opcode index along with its inverse mapped source location.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, (PathBuf, LocationRange))> + '_ {
+        self.map
+            .keys()
+            .filter_map(|&pc| self.addr_to_span(pc).map(|span| (pc, span)))
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SourceMapSpan {
     pub path: PathIndex,
     pub range: LocationRange,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LocationRange {
     pub start: usize,
     pub end: usize,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceMapFunction {
+    pub name: String,
+    pub span: SourceMapSpan,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceMapInlinedCall {
+    pub span: SourceMapSpan,
+    /// The names of the inlined functions, outermost first.
+    pub inlined: Vec<String>,
+}
@@ -11,10 +11,16 @@ pub struct Context {
     /// Unique suffix used to generate unique names for destructured tuples.
     destructured_tuple_unique_suffix: usize,
 
+    /// Unique suffix used to generate unique names for destructured function parameters.
+    destructured_param_unique_suffix: usize,
+
     /// Unique suffix used to generate unique names for variables
     /// that store values matched in match expressions.
     match_expression_matched_value_unique_suffix: usize,
 
+    /// Unique suffix used to generate unique names for the variables of `for` loops.
+    for_loop_unique_suffix: usize,
+
     /// The build target.
     build_target: BuildTarget,
 
@@ -53,6 +59,12 @@ impl Context {
         self.destructured_tuple_unique_suffix
     }
 
+    /// Returns a unique suffix used to generate a unique name for a destructured function parameter.
+    pub fn next_destructured_param_unique_suffix(&mut self) -> usize {
+        self.destructured_param_unique_suffix += 1;
+        self.destructured_param_unique_suffix
+    }
+
     /// Returns a unique suffix used to generate a unique name for a variable
     /// that stores the value matched in a match expression.
     pub fn next_match_expression_matched_value_unique_suffix(&mut self) -> usize {
@@ -60,6 +72,12 @@ impl Context {
         self.match_expression_matched_value_unique_suffix
     }
 
+    /// Returns a unique suffix used to generate unique names for the variables of a `for` loop.
+    pub fn next_for_loop_unique_suffix(&mut self) -> usize {
+        self.for_loop_unique_suffix += 1;
+        self.for_loop_unique_suffix
+    }
+
     /// Returns the build target.
     pub fn build_target(&self) -> BuildTarget {
         self.build_target
@@ -1,6 +1,8 @@
 use crate::{
     compiler_generated::{
-        generate_destructured_struct_var_name, generate_matched_value_var_name,
+        generate_destructured_param_var_name, generate_destructured_struct_var_name,
+        generate_for_loop_iterable_var_name, generate_for_loop_iterator_var_name,
+        generate_for_loop_next_value_var_name, generate_matched_value_var_name,
         generate_tuple_var_name,
     },
     language::{parsed::*, *},
@@ -21,9 +23,11 @@ use sway_ast::{
     ItemStorage, ItemStruct, ItemTrait, ItemTraitItem, ItemTypeAlias, ItemUse, LitInt, LitIntType,
     MatchBranchKind, Module, ModuleKind, Parens, PathExpr, PathExprSegment, PathType,
     PathTypeSegment, Pattern, PatternStructField, PubToken, Punctuated, QualifiedPathRoot,
-    Statement, StatementLet, Submodule, TraitType, Traits, Ty, TypeField, UseTree, WhereClause,
+    RangeLimits, Statement, StatementLet, Submodule, TraitType, Traits, Ty, TypeField, UseTree,
+    WhereClause,
 };
 use sway_error::convert_parse_tree_error::ConvertParseTreeError;
+use sway_error::error::CompileError;
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_error::warning::{CompileWarning, Warning};
 use sway_types::{
@@ -172,6 +176,14 @@ fn item_to_ast_nodes(
             item_enum_to_enum_declaration(context, handler, engines, item_enum, attributes)?,
         )),
         ItemKind::Fn(item_fn) => {
+            if item_fn.fn_signature.name.as_str() == "main"
+                && matches!(
+                    context.program_type(),
+                    Some(TreeType::Script | TreeType::Predicate)
+                )
+            {
+                error_if_fn_arg_patterns_in_abi(handler, &item_fn.fn_signature)?;
+            }
             let function_declaration = item_fn_to_function_declaration(
                 context, handler, engines, item_fn, attributes, None, None,
             )?;
@@ -496,18 +508,43 @@ fn item_fn_to_function_declaration(
             }
         }
     };
+    let (parameters, destructured_params) = fn_args_to_function_parameters(
+        context,
+        handler,
+        engines,
+        item_fn.fn_signature.arguments.into_inner(),
+    )?;
+    let mut body =
+        braced_code_block_contents_to_code_block(context, handler, engines, item_fn.body)?;
+
+    // destructure the parameters declared with patterns at the start of the body
+    let mut destructurings = vec![];
+    for (name, pattern) in destructured_params {
+        let span = pattern.span();
+        let expression = Expression {
+            kind: ExpressionKind::Variable(name),
+            span: span.clone(),
+        };
+        destructurings.extend(destructuring_to_ast_nodes(
+            context,
+            handler,
+            engines,
+            pattern,
+            None,
+            expression,
+            DestructuringKind::LetOrParam,
+            span,
+        )?);
+    }
+    body.contents.splice(0..0, destructurings);
+
     Ok(FunctionDeclaration {
         purity: get_attributed_purity(context, handler, &attributes)?,
         attributes,
         name: item_fn.fn_signature.name,
         visibility: pub_token_opt_to_visibility(item_fn.fn_signature.visibility),
-        body: braced_code_block_contents_to_code_block(context, handler, engines, item_fn.body)?,
-        parameters: fn_args_to_function_parameters(
-            context,
-            handler,
-            engines,
-            item_fn.fn_signature.arguments.into_inner(),
-        )?,
+        body,
+        parameters,
         span,
         return_type,
         type_parameters: generic_params_opt_to_type_parameters_with_parent(
@@ -672,6 +709,10 @@ fn item_impl_to_declaration(
 ) -> Result<Declaration, ErrorEmitted> {
     let block_span = item_impl.span();
     let implementing_for = ty_to_type_argument(context, handler, engines, item_impl.ty)?;
+    let implements_for_contract = matches!(
+        engines.te().get(implementing_for.type_id),
+        TypeInfo::Contract
+    );
     let items = item_impl
         .contents
         .into_inner()
@@ -682,16 +723,21 @@ fn item_impl_to_declaration(
                 return Ok(None);
             }
             Ok(Some(match item.value {
-                sway_ast::ItemImplItem::Fn(fn_item) => item_fn_to_function_declaration(
-                    context,
-                    handler,
-                    engines,
-                    fn_item,
-                    attributes,
-                    item_impl.generic_params_opt.clone(),
-                    item_impl.where_clause_opt.clone(),
-                )
-                .map(ImplItem::Fn),
+                sway_ast::ItemImplItem::Fn(fn_item) => {
+                    if implements_for_contract {
+                        error_if_fn_arg_patterns_in_abi(handler, &fn_item.fn_signature)?;
+                    }
+                    item_fn_to_function_declaration(
+                        context,
+                        handler,
+                        engines,
+                        fn_item,
+                        attributes,
+                        item_impl.generic_params_opt.clone(),
+                        item_impl.where_clause_opt.clone(),
+                    )
+                    .map(ImplItem::Fn)
+                }
                 sway_ast::ItemImplItem::Const(const_item) => item_const_to_constant_declaration(
                     context, handler, engines, const_item, attributes, true,
                 )
@@ -1261,16 +1307,32 @@ fn braced_code_block_contents_to_code_block(
     })
 }
 
+/// The generated name of a function parameter declared with a destructuring
+/// pattern, together with that pattern.
+type DestructuredParam = (Ident, Pattern);
+
+/// Converts the `fn_args` to function parameters. Parameters declared with a
+/// destructuring pattern are returned together with their generated names, so
+/// that they can be destructured at the start of the function body.
 fn fn_args_to_function_parameters(
     context: &mut Context,
     handler: &Handler,
     engines: &Engines,
     fn_args: FnArgs,
-) -> Result<Vec<FunctionParameter>, ErrorEmitted> {
+) -> Result<(Vec<FunctionParameter>, Vec<DestructuredParam>), ErrorEmitted> {
+    let mut destructured_params = vec![];
+    let mut fn_arg_to_function_parameter = |fn_arg| {
+        let (function_parameter, pattern_opt) =
+            fn_arg_to_function_parameter(context, handler, engines, fn_arg)?;
+        if let Some(pattern) = pattern_opt {
+            destructured_params.push((function_parameter.name.clone(), pattern));
+        }
+        Ok(function_parameter)
+    };
     let function_parameters = match fn_args {
         FnArgs::Static(args) => args
             .into_iter()
-            .map(|fn_arg| fn_arg_to_function_parameter(context, handler, engines, fn_arg))
+            .map(&mut fn_arg_to_function_parameter)
             .collect::<Result<_, _>>()?,
         FnArgs::NonStatic {
             self_token,
@@ -1303,8 +1365,7 @@ fn fn_args_to_function_parameters(
             }];
             if let Some((_comma_token, args)) = args_opt {
                 for arg in args {
-                    let function_parameter =
-                        fn_arg_to_function_parameter(context, handler, engines, arg)?;
+                    let function_parameter = fn_arg_to_function_parameter(arg)?;
                     function_parameters.push(function_parameter);
                 }
             }
@@ -1312,19 +1373,35 @@ fn fn_args_to_function_parameters(
         }
     };
 
-    let mut unique_params = HashSet::<Ident>::default();
+    // the variables declared in the destructuring patterns are parameters as well
+    let mut param_names = vec![];
     for fn_param in &function_parameters {
-        let already_used = !unique_params.insert(fn_param.name.clone());
+        match destructured_params
+            .iter()
+            .find(|(name, _)| *name == fn_param.name)
+        {
+            Some((_, pattern)) => {
+                let mut variables = vec![];
+                pattern_variables(pattern, &mut variables);
+                param_names.extend(variables.into_iter().map(|(name, _)| name));
+            }
+            None => param_names.push(fn_param.name.clone()),
+        }
+    }
+
+    let mut unique_params = HashSet::<Ident>::default();
+    for name in param_names {
+        let already_used = !unique_params.insert(name.clone());
         if already_used {
             let error = ConvertParseTreeError::DuplicateParameterIdentifier {
-                name: fn_param.name.clone(),
-                span: fn_param.name.span(),
+                span: name.span(),
+                name,
             };
             return Err(handler.emit_err(error.into()));
         }
     }
 
-    Ok(function_parameters)
+    Ok((function_parameters, destructured_params))
 }
 
 pub(crate) fn type_name_to_type_info_opt(name: &Ident) -> Option<TypeInfo> {
@@ -1334,6 +1411,10 @@ pub(crate) fn type_name_to_type_info_opt(name: &Ident) -> Option<TypeInfo> {
         "u32" => Some(TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo)),
         "u64" => Some(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
         "u256" => Some(TypeInfo::UnsignedInteger(IntegerBits::V256)),
+        "i8" => Some(TypeInfo::SignedInteger(IntegerBits::Eight)),
+        "i16" => Some(TypeInfo::SignedInteger(IntegerBits::Sixteen)),
+        "i32" => Some(TypeInfo::SignedInteger(IntegerBits::ThirtyTwo)),
+        "i64" => Some(TypeInfo::SignedInteger(IntegerBits::SixtyFour)),
         "bool" => Some(TypeInfo::Boolean),
         "unit" => Some(TypeInfo::Tuple(Vec::new())),
         "b256" => Some(TypeInfo::B256),
@@ -1382,6 +1463,10 @@ fn ty_to_type_info(
             let type_argument = ty_to_type_argument(context, handler, engines, *ty.into_inner())?;
             TypeInfo::Slice(type_argument)
         }
+        Ty::Ref { mut_token, ty, .. } => TypeInfo::Ref {
+            to_mutable_value: mut_token.is_some(),
+            referenced_type: ty_to_type_argument(context, handler, engines, *ty)?,
+        },
     };
     Ok(type_info)
 }
@@ -1499,17 +1584,27 @@ fn fn_signature_to_trait_fn(
         }
     };
 
+    let (parameters, destructured_params) = fn_args_to_function_parameters(
+        context,
+        handler,
+        engines,
+        fn_signature.arguments.clone().into_inner(),
+    )?;
+    // without a body, there is nothing the parameters could be destructured in
+    let errors = destructured_params
+        .into_iter()
+        .map(|(_, pattern)| fn_arg_pattern_not_supported_here(pattern))
+        .collect();
+    if let Some(errors) = emit_all(handler, errors) {
+        return Err(errors);
+    }
+
     let trait_fn = TraitFn {
         name: fn_signature.name.clone(),
         span: fn_signature.span(),
         purity: get_attributed_purity(context, handler, &attributes)?,
         attributes,
-        parameters: fn_args_to_function_parameters(
-            context,
-            handler,
-            engines,
-            fn_signature.arguments.into_inner(),
-        )?,
+        parameters,
         return_type,
     };
     Ok(trait_fn)
@@ -1986,6 +2081,22 @@ fn expr_to_expression(
             }),
             span,
         },
+        Expr::For {
+            value_pattern,
+            iterator,
+            block,
+            ..
+        } => Expression {
+            kind: for_loop_to_expression_kind(
+                context,
+                handler,
+                engines,
+                value_pattern,
+                *iterator,
+                block,
+            )?,
+            span,
+        },
         Expr::FuncApp { func, args } => {
             let kind = expr_func_app_to_expression_kind(context, handler, engines, func, args)?;
             Expression { kind, span }
@@ -2081,22 +2192,49 @@ fn expr_to_expression(
             }),
             span,
         },
-        Expr::Ref { ref_token, .. } => {
-            let error = ConvertParseTreeError::RefExprNotYetSupported {
-                span: ref_token.span(),
-            };
-            return Err(handler.emit_err(error.into()));
-        }
-        Expr::Deref { deref_token, .. } => {
-            let error = ConvertParseTreeError::DerefExprNotYetSupported {
-                span: deref_token.span(),
-            };
-            return Err(handler.emit_err(error.into()));
-        }
+        Expr::Ref {
+            mut_token, expr, ..
+        } => Expression {
+            kind: ExpressionKind::Ref(RefExpression {
+                to_mutable_value: mut_token.is_some(),
+                value: Box::new(expr_to_expression(context, handler, engines, *expr)?),
+            }),
+            span,
+        },
+        Expr::Deref { expr, .. } => Expression {
+            kind: ExpressionKind::Deref(Box::new(expr_to_expression(
+                context, handler, engines, *expr,
+            )?)),
+            span,
+        },
         Expr::Not { bang_token, expr } => {
             let expr = expr_to_expression(context, handler, engines, *expr)?;
             op_call("not", bang_token.span(), span, &[expr])?
         }
+        Expr::Neg { sub_token, expr } => match *expr {
+            Expr::Literal(sway_ast::Literal::Int(lit_int))
+                if matches!(
+                    lit_int.ty_opt,
+                    None | Some((
+                        LitIntType::I8 | LitIntType::I16 | LitIntType::I32 | LitIntType::I64,
+                        _
+                    ))
+                ) =>
+            {
+                Expression {
+                    kind: ExpressionKind::Literal(negative_int_literal_to_literal(
+                        handler,
+                        lit_int,
+                        span.clone(),
+                    )?),
+                    span,
+                }
+            }
+            expr => {
+                let expr = expr_to_expression(context, handler, engines, expr)?;
+                op_call("neg", sub_token.span(), span, &[expr])?
+            }
+        },
         Expr::Pow {
             lhs,
             double_star_token,
@@ -2266,6 +2404,10 @@ fn expr_to_expression(
             }),
             span,
         },
+        Expr::Range { start, limits, end } => Expression {
+            kind: range_to_expression_kind(context, handler, engines, *start, limits, *end)?,
+            span,
+        },
         Expr::Reassignment {
             assignable,
             expr,
@@ -2316,6 +2458,184 @@ fn expr_to_expression(
     Ok(expression)
 }
 
+/// Lowers a `for` loop to a [ForLoopExpression], whose `while` loop body gets
+/// the next value from the iterator, breaks out of the loop if there is none,
+/// and destructures the value into the `pattern` before running the `block`.
+fn for_loop_to_expression_kind(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    pattern: Pattern,
+    iterator: Expr,
+    block: Braces<CodeBlockContents>,
+) -> Result<ExpressionKind, ErrorEmitted> {
+    let iterable_span = iterator.span();
+    let pattern_span = pattern.span();
+
+    let suffix = context.next_for_loop_unique_suffix();
+    let iterable_name = Ident::new_with_override(
+        generate_for_loop_iterable_var_name(suffix),
+        iterable_span.clone(),
+    );
+    let iterator_name = Ident::new_with_override(
+        generate_for_loop_iterator_var_name(suffix),
+        iterable_span.clone(),
+    );
+    let next_value_name = Ident::new_with_override(
+        generate_for_loop_next_value_var_name(suffix),
+        pattern_span.clone(),
+    );
+
+    let iterable = expr_to_expression(context, handler, engines, iterator)?;
+    let body = braced_code_block_contents_to_code_block(context, handler, engines, block)?;
+
+    let method_call = |target: &Ident, method_name: &str| Expression {
+        kind: ExpressionKind::MethodApplication(Box::new(MethodApplicationExpression {
+            method_name_binding: TypeBinding {
+                inner: MethodName::FromModule {
+                    method_name: Ident::new_with_override(method_name.into(), target.span()),
+                },
+                type_arguments: TypeArgs::Regular(vec![]),
+                span: target.span(),
+            },
+            contract_call_params: vec![],
+            arguments: vec![Expression {
+                kind: ExpressionKind::Variable(target.clone()),
+                span: target.span(),
+            }],
+        })),
+        span: target.span(),
+    };
+
+    let mut contents = vec![];
+
+    // let __for_next_value_1 = __for_iterator_1.next();
+    let type_id = engines.te().insert(engines, TypeInfo::Unknown, None);
+    contents.push(AstNode {
+        content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+            VariableDeclaration {
+                name: next_value_name.clone(),
+                type_ascription: TypeArgument {
+                    type_id,
+                    initial_type_id: type_id,
+                    span: next_value_name.span(),
+                    call_path_tree: None,
+                },
+                body: method_call(&iterator_name, "next"),
+                is_mutable: false,
+            },
+        )),
+        span: pattern_span.clone(),
+    });
+
+    // if __for_next_value_1.is_none() { break; }
+    let break_expression = Expression {
+        kind: ExpressionKind::Break,
+        span: pattern_span.clone(),
+    };
+    let break_block = Expression {
+        kind: ExpressionKind::CodeBlock(CodeBlock {
+            contents: vec![AstNode {
+                content: AstNodeContent::Expression(break_expression),
+                span: pattern_span.clone(),
+            }],
+            whole_block_span: pattern_span.clone(),
+        }),
+        span: pattern_span.clone(),
+    };
+    contents.push(AstNode {
+        content: AstNodeContent::Expression(Expression {
+            kind: ExpressionKind::If(IfExpression {
+                condition: Box::new(method_call(&next_value_name, "is_none")),
+                then: Box::new(break_block),
+                r#else: None,
+            }),
+            span: pattern_span.clone(),
+        }),
+        span: pattern_span.clone(),
+    });
+
+    // let <pattern> = __for_next_value_1.unwrap();
+    contents.extend(destructuring_to_ast_nodes(
+        context,
+        handler,
+        engines,
+        pattern,
+        None,
+        method_call(&next_value_name, "unwrap"),
+        DestructuringKind::ForLoop,
+        pattern_span,
+    )?);
+
+    // <body>
+    // The contents are inlined rather than nested in a block, so that an
+    // implicit return in the body is reported like one in a `while` loop.
+    contents.extend(body.contents);
+
+    Ok(ExpressionKind::ForLoop(ForLoopExpression {
+        iterable: Box::new(iterable),
+        iterable_name,
+        iterator_name,
+        body: CodeBlock {
+            contents,
+            whole_block_span: body.whole_block_span,
+        },
+    }))
+}
+
+/// Lowers a range expression to an instance of `core::range::Range`, for `start..end`,
+/// or of `core::range::RangeInclusive`, for `start..=end`.
+fn range_to_expression_kind(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    start: Expr,
+    limits: RangeLimits,
+    end: Expr,
+) -> Result<ExpressionKind, ErrorEmitted> {
+    let limits_span = limits.span();
+    let field = |name: &str, value: Expression| StructExpressionField {
+        name: Ident::new_with_override(name.into(), limits_span.clone()),
+        span: value.span.clone(),
+        value,
+    };
+
+    let mut fields = vec![
+        field(
+            "start",
+            expr_to_expression(context, handler, engines, start)?,
+        ),
+        field("end", expr_to_expression(context, handler, engines, end)?),
+    ];
+    let struct_name = match limits {
+        RangeLimits::HalfOpen(_) => "Range",
+        RangeLimits::Closed(_) => {
+            let exhausted = Expression {
+                kind: ExpressionKind::Literal(Literal::Boolean(false)),
+                span: limits_span.clone(),
+            };
+            fields.push(field("exhausted", exhausted));
+            "RangeInclusive"
+        }
+    };
+
+    Ok(ExpressionKind::Struct(Box::new(StructExpression {
+        call_path_binding: TypeBinding {
+            inner: CallPath {
+                prefixes: vec![
+                    Ident::new_with_override("core".into(), limits_span.clone()),
+                    Ident::new_with_override("range".into(), limits_span.clone()),
+                ],
+                suffix: Ident::new_with_override(struct_name.into(), limits_span.clone()),
+                is_absolute: true,
+            },
+            type_arguments: TypeArgs::Regular(vec![]),
+            span: limits_span,
+        },
+        fields,
+    })))
+}
+
 fn op_call(
     name: &'static str,
     op_span: Span,
@@ -2431,43 +2751,23 @@ fn fn_arg_to_function_parameter(
     handler: &Handler,
     engines: &Engines,
     fn_arg: FnArg,
-) -> Result<FunctionParameter, ErrorEmitted> {
-    let pat_span = fn_arg.pattern.span();
-    let (reference, mutable, name) = match fn_arg.pattern {
-        Pattern::Wildcard { .. } => {
-            let error = ConvertParseTreeError::WildcardPatternsNotSupportedHere { span: pat_span };
-            return Err(handler.emit_err(error.into()));
-        }
-        Pattern::Or { .. } => {
-            let error = ConvertParseTreeError::OrPatternsNotSupportedHere { span: pat_span };
-            return Err(handler.emit_err(error.into()));
-        }
+) -> Result<(FunctionParameter, Option<Pattern>), ErrorEmitted> {
+    let (reference, mutable, name, destructured_pattern) = match fn_arg.pattern {
         Pattern::Var {
             reference,
             mutable,
             name,
-        } => (reference, mutable, name),
-        Pattern::AmbiguousSingleIdent(ident) => (None, None, ident),
-        Pattern::Literal(..) => {
-            let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span: pat_span };
-            return Err(handler.emit_err(error.into()));
-        }
-        Pattern::Constant(..) => {
-            let error = ConvertParseTreeError::ConstantPatternsNotSupportedHere { span: pat_span };
-            return Err(handler.emit_err(error.into()));
-        }
-        Pattern::Constructor { .. } | Pattern::Error(..) => {
-            let error =
-                ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span: pat_span };
-            return Err(handler.emit_err(error.into()));
-        }
-        Pattern::Struct { .. } => {
-            let error = ConvertParseTreeError::StructPatternsNotSupportedHere { span: pat_span };
-            return Err(handler.emit_err(error.into()));
-        }
-        Pattern::Tuple(..) => {
-            let error = ConvertParseTreeError::TuplePatternsNotSupportedHere { span: pat_span };
-            return Err(handler.emit_err(error.into()));
+        } => (reference, mutable, name, None),
+        Pattern::AmbiguousSingleIdent(ident) => (None, None, ident, None),
+        pattern => {
+            // the parameter gets a generated name and is destructured at the start of the body
+            let name = Ident::new_with_override(
+                generate_destructured_param_var_name(
+                    context.next_destructured_param_unique_suffix(),
+                ),
+                pattern.span(),
+            );
+            (None, None, name, Some(pattern))
         }
     };
     let mutability_span = match (&reference, &mutable) {
@@ -2483,7 +2783,58 @@ fn fn_arg_to_function_parameter(
         mutability_span,
         type_argument: ty_to_type_argument(context, handler, engines, fn_arg.ty)?,
     };
-    Ok(function_parameter)
+    Ok((function_parameter, destructured_pattern))
+}
+
+/// Emits errors for the destructuring patterns in the parameters of a function
+/// whose parameter names are part of the program ABI, i.e., `main()` of a script
+/// or a predicate and the methods implemented for `Contract`.
+fn error_if_fn_arg_patterns_in_abi(
+    handler: &Handler,
+    fn_signature: &FnSignature,
+) -> Result<(), ErrorEmitted> {
+    let args = match fn_signature.arguments.clone().into_inner() {
+        FnArgs::Static(args) => args.into_iter().collect::<Vec<_>>(),
+        FnArgs::NonStatic { args_opt, .. } => args_opt
+            .map(|(_comma_token, args)| args.into_iter().collect())
+            .unwrap_or_default(),
+    };
+    let errors = args
+        .into_iter()
+        .filter_map(|fn_arg| match fn_arg.pattern {
+            Pattern::Var { .. } | Pattern::AmbiguousSingleIdent(..) => None,
+            pattern => Some(fn_arg_pattern_not_supported_here(pattern)),
+        })
+        .collect();
+    match emit_all(handler, errors) {
+        Some(errors) => Err(errors),
+        None => Ok(()),
+    }
+}
+
+/// Returns the error for a destructuring `pattern` in a parameter of a function
+/// that has no body, e.g., in a trait or an ABI declaration, or whose parameter
+/// names are part of the program ABI.
+fn fn_arg_pattern_not_supported_here(pattern: Pattern) -> ConvertParseTreeError {
+    let span = pattern.span();
+    match pattern {
+        Pattern::Wildcard { .. } => {
+            ConvertParseTreeError::WildcardPatternsNotSupportedHere { span }
+        }
+        Pattern::Or { .. } => ConvertParseTreeError::OrPatternsNotSupportedHere { span },
+        Pattern::Literal(..) | Pattern::NegativeLiteral { .. } | Pattern::Range { .. } => {
+            ConvertParseTreeError::LiteralPatternsNotSupportedHere { span }
+        }
+        Pattern::Constant(..) => ConvertParseTreeError::ConstantPatternsNotSupportedHere { span },
+        Pattern::Constructor { .. } | Pattern::Error(..) => {
+            ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span }
+        }
+        Pattern::Struct { .. } => ConvertParseTreeError::StructPatternsNotSupportedHere { span },
+        Pattern::Tuple(..) => ConvertParseTreeError::TuplePatternsNotSupportedHere { span },
+        Pattern::Var { .. } | Pattern::AmbiguousSingleIdent(..) => {
+            unreachable!("variable patterns are never destructured")
+        }
+    }
 }
 
 fn expr_to_length(
@@ -2720,6 +3071,7 @@ fn if_expr_to_expression(
             let scrutinee_span = scrutinee.span();
             let mut branches = vec![MatchBranch {
                 scrutinee,
+                guard: None,
                 result: then_block.clone(),
                 span: Span::join(scrutinee_span, then_block_span),
             }];
@@ -2730,6 +3082,7 @@ fn if_expr_to_expression(
                         scrutinee: Scrutinee::CatchAll {
                             span: else_block_span.clone(),
                         },
+                        guard: None,
                         result: else_block,
                         span: else_block_span,
                     }
@@ -2740,6 +3093,7 @@ fn if_expr_to_expression(
                         scrutinee: Scrutinee::CatchAll {
                             span: else_block_span.clone(),
                         },
+                        guard: None,
                         // If there's no else in an `if-let` expression,
                         // then the else is equivalent to an empty block.
                         result: Expression {
@@ -2810,6 +3164,7 @@ fn match_expr_to_expression(
                         kind: ExpressionKind::Match(MatchExpression {
                             value: Box::new(var_decl_exp),
                             branches,
+                            destructuring: None,
                         }),
                         span: span.clone(),
                     }),
@@ -2992,9 +3347,45 @@ fn literal_to_literal(
                         Literal::U64(value)
                     }
                     LitIntType::U256 => Literal::U256(parsed.into()),
-                    LitIntType::I8 | LitIntType::I16 | LitIntType::I32 | LitIntType::I64 => {
-                        let error = ConvertParseTreeError::SignedIntegersNotSupported { span };
-                        return Err(handler.emit_err(error.into()));
+                    LitIntType::I8 => {
+                        let value = match i8::try_from(parsed) {
+                            Ok(value) => value,
+                            Err(..) => {
+                                let error = ConvertParseTreeError::I8LiteralOutOfRange { span };
+                                return Err(handler.emit_err(error.into()));
+                            }
+                        };
+                        Literal::I8(value)
+                    }
+                    LitIntType::I16 => {
+                        let value = match i16::try_from(parsed) {
+                            Ok(value) => value,
+                            Err(..) => {
+                                let error = ConvertParseTreeError::I16LiteralOutOfRange { span };
+                                return Err(handler.emit_err(error.into()));
+                            }
+                        };
+                        Literal::I16(value)
+                    }
+                    LitIntType::I32 => {
+                        let value = match i32::try_from(parsed) {
+                            Ok(value) => value,
+                            Err(..) => {
+                                let error = ConvertParseTreeError::I32LiteralOutOfRange { span };
+                                return Err(handler.emit_err(error.into()));
+                            }
+                        };
+                        Literal::I32(value)
+                    }
+                    LitIntType::I64 => {
+                        let value = match i64::try_from(parsed) {
+                            Ok(value) => value,
+                            Err(..) => {
+                                let error = ConvertParseTreeError::I64LiteralOutOfRange { span };
+                                return Err(handler.emit_err(error.into()));
+                            }
+                        };
+                        Literal::I64(value)
                     }
                 },
             }
@@ -3003,6 +3394,92 @@ fn literal_to_literal(
     Ok(literal)
 }
 
+/// Converts the integer literal of a negation `-literal` into a negative [Literal].
+/// Unsuffixed literals become [Literal::NegativeNumeric] and get their type
+/// during type checking, just like [Literal::Numeric].
+fn range_pattern_bound_to_literal(
+    context: &mut Context,
+    handler: &Handler,
+    pattern: Pattern,
+) -> Result<Literal, ErrorEmitted> {
+    let span = pattern.span();
+    match pattern {
+        Pattern::Literal(literal) => literal_to_literal(context, handler, literal),
+        Pattern::NegativeLiteral { literal, .. } => {
+            negative_int_literal_to_literal(handler, literal, span)
+        }
+        _ => Err(handler.emit_err(CompileError::Internal(
+            "range pattern bounds must be integer literals",
+            span,
+        ))),
+    }
+}
+
+fn negative_int_literal_to_literal(
+    handler: &Handler,
+    lit_int: LitInt,
+    span: Span,
+) -> Result<Literal, ErrorEmitted> {
+    let out_of_range = |error: ConvertParseTreeError| handler.emit_err(error.into());
+    let value = match u64::try_from(&lit_int.parsed) {
+        Ok(magnitude) => -i128::from(magnitude),
+        Err(..) => i128::MIN,
+    };
+    let literal = match lit_int.ty_opt {
+        None => match i64::try_from(value) {
+            Ok(0) => Literal::Numeric(0),
+            Ok(value) => Literal::NegativeNumeric(value),
+            Err(..) => {
+                return Err(out_of_range(ConvertParseTreeError::IntLiteralOutOfRange {
+                    span,
+                }))
+            }
+        },
+        Some((LitIntType::I8, _)) => Literal::I8(
+            i8::try_from(value)
+                .map_err(|_| out_of_range(ConvertParseTreeError::I8LiteralOutOfRange { span }))?,
+        ),
+        Some((LitIntType::I16, _)) => Literal::I16(
+            i16::try_from(value)
+                .map_err(|_| out_of_range(ConvertParseTreeError::I16LiteralOutOfRange { span }))?,
+        ),
+        Some((LitIntType::I32, _)) => Literal::I32(
+            i32::try_from(value)
+                .map_err(|_| out_of_range(ConvertParseTreeError::I32LiteralOutOfRange { span }))?,
+        ),
+        Some((LitIntType::I64, _)) => Literal::I64(
+            i64::try_from(value)
+                .map_err(|_| out_of_range(ConvertParseTreeError::I64LiteralOutOfRange { span }))?,
+        ),
+        Some((LitIntType::U8, _)) => {
+            return Err(out_of_range(ConvertParseTreeError::U8LiteralOutOfRange {
+                span,
+            }))
+        }
+        Some((LitIntType::U16, _)) => {
+            return Err(out_of_range(ConvertParseTreeError::U16LiteralOutOfRange {
+                span,
+            }))
+        }
+        Some((LitIntType::U32, _)) => {
+            return Err(out_of_range(ConvertParseTreeError::U32LiteralOutOfRange {
+                span,
+            }))
+        }
+        Some((LitIntType::U64, _)) => {
+            return Err(out_of_range(ConvertParseTreeError::U64LiteralOutOfRange {
+                span,
+            }))
+        }
+        Some((LitIntType::U256, _)) => {
+            return Err(out_of_range(ConvertParseTreeError::IntLiteralOutOfRange {
+                span,
+            }))
+        }
+    };
+    Ok(literal)
+}
+
 /// Like [path_expr_to_call_path], but instead can potentially return type arguments.
 /// Use this when converting a call path that could potentially include type arguments, i.e. the
 /// turbofish.
@@ -3258,6 +3735,12 @@ fn match_branch_to_match_branch(
     let span = match_branch.span();
     Ok(MatchBranch {
         scrutinee: pattern_to_scrutinee(context, handler, match_branch.pattern)?,
+        guard: match match_branch.guard_opt {
+            Some((_if_token, guard)) => {
+                Some(expr_to_expression(context, handler, engines, *guard)?)
+            }
+            None => None,
+        },
         result: match match_branch.kind {
             MatchBranchKind::Block { block, .. } => {
                 let span = block.span();
@@ -3282,6 +3765,34 @@ fn statement_let_to_ast_nodes(
     engines: &Engines,
     statement_let: StatementLet,
 ) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let span = statement_let.span();
+    let initial_expression = expr_to_expression(context, handler, engines, statement_let.expr)?;
+    destructuring_to_ast_nodes(
+        context,
+        handler,
+        engines,
+        statement_let.pattern,
+        statement_let.ty_opt.map(|(_colon_token, ty)| ty),
+        initial_expression,
+        DestructuringKind::LetOrParam,
+        span,
+    )
+}
+
+/// Lowers the destructuring of `expression` into `pattern` to variable
+/// declarations, as in `let <pattern>: <ty_opt> = <expression>;`.
+#[allow(clippy::too_many_arguments)]
+fn destructuring_to_ast_nodes(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    pattern: Pattern,
+    ty_opt: Option<Ty>,
+    expression: Expression,
+    kind: DestructuringKind,
+    span: Span,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    #[allow(clippy::too_many_arguments)]
     fn unfold(
         context: &mut Context,
         handler: &Handler,
@@ -3289,6 +3800,7 @@ fn statement_let_to_ast_nodes(
         pattern: Pattern,
         ty_opt: Option<Ty>,
         expression: Expression,
+        kind: DestructuringKind,
         span: Span,
     ) -> Result<Vec<AstNode>, ErrorEmitted> {
         let ast_nodes = match pattern {
@@ -3332,18 +3844,15 @@ fn statement_let_to_ast_nodes(
                 };
                 vec![ast_node]
             }
-            Pattern::Literal(..) => {
-                let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span };
-                return Err(handler.emit_err(error.into()));
-            }
-            Pattern::Constant(..) => {
-                let error = ConvertParseTreeError::ConstantPatternsNotSupportedHere { span };
-                return Err(handler.emit_err(error.into()));
-            }
-            Pattern::Constructor { .. } | Pattern::Error(..) => {
-                let error = ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span };
-                return Err(handler.emit_err(error.into()));
-            }
+            Pattern::Literal(..)
+            | Pattern::NegativeLiteral { .. }
+            | Pattern::Range { .. }
+            | Pattern::Constant(..)
+            | Pattern::Constructor { .. }
+            | Pattern::Or { .. }
+            | Pattern::Error(..) => match_destructuring_to_ast_nodes(
+                context, handler, engines, pattern, ty_opt, expression, kind, span,
+            )?,
             Pattern::Struct { path, fields, .. } => {
                 let mut ast_nodes = Vec::new();
 
@@ -3429,15 +3938,12 @@ fn statement_let_to_ast_nodes(
                             }),
                             span: span.clone(),
                         },
+                        kind,
                         span.clone(),
                     )?);
                 }
                 ast_nodes
             }
-            Pattern::Or { .. } => {
-                let error = ConvertParseTreeError::OrPatternsNotSupportedHere { span };
-                return Err(handler.emit_err(error.into()));
-            }
             Pattern::Tuple(pat_tuple) => {
                 let mut ast_nodes = Vec::new();
 
@@ -3578,6 +4084,7 @@ fn statement_let_to_ast_nodes(
                             }),
                             span: span.clone(),
                         },
+                        kind,
                         span.clone(),
                     )?);
                 }
@@ -3586,19 +4093,184 @@ fn statement_let_to_ast_nodes(
         };
         Ok(ast_nodes)
     }
-    let span = statement_let.span();
-    let initial_expression = expr_to_expression(context, handler, engines, statement_let.expr)?;
     unfold(
-        context,
-        handler,
-        engines,
-        statement_let.pattern,
-        statement_let.ty_opt.map(|(_colon_token, ty)| ty),
-        initial_expression,
-        span,
+        context, handler, engines, pattern, ty_opt, expression, kind, span,
     )
 }
 
+/// Lowers the destructuring of `expression` into a `pattern` that might be
+/// refutable, e.g., because it contains enum constructors or literals, to a
+/// single-arm match expression that returns the values of the variables
+/// declared in the `pattern`:
+///
+/// ```ignore
+/// let __matched_value_1: <ty_opt> = <expression>;
+/// let __tuple_1 = match __matched_value_1 {
+///     <pattern> => (<var_1>, ..., <var_n>),
+/// };
+/// let <var_1> = __tuple_1.0;
+/// ...
+/// let <var_n> = __tuple_1.<n - 1>;
+/// ```
+///
+/// The match expression is marked as destructuring, so that the usefulness
+/// analysis reports a refutable `pattern` as an error.
+#[allow(clippy::too_many_arguments)]
+fn match_destructuring_to_ast_nodes(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    pattern: Pattern,
+    ty_opt: Option<Ty>,
+    expression: Expression,
+    kind: DestructuringKind,
+    span: Span,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let pattern_span = pattern.span();
+    let unknown_type_argument = |span: Span| {
+        let type_id = engines.te().insert(engines, TypeInfo::Unknown, None);
+        TypeArgument {
+            type_id,
+            initial_type_id: type_id,
+            span,
+            call_path_tree: None,
+        }
+    };
+    let var_decl = |name: Ident, type_ascription, body, is_mutable| AstNode {
+        content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+            VariableDeclaration {
+                name,
+                type_ascription,
+                body,
+                is_mutable,
+            },
+        )),
+        span: span.clone(),
+    };
+    let var_exp = |name: Ident| Expression {
+        kind: ExpressionKind::Variable(name),
+        span: pattern_span.clone(),
+    };
+
+    let mut ast_nodes = vec![];
+
+    // Store the matched value, so that it is evaluated only once.
+    let matched_value_name = Ident::new_with_override(
+        generate_matched_value_var_name(
+            context.next_match_expression_matched_value_unique_suffix(),
+        ),
+        pattern_span.clone(),
+    );
+    let type_ascription = match ty_opt {
+        Some(ty) => ty_to_type_argument(context, handler, engines, ty)?,
+        None => unknown_type_argument(matched_value_name.span()),
+    };
+    ast_nodes.push(var_decl(
+        matched_value_name.clone(),
+        type_ascription,
+        expression,
+        false,
+    ));
+
+    let mut variables = vec![];
+    pattern_variables(&pattern, &mut variables);
+
+    let result = Expression {
+        kind: ExpressionKind::Tuple(
+            variables
+                .iter()
+                .map(|(name, _)| var_exp(name.clone()))
+                .collect(),
+        ),
+        span: pattern_span.clone(),
+    };
+    let match_exp = Expression {
+        kind: ExpressionKind::Match(MatchExpression {
+            value: Box::new(var_exp(matched_value_name)),
+            branches: vec![MatchBranch {
+                scrutinee: pattern_to_scrutinee(context, handler, pattern)?,
+                guard: None,
+                result,
+                span: pattern_span.clone(),
+            }],
+            destructuring: Some(kind),
+        }),
+        span: pattern_span.clone(),
+    };
+
+    // Extract the values of the variables from the tuple returned by the match expression.
+    let tuple_name = Ident::new_with_override(
+        generate_tuple_var_name(context.next_destructured_tuple_unique_suffix()),
+        pattern_span.clone(),
+    );
+    ast_nodes.push(var_decl(
+        tuple_name.clone(),
+        unknown_type_argument(tuple_name.span()),
+        match_exp,
+        false,
+    ));
+    for (index, (name, is_mutable)) in variables.into_iter().enumerate() {
+        let body = Expression {
+            kind: ExpressionKind::TupleIndex(TupleIndexExpression {
+                prefix: Box::new(var_exp(tuple_name.clone())),
+                index,
+                index_span: name.span(),
+            }),
+            span: name.span(),
+        };
+        ast_nodes.push(var_decl(
+            name.clone(),
+            unknown_type_argument(name.span()),
+            body,
+            is_mutable,
+        ));
+    }
+    Ok(ast_nodes)
+}
+
+/// Collects the variables declared in the `pattern`, together with their
+/// mutability, in the order of their appearance. Since all the alternatives
+/// of an or-pattern declare the same variables, only the variables of the
+/// first alternative are collected.
+fn pattern_variables(pattern: &Pattern, variables: &mut Vec<(Ident, bool)>) {
+    match pattern {
+        Pattern::Var { mutable, name, .. } => variables.push((name.clone(), mutable.is_some())),
+        Pattern::AmbiguousSingleIdent(ident) => variables.push((ident.clone(), false)),
+        Pattern::Or { lhs, .. } => pattern_variables(lhs, variables),
+        Pattern::Constructor { args, .. } => {
+            for arg in args.get() {
+                pattern_variables(arg, variables);
+            }
+        }
+        Pattern::Struct { fields, .. } => {
+            for field in fields.get() {
+                match field {
+                    PatternStructField::Field {
+                        pattern_opt: Some((_colon_token, pattern)),
+                        ..
+                    } => pattern_variables(pattern, variables),
+                    PatternStructField::Field {
+                        field_name,
+                        pattern_opt: None,
+                    } => variables.push((field_name.clone(), false)),
+                    PatternStructField::Rest { .. } => (),
+                }
+            }
+        }
+        Pattern::Tuple(pat_tuple) => {
+            for pattern in pat_tuple.get() {
+                pattern_variables(pattern, variables);
+            }
+        }
+        Pattern::Wildcard { .. }
+        | Pattern::Literal(..)
+        | Pattern::NegativeLiteral { .. }
+        | Pattern::Range { .. }
+        | Pattern::Constant(..)
+        | Pattern::Error(..) => (),
+    }
+}
+
 fn submodule_to_include_statement(dependency: &Submodule) -> IncludeStatement {
     IncludeStatement {
         span: dependency.span(),
@@ -3698,6 +4370,16 @@ fn pattern_to_scrutinee(
             value: literal_to_literal(context, handler, literal)?,
             span,
         },
+        Pattern::NegativeLiteral { literal, .. } => Scrutinee::Literal {
+            value: negative_int_literal_to_literal(handler, literal, span.clone())?,
+            span,
+        },
+        Pattern::Range { start, limits, end } => Scrutinee::Range {
+            start: range_pattern_bound_to_literal(context, handler, *start)?,
+            end: range_pattern_bound_to_literal(context, handler, *end)?,
+            is_inclusive: matches!(limits, RangeLimits::Closed(_)),
+            span,
+        },
         Pattern::Constant(path_expr) => {
             let call_path = path_expr_to_call_path(context, handler, path_expr)?;
             let call_path_span = call_path.span();
@@ -3804,6 +4486,7 @@ fn ty_to_type_parameter(
         Ty::StringArray { .. } => panic!("str types are not allowed in this position"),
         Ty::Ptr { .. } => panic!("__ptr types are not allowed in this position"),
         Ty::Slice { .. } => panic!("__slice types are not allowed in this position"),
+        Ty::Ref { .. } => panic!("reference types are not allowed in this position"),
     };
     let custom_type = type_engine.insert(
         engines,
@@ -3976,6 +4659,12 @@ fn assignable_to_expression(
                 span,
             }
         }
+        Assignable::Deref { expr, .. } => Expression {
+            kind: ExpressionKind::Deref(Box::new(expr_to_expression(
+                context, handler, engines, *expr,
+            )?)),
+            span,
+        },
     };
     Ok(expression)
 }
@@ -3998,8 +4687,13 @@ fn assignable_to_reassignment_target(
             Assignable::Var(_) => break,
             Assignable::Index { .. } => break,
             Assignable::TupleFieldProjection { .. } => break,
+            Assignable::Deref { .. } => break,
         }
     }
+    if let Assignable::Deref { expr, .. } = assignable {
+        let expression = expr_to_expression(context, handler, engines, *expr)?;
+        return Ok(ReassignmentTarget::Deref(Box::new(expression)));
+    }
     let expression = assignable_to_expression(context, handler, engines, assignable)?;
     Ok(ReassignmentTarget::VariableExpression(Box::new(expression)))
 }
@@ -254,6 +254,9 @@ impl TypeEngine {
             }
             TypeInfo::Ptr(targ) => self.contains_numeric(decl_engine, targ.type_id),
             TypeInfo::Slice(targ) => self.contains_numeric(decl_engine, targ.type_id),
+            TypeInfo::Ref {
+                referenced_type, ..
+            } => self.contains_numeric(decl_engine, referenced_type.type_id),
             TypeInfo::Unknown
             | TypeInfo::UnknownGeneric { .. }
             | TypeInfo::Placeholder(..)
@@ -261,6 +264,7 @@ impl TypeEngine {
             | TypeInfo::StringArray(..)
             | TypeInfo::StringSlice
             | TypeInfo::UnsignedInteger(..)
+            | TypeInfo::SignedInteger(..)
             | TypeInfo::Boolean
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Custom { .. }
@@ -307,6 +311,9 @@ impl TypeEngine {
             }
             TypeInfo::Ptr(targ) => self.decay_numeric(handler, engines, targ.type_id, span)?,
             TypeInfo::Slice(targ) => self.decay_numeric(handler, engines, targ.type_id, span)?,
+            TypeInfo::Ref {
+                referenced_type, ..
+            } => self.decay_numeric(handler, engines, referenced_type.type_id, span)?,
 
             TypeInfo::Unknown
             | TypeInfo::UnknownGeneric { .. }
@@ -315,6 +322,7 @@ impl TypeEngine {
             | TypeInfo::StringSlice
             | TypeInfo::StringArray(..)
             | TypeInfo::UnsignedInteger(..)
+            | TypeInfo::SignedInteger(..)
             | TypeInfo::Boolean
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Custom { .. }
@@ -366,6 +374,7 @@ fn info_to_source_id(ty: &TypeInfo) -> Option<SourceId> {
     match ty {
         TypeInfo::Unknown
         | TypeInfo::UnsignedInteger(_)
+        | TypeInfo::SignedInteger(_)
         | TypeInfo::Numeric
         | TypeInfo::Boolean
         | TypeInfo::B256
@@ -209,6 +209,7 @@ impl TypeId {
             | TypeInfo::StringArray(_)
             | TypeInfo::StringSlice
             | TypeInfo::UnsignedInteger(_)
+            | TypeInfo::SignedInteger(_)
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
             | TypeInfo::Boolean
@@ -350,6 +351,16 @@ impl TypeId {
                         .extract_any_including_self(engines, filter_fn, vec![]),
                 );
             }
+            TypeInfo::Ref {
+                referenced_type, ..
+            } => {
+                extend(
+                    &mut found,
+                    referenced_type
+                        .type_id
+                        .extract_any_including_self(engines, filter_fn, vec![]),
+                );
+            }
             TypeInfo::Slice(ty) => {
                 extend(
                     &mut found,
@@ -124,6 +124,9 @@ pub enum TypeInfo {
     StringSlice,
     StringArray(Length),
     UnsignedInteger(IntegerBits),
+    /// Represents a two's complement signed integer.
+    /// `IntegerBits::V256` is not a valid width for signed integers.
+    SignedInteger(IntegerBits),
     Enum(DeclRefEnum),
     Struct(DeclRefStruct),
     Boolean,
@@ -183,6 +186,12 @@ pub enum TypeInfo {
         name: Ident,
         trait_type_id: TypeId,
     },
+    /// A reference to a value of `referenced_type`, `&T` or `&mut T`.
+    /// These are represented in memory as u64 holding the address of the referenced value.
+    Ref {
+        to_mutable_value: bool,
+        referenced_type: TypeArgument,
+    },
 }
 
 impl HashWithEngines for TypeInfo {
@@ -192,7 +201,7 @@ impl HashWithEngines for TypeInfo {
             TypeInfo::StringArray(len) => {
                 len.hash(state);
             }
-            TypeInfo::UnsignedInteger(bits) => {
+            TypeInfo::UnsignedInteger(bits) | TypeInfo::SignedInteger(bits) => {
                 bits.hash(state);
             }
             TypeInfo::Tuple(fields) => {
@@ -254,6 +263,13 @@ impl HashWithEngines for TypeInfo {
             TypeInfo::Slice(ty) => {
                 ty.hash(state, engines);
             }
+            TypeInfo::Ref {
+                to_mutable_value,
+                referenced_type,
+            } => {
+                to_mutable_value.hash(state);
+                referenced_type.hash(state, engines);
+            }
             TypeInfo::TraitType {
                 name,
                 trait_type_id,
@@ -313,6 +329,7 @@ impl PartialEqWithEngines for TypeInfo {
             (Self::StringSlice, Self::StringSlice) => true,
             (Self::StringArray(l), Self::StringArray(r)) => l.val() == r.val(),
             (Self::UnsignedInteger(l), Self::UnsignedInteger(r)) => l == r,
+            (Self::SignedInteger(l), Self::SignedInteger(r)) => l == r,
             (Self::Enum(l_decl_ref), Self::Enum(r_decl_ref)) => {
                 let l_decl = engines.de().get_enum(l_decl_ref);
                 let r_decl = engines.de().get_enum(r_decl_ref);
@@ -389,6 +406,21 @@ impl PartialEqWithEngines for TypeInfo {
                         .get(*l_trait_type_id)
                         .eq(&type_engine.get(*r_trait_type_id), engines)
             }
+            (
+                Self::Ref {
+                    to_mutable_value: l_to_mut,
+                    referenced_type: l_ty,
+                },
+                Self::Ref {
+                    to_mutable_value: r_to_mut,
+                    referenced_type: r_ty,
+                },
+            ) => {
+                l_to_mut == r_to_mut
+                    && type_engine
+                        .get(l_ty.type_id)
+                        .eq(&type_engine.get(r_ty.type_id), engines)
+            }
             (l, r) => l.discriminant_value() == r.discriminant_value(),
         }
     }
@@ -434,6 +466,7 @@ impl OrdWithEngines for TypeInfo {
                 .then_with(|| l_root_type_id.cmp(r_root_type_id)),
             (Self::StringArray(l), Self::StringArray(r)) => l.val().cmp(&r.val()),
             (Self::UnsignedInteger(l), Self::UnsignedInteger(r)) => l.cmp(r),
+            (Self::SignedInteger(l), Self::SignedInteger(r)) => l.cmp(r),
             (Self::Enum(l_decl_ref), Self::Enum(r_decl_ref)) => {
                 let l_decl = decl_engine.get_enum(l_decl_ref);
                 let r_decl = decl_engine.get_enum(r_decl_ref);
@@ -500,6 +533,20 @@ impl OrdWithEngines for TypeInfo {
             ) => l_trait_type_id
                 .cmp(r_trait_type_id)
                 .then_with(|| l_name.cmp(r_name)),
+            (
+                Self::Ref {
+                    to_mutable_value: l_to_mut,
+                    referenced_type: l_ty,
+                },
+                Self::Ref {
+                    to_mutable_value: r_to_mut,
+                    referenced_type: r_ty,
+                },
+            ) => l_to_mut.cmp(r_to_mut).then_with(|| {
+                type_engine
+                    .get(l_ty.type_id)
+                    .cmp(&type_engine.get(r_ty.type_id), engines)
+            }),
 
             (l, r) => l.discriminant_value().cmp(&r.discriminant_value()),
         }
@@ -524,6 +571,14 @@ impl DisplayWithEngines for TypeInfo {
                 IntegerBits::V256 => "u256",
             }
             .into(),
+            SignedInteger(x) => match x {
+                IntegerBits::Eight => "i8",
+                IntegerBits::Sixteen => "i16",
+                IntegerBits::ThirtyTwo => "i32",
+                IntegerBits::SixtyFour => "i64",
+                IntegerBits::V256 => "i256",
+            }
+            .into(),
             Boolean => "bool".into(),
             Custom {
                 qualified_call_path: call_path,
@@ -569,6 +624,14 @@ impl DisplayWithEngines for TypeInfo {
             Slice(ty) => {
                 format!("__slice[{}]", engines.help_out(ty))
             }
+            Ref {
+                to_mutable_value,
+                referenced_type,
+            } => format!(
+                "&{}{}",
+                if *to_mutable_value { "mut " } else { "" },
+                engines.help_out(referenced_type)
+            ),
             Alias { name, .. } => name.to_string(),
             TraitType {
                 name,
@@ -597,6 +660,14 @@ impl DebugWithEngines for TypeInfo {
                 IntegerBits::V256 => "u256",
             }
             .into(),
+            SignedInteger(x) => match x {
+                IntegerBits::Eight => "i8",
+                IntegerBits::Sixteen => "i16",
+                IntegerBits::ThirtyTwo => "i32",
+                IntegerBits::SixtyFour => "i64",
+                IntegerBits::V256 => "i256",
+            }
+            .into(),
             Boolean => "bool".into(),
             Custom {
                 qualified_call_path: call_path,
@@ -667,6 +738,14 @@ impl DebugWithEngines for TypeInfo {
             Slice(ty) => {
                 format!("__slice[{:?}]", engines.help_out(ty))
             }
+            Ref {
+                to_mutable_value,
+                referenced_type,
+            } => format!(
+                "&{}{:?}",
+                if *to_mutable_value { "mut " } else { "" },
+                engines.help_out(referenced_type)
+            ),
             Alias { name, ty } => {
                 format!("type {} = {:?}", name, engines.help_out(ty))
             }
@@ -711,6 +790,8 @@ impl TypeInfo {
             TypeInfo::Slice(..) => 22,
             TypeInfo::StringSlice => 23,
             TypeInfo::TraitType { .. } => 24,
+            TypeInfo::Ref { .. } => 25,
+            TypeInfo::SignedInteger(_) => 26,
         }
     }
 
@@ -768,6 +849,17 @@ impl TypeInfo {
                 }
                 .into()
             }
+            SignedInteger(bits) => {
+                use IntegerBits::*;
+                match bits {
+                    Eight => "i8",
+                    Sixteen => "i16",
+                    ThirtyTwo => "i32",
+                    SixtyFour => "i64",
+                    V256 => "i256",
+                }
+                .into()
+            }
             Boolean => "bool".into(),
 
             Tuple(fields) => {
@@ -1042,6 +1134,7 @@ impl TypeInfo {
                 | TypeInfo::UnsignedInteger(IntegerBits::Sixteen)
                 | TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo)
                 | TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)
+                | TypeInfo::SignedInteger(_)
                 | TypeInfo::RawUntypedPtr
                 | TypeInfo::Numeric
         ) || self.is_unit()
@@ -1093,6 +1186,7 @@ impl TypeInfo {
             | TypeInfo::StringArray(_)
             | TypeInfo::StringSlice
             | TypeInfo::UnsignedInteger(_)
+            | TypeInfo::SignedInteger(_)
             | TypeInfo::Boolean
             | TypeInfo::Tuple(_)
             | TypeInfo::ContractCaller { .. }
@@ -1109,7 +1203,8 @@ impl TypeInfo {
             | TypeInfo::Placeholder(_)
             | TypeInfo::TypeParam(_)
             | TypeInfo::Alias { .. }
-            | TypeInfo::TraitType { .. } => {
+            | TypeInfo::TraitType { .. }
+            | TypeInfo::Ref { .. } => {
                 Err(handler.emit_err(CompileError::TypeArgumentsNotAllowed { span: span.clone() }))
             }
         }
@@ -1124,6 +1219,7 @@ impl TypeInfo {
     ) -> Result<(), ErrorEmitted> {
         match self {
             TypeInfo::UnsignedInteger(_)
+            | TypeInfo::SignedInteger(_)
             | TypeInfo::Enum { .. }
             | TypeInfo::Struct { .. }
             | TypeInfo::Boolean
@@ -1146,7 +1242,8 @@ impl TypeInfo {
             | TypeInfo::Storage { .. }
             | TypeInfo::Placeholder(_)
             | TypeInfo::TypeParam(_)
-            | TypeInfo::TraitType { .. } => Err(handler.emit_err(CompileError::Unimplemented(
+            | TypeInfo::TraitType { .. }
+            | TypeInfo::Ref { .. } => Err(handler.emit_err(CompileError::Unimplemented(
                 "matching on this type is unsupported right now",
                 span.clone(),
             ))),
@@ -1167,6 +1264,7 @@ impl TypeInfo {
         }
         match self {
             TypeInfo::UnsignedInteger(_)
+            | TypeInfo::SignedInteger(_)
             | TypeInfo::Enum { .. }
             | TypeInfo::Struct { .. }
             | TypeInfo::Boolean
@@ -1184,7 +1282,8 @@ impl TypeInfo {
             | TypeInfo::Numeric
             | TypeInfo::Alias { .. }
             | TypeInfo::UnknownGeneric { .. }
-            | TypeInfo::TraitType { .. } => Ok(()),
+            | TypeInfo::TraitType { .. }
+            | TypeInfo::Ref { .. } => Ok(()),
             TypeInfo::Unknown
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Storage { .. }
@@ -1285,6 +1384,7 @@ impl TypeInfo {
             TypeInfo::StringArray(_)
             | TypeInfo::StringSlice
             | TypeInfo::UnsignedInteger(_)
+            | TypeInfo::SignedInteger(_)
             | TypeInfo::Boolean
             | TypeInfo::B256
             | TypeInfo::RawUntypedPtr
@@ -1304,7 +1404,8 @@ impl TypeInfo {
             | TypeInfo::Numeric
             | TypeInfo::Placeholder(_)
             | TypeInfo::TypeParam(_)
-            | TypeInfo::Alias { .. } => true,
+            | TypeInfo::Alias { .. }
+            | TypeInfo::Ref { .. } => true,
         }
     }
 
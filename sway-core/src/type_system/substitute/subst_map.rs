@@ -261,6 +261,7 @@ impl TypeSubstMap {
             | (TypeInfo::StringSlice, TypeInfo::StringSlice)
             | (TypeInfo::StringArray(_), TypeInfo::StringArray(_))
             | (TypeInfo::UnsignedInteger(_), TypeInfo::UnsignedInteger(_))
+            | (TypeInfo::SignedInteger(_), TypeInfo::SignedInteger(_))
             | (TypeInfo::ContractCaller { .. }, TypeInfo::ContractCaller { .. }) => TypeSubstMap {
                 mapping: BTreeMap::new(),
             },
@@ -465,11 +466,26 @@ impl TypeSubstMap {
                 ty.type_id = type_id;
                 type_engine.insert(engines, TypeInfo::Slice(ty.clone()), ty.span.source_id())
             }),
+            TypeInfo::Ref {
+                to_mutable_value,
+                referenced_type: mut ty,
+            } => self.find_match(ty.type_id, engines).map(|type_id| {
+                ty.type_id = type_id;
+                type_engine.insert(
+                    engines,
+                    TypeInfo::Ref {
+                        to_mutable_value,
+                        referenced_type: ty.clone(),
+                    },
+                    ty.span.source_id(),
+                )
+            }),
             TypeInfo::TraitType { .. } => iter_for_match(engines, self, &type_info),
             TypeInfo::Unknown
             | TypeInfo::StringArray(..)
             | TypeInfo::StringSlice
             | TypeInfo::UnsignedInteger(..)
+            | TypeInfo::SignedInteger(..)
             | TypeInfo::Boolean
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::B256
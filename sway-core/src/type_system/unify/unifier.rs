@@ -143,6 +143,24 @@ impl<'a> Unifier<'a> {
             (Array(re, rc), Array(ee, ec)) if rc.val() == ec.val() => {
                 self.unify_arrays(handler, received, expected, span, re.type_id, ee.type_id)
             }
+            // A `&mut T` coerces to a `&T`, but not the other way around.
+            (
+                Ref {
+                    to_mutable_value: r_to_mut,
+                    referenced_type: r_ty,
+                },
+                Ref {
+                    to_mutable_value: e_to_mut,
+                    referenced_type: e_ty,
+                },
+            ) if r_to_mut || !e_to_mut => self.unify_referenced_types(
+                handler,
+                received,
+                expected,
+                span,
+                r_ty.type_id,
+                e_ty.type_id,
+            ),
             (Struct(r_decl_ref), Struct(e_decl_ref)) => {
                 let r_decl = self.engines.de().get_struct(&r_decl_ref);
                 let e_decl = self.engines.de().get_struct(&e_decl_ref);
@@ -250,10 +268,11 @@ impl<'a> Unifier<'a> {
             // For integers and numerics, we (potentially) unify the numeric
             // with the integer.
             (UnsignedInteger(r), UnsignedInteger(e)) if r == e => (),
-            (Numeric, e @ UnsignedInteger(_)) => {
+            (SignedInteger(r), SignedInteger(e)) if r == e => (),
+            (Numeric, e @ (UnsignedInteger(_) | SignedInteger(_))) => {
                 self.replace_received_with_expected(handler, received, expected, &Numeric, e, span)
             }
-            (r @ UnsignedInteger(_), Numeric) => {
+            (r @ (UnsignedInteger(_) | SignedInteger(_)), Numeric) => {
                 self.replace_expected_with_received(handler, received, expected, r, &Numeric, span)
             }
 
@@ -461,6 +480,20 @@ impl<'a> Unifier<'a> {
         }
     }
 
+    fn unify_referenced_types(
+        &self,
+        handler: &Handler,
+        received: TypeId,
+        expected: TypeId,
+        span: &Span,
+        r: TypeId,
+        e: TypeId,
+    ) {
+        // Same as for the arrays, if the referenced types do not match we want to
+        // report the reference types as mismatching.
+        self.unify_arrays(handler, received, expected, span, r, e)
+    }
+
     fn assign_args<T>(&self, r: T, e: T) -> (String, String)
     where
         WithEngines<'a, T>: fmt::Debug,
@@ -236,6 +236,24 @@ impl<'a> UnifyCheck<'a> {
             (Array(l0, l1), Array(r0, r1)) => {
                 return self.check_inner(l0.type_id, r0.type_id) && l1.val() == r1.val();
             }
+            (
+                Ref {
+                    to_mutable_value: l_to_mut,
+                    referenced_type: l_ty,
+                },
+                Ref {
+                    to_mutable_value: r_to_mut,
+                    referenced_type: r_ty,
+                },
+            ) => {
+                // A `&mut T` coerces to a `&T`, but not the other way around.
+                let mutability_matches = if matches!(self.mode, Coercion) {
+                    *l_to_mut || !*r_to_mut
+                } else {
+                    l_to_mut == r_to_mut
+                };
+                return mutability_matches && self.check_inner(l_ty.type_id, r_ty.type_id);
+            }
             (Tuple(l_types), Tuple(r_types)) => {
                 let l_types = l_types.iter().map(|x| x.type_id).collect::<Vec<_>>();
                 let r_types = r_types.iter().map(|x| x.type_id).collect::<Vec<_>>();
@@ -402,6 +420,9 @@ impl<'a> UnifyCheck<'a> {
                     (UnsignedInteger(_), UnsignedInteger(_)) => true,
                     (Numeric, UnsignedInteger(_)) => true,
                     (UnsignedInteger(_), Numeric) => true,
+                    (SignedInteger(_), SignedInteger(_)) => true,
+                    (Numeric, SignedInteger(_)) => true,
+                    (SignedInteger(_), Numeric) => true,
 
                     (StringSlice, StringSlice) => true,
                     (StringArray(l), StringArray(r)) => l.val() == r.val(),
@@ -506,6 +527,7 @@ impl<'a> UnifyCheck<'a> {
                 (TypeInfo::StringSlice, TypeInfo::StringSlice) => true,
                 (TypeInfo::StringArray(l), TypeInfo::StringArray(r)) => l.val() == r.val(),
                 (TypeInfo::UnsignedInteger(l), TypeInfo::UnsignedInteger(r)) => l == r,
+                (TypeInfo::SignedInteger(l), TypeInfo::SignedInteger(r)) => l == r,
                 (TypeInfo::RawUntypedPtr, TypeInfo::RawUntypedPtr) => true,
                 (TypeInfo::RawUntypedSlice, TypeInfo::RawUntypedSlice) => true,
                 (
@@ -680,8 +702,8 @@ impl<'a> UnifyCheck<'a> {
                                 (a, b),
                                 (_, Placeholder(_))
                                     | (Placeholder(_), _)
-                                    | (UnsignedInteger(_), Numeric)
-                                    | (Numeric, UnsignedInteger(_))
+                                    | (UnsignedInteger(_) | SignedInteger(_), Numeric)
+                                    | (Numeric, UnsignedInteger(_) | SignedInteger(_))
                             ))
                         {
                             continue;
@@ -700,8 +722,8 @@ impl<'a> UnifyCheck<'a> {
                             (a, b),
                             (_, Placeholder(_))
                                 | (Placeholder(_), _)
-                                | (UnsignedInteger(_), Numeric)
-                                | (Numeric, UnsignedInteger(_))
+                                | (UnsignedInteger(_) | SignedInteger(_), Numeric)
+                                | (Numeric, UnsignedInteger(_) | SignedInteger(_))
                         ))
                     {
                         continue;
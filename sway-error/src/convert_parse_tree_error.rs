@@ -41,8 +41,14 @@ pub enum ConvertParseTreeError {
     U32LiteralOutOfRange { span: Span },
     #[error("u64 literal out of range")]
     U64LiteralOutOfRange { span: Span },
-    #[error("signed integers are not supported")]
-    SignedIntegersNotSupported { span: Span },
+    #[error("i8 literal out of range")]
+    I8LiteralOutOfRange { span: Span },
+    #[error("i16 literal out of range")]
+    I16LiteralOutOfRange { span: Span },
+    #[error("i32 literal out of range")]
+    I32LiteralOutOfRange { span: Span },
+    #[error("i64 literal out of range")]
+    I64LiteralOutOfRange { span: Span },
     #[error("ref variables are not supported")]
     RefVariablesNotSupported { span: Span },
     #[error("literal patterns not supported in this position")]
@@ -105,10 +111,6 @@ pub enum ConvertParseTreeError {
     CannotAnnotateDependency { span: Span },
     #[error("Expected dependency at the beginning before any other items.")]
     ExpectedDependencyAtBeginning { span: Span },
-    #[error("Ref expressions are not supported yet.")]
-    RefExprNotYetSupported { span: Span },
-    #[error("Deref expressions are not supported yet.")]
-    DerefExprNotYetSupported { span: Span },
     #[error("Constant requires expression.")]
     ConstantRequiresExpression { span: Span },
     #[error("Constant requires type ascription.")]
@@ -145,7 +147,10 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::U16LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::U32LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::U64LiteralOutOfRange { span } => span.clone(),
-            ConvertParseTreeError::SignedIntegersNotSupported { span } => span.clone(),
+            ConvertParseTreeError::I8LiteralOutOfRange { span } => span.clone(),
+            ConvertParseTreeError::I16LiteralOutOfRange { span } => span.clone(),
+            ConvertParseTreeError::I32LiteralOutOfRange { span } => span.clone(),
+            ConvertParseTreeError::I64LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::RefVariablesNotSupported { span } => span.clone(),
             ConvertParseTreeError::LiteralPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ConstantPatternsNotSupportedHere { span } => span.clone(),
@@ -177,8 +182,6 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::CannotDocCommentDependency { span } => span.clone(),
             ConvertParseTreeError::CannotAnnotateDependency { span } => span.clone(),
             ConvertParseTreeError::ExpectedDependencyAtBeginning { span } => span.clone(),
-            ConvertParseTreeError::RefExprNotYetSupported { span } => span.clone(),
-            ConvertParseTreeError::DerefExprNotYetSupported { span } => span.clone(),
             ConvertParseTreeError::ConstantRequiresExpression { span } => span.clone(),
             ConvertParseTreeError::ConstantRequiresTypeAscription { span } => span.clone(),
             ConvertParseTreeError::InvalidCfgTargetArgValue { span, .. } => span.clone(),
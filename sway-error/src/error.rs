@@ -85,6 +85,17 @@ pub enum CompileError {
     },
     #[error("Assignment to immutable variable. Variable {name} is not declared as mutable.")]
     AssignmentToNonMutable { name: Ident, span: Span },
+    #[error("Assignment through an immutable reference. Only `&mut` references can be assigned through.")]
+    AssignmentViaNonMutableReference { span: Span },
+    #[error(
+        "A mutable reference cannot reference immutable variable \"{decl_name}\". \
+         Declare \"{decl_name}\" as mutable or take an immutable reference instead."
+    )]
+    RefMutCannotReferenceImmutableVariable { decl_name: Ident, span: Span },
+    #[error("A mutable reference cannot be taken through an immutable reference.")]
+    RefMutCannotReferenceImmutableReference { span: Span },
+    #[error("Expression of type \"{expression_type}\" cannot be dereferenced. Only references can be dereferenced.")]
+    ExpressionCannotBeDereferenced { expression_type: String, span: Span },
     #[error(
         "Cannot call method \"{method_name}\" on variable \"{variable_name}\" because \
             \"{variable_name}\" is not declared as mutable."
@@ -505,6 +516,16 @@ pub enum CompileError {
         missing_patterns: String,
         span: Span,
     },
+    #[error("Refutable pattern in a `let` statement or function parameter. Missing patterns {missing_patterns}")]
+    RefutablePatternInDestructuring {
+        missing_patterns: String,
+        span: Span,
+    },
+    #[error("Refutable pattern in a `for` loop. Missing patterns {missing_patterns}")]
+    RefutablePatternInForLoop {
+        missing_patterns: String,
+        span: Span,
+    },
     #[error("Pattern does not mention {}: {}",
         if missing_fields.len() == 1 { "field" } else { "fields" },
         missing_fields.join(", "))]
@@ -512,6 +533,8 @@ pub enum CompileError {
         missing_fields: Vec<String>,
         span: Span,
     },
+    #[error("This range pattern is empty and cannot match any value.")]
+    MatchRangePatternEmpty { span: Span },
     #[error("Variable \"{variable}\" is not defined in all alternatives.")]
     MatchArmVariableNotDefinedInAllAlternatives {
         match_value: Span,
@@ -651,6 +674,10 @@ pub enum CompileError {
     BreakOutsideLoop { span: Span },
     #[error("\"continue\" used outside of a loop")]
     ContinueOutsideLoop { span: Span },
+    #[error("A `for` loop cannot iterate over a value of type \"{ty}\", because the type does not implement the `Iterator` trait. Try iterating over an iterator of the value instead, e.g. by calling its `iter` method.")]
+    ForLoopIterableNotIterator { ty: String, span: Span },
+    #[error("A `for` loop requires the standard library, whose `Iterator` trait it iterates with, but \"std\" is not a dependency of this package.")]
+    ForLoopWithoutStd { span: Span },
     /// This will be removed once loading contract IDs in a dependency namespace is refactored and no longer manual:
     /// https://github.com/FuelLabs/sway/issues/3077
     #[error("Contract ID is not a constant item.")]
@@ -697,6 +724,8 @@ pub enum CompileError {
     },
     #[error("Configurable constants are not allowed in libraries.")]
     ConfigurableInLibrary { span: Span },
+    #[error("Unknown IR optimization pass \"{name}\".")]
+    UnknownOptimizationPass { name: String, span: Span },
     #[error("Multiple applicable items in scope. {}", {
         let mut candidates = "".to_string();
         let mut as_traits = as_traits.clone();
@@ -765,6 +794,10 @@ impl Spanned for CompileError {
             MultipleDefinitionsOfType { span, .. } => span.clone(),
             MultipleDefinitionsOfMatchArmVariable { duplicate, .. } => duplicate.clone(),
             AssignmentToNonMutable { span, .. } => span.clone(),
+            AssignmentViaNonMutableReference { span } => span.clone(),
+            RefMutCannotReferenceImmutableVariable { span, .. } => span.clone(),
+            RefMutCannotReferenceImmutableReference { span } => span.clone(),
+            ExpressionCannotBeDereferenced { span, .. } => span.clone(),
             MutableParameterNotSupported { span, .. } => span.clone(),
             ImmutableArgumentToMutableParameter { span } => span.clone(),
             RefMutableNotAllowedInContractAbi { span, .. } => span.clone(),
@@ -853,7 +886,10 @@ impl Spanned for CompileError {
             ShadowsOtherSymbol { name } => name.span(),
             GenericShadowsGeneric { name } => name.span(),
             MatchExpressionNonExhaustive { span, .. } => span.clone(),
+            RefutablePatternInDestructuring { span, .. } => span.clone(),
+            RefutablePatternInForLoop { span, .. } => span.clone(),
             MatchStructPatternMissingFields { span, .. } => span.clone(),
+            MatchRangePatternEmpty { span } => span.clone(),
             MatchArmVariableNotDefinedInAllAlternatives { variable, .. } => variable.span(),
             MatchArmVariableMismatchedType { variable, .. } => variable.span(),
             NotAnEnum { span, .. } => span.clone(),
@@ -900,6 +936,8 @@ impl Spanned for CompileError {
             IntrinsicIncorrectNumTArgs { span, .. } => span.clone(),
             BreakOutsideLoop { span } => span.clone(),
             ContinueOutsideLoop { span } => span.clone(),
+            ForLoopIterableNotIterator { span, .. } => span.clone(),
+            ForLoopWithoutStd { span } => span.clone(),
             ContractIdConstantNotAConstDecl { span } => span.clone(),
             ContractIdValueNotALiteral { span } => span.clone(),
             RefMutableNotAllowedInMain { span, .. } => span.clone(),
@@ -910,6 +948,7 @@ impl Spanned for CompileError {
             CoinsPassedToNonPayableMethod { span, .. } => span.clone(),
             TraitImplPayabilityMismatch { span, .. } => span.clone(),
             ConfigurableInLibrary { span } => span.clone(),
+            UnknownOptimizationPass { span, .. } => span.clone(),
             MultipleApplicableItemsInScope { span, .. } => span.clone(),
             NonStrGenericType { span } => span.clone(),
             CannotBeEvaluatedToConst { span } => span.clone(),
@@ -1148,4 +1187,28 @@ pub enum TypeNotAllowedReason {
 
     #[error("`str` or a type containing `str` on `const` is not allowed.")]
     StringSliceInConst,
+
+    #[error("A reference or a type containing a reference on `main()` arguments is not allowed.")]
+    ReferenceInMainParameters,
+
+    #[error(
+        "Returning a reference or a type containing a reference from `main()` is not allowed."
+    )]
+    ReferenceInMainReturn,
+
+    #[error(
+        "A reference or a type containing a reference on contract ABI method arguments is not allowed."
+    )]
+    ReferenceInAbiParameters,
+
+    #[error(
+        "Returning a reference or a type containing a reference from a contract ABI method is not allowed."
+    )]
+    ReferenceInAbiReturn,
+
+    #[error("A reference or a type containing a reference on `configurables` is not allowed.")]
+    ReferenceInConfigurables,
+
+    #[error("Logging a reference or a type containing a reference is not allowed.")]
+    ReferenceInLoggedType,
 }
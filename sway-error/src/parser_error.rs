@@ -60,6 +60,8 @@ pub enum ParseErrorKind {
     ExpectedOpenBracket,
     #[error("Expected a literal.")]
     ExpectedLiteral,
+    #[error("Expected an integer literal.")]
+    ExpectedIntLiteral,
     #[error("Expected a module kind (script, contract, predicate or library).")]
     ExpectedModuleKind,
     #[error("Expected `{}`.", kinds.iter().map(PunctKind::as_char).collect::<String>())]
@@ -0,0 +1,266 @@
+//! Natural loop analysis.
+//!
+//! A natural loop is identified by its back edges, the edges from the blocks of the loop to its
+//! header, which dominates them. The body of the loop is made of the blocks which can reach a
+//! back edge without going through the header. Back edges to the same header form a single loop.
+//!
+//! Natural loops are either disjoint or nested within one another, so that each loop records the
+//! innermost loop enclosing it.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::ops::Index;
+
+use crate::{
+    block::Block, AnalysisResult, AnalysisResultT, AnalysisResults, BranchToWithArgs, Context,
+    DomTree, Function, IrError, Pass, PassMutability, PostOrder, ScopedPass, DOMINATORS_NAME,
+    POSTORDER_NAME,
+};
+
+/// Identifies a [`Loop`] within the [`LoopInfo`] of a function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LoopId(usize);
+
+/// A natural loop.
+#[derive(Clone, Debug)]
+pub struct Loop {
+    /// The block at which every iteration starts, dominating the other blocks of the loop.
+    pub header: Block,
+    /// The blocks of the loop, including those of the loops nested within it, in reverse
+    /// post-order.  The header is the first block.
+    pub blocks: Vec<Block>,
+    /// The blocks of the loop which branch back to the header.
+    pub latches: Vec<Block>,
+    /// The blocks outside of the loop which are branched to from within it.
+    pub exits: Vec<Block>,
+    /// The innermost loop enclosing this one.
+    pub parent: Option<LoopId>,
+    /// The nesting depth of the loop, starting at 1 for outermost loops.
+    pub depth: usize,
+}
+
+/// The natural loops of a function.
+#[derive(Clone, Debug, Default)]
+pub struct LoopInfo {
+    /// The loops, where enclosing loops come before the loops nested within them.
+    loops: Vec<Loop>,
+    /// The innermost loop containing each block within a loop.
+    innermost: FxHashMap<Block, LoopId>,
+}
+impl AnalysisResultT for LoopInfo {}
+
+impl Index<LoopId> for LoopInfo {
+    type Output = Loop;
+
+    fn index(&self, id: LoopId) -> &Loop {
+        &self.loops[id.0]
+    }
+}
+
+impl LoopInfo {
+    /// Get an iterator over the loops, where enclosing loops come before their nested loops.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (LoopId, &Loop)> {
+        self.loops
+            .iter()
+            .enumerate()
+            .map(|(idx, lp)| (LoopId(idx), lp))
+    }
+
+    /// Whether the function has no loops.
+    pub fn is_empty(&self) -> bool {
+        self.loops.is_empty()
+    }
+
+    /// The innermost loop containing `block`, if it is within a loop.
+    pub fn innermost_loop(&self, block: &Block) -> Option<LoopId> {
+        self.innermost.get(block).copied()
+    }
+
+    /// Whether the loop `id` contains `block`, either directly or within a nested loop.
+    pub fn contains(&self, id: LoopId, block: &Block) -> bool {
+        let mut lp = self.innermost_loop(block);
+        while let Some(lp_id) = lp {
+            if lp_id == id {
+                return true;
+            }
+            lp = self[lp_id].parent;
+        }
+        false
+    }
+
+    /// Get the preheader of a loop, the block outside of the loop which is the only predecessor of
+    /// the header outside of the loop and which branches to nothing but the header.
+    pub fn get_preheader(&self, context: &Context, id: LoopId) -> Option<Block> {
+        let mut outside_preds = self[id]
+            .header
+            .pred_iter(context)
+            .filter(|pred| !self.contains(id, pred));
+        match (outside_preds.next(), outside_preds.next()) {
+            (Some(pred), None) if pred.successors(context).len() == 1 => Some(*pred),
+            _ => None,
+        }
+    }
+
+    /// Get the preheader of a loop, inserting a new one if it doesn't have one.
+    ///
+    /// A new preheader takes the same arguments as the header and passes them on to it, while the
+    /// predecessors of the header outside of the loop branch to the preheader instead.  It is
+    /// added to the loops enclosing this one.
+    ///
+    /// Returns `None` if no preheader can be inserted, which is the case when the header is the
+    /// entry block or when a block outside of the loop branches to the header twice.
+    pub fn get_or_insert_preheader(&mut self, context: &mut Context, id: LoopId) -> Option<Block> {
+        if let Some(preheader) = self.get_preheader(context, id) {
+            return Some(preheader);
+        }
+        let header = self[id].header;
+        let function = header.get_function(context);
+        if header == function.get_entry_block(context) {
+            return None;
+        }
+        let outside_preds: Vec<Block> = header
+            .pred_iter(context)
+            .filter(|pred| !self.contains(id, pred))
+            .copied()
+            .collect();
+        // Both branches of a conditional branch to the header may pass different arguments.
+        if outside_preds.iter().any(|pred| {
+            pred.successors(context)
+                .iter()
+                .filter(|succ| succ.block == header)
+                .count()
+                > 1
+        }) {
+            return None;
+        }
+
+        let label = format!("{}_preheader", header.get_label(context));
+        let preheader = function
+            .create_block_before(context, &header, Some(label))
+            .unwrap();
+        let arg_tys: Vec<_> = header
+            .arg_iter(context)
+            .map(|arg| arg.get_type(context).unwrap())
+            .collect();
+        for ty in arg_tys {
+            preheader.new_arg(context, ty);
+        }
+        for pred in outside_preds {
+            let params = pred.get_succ_params(context, &header);
+            pred.replace_successor(context, header, preheader, params);
+        }
+        let params = preheader.arg_iter(context).copied().collect();
+        preheader.ins(context).branch(header, params);
+
+        let mut enclosing = self[id].parent;
+        if let Some(parent) = enclosing {
+            self.innermost.insert(preheader, parent);
+        }
+        while let Some(enclosing_id) = enclosing {
+            let blocks = &mut self.loops[enclosing_id.0].blocks;
+            let header_idx = blocks.iter().position(|block| *block == header).unwrap();
+            blocks.insert(header_idx, preheader);
+            enclosing = self[enclosing_id].parent;
+        }
+        Some(preheader)
+    }
+}
+
+pub const LOOPS_NAME: &str = "loops";
+
+pub fn create_loops_pass() -> Pass {
+    Pass {
+        name: LOOPS_NAME,
+        descr: "Natural loops computation",
+        deps: vec![POSTORDER_NAME, DOMINATORS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Analysis(compute_loops_pass)),
+    }
+}
+
+pub fn compute_loops_pass(
+    context: &Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<AnalysisResult, IrError> {
+    let po: &PostOrder = analyses.get_analysis_result(function);
+    let dom_tree: &DomTree = analyses.get_analysis_result(function);
+    Ok(Box::new(compute_loops(context, po, dom_tree)))
+}
+
+/// Compute the natural loops of a function.
+/// Unreachable blocks aren't part of any loop.
+pub fn compute_loops(context: &Context, po: &PostOrder, dom_tree: &DomTree) -> LoopInfo {
+    let mut info = LoopInfo::default();
+    let mut bodies: Vec<FxHashSet<Block>> = Vec::new();
+    let rpo = |block: &Block| std::cmp::Reverse(po.block_to_po[block]);
+
+    // A header dominates the headers of the loops nested within it, so visiting the headers in
+    // reverse post-order visits enclosing loops first.
+    for header in po.po_to_block.iter().rev() {
+        let mut latches: Vec<Block> = header
+            .pred_iter(context)
+            .filter(|pred| po.block_to_po.contains_key(pred) && dominates(dom_tree, header, pred))
+            .copied()
+            .collect();
+        if latches.is_empty() {
+            continue;
+        }
+        latches.sort_by_key(rpo);
+
+        // Walk back from the latches until the header is reached.
+        let mut body = FxHashSet::from_iter([*header]);
+        let mut worklist = latches.clone();
+        while let Some(block) = worklist.pop() {
+            if body.insert(block) {
+                worklist.extend(
+                    block
+                        .pred_iter(context)
+                        .filter(|pred| po.block_to_po.contains_key(pred)),
+                );
+            }
+        }
+        let mut blocks: Vec<Block> = body.iter().copied().collect();
+        blocks.sort_by_key(rpo);
+
+        let mut exits = Vec::new();
+        for block in &blocks {
+            for BranchToWithArgs { block: succ, .. } in block.successors(context) {
+                if !body.contains(&succ) && !exits.contains(&succ) {
+                    exits.push(succ);
+                }
+            }
+        }
+
+        // The innermost enclosing loop is the last one visited which contains this header.
+        let parent = bodies
+            .iter()
+            .rposition(|enclosing| enclosing.contains(header))
+            .map(LoopId);
+        let depth = parent.map_or(1, |parent| info[parent].depth + 1);
+        let id = LoopId(info.loops.len());
+        for block in &blocks {
+            info.innermost.insert(*block, id);
+        }
+        info.loops.push(Loop {
+            header: *header,
+            blocks,
+            latches,
+            exits,
+            parent,
+            depth,
+        });
+        bodies.push(body);
+    }
+    info
+}
+
+/// Whether `dominator` dominates `block`.
+fn dominates(dom_tree: &DomTree, dominator: &Block, block: &Block) -> bool {
+    let mut node = Some(*block);
+    while let Some(block) = node {
+        if block == *dominator {
+            return true;
+        }
+        node = dom_tree[&block].parent;
+    }
+    false
+}
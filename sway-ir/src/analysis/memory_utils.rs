@@ -359,3 +359,52 @@ pub fn pointee_size(context: &Context, ptr_val: Value) -> u64 {
         .expect("Expected arg to be a pointer")
         .size_in_bytes(context)
 }
+
+/// Whether a write of `len` bytes, or of an unknown number of bytes, to `dst_val_ptr` may overlap
+/// with the memory loaded from `src_val_ptr`.
+///
+/// Pointers to distinct locals never overlap. Pointers whose symbols can't be determined may point
+/// anywhere and pointer arguments may point to the same memory, so these are assumed to overlap.
+pub fn may_clobber(
+    context: &Context,
+    dst_val_ptr: Value,
+    len: Option<u64>,
+    src_val_ptr: Value,
+) -> bool {
+    if dst_val_ptr == src_val_ptr {
+        return true;
+    }
+    let dst_symbols = get_symbols(context, dst_val_ptr);
+    let src_symbols = get_symbols(context, src_val_ptr);
+    if dst_symbols.is_empty() || src_symbols.is_empty() {
+        return true;
+    }
+    let distinct_args = dst_symbols.iter().any(|dst| {
+        src_symbols
+            .iter()
+            .any(|src| dst != src && matches!((dst, src), (Symbol::Arg(_), Symbol::Arg(_))))
+    });
+    if distinct_args {
+        return true;
+    }
+    let common_symbols: FxHashSet<_> = dst_symbols.intersection(&src_symbols).collect();
+    if common_symbols.is_empty() {
+        return false;
+    }
+    // The offsets within the symbols are only known when indexed by constants.
+    let Some(len) = len else {
+        return true;
+    };
+    if get_memory_offsets(context, dst_val_ptr).len() != dst_symbols.len()
+        || get_memory_offsets(context, src_val_ptr).len() != src_symbols.len()
+    {
+        return true;
+    }
+    may_alias(
+        context,
+        dst_val_ptr,
+        len,
+        src_val_ptr,
+        pointee_size(context, src_val_ptr),
+    )
+}
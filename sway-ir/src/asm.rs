@@ -59,3 +59,13 @@ impl AsmBlock {
         }
     }
 }
+
+/// Whether the named opcode reads from contract storage.
+pub fn is_storage_read_opcode(op_name: &str) -> bool {
+    matches!(op_name, "srw" | "srwq")
+}
+
+/// Whether the named opcode writes to or clears contract storage.
+pub fn is_storage_write_opcode(op_name: &str) -> bool {
+    matches!(op_name, "scwq" | "sww" | "swwq")
+}
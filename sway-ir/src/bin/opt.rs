@@ -1,11 +1,14 @@
 use std::{
     io::{BufReader, BufWriter, Read, Write},
-    process::exit,
+    process::{exit, Command, Stdio},
 };
 
 use anyhow::anyhow;
 use sway_ir::{
-    insert_after_each, register_known_passes, PassGroup, PassManager, MODULEPRINTER_NAME,
+    bisect_transforms, create_fuel_lowering_pass_group, create_o0_pass_group, create_o1_pass_group,
+    create_o2_pass_group, create_os_pass_group, insert_after_each,
+    interpreter::{execute, Execution},
+    register_known_passes, BisectOutcome, Context, PassGroup, PassManager, MODULEPRINTER_NAME,
     MODULEVERIFIER_NAME,
 };
 use sway_types::SourceEngine;
@@ -25,14 +28,22 @@ fn main() -> Result<(), anyhow::Error> {
 
     let source_engine = SourceEngine::default();
 
+    if config.bisect {
+        return bisect(&input_str, &source_engine, &config);
+    }
+
     // Parse it. XXX Improve this error message too.
     let mut ir = sway_ir::parser::parse(&input_str, &source_engine)?;
 
+    // Run the function before the passes, to compare with running it after them.
+    let run_before = config
+        .run_fn
+        .as_ref()
+        .map(|fn_name| run_function(&ir, fn_name))
+        .transpose()?;
+
     // Perform optimisation passes in order.
-    let mut passes = PassGroup::default();
-    for pass in config.passes {
-        passes.append_pass(pass);
-    }
+    let mut passes = config.passes;
     if config.print_after_each {
         passes = insert_after_each(passes, MODULEPRINTER_NAME);
     }
@@ -41,12 +52,128 @@ fn main() -> Result<(), anyhow::Error> {
     }
     pass_mgr.run(&mut ir, &passes)?;
 
+    if let (Some(fn_name), Some(before)) = (&config.run_fn, run_before) {
+        let after = run_function(&ir, fn_name)?;
+        if before != after {
+            print!("Running '{fn_name}' before the passes:\n{before}\n");
+            print!("Running '{fn_name}' after the passes:\n{after}");
+            exit(1);
+        }
+        print!("{after}");
+
+        // The IR is only written when it's asked for.
+        if config.output_path.is_none() {
+            return Ok(());
+        }
+    }
+
     // Write the output file or standard out.
     write_to_output(ir, &config.output_path)?;
 
     Ok(())
 }
 
+fn run_function(ir: &Context, fn_name: &str) -> Result<Execution, anyhow::Error> {
+    let function = ir
+        .module_iter()
+        .flat_map(|module| module.function_iter(ir))
+        .find(|function| function.get_name(ir) == fn_name)
+        .ok_or_else(|| anyhow!("Function '{fn_name}' not found."))?;
+    Ok(execute(ir, function, &[])?)
+}
+
+// -------------------------------------------------------------------------------------------------
+// Bisecting a miscompilation to the transformation which changes the result of a checker.
+
+fn bisect(
+    input_str: &str,
+    source_engine: &SourceEngine,
+    config: &Config,
+) -> Result<(), anyhow::Error> {
+    let check = |ir: &Context| -> Result<String, anyhow::Error> {
+        match (&config.run_fn, &config.check_cmd) {
+            (Some(fn_name), _) => Ok(match run_function(ir, fn_name) {
+                Ok(execution) => execution.to_string(),
+                Err(err) => format!("error: {err}"),
+            }),
+            (None, Some(check_cmd)) => run_check_command(ir, check_cmd),
+            (None, None) => Err(anyhow!("--bisect requires either --run or --check.")),
+        }
+    };
+
+    let outcome = bisect_transforms(
+        || Ok(sway_ir::parser::parse(input_str, source_engine)?),
+        register_known_passes,
+        &config.passes,
+        check,
+    )?;
+    let (transforms, culprit, before, after, ir) = match outcome {
+        BisectOutcome::Unchanged { transforms, result } => {
+            println!(
+                "The result is unchanged by all {} transformations:\n{}",
+                transforms.len(),
+                result.trim_end()
+            );
+            return Ok(());
+        }
+        BisectOutcome::Changed {
+            transforms,
+            culprit,
+            before,
+            after,
+            ir,
+        } => (transforms, culprit, before, after, ir),
+    };
+
+    let transform = &transforms[culprit];
+    let scope = match &transform.function {
+        Some(fn_name) => format!("function '{fn_name}'"),
+        None => "the module".to_owned(),
+    };
+    println!(
+        "The result is changed by transformation {} of {}, which is pass '{}' (number {} in the \
+        pipeline) on {scope}.",
+        culprit + 1,
+        transforms.len(),
+        transform.pass,
+        transform.pass_idx + 1,
+    );
+    print!(
+        "Result before:\n{}\nResult after:\n{}\n",
+        before.trim_end(),
+        after.trim_end()
+    );
+    if config.output_path.is_some() {
+        // Write the IR as it is after the transformation, to look at it in detail.
+        write_to_output(ir, &config.output_path)?;
+    }
+    exit(1);
+}
+
+/// Run `check_cmd` with `sh -c`, so that it may be a pipeline or use shell quoting, with the
+/// printed `ir` as its standard input. The result is whether it succeeded.
+fn run_check_command(ir: &Context, check_cmd: &str) -> Result<String, anyhow::Error> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(check_cmd)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()?;
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(sway_ir::printer::to_string(ir).as_bytes())?;
+    let status = child.wait()?;
+    Ok(if status.success() {
+        "success".to_owned()
+    } else {
+        format!("failure, {status}")
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 fn read_from_input(path_str: &Option<String>) -> std::io::Result<String> {
     let mut input = Vec::new();
     match path_str {
@@ -83,13 +210,16 @@ fn write_to_output<S: Into<String>>(ir_str: S, path_str: &Option<String>) -> std
 struct Config {
     input_path: Option<String>,
     output_path: Option<String>,
+    run_fn: Option<String>,
+    check_cmd: Option<String>,
+    bisect: bool,
 
     verify_after_each: bool,
     print_after_each: bool,
     _time_passes: bool,
     _stats: bool,
 
-    passes: Vec<&'static str>,
+    passes: PassGroup,
 }
 
 // This is a little clumsy in that it needs to consume items from the iterator carefully in each
@@ -122,6 +252,12 @@ impl<'a, I: Iterator<Item = String>> ConfigBuilder<'a, I> {
                 match opt.as_str() {
                     "-i" => self.build_input(),
                     "-o" => self.build_output(),
+                    "--run" => self.build_run(),
+                    "--check" => self.build_check(),
+                    "--bisect" => {
+                        self.cfg.bisect = true;
+                        self.build_root()
+                    }
                     "-verify-after-each" => {
                         self.cfg.verify_after_each = true;
                         self.build_root()
@@ -130,12 +266,30 @@ impl<'a, I: Iterator<Item = String>> ConfigBuilder<'a, I> {
                         self.cfg.print_after_each = true;
                         self.build_root()
                     }
+                    "-O0" | "-O1" | "-O2" | "-Os" => {
+                        self.cfg.passes.append_group(match opt.as_str() {
+                            "-O0" => create_o0_pass_group(),
+                            "-O1" => create_o1_pass_group(),
+                            "-O2" => create_o2_pass_group(),
+                            _ => create_os_pass_group(),
+                        });
+                        self.build_root()
+                    }
+                    "-fuel-lowering" => {
+                        self.cfg
+                            .passes
+                            .append_group(create_fuel_lowering_pass_group());
+                        self.build_root()
+                    }
                     "-h" => {
                         print!(
-                            "Usage: opt [passname...] -i input_file -o output_file\n\n{}",
+                            "Usage: opt [passname...] [-O0|-O1|-O2|-Os] [-fuel-lowering] -i input_file -o output_file --run function --bisect --check command\n\n{}",
                             self.pass_mgr.help_text()
                         );
                         print!("\n\nIn the absence of -i or -o options, input is taken from stdin and output is printed to stdout.\n");
+                        print!("\nWith --run, the function is interpreted before and after the passes and its results are printed instead of the output, unless -o is given.  If the results differ then both are printed and opt fails.\n");
+                        print!("\nThe -O options run the passes of an optimization level, as with `opt-level` in a build profile, and -fuel-lowering runs the passes lowering the IR for the FuelVM target which the compiler runs after them.  They may be mixed with pass names and each other, e.g. `-O1 -fuel-lowering` runs the passes of a default build.\n");
+                        print!("\nWith --bisect, the passes are run on a growing number of modules and functions until the result changes, which is either that of --run or whether the --check command succeeds when given the IR on its standard input.  The --check command is run with `sh -c`, so it may be a pipeline or quote its arguments.  The transformation which changed it is reported and the IR after it is written with -o.\n");
                         exit(0);
                     }
 
@@ -173,9 +327,31 @@ impl<'a, I: Iterator<Item = String>> ConfigBuilder<'a, I> {
         }
     }
 
+    fn build_run(mut self) -> Result<Config, anyhow::Error> {
+        match self.next {
+            None => Err(anyhow!("--run option requires an argument.")),
+            Some(fn_name) => {
+                self.cfg.run_fn = Some(fn_name);
+                self.next = self.rest.next();
+                self.build_root()
+            }
+        }
+    }
+
+    fn build_check(mut self) -> Result<Config, anyhow::Error> {
+        match self.next {
+            None => Err(anyhow!("--check option requires an argument.")),
+            Some(check_cmd) => {
+                self.cfg.check_cmd = Some(check_cmd);
+                self.next = self.rest.next();
+                self.build_root()
+            }
+        }
+    }
+
     fn build_pass(mut self, name: &str) -> Result<Config, anyhow::Error> {
         if let Some(pass) = self.pass_mgr.lookup_registered_pass(name) {
-            self.cfg.passes.push(pass.name);
+            self.cfg.passes.append_pass(pass.name);
             self.build_root()
         } else {
             Err(anyhow!(
@@ -197,19 +197,22 @@ pub enum FuelVmInstruction {
 }
 
 /// Comparison operations.
-#[derive(Debug, Clone, Copy, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Predicate {
     Equal,
     LessThan,
     GreaterThan,
+    /// Signed comparisons of `u64` values holding two's complement integers.
+    SLessThan,
+    SGreaterThan,
 }
 
-#[derive(Debug, Clone, Copy, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UnaryOpKind {
     Not,
 }
 
-#[derive(Debug, Clone, Copy, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BinaryOpKind {
     Add,
     Sub,
@@ -221,6 +224,15 @@ pub enum BinaryOpKind {
     Mod,
     Rsh,
     Lsh,
+    /// Signed operations on `u64` values holding two's complement integers.
+    /// `SAdd`, `SSub` and `SMul` wrap on overflow, `SDiv` rounds towards zero,
+    /// `SMod` takes the sign of the dividend and `SRsh` is an arithmetic shift.
+    SAdd,
+    SSub,
+    SMul,
+    SDiv,
+    SMod,
+    SRsh,
 }
 
 /// Special registers in the Fuel Virtual Machine.
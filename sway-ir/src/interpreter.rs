@@ -0,0 +1,1525 @@
+//! A reference interpreter for the IR.
+//!
+//! The [`Interpreter`] executes a [`Function`] on given arguments, simulating the memory, storage,
+//! logs and reverts of the Fuel VM closely enough that the results of running a module before and
+//! after an optimization pass may be compared, without going through codegen and the VM.
+//!
+//! Memory is a flat array of bytes.  The locals of each call are allocated on a stack growing up
+//! from the bottom and freed when the call returns, while `aloc` allocates on a heap growing down
+//! from the top.  Values are laid out as they are by the Fuel backend, with big-endian words, `u8`
+//! and `bool` taking a single byte and aggregates laid out by [`Type::get_indexed_offset`].  Values
+//! which fit in a register are held as words, so that arithmetic on the integers narrower than 64
+//! bits, overflow and the `$of`, `$err` and `$flag` registers behave as they do in the VM.
+//!
+//! Only the instructions which don't depend on the transaction or on other contracts are
+//! supported, along with the subset of the VM opcodes in ASM blocks which are used to implement the
+//! standard library.  Anything else stops execution with an [`InterpreterError`].
+
+use std::{collections::BTreeMap, fmt};
+
+use rustc_hash::FxHashMap;
+use sway_types::{ident::Ident, u256::U256};
+
+use crate::{
+    asm::{AsmArg, AsmBlock, AsmInstruction},
+    block::Block,
+    constant::{Constant, ConstantValue},
+    context::Context,
+    function::Function,
+    instruction::{
+        BinaryOpKind, BranchToWithArgs, FuelVmInstruction, InstOp, Predicate, Register, UnaryOpKind,
+    },
+    irtype::{Type, TypeContent},
+    local_var::LocalVar,
+    value::Value,
+};
+
+/// The default number of instructions executed before giving up, which catches infinite loops.
+pub const DEFAULT_STEP_LIMIT: u64 = 10_000_000;
+
+/// The size of the VM memory.
+const MEMORY_SIZE: u64 = 64 * 1024 * 1024;
+
+/// The bottom of the stack, which leaves the null pointer invalid.
+const STACK_START: u64 = 8;
+
+/// The maximum depth of nested calls.
+const MAX_CALL_DEPTH: usize = 256;
+
+// The bits of the `$flag` register.
+const FLAG_UNSAFE_MATH: u64 = 0x1;
+const FLAG_WRAPPING: u64 = 0x2;
+
+/// The storage of a contract, mapping keys to slots.
+pub type ContractStorage = BTreeMap<[u8; 32], [u8; 32]>;
+
+/// A value computed by the interpreter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuntimeValue {
+    Unit,
+    Bool(bool),
+    /// An integer which fits in a register.
+    Uint(u64),
+    U256(U256),
+    B256(U256),
+    Ptr(u64),
+    /// The bytes of an aggregate or a string.
+    Bytes(Vec<u8>),
+}
+
+/// How execution of a function ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Return(RuntimeValue),
+    Revert(u64),
+    Panic(PanicReason),
+}
+
+/// The reasons for which the VM panics, as far as they're simulated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanicReason {
+    ArithmeticOverflow,
+    ArithmeticError,
+    MemoryOverflow,
+    MemoryWriteOverlap,
+}
+
+/// A value logged by a `log` instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub id: u64,
+    pub data: Vec<u8>,
+}
+
+/// An error preventing the interpreter from executing a function.
+#[derive(Debug)]
+pub enum InterpreterError {
+    ArgumentCountMismatch(String, usize, usize),
+    CallDepthExceeded(usize),
+    InvalidAsm(String),
+    InvalidValue(String),
+    StepLimitExceeded(u64),
+    UndefinedValue(String),
+    UnsupportedAsmOp(String),
+    UnsupportedInstruction(String),
+    UnsupportedRegister(String),
+}
+
+impl std::error::Error for InterpreterError {}
+
+impl fmt::Display for InterpreterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            InterpreterError::ArgumentCountMismatch(fn_str, expected, found) => write!(
+                f,
+                "Function {fn_str} expects {expected} arguments but was given {found}."
+            ),
+            InterpreterError::CallDepthExceeded(depth) => {
+                write!(f, "Calls were nested more than {depth} deep.")
+            }
+            InterpreterError::InvalidAsm(why_str) => write!(f, "Invalid ASM block: {why_str}."),
+            InterpreterError::InvalidValue(why_str) => write!(f, "Invalid value: {why_str}."),
+            InterpreterError::StepLimitExceeded(limit) => {
+                write!(f, "Execution didn't end within {limit} steps.")
+            }
+            InterpreterError::UndefinedValue(fn_str) => write!(
+                f,
+                "A value in function {fn_str} was used before it was defined."
+            ),
+            InterpreterError::UnsupportedAsmOp(op_str) => {
+                write!(f, "ASM op '{op_str}' is not supported by the interpreter.")
+            }
+            InterpreterError::UnsupportedInstruction(inst_str) => write!(
+                f,
+                "Instruction '{inst_str}' is not supported by the interpreter."
+            ),
+            InterpreterError::UnsupportedRegister(reg_str) => {
+                write!(
+                    f,
+                    "Register '{reg_str}' is not supported by the interpreter."
+                )
+            }
+        }
+    }
+}
+
+impl fmt::Display for RuntimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            RuntimeValue::Unit => write!(f, "()"),
+            RuntimeValue::Bool(b) => write!(f, "{b}"),
+            RuntimeValue::Uint(n) => write!(f, "{n}"),
+            RuntimeValue::U256(n) => write!(f, "{n}"),
+            RuntimeValue::B256(b) => write!(f, "0x{}", to_hex(&b.to_be_bytes())),
+            RuntimeValue::Ptr(addr) => write!(f, "ptr 0x{addr:x}"),
+            RuntimeValue::Bytes(bytes) => write!(f, "0x{}", to_hex(bytes)),
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Outcome::Return(value) => write!(f, "ret {value}"),
+            Outcome::Revert(code) => write!(f, "revert {code}"),
+            Outcome::Panic(reason) => write!(f, "panic {reason:?}"),
+        }
+    }
+}
+
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "log {} 0x{}", self.id, to_hex(&self.data))
+    }
+}
+
+/// What can be observed of running a function, for comparing runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Execution {
+    pub outcome: Outcome,
+    pub logs: Vec<LogEntry>,
+    pub storage: ContractStorage,
+}
+
+impl fmt::Display for Execution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        writeln!(f, "{}", self.outcome)?;
+        for log in &self.logs {
+            writeln!(f, "{log}")?;
+        }
+        for (key, slot) in &self.storage {
+            writeln!(f, "storage 0x{}: 0x{}", to_hex(key), to_hex(slot))?;
+        }
+        Ok(())
+    }
+}
+
+/// Run `function` on `args` with empty storage.
+pub fn execute(
+    context: &Context,
+    function: Function,
+    args: &[RuntimeValue],
+) -> Result<Execution, InterpreterError> {
+    let mut interpreter = Interpreter::new(context);
+    let outcome = interpreter.run(function, args)?;
+    Ok(Execution {
+        outcome,
+        logs: interpreter.logs,
+        storage: interpreter.storage,
+    })
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+impl RuntimeValue {
+    /// The value as it's held in a register, if it fits in one.
+    pub fn to_word(&self) -> Option<u64> {
+        match self {
+            RuntimeValue::Unit => Some(0),
+            RuntimeValue::Bool(b) => Some(*b as u64),
+            RuntimeValue::Uint(n) | RuntimeValue::Ptr(n) => Some(*n),
+            RuntimeValue::U256(_) | RuntimeValue::B256(_) | RuntimeValue::Bytes(_) => None,
+        }
+    }
+
+    /// A value of type `ty` held in a register.
+    fn from_word(context: &Context, ty: Type, word: u64) -> RuntimeValue {
+        match ty.get_content(context) {
+            TypeContent::Unit => RuntimeValue::Unit,
+            TypeContent::Bool => RuntimeValue::Bool(word != 0),
+            TypeContent::Pointer(_) => RuntimeValue::Ptr(word),
+            _ => RuntimeValue::Uint(word),
+        }
+    }
+
+    /// The value as it's laid out in memory as a `ty`.
+    fn to_bytes(&self, context: &Context, ty: Type) -> Vec<u8> {
+        let size = ty.size_in_bytes(context) as usize;
+        let mut bytes = match self {
+            RuntimeValue::U256(n) | RuntimeValue::B256(n) => n.to_be_bytes().to_vec(),
+            RuntimeValue::Bytes(bytes) => {
+                let mut bytes = bytes.clone();
+                bytes.resize(size, 0);
+                return bytes;
+            }
+            _ => self.to_word().unwrap().to_be_bytes().to_vec(),
+        };
+        // Scalars are big-endian, so narrower types keep the least significant bytes.
+        if bytes.len() >= size {
+            bytes.split_off(bytes.len() - size)
+        } else {
+            let mut padded = vec![0; size - bytes.len()];
+            padded.append(&mut bytes);
+            padded
+        }
+    }
+
+    /// A value of type `ty` laid out in memory.
+    fn from_bytes(context: &Context, ty: Type, bytes: &[u8]) -> RuntimeValue {
+        let word = || {
+            let mut word = [0; 8];
+            word[8 - bytes.len().min(8)..].copy_from_slice(&bytes[..bytes.len().min(8)]);
+            u64::from_be_bytes(word)
+        };
+        match ty.get_content(context) {
+            TypeContent::Unit => RuntimeValue::Unit,
+            TypeContent::Bool => RuntimeValue::Bool(bytes[0] != 0),
+            TypeContent::Uint(256) => RuntimeValue::U256(U256::from_be_bytes(
+                bytes.try_into().expect("u256 is 32 bytes"),
+            )),
+            TypeContent::Uint(_) => RuntimeValue::Uint(word()),
+            TypeContent::Pointer(_) => RuntimeValue::Ptr(word()),
+            TypeContent::B256 => RuntimeValue::B256(U256::from_be_bytes(
+                bytes.try_into().expect("b256 is 32 bytes"),
+            )),
+            _ => RuntimeValue::Bytes(bytes.to_vec()),
+        }
+    }
+
+    /// The value of a constant.
+    fn from_constant(
+        context: &Context,
+        constant: &Constant,
+    ) -> Result<RuntimeValue, InterpreterError> {
+        Ok(match &constant.value {
+            ConstantValue::Unit => RuntimeValue::Unit,
+            ConstantValue::Bool(b) => RuntimeValue::Bool(*b),
+            ConstantValue::Uint(n) => RuntimeValue::Uint(*n),
+            ConstantValue::U256(n) => RuntimeValue::U256(n.clone()),
+            ConstantValue::B256(b) => RuntimeValue::B256(b.clone()),
+            ConstantValue::Undef
+            | ConstantValue::String(_)
+            | ConstantValue::Array(_)
+            | ConstantValue::Struct(_) => {
+                RuntimeValue::from_bytes(context, constant.ty, &constant_bytes(context, constant)?)
+            }
+        })
+    }
+}
+
+/// The bytes of a constant laid out in memory.
+fn constant_bytes(context: &Context, constant: &Constant) -> Result<Vec<u8>, InterpreterError> {
+    let mut bytes = vec![0; constant.ty.size_in_bytes(context) as usize];
+    let mut copy_at = |offset: u64, elem: &Constant| -> Result<(), InterpreterError> {
+        let elem_bytes = constant_bytes(context, elem)?;
+        let offset = offset as usize;
+        bytes[offset..offset + elem_bytes.len()].copy_from_slice(&elem_bytes);
+        Ok(())
+    };
+    match &constant.value {
+        ConstantValue::Undef => (),
+        ConstantValue::String(s) => {
+            if !constant.ty.is_string_array(context) {
+                return Err(InterpreterError::InvalidValue(format!(
+                    "string constant of type {}",
+                    constant.ty.as_string(context)
+                )));
+            }
+            bytes[..s.len()].copy_from_slice(s);
+        }
+        ConstantValue::Array(elems) => {
+            for (idx, elem) in elems.iter().enumerate() {
+                copy_at(idx as u64 * elem.ty.size_in_bytes(context), elem)?;
+            }
+        }
+        ConstantValue::Struct(fields) => {
+            for (idx, field) in fields.iter().enumerate() {
+                let offset = constant
+                    .ty
+                    .get_indexed_offset(context, &[idx as u64])
+                    .expect("field of a struct constant");
+                copy_at(offset, field)?;
+            }
+        }
+        _ => bytes = RuntimeValue::from_constant(context, constant)?.to_bytes(context, constant.ty),
+    }
+    Ok(bytes)
+}
+
+/// Whether values of type `ty` are held in registers rather than by reference.
+fn is_word_type(context: &Context, ty: Type) -> bool {
+    matches!(
+        ty.get_content(context),
+        TypeContent::Unit
+            | TypeContent::Bool
+            | TypeContent::Pointer(_)
+            | TypeContent::Uint(8 | 16 | 32 | 64)
+    )
+}
+
+/// Why execution stopped before returning.
+enum Halt {
+    Revert(u64),
+    Panic(PanicReason),
+    Error(InterpreterError),
+}
+
+impl From<InterpreterError> for Halt {
+    fn from(err: InterpreterError) -> Self {
+        Halt::Error(err)
+    }
+}
+
+/// The values and locals of a call.
+#[derive(Default)]
+struct Frame {
+    values: FxHashMap<Value, RuntimeValue>,
+    locals: FxHashMap<LocalVar, u64>,
+}
+
+/// What follows an instruction.
+enum Step {
+    Next(RuntimeValue),
+    Branch(Block),
+    Return(RuntimeValue),
+}
+
+/// An interpreter of the functions in a [`Context`].
+///
+/// The storage and logs persist between runs, so several functions may be run in sequence on the
+/// same contract.
+pub struct Interpreter<'a, 'eng> {
+    context: &'a Context<'eng>,
+    memory: Vec<u8>,
+    sp: u64,
+    hp: u64,
+    of: u64,
+    err: u64,
+    flag: u64,
+    storage: ContractStorage,
+    logs: Vec<LogEntry>,
+    steps: u64,
+    step_limit: u64,
+    call_depth: usize,
+}
+
+impl<'a, 'eng> Interpreter<'a, 'eng> {
+    pub fn new(context: &'a Context<'eng>) -> Self {
+        Interpreter {
+            context,
+            memory: vec![0; MEMORY_SIZE as usize],
+            sp: STACK_START,
+            hp: MEMORY_SIZE,
+            of: 0,
+            err: 0,
+            flag: 0,
+            storage: ContractStorage::new(),
+            logs: Vec::new(),
+            steps: 0,
+            step_limit: DEFAULT_STEP_LIMIT,
+            call_depth: 0,
+        }
+    }
+
+    /// Start with the slots of `storage` set.
+    pub fn with_storage(mut self, storage: ContractStorage) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// Give up after executing `step_limit` instructions.
+    pub fn with_step_limit(mut self, step_limit: u64) -> Self {
+        self.step_limit = step_limit;
+        self
+    }
+
+    pub fn storage(&self) -> &ContractStorage {
+        &self.storage
+    }
+
+    pub fn logs(&self) -> &[LogEntry] {
+        &self.logs
+    }
+
+    /// Run `function` on `args`.
+    ///
+    /// Arguments which aren't pointers are copied to memory when the function expects pointers,
+    /// while returned pointers are dereferenced, as their addresses are only meaningful within
+    /// the interpreter.  This allows the functions to be run the same way before and after their
+    /// arguments and return values are demoted.
+    pub fn run(
+        &mut self,
+        function: Function,
+        args: &[RuntimeValue],
+    ) -> Result<Outcome, InterpreterError> {
+        let context = self.context;
+        let num_args = function.num_args(context);
+        if args.len() != num_args {
+            return Err(InterpreterError::ArgumentCountMismatch(
+                function.get_name(context).to_owned(),
+                num_args,
+                args.len(),
+            ));
+        }
+
+        match self.run_to_halt(function, args) {
+            Ok(value) => Ok(Outcome::Return(value)),
+            Err(Halt::Revert(code)) => Ok(Outcome::Revert(code)),
+            Err(Halt::Panic(reason)) => Ok(Outcome::Panic(reason)),
+            Err(Halt::Error(err)) => Err(err),
+        }
+    }
+
+    fn run_to_halt(
+        &mut self,
+        function: Function,
+        args: &[RuntimeValue],
+    ) -> Result<RuntimeValue, Halt> {
+        let context = self.context;
+        let mut arg_vals = Vec::with_capacity(args.len());
+        for ((_, param), arg) in function.args_iter(context).zip(args) {
+            let pointee_ty = param
+                .get_type(context)
+                .and_then(|ty| ty.get_pointee_type(context));
+            arg_vals.push(match (pointee_ty, arg) {
+                (Some(pointee_ty), arg) if !matches!(arg, RuntimeValue::Ptr(_)) => {
+                    let addr = self.alloc_stack(pointee_ty.size_in_bytes(context))?;
+                    self.write_value(pointee_ty, addr, arg)?;
+                    RuntimeValue::Ptr(addr)
+                }
+                _ => arg.clone(),
+            });
+        }
+
+        let ret_val = self.call(function, arg_vals)?;
+        match function.get_return_type(context).get_pointee_type(context) {
+            Some(pointee_ty) => self.read_value(pointee_ty, word(&ret_val)?),
+            None => Ok(ret_val),
+        }
+    }
+
+    fn call(&mut self, function: Function, args: Vec<RuntimeValue>) -> Result<RuntimeValue, Halt> {
+        let context = self.context;
+        if self.call_depth == MAX_CALL_DEPTH {
+            return Err(InterpreterError::CallDepthExceeded(MAX_CALL_DEPTH).into());
+        }
+
+        let mut frame = Frame::default();
+        for ((_, param), arg) in function.args_iter(context).zip(args) {
+            frame.values.insert(*param, arg);
+        }
+        for (_, local) in function.locals_iter(context) {
+            let ty = local.get_inner_type(context);
+            let addr = self.alloc_stack(ty.size_in_bytes(context))?;
+            if let Some(initializer) = local.get_initializer(context) {
+                let value = RuntimeValue::from_constant(context, initializer)?;
+                self.write_value(ty, addr, &value)?;
+            }
+            frame.locals.insert(*local, addr);
+        }
+
+        self.call_depth += 1;
+        let result = self.run_blocks(function, &mut frame);
+        self.call_depth -= 1;
+        result
+    }
+
+    fn run_blocks(&mut self, function: Function, frame: &mut Frame) -> Result<RuntimeValue, Halt> {
+        let context = self.context;
+        let mut block = function.get_entry_block(context);
+        loop {
+            let mut next_block = None;
+            for inst in block.instruction_iter(context) {
+                self.count_step()?;
+                match self.step(function, frame, inst)? {
+                    Step::Next(value) => {
+                        frame.values.insert(inst, value);
+                    }
+                    Step::Branch(to_block) => {
+                        next_block = Some(to_block);
+                        break;
+                    }
+                    Step::Return(value) => return Ok(value),
+                }
+            }
+            block = next_block.ok_or_else(|| {
+                InterpreterError::InvalidValue(format!(
+                    "block {} has no terminator",
+                    block.get_label(context)
+                ))
+            })?;
+        }
+    }
+
+    fn count_step(&mut self) -> Result<(), Halt> {
+        self.steps += 1;
+        if self.steps > self.step_limit {
+            Err(InterpreterError::StepLimitExceeded(self.step_limit).into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn value(
+        &self,
+        function: Function,
+        frame: &Frame,
+        value: Value,
+    ) -> Result<RuntimeValue, InterpreterError> {
+        match value.get_constant_or_configurable(self.context) {
+            Some(constant) => RuntimeValue::from_constant(self.context, constant),
+            None => frame.values.get(&value).cloned().ok_or_else(|| {
+                InterpreterError::UndefinedValue(function.get_name(self.context).to_owned())
+            }),
+        }
+    }
+
+    fn word(&self, function: Function, frame: &Frame, value: Value) -> Result<u64, Halt> {
+        word(&self.value(function, frame, value)?)
+    }
+
+    /// Set the values of the arguments of the block branched to.
+    fn branch(
+        &self,
+        function: Function,
+        frame: &mut Frame,
+        to_block: &BranchToWithArgs,
+    ) -> Result<Step, Halt> {
+        let args = to_block
+            .args
+            .iter()
+            .map(|arg| self.value(function, frame, *arg))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (param, arg) in to_block.block.arg_iter(self.context).zip(args) {
+            frame.values.insert(*param, arg);
+        }
+        Ok(Step::Branch(to_block.block))
+    }
+
+    fn step(&mut self, function: Function, frame: &mut Frame, inst: Value) -> Result<Step, Halt> {
+        let context = self.context;
+        let ty = || inst.get_type(context).expect("instruction has a type");
+        let value = match &inst.get_instruction(context).unwrap().op {
+            InstOp::AsmBlock(asm, args) => self.asm_block(function, frame, asm, args)?,
+            InstOp::UnaryOp {
+                op: UnaryOpKind::Not,
+                arg,
+            } => match self.value(function, frame, *arg)? {
+                RuntimeValue::Bool(b) => RuntimeValue::Bool(!b),
+                RuntimeValue::U256(n) => RuntimeValue::U256(!&n),
+                RuntimeValue::B256(b) => RuntimeValue::B256(!&b),
+                arg => {
+                    let result = !word(&arg)?;
+                    self.of = 0;
+                    self.err = 0;
+                    RuntimeValue::from_word(context, ty(), result)
+                }
+            },
+            InstOp::BinaryOp { op, arg1, arg2 } => {
+                let lhs = self.value(function, frame, *arg1)?;
+                let rhs = self.value(function, frame, *arg2)?;
+                match lhs {
+                    RuntimeValue::U256(lhs) => {
+                        RuntimeValue::U256(self.wide_binary_op(*op, &lhs, &rhs)?)
+                    }
+                    RuntimeValue::B256(lhs) => {
+                        RuntimeValue::B256(self.wide_binary_op(*op, &lhs, &rhs)?)
+                    }
+                    lhs => {
+                        let result = self.alu(*op, word(&lhs)?, word(&rhs)?)?;
+                        RuntimeValue::from_word(context, ty(), result)
+                    }
+                }
+            }
+            InstOp::BitCast(value, to_ty) => {
+                let value = self.word(function, frame, *value)?;
+                RuntimeValue::from_word(context, *to_ty, value)
+            }
+            InstOp::Branch(to_block) => return self.branch(function, frame, to_block),
+            InstOp::Call(callee, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| self.value(function, frame, *arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                // The callee's locals are freed when it returns.
+                let sp = self.sp;
+                let result = self.call(*callee, args);
+                self.sp = sp;
+                result?
+            }
+            InstOp::CastPtr(value, _) => self.value(function, frame, *value)?,
+            InstOp::Cmp(pred, lhs, rhs) => {
+                let lhs = self.value(function, frame, *lhs)?;
+                let rhs = self.value(function, frame, *rhs)?;
+                RuntimeValue::Bool(compare(*pred, &lhs, &rhs)?)
+            }
+            InstOp::ConditionalBranch {
+                cond_value,
+                true_block,
+                false_block,
+            } => {
+                let to_block = if self.word(function, frame, *cond_value)? != 0 {
+                    true_block
+                } else {
+                    false_block
+                };
+                return self.branch(function, frame, to_block);
+            }
+            InstOp::ContractCall { .. } => {
+                return Err(InterpreterError::UnsupportedInstruction("contract_call".into()).into())
+            }
+            InstOp::FuelVm(fuel_vm_instr) => self.fuel_vm_instr(function, frame, fuel_vm_instr)?,
+            InstOp::GetLocal(local_var) => {
+                RuntimeValue::Ptr(*frame.locals.get(local_var).ok_or_else(|| {
+                    InterpreterError::InvalidValue(format!(
+                        "local of another function used in {}",
+                        function.get_name(context)
+                    ))
+                })?)
+            }
+            InstOp::GetElemPtr { base, indices, .. } => {
+                let base_ty = base
+                    .match_ptr_type(context)
+                    .expect("get_elem_ptr base is a pointer");
+                let base = self.word(function, frame, *base)?;
+                let indices = indices
+                    .iter()
+                    .map(|idx| self.word(function, frame, *idx))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let offset = base_ty
+                    .get_indexed_offset(context, &indices)
+                    .ok_or_else(|| {
+                        InterpreterError::InvalidValue(format!(
+                            "indices {indices:?} into {}",
+                            base_ty.as_string(context)
+                        ))
+                    })?;
+                RuntimeValue::Ptr(base.wrapping_add(offset))
+            }
+            InstOp::IntToPtr(value, ptr_ty) => {
+                RuntimeValue::from_word(context, *ptr_ty, self.word(function, frame, *value)?)
+            }
+            InstOp::Load(src_val_ptr) => {
+                let src = self.word(function, frame, *src_val_ptr)?;
+                self.read_value(ty(), src)?
+            }
+            InstOp::MemCopyBytes {
+                dst_val_ptr,
+                src_val_ptr,
+                byte_len,
+            } => {
+                let dst = self.word(function, frame, *dst_val_ptr)?;
+                let src = self.word(function, frame, *src_val_ptr)?;
+                self.copy_memory(dst, src, *byte_len)?;
+                RuntimeValue::Unit
+            }
+            InstOp::MemCopyVal {
+                dst_val_ptr,
+                src_val_ptr,
+            } => {
+                let len = dst_val_ptr
+                    .match_ptr_type(context)
+                    .expect("mem_copy_val destination is a pointer")
+                    .size_in_bytes(context);
+                let dst = self.word(function, frame, *dst_val_ptr)?;
+                let src = self.word(function, frame, *src_val_ptr)?;
+                self.copy_memory(dst, src, len)?;
+                RuntimeValue::Unit
+            }
+            InstOp::Nop => RuntimeValue::Unit,
+            InstOp::PtrToInt(value, int_ty) => {
+                RuntimeValue::from_word(context, *int_ty, self.word(function, frame, *value)?)
+            }
+            InstOp::Ret(value, _) => return Ok(Step::Return(self.value(function, frame, *value)?)),
+            InstOp::Store {
+                dst_val_ptr,
+                stored_val,
+            } => {
+                let stored_ty = stored_val
+                    .get_type(context)
+                    .expect("stored value has a type");
+                let dst = self.word(function, frame, *dst_val_ptr)?;
+                let value = self.value(function, frame, *stored_val)?;
+                self.write_value(stored_ty, dst, &value)?;
+                RuntimeValue::Unit
+            }
+        };
+        Ok(Step::Next(value))
+    }
+
+    fn fuel_vm_instr(
+        &mut self,
+        function: Function,
+        frame: &Frame,
+        instr: &FuelVmInstruction,
+    ) -> Result<RuntimeValue, Halt> {
+        let context = self.context;
+        Ok(match instr {
+            FuelVmInstruction::Gtf { .. } => {
+                return Err(InterpreterError::UnsupportedInstruction("gtf".into()).into())
+            }
+            FuelVmInstruction::Log {
+                log_val,
+                log_ty,
+                log_id,
+            } => {
+                let id = self.word(function, frame, *log_id)?;
+                let value = self.value(function, frame, *log_val)?;
+                let data = match log_ty.get_pointee_type(context) {
+                    Some(pointee_ty) => {
+                        self.read_bytes(word(&value)?, pointee_ty.size_in_bytes(context))?
+                    }
+                    // Values in registers are logged as words, as by the `log` opcode.
+                    None => match value.to_word() {
+                        Some(word) => word.to_be_bytes().to_vec(),
+                        None => value.to_bytes(context, *log_ty),
+                    },
+                };
+                self.logs.push(LogEntry { id, data });
+                RuntimeValue::Unit
+            }
+            FuelVmInstruction::ReadRegister(reg) => RuntimeValue::Uint(match reg {
+                Register::Of => self.of,
+                Register::Error => self.err,
+                Register::Flag => self.flag,
+                Register::Hp => self.hp,
+                Register::Sp => self.sp,
+                _ => {
+                    return Err(InterpreterError::UnsupportedRegister(
+                        format!("{reg:?}").to_lowercase(),
+                    )
+                    .into())
+                }
+            }),
+            FuelVmInstruction::Revert(code) => {
+                return Err(Halt::Revert(self.word(function, frame, *code)?))
+            }
+            FuelVmInstruction::Smo { .. } => {
+                return Err(InterpreterError::UnsupportedInstruction("smo".into()).into())
+            }
+            FuelVmInstruction::StateClear {
+                key,
+                number_of_slots,
+            } => {
+                let key = self.word(function, frame, *key)?;
+                let num_slots = self.word(function, frame, *number_of_slots)?;
+                RuntimeValue::Bool(self.state_clear(key, num_slots)?)
+            }
+            FuelVmInstruction::StateLoadQuadWord {
+                load_val,
+                key,
+                number_of_slots,
+            } => {
+                let dst = self.word(function, frame, *load_val)?;
+                let key = self.word(function, frame, *key)?;
+                let num_slots = self.word(function, frame, *number_of_slots)?;
+                RuntimeValue::Bool(self.state_read_quad(dst, key, num_slots)?)
+            }
+            FuelVmInstruction::StateLoadWord(key) => {
+                let key = self.word(function, frame, *key)?;
+                RuntimeValue::Uint(self.state_read_word(key)?.0)
+            }
+            FuelVmInstruction::StateStoreQuadWord {
+                stored_val,
+                key,
+                number_of_slots,
+            } => {
+                let src = self.word(function, frame, *stored_val)?;
+                let key = self.word(function, frame, *key)?;
+                let num_slots = self.word(function, frame, *number_of_slots)?;
+                RuntimeValue::Bool(self.state_write_quad(key, src, num_slots)?)
+            }
+            FuelVmInstruction::StateStoreWord { stored_val, key } => {
+                let value = self.word(function, frame, *stored_val)?;
+                let key = self.word(function, frame, *key)?;
+                RuntimeValue::Bool(self.state_write_word(key, value)?)
+            }
+            FuelVmInstruction::WideUnaryOp {
+                op: UnaryOpKind::Not,
+                result,
+                arg,
+            } => {
+                let arg = self.read_u256(self.word(function, frame, *arg)?)?;
+                let result = self.value(function, frame, *result)?;
+                self.write_u256(word(&result)?, !&arg)?;
+                result
+            }
+            FuelVmInstruction::WideBinaryOp {
+                op,
+                result,
+                arg1,
+                arg2,
+            } => {
+                let lhs = self.read_u256(self.word(function, frame, *arg1)?)?;
+                let rhs = self.word(function, frame, *arg2)?;
+                // Shift amounts are words while the other operands are pointers.
+                let rhs = if matches!(op, BinaryOpKind::Lsh | BinaryOpKind::Rsh) {
+                    RuntimeValue::Uint(rhs)
+                } else {
+                    RuntimeValue::U256(self.read_u256(rhs)?)
+                };
+                let value = self.wide_binary_op(*op, &lhs, &rhs)?;
+                let result = self.value(function, frame, *result)?;
+                self.write_u256(word(&result)?, value)?;
+                result
+            }
+            FuelVmInstruction::WideModularOp {
+                op,
+                result,
+                arg1,
+                arg2,
+                arg3,
+            } => {
+                let lhs = self.read_u256(self.word(function, frame, *arg1)?)?;
+                let rhs = self.read_u256(self.word(function, frame, *arg2)?)?;
+                let modulus = self.read_u256(self.word(function, frame, *arg3)?)?;
+                let value = self.wide_modular_op(*op, &lhs, &rhs, &modulus)?;
+                let result = self.value(function, frame, *result)?;
+                self.write_u256(word(&result)?, value)?;
+                result
+            }
+            FuelVmInstruction::WideCmpOp { op, arg1, arg2 } => {
+                let lhs = self.read_u256(self.word(function, frame, *arg1)?)?;
+                let rhs = self.read_u256(self.word(function, frame, *arg2)?)?;
+                RuntimeValue::Bool(compare(
+                    *op,
+                    &RuntimeValue::U256(lhs),
+                    &RuntimeValue::U256(rhs),
+                )?)
+            }
+        })
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // Arithmetic, following the ALU of the VM.
+
+    fn alu(&mut self, op: BinaryOpKind, lhs: u64, rhs: u64) -> Result<u64, Halt> {
+        let (lhs_wide, rhs_wide) = (lhs as u128, rhs as u128);
+        match op {
+            BinaryOpKind::Add => self.overflowing(lhs_wide + rhs_wide),
+            BinaryOpKind::Sub => self.overflowing(lhs_wide.wrapping_sub(rhs_wide)),
+            BinaryOpKind::Mul => self.overflowing(lhs_wide * rhs_wide),
+            BinaryOpKind::Div => self.fallible(lhs.checked_div(rhs)),
+            BinaryOpKind::Mod => self.fallible(lhs.checked_rem(rhs)),
+            BinaryOpKind::And => Ok(self.set(lhs & rhs)),
+            BinaryOpKind::Or => Ok(self.set(lhs | rhs)),
+            BinaryOpKind::Xor => Ok(self.set(lhs ^ rhs)),
+            BinaryOpKind::Lsh => Ok(self.set(
+                u32::try_from(rhs)
+                    .ok()
+                    .and_then(|rhs| lhs.checked_shl(rhs))
+                    .unwrap_or(0),
+            )),
+            BinaryOpKind::Rsh => Ok(self.set(
+                u32::try_from(rhs)
+                    .ok()
+                    .and_then(|rhs| lhs.checked_shr(rhs))
+                    .unwrap_or(0),
+            )),
+            // The signed operations are expanded by the backend into sequences which always wrap.
+            BinaryOpKind::SAdd => Ok(self.set((lhs as i64).wrapping_add(rhs as i64) as u64)),
+            BinaryOpKind::SSub => Ok(self.set((lhs as i64).wrapping_sub(rhs as i64) as u64)),
+            BinaryOpKind::SMul => Ok(self.set((lhs as i64).wrapping_mul(rhs as i64) as u64)),
+            BinaryOpKind::SDiv => {
+                self.fallible((rhs != 0).then(|| (lhs as i64).wrapping_div(rhs as i64) as u64))
+            }
+            BinaryOpKind::SMod => {
+                self.fallible((rhs != 0).then(|| (lhs as i64).wrapping_rem(rhs as i64) as u64))
+            }
+            BinaryOpKind::SRsh => Ok(self.set(((lhs as i64) >> rhs.min(63)) as u64)),
+        }
+    }
+
+    fn exp(&mut self, base: u64, exponent: u64) -> Result<u64, Halt> {
+        let result = u32::try_from(exponent)
+            .ok()
+            .and_then(|exponent| base.checked_pow(exponent))
+            .or((base < 2).then_some(base));
+        match result {
+            Some(result) => Ok(self.set(result)),
+            None if self.flag & FLAG_WRAPPING != 0 => {
+                self.of = 1;
+                self.err = 0;
+                Ok(0)
+            }
+            None => Err(Halt::Panic(PanicReason::ArithmeticOverflow)),
+        }
+    }
+
+    /// Keep the high bits of `result` in `$of`, unless wrapping is disabled and it overflowed.
+    fn overflowing(&mut self, result: u128) -> Result<u64, Halt> {
+        if result > u64::MAX as u128 && self.flag & FLAG_WRAPPING == 0 {
+            return Err(Halt::Panic(PanicReason::ArithmeticOverflow));
+        }
+        self.of = (result >> 64) as u64;
+        self.err = 0;
+        Ok(result as u64)
+    }
+
+    /// Set `$err` when there's no `result`, unless unsafe math is disabled.
+    fn fallible(&mut self, result: Option<u64>) -> Result<u64, Halt> {
+        if result.is_none() && self.flag & FLAG_UNSAFE_MATH == 0 {
+            return Err(Halt::Panic(PanicReason::ArithmeticError));
+        }
+        self.of = 0;
+        self.err = result.is_none() as u64;
+        Ok(result.unwrap_or(0))
+    }
+
+    fn set(&mut self, result: u64) -> u64 {
+        self.of = 0;
+        self.err = 0;
+        result
+    }
+
+    fn wide_binary_op(
+        &mut self,
+        op: BinaryOpKind,
+        lhs: &U256,
+        rhs: &RuntimeValue,
+    ) -> Result<U256, Halt> {
+        let rhs = match (op, rhs) {
+            (BinaryOpKind::Lsh, rhs) => return Ok(shift_left(lhs, word(rhs)?)),
+            (BinaryOpKind::Rsh, rhs) => return Ok(lhs.shr(&word(rhs)?)),
+            (_, RuntimeValue::U256(rhs) | RuntimeValue::B256(rhs)) => rhs,
+            (_, rhs) => {
+                return Err(
+                    InterpreterError::InvalidValue(format!("expected a u256, found {rhs}")).into(),
+                )
+            }
+        };
+        match op {
+            BinaryOpKind::Add => self.wide_overflowing(lhs.checked_add(rhs)),
+            BinaryOpKind::Sub => self.wide_overflowing(lhs.checked_sub(rhs)),
+            BinaryOpKind::Mul => self.wide_overflowing(lhs.checked_mul(rhs)),
+            BinaryOpKind::Div => self.wide_fallible(lhs.checked_div(rhs)),
+            BinaryOpKind::Mod => self.wide_fallible(lhs.checked_rem(rhs)),
+            BinaryOpKind::And => Ok(lhs & rhs),
+            BinaryOpKind::Or => Ok(lhs | rhs),
+            BinaryOpKind::Xor => Ok(lhs ^ rhs),
+            BinaryOpKind::Lsh | BinaryOpKind::Rsh => unreachable!("shifts handled above"),
+            BinaryOpKind::SAdd
+            | BinaryOpKind::SSub
+            | BinaryOpKind::SMul
+            | BinaryOpKind::SDiv
+            | BinaryOpKind::SMod
+            | BinaryOpKind::SRsh => Err(InterpreterError::InvalidValue(format!(
+                "signed operations are not supported on u256, found {op:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn wide_modular_op(
+        &mut self,
+        op: BinaryOpKind,
+        lhs: &U256,
+        rhs: &U256,
+        modulus: &U256,
+    ) -> Result<U256, Halt> {
+        let Some(lhs) = lhs.checked_rem(modulus) else {
+            return self.wide_fallible(None);
+        };
+        let rhs = rhs.checked_rem(modulus).unwrap();
+        let result = match op {
+            BinaryOpKind::Add => add_mod(&lhs, &rhs, modulus),
+            BinaryOpKind::Mul => {
+                // Double and add, as the product may not fit in 256 bits.
+                (0..256u64).rev().fold(U256::from(0), |acc, bit| {
+                    let acc = add_mod(&acc, &acc, modulus);
+                    if &rhs.shr(&bit) & &U256::from(1) == U256::from(1) {
+                        add_mod(&acc, &lhs, modulus)
+                    } else {
+                        acc
+                    }
+                })
+            }
+            _ => {
+                return Err(InterpreterError::UnsupportedInstruction(format!(
+                    "wide modular {op:?}"
+                ))
+                .into())
+            }
+        };
+        self.wide_fallible(Some(result))
+    }
+
+    fn wide_overflowing(&mut self, result: Option<U256>) -> Result<U256, Halt> {
+        match result {
+            Some(result) => {
+                self.of = 0;
+                self.err = 0;
+                Ok(result)
+            }
+            None if self.flag & FLAG_WRAPPING != 0 => Err(
+                InterpreterError::UnsupportedInstruction("wrapping u256 arithmetic".into()).into(),
+            ),
+            None => Err(Halt::Panic(PanicReason::ArithmeticOverflow)),
+        }
+    }
+
+    fn wide_fallible(&mut self, result: Option<U256>) -> Result<U256, Halt> {
+        if result.is_none() && self.flag & FLAG_UNSAFE_MATH == 0 {
+            return Err(Halt::Panic(PanicReason::ArithmeticError));
+        }
+        self.of = 0;
+        self.err = result.is_none() as u64;
+        Ok(result.unwrap_or_else(|| U256::from(0)))
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // Memory.
+
+    /// The range of memory at `addr`, which must be on the stack or the heap.
+    fn range(&self, addr: u64, len: u64) -> Result<std::ops::Range<usize>, Halt> {
+        if len == 0 {
+            return Ok(0..0);
+        }
+        let end = addr
+            .checked_add(len)
+            .ok_or(Halt::Panic(PanicReason::MemoryOverflow))?;
+        let is_on_stack = STACK_START <= addr && end <= self.sp;
+        let is_on_heap = self.hp <= addr && end <= MEMORY_SIZE;
+        if is_on_stack || is_on_heap {
+            Ok(addr as usize..end as usize)
+        } else {
+            Err(Halt::Panic(PanicReason::MemoryOverflow))
+        }
+    }
+
+    fn read_bytes(&self, addr: u64, len: u64) -> Result<Vec<u8>, Halt> {
+        Ok(self.memory[self.range(addr, len)?].to_vec())
+    }
+
+    fn write_bytes(&mut self, addr: u64, bytes: &[u8]) -> Result<(), Halt> {
+        let range = self.range(addr, bytes.len() as u64)?;
+        self.memory[range].copy_from_slice(bytes);
+        Ok(())
+    }
+
+    fn read_value(&self, ty: Type, addr: u64) -> Result<RuntimeValue, Halt> {
+        let bytes = self.read_bytes(addr, ty.size_in_bytes(self.context))?;
+        Ok(RuntimeValue::from_bytes(self.context, ty, &bytes))
+    }
+
+    fn write_value(&mut self, ty: Type, addr: u64, value: &RuntimeValue) -> Result<(), Halt> {
+        let bytes = value.to_bytes(self.context, ty);
+        self.write_bytes(addr, &bytes)
+    }
+
+    fn read_u256(&self, addr: u64) -> Result<U256, Halt> {
+        let bytes = self.read_bytes(addr, 32)?;
+        Ok(U256::from_be_bytes(bytes.as_slice().try_into().unwrap()))
+    }
+
+    fn write_u256(&mut self, addr: u64, value: U256) -> Result<(), Halt> {
+        self.write_bytes(addr, &value.to_be_bytes())
+    }
+
+    fn copy_memory(&mut self, dst: u64, src: u64, len: u64) -> Result<(), Halt> {
+        let src_range = self.range(src, len)?;
+        let dst_range = self.range(dst, len)?;
+        if len != 0 && dst < src + len && src < dst + len {
+            return Err(Halt::Panic(PanicReason::MemoryWriteOverlap));
+        }
+        self.memory.copy_within(src_range, dst_range.start);
+        Ok(())
+    }
+
+    /// Allocate zeroed memory on the stack, rounded up to a whole number of words.
+    fn alloc_stack(&mut self, size: u64) -> Result<u64, Halt> {
+        let addr = self.sp;
+        let sp = addr
+            .checked_add(crate::size_bytes_round_up_to_word_alignment!(size))
+            .filter(|sp| *sp <= self.hp)
+            .ok_or(Halt::Panic(PanicReason::MemoryOverflow))?;
+        self.memory[addr as usize..sp as usize].fill(0);
+        self.sp = sp;
+        Ok(addr)
+    }
+
+    /// Allocate memory on the heap, as by `aloc`.  Memory is never reused, so it's already zeroed.
+    fn alloc_heap(&mut self, size: u64) -> Result<(), Halt> {
+        self.hp = self
+            .hp
+            .checked_sub(size)
+            .filter(|hp| *hp >= self.sp)
+            .ok_or(Halt::Panic(PanicReason::MemoryOverflow))?;
+        Ok(())
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // Storage, with the results of the VM opcodes.
+
+    fn state_key(&self, key_addr: u64, idx: u64) -> Result<[u8; 32], Halt> {
+        self.read_u256(key_addr)?
+            .checked_add(&U256::from(idx))
+            .map(|key| key.to_be_bytes())
+            .ok_or_else(|| InterpreterError::InvalidValue("storage key overflow".into()).into())
+    }
+
+    /// Returns the first word of the slot and whether it was set.
+    fn state_read_word(&self, key_addr: u64) -> Result<(u64, bool), Halt> {
+        let key = self.state_key(key_addr, 0)?;
+        Ok(match self.storage.get(&key) {
+            Some(slot) => (u64::from_be_bytes(slot[..8].try_into().unwrap()), true),
+            None => (0, false),
+        })
+    }
+
+    /// Returns whether the slot was already set.
+    fn state_write_word(&mut self, key_addr: u64, value: u64) -> Result<bool, Halt> {
+        let key = self.state_key(key_addr, 0)?;
+        let mut slot = [0; 32];
+        slot[..8].copy_from_slice(&value.to_be_bytes());
+        Ok(self.storage.insert(key, slot).is_some())
+    }
+
+    /// Returns whether all of the slots were set.
+    fn state_read_quad(&mut self, dst: u64, key_addr: u64, num_slots: u64) -> Result<bool, Halt> {
+        let mut all_set = true;
+        for idx in 0..num_slots {
+            let key = self.state_key(key_addr, idx)?;
+            let slot = self.storage.get(&key).copied().unwrap_or_else(|| {
+                all_set = false;
+                [0; 32]
+            });
+            self.write_bytes(dst + idx * 32, &slot)?;
+        }
+        Ok(all_set)
+    }
+
+    /// Returns whether any of the slots weren't already set.
+    fn state_write_quad(&mut self, key_addr: u64, src: u64, num_slots: u64) -> Result<bool, Halt> {
+        let mut any_unset = false;
+        for idx in 0..num_slots {
+            let key = self.state_key(key_addr, idx)?;
+            let slot = self.read_bytes(src + idx * 32, 32)?.try_into().unwrap();
+            any_unset |= self.storage.insert(key, slot).is_none();
+        }
+        Ok(any_unset)
+    }
+
+    /// Returns whether all of the slots were set.
+    fn state_clear(&mut self, key_addr: u64, num_slots: u64) -> Result<bool, Halt> {
+        let mut all_set = true;
+        for idx in 0..num_slots {
+            let key = self.state_key(key_addr, idx)?;
+            all_set &= self.storage.remove(&key).is_some();
+        }
+        Ok(all_set)
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // ASM blocks.
+
+    fn asm_block(
+        &mut self,
+        function: Function,
+        frame: &Frame,
+        asm: &AsmBlock,
+        args: &[AsmArg],
+    ) -> Result<RuntimeValue, Halt> {
+        let context = self.context;
+        let mut registers = FxHashMap::default();
+        for arg in args {
+            let reg_val = match arg.initializer {
+                None => 0,
+                Some(initializer) => {
+                    let value = self.value(function, frame, initializer)?;
+                    match value.to_word() {
+                        Some(word) => word,
+                        None => {
+                            // Values which don't fit in a register are passed by reference.
+                            let ty = initializer.get_type(context).unwrap();
+                            let addr = self.alloc_stack(ty.size_in_bytes(context))?;
+                            self.write_value(ty, addr, &value)?;
+                            addr
+                        }
+                    }
+                }
+            };
+            registers.insert(arg.name.as_str().to_owned(), reg_val);
+        }
+
+        for op in &asm.body {
+            self.count_step()?;
+            self.asm_op(&mut registers, op)?;
+        }
+
+        let ret_val = match &asm.return_name {
+            Some(name) => self.read_register(&registers, name)?,
+            None => 0,
+        };
+        if is_word_type(context, asm.return_type) {
+            Ok(RuntimeValue::from_word(context, asm.return_type, ret_val))
+        } else {
+            self.read_value(asm.return_type, ret_val)
+        }
+    }
+
+    fn read_register(
+        &self,
+        registers: &FxHashMap<String, u64>,
+        name: &Ident,
+    ) -> Result<u64, InterpreterError> {
+        match name.as_str() {
+            "zero" => Ok(0),
+            "one" => Ok(1),
+            "of" => Ok(self.of),
+            "err" => Ok(self.err),
+            "flag" => Ok(self.flag),
+            "hp" => Ok(self.hp),
+            "sp" => Ok(self.sp),
+            name => registers
+                .get(name)
+                .copied()
+                .ok_or_else(|| InterpreterError::UnsupportedRegister(name.to_owned())),
+        }
+    }
+
+    fn asm_op(
+        &mut self,
+        registers: &mut FxHashMap<String, u64>,
+        op: &AsmInstruction,
+    ) -> Result<(), Halt> {
+        let reg = |this: &Self, idx: usize| -> Result<u64, InterpreterError> {
+            this.read_register(registers, asm_op_arg(op, idx)?)
+        };
+        let imm = || -> Result<u64, InterpreterError> {
+            op.immediate
+                .as_ref()
+                .and_then(|imm| imm.as_str().strip_prefix('i')?.parse().ok())
+                .ok_or_else(|| {
+                    InterpreterError::InvalidAsm(format!("'{}' requires an immediate", op.op_name))
+                })
+        };
+
+        let alu_op = |name: &str| match name {
+            "add" => Some(BinaryOpKind::Add),
+            "sub" => Some(BinaryOpKind::Sub),
+            "mul" => Some(BinaryOpKind::Mul),
+            "div" => Some(BinaryOpKind::Div),
+            "mod" => Some(BinaryOpKind::Mod),
+            "and" => Some(BinaryOpKind::And),
+            "or" => Some(BinaryOpKind::Or),
+            "xor" => Some(BinaryOpKind::Xor),
+            "sll" => Some(BinaryOpKind::Lsh),
+            "srl" => Some(BinaryOpKind::Rsh),
+            _ => None,
+        };
+
+        let op_name = op.op_name.as_str();
+        let result = match op_name {
+            "move" => Some(reg(self, 1)?),
+            "not" => {
+                let value = !reg(self, 1)?;
+                Some(self.set(value))
+            }
+            "eq" | "gt" | "lt" => {
+                let (lhs, rhs) = (reg(self, 1)?, reg(self, 2)?);
+                let result = match op_name {
+                    "eq" => lhs == rhs,
+                    "gt" => lhs > rhs,
+                    _ => lhs < rhs,
+                };
+                Some(self.set(result as u64))
+            }
+            "exp" | "expi" => {
+                let base = reg(self, 1)?;
+                let exponent = if op_name == "exp" {
+                    reg(self, 2)?
+                } else {
+                    imm()?
+                };
+                Some(self.exp(base, exponent)?)
+            }
+            "lw" => {
+                let addr = reg(self, 1)?.wrapping_add(imm()? * 8);
+                let bytes = self.read_bytes(addr, 8)?;
+                Some(u64::from_be_bytes(bytes.try_into().unwrap()))
+            }
+            "lb" => {
+                let addr = reg(self, 1)?.wrapping_add(imm()?);
+                Some(self.read_bytes(addr, 1)?[0] as u64)
+            }
+            "sw" => {
+                let addr = reg(self, 0)?.wrapping_add(imm()? * 8);
+                self.write_bytes(addr, &reg(self, 1)?.to_be_bytes())?;
+                None
+            }
+            "sb" => {
+                let addr = reg(self, 0)?.wrapping_add(imm()?);
+                self.write_bytes(addr, &[reg(self, 1)? as u8])?;
+                None
+            }
+            "mcp" | "mcpi" => {
+                let len = if op_name == "mcp" {
+                    reg(self, 2)?
+                } else {
+                    imm()?
+                };
+                self.copy_memory(reg(self, 0)?, reg(self, 1)?, len)?;
+                None
+            }
+            "mcl" | "mcli" => {
+                let len = if op_name == "mcl" {
+                    reg(self, 1)?
+                } else {
+                    imm()?
+                };
+                let range = self.range(reg(self, 0)?, len)?;
+                self.memory[range].fill(0);
+                None
+            }
+            "meq" => {
+                let len = reg(self, 3)?;
+                let lhs = self.read_bytes(reg(self, 1)?, len)?;
+                let rhs = self.read_bytes(reg(self, 2)?, len)?;
+                Some((lhs == rhs) as u64)
+            }
+            "aloc" => {
+                self.alloc_heap(reg(self, 0)?)?;
+                None
+            }
+            "flag" => {
+                self.flag = reg(self, 0)?;
+                None
+            }
+            "rvrt" => return Err(Halt::Revert(reg(self, 0)?)),
+            "log" => {
+                let data = reg(self, 0)?.to_be_bytes().to_vec();
+                self.logs.push(LogEntry {
+                    id: reg(self, 1)?,
+                    data,
+                });
+                None
+            }
+            "logd" => {
+                let data = self.read_bytes(reg(self, 2)?, reg(self, 3)?)?;
+                self.logs.push(LogEntry {
+                    id: reg(self, 1)?,
+                    data,
+                });
+                None
+            }
+            "srw" => {
+                let (value, was_set) = self.state_read_word(reg(self, 2)?)?;
+                asm_write_register(registers, op, 1, was_set as u64)?;
+                Some(value)
+            }
+            "sww" => {
+                let was_set = self.state_write_word(reg(self, 0)?, reg(self, 2)?)?;
+                asm_write_register(registers, op, 1, was_set as u64)?;
+                None
+            }
+            "srwq" => {
+                let all_set = self.state_read_quad(reg(self, 0)?, reg(self, 2)?, reg(self, 3)?)?;
+                asm_write_register(registers, op, 1, all_set as u64)?;
+                None
+            }
+            "swwq" => {
+                let any_unset =
+                    self.state_write_quad(reg(self, 0)?, reg(self, 2)?, reg(self, 3)?)?;
+                asm_write_register(registers, op, 1, any_unset as u64)?;
+                None
+            }
+            "scwq" => {
+                let all_set = self.state_clear(reg(self, 0)?, reg(self, 2)?)?;
+                asm_write_register(registers, op, 1, all_set as u64)?;
+                None
+            }
+            name => match (alu_op(name), name.strip_suffix('i').and_then(alu_op)) {
+                (Some(alu_op), _) => {
+                    let (lhs, rhs) = (reg(self, 1)?, reg(self, 2)?);
+                    Some(self.alu(alu_op, lhs, rhs)?)
+                }
+                (None, Some(alu_op)) => {
+                    let lhs = reg(self, 1)?;
+                    Some(self.alu(alu_op, lhs, imm()?)?)
+                }
+                (None, None) => {
+                    return Err(InterpreterError::UnsupportedAsmOp(name.to_owned()).into())
+                }
+            },
+        };
+        if let Some(result) = result {
+            asm_write_register(registers, op, 0, result)?;
+        }
+        Ok(())
+    }
+}
+
+fn asm_op_arg(op: &AsmInstruction, idx: usize) -> Result<&Ident, InterpreterError> {
+    op.args.get(idx).ok_or_else(|| {
+        InterpreterError::InvalidAsm(format!(
+            "'{}' requires at least {} registers",
+            op.op_name,
+            idx + 1
+        ))
+    })
+}
+
+/// Set the register which is the `idx`th argument of `op`.  Only the registers of the ASM block
+/// may be written.
+fn asm_write_register(
+    registers: &mut FxHashMap<String, u64>,
+    op: &AsmInstruction,
+    idx: usize,
+    value: u64,
+) -> Result<(), InterpreterError> {
+    let name = asm_op_arg(op, idx)?.as_str();
+    match registers.get_mut(name) {
+        Some(reg) => {
+            *reg = value;
+            Ok(())
+        }
+        None => Err(InterpreterError::UnsupportedRegister(name.to_owned())),
+    }
+}
+
+fn word(value: &RuntimeValue) -> Result<u64, Halt> {
+    value.to_word().ok_or_else(|| {
+        InterpreterError::InvalidValue(format!("expected a word, found {value}")).into()
+    })
+}
+
+fn compare(pred: Predicate, lhs: &RuntimeValue, rhs: &RuntimeValue) -> Result<bool, Halt> {
+    let ordering = match (lhs, rhs) {
+        (
+            RuntimeValue::U256(lhs) | RuntimeValue::B256(lhs),
+            RuntimeValue::U256(rhs) | RuntimeValue::B256(rhs),
+        ) => lhs.cmp(rhs),
+        (RuntimeValue::Bytes(lhs), RuntimeValue::Bytes(rhs)) if pred == Predicate::Equal => {
+            lhs.cmp(rhs)
+        }
+        (lhs, rhs) if matches!(pred, Predicate::SLessThan | Predicate::SGreaterThan) => {
+            (word(lhs)? as i64).cmp(&(word(rhs)? as i64))
+        }
+        (lhs, rhs) => word(lhs)?.cmp(&word(rhs)?),
+    };
+    Ok(match pred {
+        Predicate::Equal => ordering.is_eq(),
+        Predicate::LessThan | Predicate::SLessThan => ordering.is_lt(),
+        Predicate::GreaterThan | Predicate::SGreaterThan => ordering.is_gt(),
+    })
+}
+
+/// Shift left, losing the bits shifted out of the top.
+fn shift_left(value: &U256, amount: u64) -> U256 {
+    let bytes = value.to_be_bytes();
+    let mut shifted = [0; 32];
+    if amount < 256 {
+        let (byte_shift, bit_shift) = ((amount / 8) as usize, amount % 8);
+        for idx in 0..32 - byte_shift {
+            let high = bytes[idx + byte_shift] << bit_shift;
+            let low = match bytes.get(idx + byte_shift + 1) {
+                Some(next) if bit_shift != 0 => next >> (8 - bit_shift),
+                _ => 0,
+            };
+            shifted[idx] = high | low;
+        }
+    }
+    U256::from_be_bytes(&shifted)
+}
+
+/// Add `lhs` and `rhs` modulo `modulus`, which both are less than, without overflowing.
+fn add_mod(lhs: &U256, rhs: &U256, modulus: &U256) -> U256 {
+    let gap = modulus.checked_sub(rhs).unwrap();
+    match lhs.checked_sub(&gap) {
+        Some(wrapped) => wrapped,
+        None => lhs.checked_add(rhs).unwrap(),
+    }
+}
@@ -53,6 +53,7 @@ pub mod function;
 pub use function::*;
 pub mod instruction;
 pub use instruction::*;
+pub mod interpreter;
 pub mod irtype;
 pub use irtype::*;
 pub mod metadata;
@@ -21,8 +21,14 @@ pub mod constants;
 pub use constants::*;
 pub mod dce;
 pub use dce::*;
+pub mod dse;
+pub use dse::*;
+pub mod gvn;
+pub use gvn::*;
 pub mod inline;
 pub use inline::*;
+pub mod licm;
+pub use licm::*;
 pub mod mem2reg;
 pub use mem2reg::*;
 pub mod memcpyopt;
@@ -31,6 +37,10 @@ pub mod misc_demotion;
 pub use misc_demotion::*;
 pub mod ret_demotion;
 pub use ret_demotion::*;
+pub mod sccp;
+pub use sccp::*;
+pub mod storageopt;
+pub use storageopt::*;
 pub mod simplify_cfg;
 pub use simplify_cfg::*;
 pub mod sroa;
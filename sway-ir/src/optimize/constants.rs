@@ -11,7 +11,8 @@ use crate::{
     function::Function,
     instruction::InstOp,
     value::ValueDatum,
-    AnalysisResults, BranchToWithArgs, Instruction, Pass, PassMutability, Predicate, ScopedPass,
+    AnalysisResults, BinaryOpKind, BranchToWithArgs, Instruction, Pass, PassMutability, Predicate,
+    ScopedPass, UnaryOpKind,
 };
 
 pub const CONSTCOMBINE_NAME: &str = "constcombine";
@@ -130,37 +131,7 @@ fn combine_cmp(context: &mut Context, function: &Function) -> bool {
                 }) if val1.is_constant(context) && val2.is_constant(context) => {
                     let val1 = val1.get_constant(context).unwrap();
                     let val2 = val2.get_constant(context).unwrap();
-
-                    use ConstantValue::*;
-                    match pred {
-                        Predicate::Equal => Some((inst_val, block, val1.eq(context, val2))),
-                        Predicate::GreaterThan => {
-                            let r = match (&val1.value, &val2.value) {
-                                (Uint(val1), Uint(val2)) => val1 > val2,
-                                (U256(val1), U256(val2)) => val1 > val2,
-                                (B256(val1), B256(val2)) => val1 > val2,
-                                _ => {
-                                    unreachable!(
-                                        "Type checker allowed non integer value for GreaterThan"
-                                    )
-                                }
-                            };
-                            Some((inst_val, block, r))
-                        }
-                        Predicate::LessThan => {
-                            let r = match (&val1.value, &val2.value) {
-                                (Uint(val1), Uint(val2)) => val1 < val2,
-                                (U256(val1), U256(val2)) => val1 < val2,
-                                (B256(val1), B256(val2)) => val1 < val2,
-                                _ => {
-                                    unreachable!(
-                                        "Type checker allowed non integer value for GreaterThan"
-                                    )
-                                }
-                            };
-                            Some((inst_val, block, r))
-                        }
-                    }
+                    Some((inst_val, block, fold_cmp(context, pred, val1, val2)))
                 }
                 _ => None,
             },
@@ -188,45 +159,7 @@ fn combine_binary_op(context: &mut Context, function: &Function) -> bool {
                 }) if arg1.is_constant(context) && arg2.is_constant(context) => {
                     let val1 = arg1.get_constant(context).unwrap();
                     let val2 = arg2.get_constant(context).unwrap();
-                    use crate::BinaryOpKind::*;
-                    use ConstantValue::*;
-                    let v = match (op, &val1.value, &val2.value) {
-                        (Add, Uint(l), Uint(r)) => l.checked_add(*r).map(Uint),
-                        (Add, U256(l), U256(r)) => l.checked_add(r).map(U256),
-
-                        (Sub, Uint(l), Uint(r)) => l.checked_sub(*r).map(Uint),
-                        (Sub, U256(l), U256(r)) => l.checked_sub(r).map(U256),
-
-                        (Mul, Uint(l), Uint(r)) => l.checked_mul(*r).map(Uint),
-                        (Mul, U256(l), U256(r)) => l.checked_mul(r).map(U256),
-
-                        (Div, Uint(l), Uint(r)) => l.checked_div(*r).map(Uint),
-                        (Div, U256(l), U256(r)) => l.checked_div(r).map(U256),
-
-                        (And, Uint(l), Uint(r)) => Some(Uint(l & r)),
-                        (And, U256(l), U256(r)) => Some(U256(l & r)),
-
-                        (Or, Uint(l), Uint(r)) => Some(Uint(l | r)),
-                        (Or, U256(l), U256(r)) => Some(U256(l | r)),
-
-                        (Xor, Uint(l), Uint(r)) => Some(Uint(l ^ r)),
-                        (Xor, U256(l), U256(r)) => Some(U256(l ^ r)),
-
-                        (Mod, Uint(l), Uint(r)) => Some(Uint(l % r)),
-                        (Mod, U256(l), U256(r)) => Some(U256(l % r)),
-
-                        (Rsh, Uint(l), Uint(r)) => u32::try_from(*r)
-                            .ok()
-                            .and_then(|r| l.checked_shr(r).map(Uint)),
-                        (Rsh, U256(l), Uint(r)) => Some(U256(l.shr(r))),
-
-                        (Lsh, Uint(l), Uint(r)) => u32::try_from(*r)
-                            .ok()
-                            .and_then(|r| l.checked_shl(r).map(Uint)),
-                        (Lsh, U256(l), Uint(r)) => l.checked_shl(r).map(U256),
-                        _ => None,
-                    };
-                    v.map(|value| (inst_val, block, Constant { ty: val1.ty, value }))
+                    fold_binary_op(op, val1, val2).map(|new_value| (inst_val, block, new_value))
                 }
                 _ => None,
             },
@@ -250,23 +183,7 @@ fn combine_unary_op(context: &mut Context, function: &Function) -> bool {
                     ..
                 }) if arg.is_constant(context) => {
                     let val = arg.get_constant(context).unwrap();
-                    use crate::UnaryOpKind::*;
-                    use ConstantValue::*;
-                    let v = match (op, &val.value) {
-                        (Not, Uint(v)) => val.ty.get_uint_width(context).and_then(|width| {
-                            let max = match width {
-                                8 => u8::MAX as u64,
-                                16 => u16::MAX as u64,
-                                32 => u32::MAX as u64,
-                                64 => u64::MAX,
-                                _ => return None,
-                            };
-                            Some(Uint((!v) & max))
-                        }),
-                        (Not, U256(v)) => Some(U256(!v)),
-                        _ => None,
-                    };
-                    v.map(|value| (inst_val, block, Constant { ty: val.ty, value }))
+                    fold_unary_op(context, op, val).map(|new_value| (inst_val, block, new_value))
                 }
                 _ => None,
             },
@@ -280,6 +197,131 @@ fn combine_unary_op(context: &mut Context, function: &Function) -> bool {
     })
 }
 
+/// Evaluate a comparison of two constants.
+pub(crate) fn fold_cmp(
+    context: &Context,
+    pred: &Predicate,
+    val1: &Constant,
+    val2: &Constant,
+) -> bool {
+    use ConstantValue::*;
+    match pred {
+        Predicate::Equal => val1.eq(context, val2),
+        Predicate::GreaterThan => match (&val1.value, &val2.value) {
+            (Uint(val1), Uint(val2)) => val1 > val2,
+            (U256(val1), U256(val2)) => val1 > val2,
+            (B256(val1), B256(val2)) => val1 > val2,
+            _ => {
+                unreachable!("Type checker allowed non integer value for GreaterThan")
+            }
+        },
+        Predicate::LessThan => match (&val1.value, &val2.value) {
+            (Uint(val1), Uint(val2)) => val1 < val2,
+            (U256(val1), U256(val2)) => val1 < val2,
+            (B256(val1), B256(val2)) => val1 < val2,
+            _ => {
+                unreachable!("Type checker allowed non integer value for GreaterThan")
+            }
+        },
+        Predicate::SGreaterThan => match (&val1.value, &val2.value) {
+            (Uint(val1), Uint(val2)) => (*val1 as i64) > (*val2 as i64),
+            _ => {
+                unreachable!("Type checker allowed non u64 value for SGreaterThan")
+            }
+        },
+        Predicate::SLessThan => match (&val1.value, &val2.value) {
+            (Uint(val1), Uint(val2)) => (*val1 as i64) < (*val2 as i64),
+            _ => {
+                unreachable!("Type checker allowed non u64 value for SLessThan")
+            }
+        },
+    }
+}
+
+/// Evaluate a binary operation on two constants, unless it overflows or is otherwise undefined.
+pub(crate) fn fold_binary_op(
+    op: &BinaryOpKind,
+    val1: &Constant,
+    val2: &Constant,
+) -> Option<Constant> {
+    use BinaryOpKind::*;
+    use ConstantValue::*;
+    let v = match (op, &val1.value, &val2.value) {
+        (Add, Uint(l), Uint(r)) => l.checked_add(*r).map(Uint),
+        (Add, U256(l), U256(r)) => l.checked_add(r).map(U256),
+
+        (Sub, Uint(l), Uint(r)) => l.checked_sub(*r).map(Uint),
+        (Sub, U256(l), U256(r)) => l.checked_sub(r).map(U256),
+
+        (Mul, Uint(l), Uint(r)) => l.checked_mul(*r).map(Uint),
+        (Mul, U256(l), U256(r)) => l.checked_mul(r).map(U256),
+
+        (Div, Uint(l), Uint(r)) => l.checked_div(*r).map(Uint),
+        (Div, U256(l), U256(r)) => l.checked_div(r).map(U256),
+
+        (And, Uint(l), Uint(r)) => Some(Uint(l & r)),
+        (And, U256(l), U256(r)) => Some(U256(l & r)),
+
+        (Or, Uint(l), Uint(r)) => Some(Uint(l | r)),
+        (Or, U256(l), U256(r)) => Some(U256(l | r)),
+
+        (Xor, Uint(l), Uint(r)) => Some(Uint(l ^ r)),
+        (Xor, U256(l), U256(r)) => Some(U256(l ^ r)),
+
+        (Mod, Uint(l), Uint(r)) => l.checked_rem(*r).map(Uint),
+        (Mod, U256(l), U256(r)) => l.checked_rem(r).map(U256),
+
+        (Rsh, Uint(l), Uint(r)) => u32::try_from(*r)
+            .ok()
+            .and_then(|r| l.checked_shr(r).map(Uint)),
+        (Rsh, U256(l), Uint(r)) => Some(U256(l.shr(r))),
+
+        (Lsh, Uint(l), Uint(r)) => u32::try_from(*r)
+            .ok()
+            .and_then(|r| l.checked_shl(r).map(Uint)),
+        (Lsh, U256(l), Uint(r)) => l.checked_shl(r).map(U256),
+
+        // Signed operations wrap, so only a zero divisor prevents folding.
+        (SAdd, Uint(l), Uint(r)) => Some(Uint((*l as i64).wrapping_add(*r as i64) as u64)),
+        (SSub, Uint(l), Uint(r)) => Some(Uint((*l as i64).wrapping_sub(*r as i64) as u64)),
+        (SMul, Uint(l), Uint(r)) => Some(Uint((*l as i64).wrapping_mul(*r as i64) as u64)),
+        (SDiv, Uint(l), Uint(r)) => {
+            (*r != 0).then(|| Uint((*l as i64).wrapping_div(*r as i64) as u64))
+        }
+        (SMod, Uint(l), Uint(r)) => {
+            (*r != 0).then(|| Uint((*l as i64).wrapping_rem(*r as i64) as u64))
+        }
+        (SRsh, Uint(l), Uint(r)) => Some(Uint(((*l as i64) >> (*r).min(63)) as u64)),
+        _ => None,
+    };
+    v.map(|value| Constant { ty: val1.ty, value })
+}
+
+/// Evaluate a unary operation on a constant.
+pub(crate) fn fold_unary_op(
+    context: &Context,
+    op: &UnaryOpKind,
+    val: &Constant,
+) -> Option<Constant> {
+    use ConstantValue::*;
+    use UnaryOpKind::*;
+    let v = match (op, &val.value) {
+        (Not, Uint(v)) => val.ty.get_uint_width(context).and_then(|width| {
+            let max = match width {
+                8 => u8::MAX as u64,
+                16 => u16::MAX as u64,
+                32 => u32::MAX as u64,
+                64 => u64::MAX,
+                _ => return None,
+            };
+            Some(Uint((!v) & max))
+        }),
+        (Not, U256(v)) => Some(U256(!v)),
+        _ => None,
+    };
+    v.map(|value| Constant { ty: val.ty, value })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::optimize::tests::*;
@@ -343,12 +385,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn signed_binary_op_are_optimized() {
+        let neg = |n: i64| (n as u64).to_string();
+        assert_operator("u64", "sadd", &neg(-1), Some("3"), Some("2"));
+        assert_operator(
+            "u64",
+            "sadd",
+            &neg(i64::MAX),
+            Some("1"),
+            Some(&neg(i64::MIN)),
+        );
+        assert_operator("u64", "ssub", "1", Some("3"), Some(&neg(-2)));
+        assert_operator("u64", "smul", &neg(-3), Some("5"), Some(&neg(-15)));
+        assert_operator("u64", "sdiv", &neg(-7), Some("2"), Some(&neg(-3)));
+        assert_operator("u64", "smod", &neg(-7), Some("2"), Some(&neg(-1)));
+        assert_operator("u64", "srsh", &neg(-16), Some("2"), Some(&neg(-4)));
+        assert_operator("u64", "srsh", &neg(-16), Some("64"), Some(&neg(-1)));
+        assert_operator("u64", "sdiv", "1", Some("0"), None);
+        assert_operator("u64", "smod", "1", Some("0"), None);
+    }
+
     #[test]
     fn binary_op_are_not_optimized() {
         assert_operator("u64", "add", &u64::MAX.to_string(), Some("1"), None);
         assert_operator("u64", "sub", "0", Some("1"), None);
         assert_operator("u64", "mul", &u64::MAX.to_string(), Some("2"), None);
         assert_operator("u64", "div", "1", Some("0"), None);
+        assert_operator("u64", "mod", "1", Some("0"), None);
 
         assert_operator("u64", "rsh", "1", Some("64"), None);
         assert_operator("u64", "lsh", "1", Some("64"), None);
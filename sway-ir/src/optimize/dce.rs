@@ -58,11 +58,14 @@ fn is_removable_store(
         InstOp::MemCopyBytes { dst_val_ptr, .. }
         | InstOp::MemCopyVal { dst_val_ptr, .. }
         | InstOp::Store { dst_val_ptr, .. } => {
+            // A store to a pointer which doesn't refer to any known symbol, e.g., one
+            // created by `int_to_ptr`, may write to any memory and cannot be removed.
             let syms = get_symbols(context, dst_val_ptr);
-            syms.iter().all(|sym| {
-                !escaped_symbols.contains(sym)
-                    && num_symbol_uses.get(sym).map_or(0, |uses| *uses) == 0
-            })
+            !syms.is_empty()
+                && syms.iter().all(|sym| {
+                    !escaped_symbols.contains(sym)
+                        && num_symbol_uses.get(sym).map_or(0, |uses| *uses) == 0
+                })
         }
         _ => false,
     }
@@ -0,0 +1,283 @@
+//! ## Dead Store Elimination
+//!
+//! This optimization removes the stores and copies to local memory which is never read
+//! afterwards, either because it's overwritten first or because the function returns.  DCE only
+//! removes those to locals which are never read at all, while the demotion passes create many
+//! temporaries whose initializing copies are then overwritten.
+//!
+//! The memory which isn't read again is found with a backward data-flow analysis, so that it's
+//! dead at the end of a block when it's dead at the start of all of its successors.  Only locals
+//! which don't escape are considered, and pointers whose symbols can't be determined may read any
+//! of them.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    get_symbols, memory_utils, AnalysisResults, Block, BranchToWithArgs, Context, EscapedSymbols,
+    Function, InstOp, IrError, Pass, PassMutability, PostOrder, ScopedPass, Symbol, Value,
+    ESCAPED_SYMBOLS_NAME, POSTORDER_NAME,
+};
+
+pub const DSE_NAME: &str = "dse";
+
+pub fn create_dse_pass() -> Pass {
+    Pass {
+        name: DSE_NAME,
+        descr: "Dead store elimination.",
+        deps: vec![ESCAPED_SYMBOLS_NAME, POSTORDER_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(dse)),
+    }
+}
+
+/// The local memory which isn't read again from some point of a function.
+#[derive(Clone, PartialEq)]
+struct DeadMemory {
+    /// The locals which aren't read again at all.
+    locals: FxHashSet<Symbol>,
+    /// The ranges of the other locals which are overwritten before they're read again.
+    ranges: FxHashSet<Range>,
+}
+
+/// A range of bytes within a symbol.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Range {
+    symbol: Symbol,
+    offset: u64,
+    len: u64,
+}
+
+/// The memory a pointer may point to.
+struct Pointee {
+    /// The symbols pointed to, which are empty when they can't be determined.
+    symbols: Vec<Symbol>,
+    /// The range pointed to, when it's within a single symbol at a constant offset.
+    range: Option<Range>,
+}
+
+/// How an instruction accesses memory.
+enum MemoryAccess {
+    /// A store or a copy, and the memory copied from.
+    Store { dst: Pointee, src: Option<Pointee> },
+    /// Reads from memory.
+    Reads(Vec<Pointee>),
+}
+
+pub fn dse(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let escaped_symbols: &EscapedSymbols = analyses.get_analysis_result(function);
+    let po: &PostOrder = analyses.get_analysis_result(function);
+
+    let accesses: FxHashMap<Value, MemoryAccess> = function
+        .instruction_iter(context)
+        .filter_map(|(_, inst)| Some((inst, memory_access(context, inst)?)))
+        .collect();
+
+    // All the locals are dead once the function returns.
+    let all_dead = DeadMemory {
+        locals: function
+            .locals_iter(context)
+            .map(|(_, local)| Symbol::Local(*local))
+            .filter(|symbol| !escaped_symbols.contains(symbol))
+            .collect(),
+        ranges: FxHashSet::default(),
+    };
+    // The successors yet to be visited are back edges, which are met once visited.
+    let dead_at_end =
+        |context: &Context, dead_at_start: &FxHashMap<Block, DeadMemory>, block: Block| {
+            let mut dead = all_dead.clone();
+            for BranchToWithArgs { block: succ, .. } in block.successors(context) {
+                if let Some(succ_dead) = dead_at_start.get(&succ) {
+                    dead.meet(succ_dead);
+                }
+            }
+            dead
+        };
+
+    let mut dead_at_start: FxHashMap<Block, DeadMemory> = FxHashMap::default();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for block in &po.po_to_block {
+            let mut dead = dead_at_end(context, &dead_at_start, *block);
+            for inst in block.instruction_iter(context).rev() {
+                if let Some(access) = accesses.get(&inst) {
+                    dead.visit(&all_dead.locals, access);
+                }
+            }
+            if dead_at_start.get(block) != Some(&dead) {
+                dead_at_start.insert(*block, dead);
+                changed = true;
+            }
+        }
+    }
+
+    let mut dead_stores = FxHashSet::default();
+    for block in &po.po_to_block {
+        let mut dead = dead_at_end(context, &dead_at_start, *block);
+        for inst in block.instruction_iter(context).rev() {
+            if let Some(access) = accesses.get(&inst) {
+                if dead.visit(&all_dead.locals, access) {
+                    dead_stores.insert(inst);
+                }
+            }
+        }
+    }
+    if dead_stores.is_empty() {
+        return Ok(false);
+    }
+    for block in function.block_iter(context).collect::<Vec<_>>() {
+        block.remove_instructions(context, |inst| dead_stores.contains(&inst));
+    }
+    Ok(true)
+}
+
+fn memory_access(context: &Context, inst: Value) -> Option<MemoryAccess> {
+    let access = match &inst.get_instruction(context).unwrap().op {
+        InstOp::Store { dst_val_ptr, .. } => {
+            let len = memory_utils::pointee_size(context, *dst_val_ptr);
+            MemoryAccess::Store {
+                dst: Pointee::new(context, *dst_val_ptr, Some(len)),
+                src: None,
+            }
+        }
+        InstOp::MemCopyVal {
+            dst_val_ptr,
+            src_val_ptr,
+        } => {
+            let len = memory_utils::pointee_size(context, *dst_val_ptr);
+            MemoryAccess::Store {
+                dst: Pointee::new(context, *dst_val_ptr, Some(len)),
+                src: Some(Pointee::new(context, *src_val_ptr, Some(len))),
+            }
+        }
+        InstOp::MemCopyBytes {
+            dst_val_ptr,
+            src_val_ptr,
+            byte_len,
+        } => MemoryAccess::Store {
+            dst: Pointee::new(context, *dst_val_ptr, Some(*byte_len)),
+            src: Some(Pointee::new(context, *src_val_ptr, Some(*byte_len))),
+        },
+        InstOp::Load(src_val_ptr) => {
+            let len = memory_utils::pointee_size(context, *src_val_ptr);
+            MemoryAccess::Reads(vec![Pointee::new(context, *src_val_ptr, Some(len))])
+        }
+        _ => {
+            // Values other than pointers may be passed to calls or returned, which don't read
+            // memory.
+            let reads: Vec<_> = memory_utils::get_loaded_ptr_values(context, inst)
+                .into_iter()
+                .filter(|ptr| ptr.get_type(context).is_some_and(|ty| ty.is_ptr(context)))
+                .map(|ptr| Pointee::new(context, ptr, None))
+                .collect();
+            if reads.is_empty() {
+                return None;
+            }
+            MemoryAccess::Reads(reads)
+        }
+    };
+    Some(access)
+}
+
+impl Pointee {
+    /// The memory pointed to by `ptr`, of which `len` bytes are accessed, if known.
+    fn new(context: &Context, ptr: Value, len: Option<u64>) -> Pointee {
+        let symbols: Vec<Symbol> = get_symbols(context, ptr).into_iter().collect();
+        let range = match (symbols.as_slice(), len) {
+            ([symbol], Some(len)) => memory_utils::get_memory_offsets(context, ptr)
+                .get(symbol)
+                .map(|offset| Range {
+                    symbol: *symbol,
+                    offset: *offset,
+                    len,
+                }),
+            _ => None,
+        };
+        Pointee { symbols, range }
+    }
+}
+
+impl DeadMemory {
+    /// Keep only the memory dead both here and in `other`.
+    fn meet(&mut self, other: &DeadMemory) {
+        let ranges = self
+            .ranges
+            .iter()
+            .filter(|range| other.is_dead_range(range))
+            .chain(
+                other
+                    .ranges
+                    .iter()
+                    .filter(|range| self.locals.contains(&range.symbol)),
+            )
+            .copied()
+            .collect();
+        self.ranges = ranges;
+        self.locals.retain(|symbol| other.locals.contains(symbol));
+    }
+
+    /// Update the dead memory before an instruction accessing memory with `access` is executed.
+    /// Returns whether the instruction is a dead store, which is then ignored, as it's removed.
+    ///
+    /// Only the ranges of `tracked_locals` are marked as dead when they're overwritten.
+    fn visit(&mut self, tracked_locals: &FxHashSet<Symbol>, access: &MemoryAccess) -> bool {
+        match access {
+            MemoryAccess::Store { dst, src } => {
+                if self.is_dead(dst) {
+                    return true;
+                }
+                if let Some(range) = dst.range {
+                    if tracked_locals.contains(&range.symbol) {
+                        self.ranges.insert(range);
+                    }
+                }
+                if let Some(src) = src {
+                    self.read(src);
+                }
+            }
+            MemoryAccess::Reads(reads) => {
+                for src in reads {
+                    self.read(src);
+                }
+            }
+        }
+        false
+    }
+
+    /// Whether all of the memory pointed to is dead.
+    fn is_dead(&self, pointee: &Pointee) -> bool {
+        let is_dead_local = !pointee.symbols.is_empty()
+            && pointee
+                .symbols
+                .iter()
+                .all(|symbol| self.locals.contains(symbol));
+        is_dead_local
+            || pointee
+                .range
+                .is_some_and(|range| self.is_dead_range(&range))
+    }
+
+    fn is_dead_range(&self, range: &Range) -> bool {
+        self.locals.contains(&range.symbol) || self.ranges.contains(range)
+    }
+
+    /// Forget the dead memory which may be read from what's pointed to.
+    fn read(&mut self, pointee: &Pointee) {
+        if pointee.symbols.is_empty() {
+            self.locals.clear();
+            self.ranges.clear();
+            return;
+        }
+        self.locals
+            .retain(|symbol| !pointee.symbols.contains(symbol));
+        self.ranges.retain(|range| match pointee.range {
+            Some(read) if read.symbol == range.symbol => {
+                read.offset + read.len <= range.offset || range.offset + range.len <= read.offset
+            }
+            _ => !pointee.symbols.contains(&range.symbol),
+        });
+    }
+}
@@ -0,0 +1,250 @@
+//! ## Global Value Numbering
+//!
+//! This optimization replaces instructions which recompute a value already computed by a
+//! dominating instruction with that value. The pass walks the dominator tree, keeping
+//!   1. A scoped table of the pure expressions available in the current block, i.e. those
+//!      computed by its dominators, keyed by their opcode and value numbered operands,
+//!   2. A table of the values loaded from or stored to memory which haven't since been
+//!      clobbered. These are only carried over to a block when its sole predecessor is its
+//!      immediate dominator, as stores along other paths could clobber them.
+//!
+//! Redundant instructions are removed and their uses replaced with the available value.
+
+use rustc_hash::{FxHashMap, FxHasher};
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    memory_utils, AnalysisResults, BinaryOpKind, Block, Context, DomTree, Function, InstOp,
+    IrError, LocalVar, Pass, PassMutability, Predicate, ScopedPass, Type, UnaryOpKind, Value,
+    DOMINATORS_NAME,
+};
+
+pub const GVN_NAME: &str = "gvn";
+
+pub fn create_gvn_pass() -> Pass {
+    Pass {
+        name: GVN_NAME,
+        descr: "Global value numbering and common subexpression elimination.",
+        deps: vec![DOMINATORS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(gvn)),
+    }
+}
+
+/// A pure expression, with its operands value numbered.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Expr {
+    UnaryOp(UnaryOpKind, Value),
+    BinaryOp(BinaryOpKind, Value, Value),
+    BitCast(Value, Type),
+    CastPtr(Value, Type),
+    Cmp(Predicate, Value, Value),
+    GetLocal(LocalVar),
+    GetElemPtr(Value, Type, Vec<Value>),
+    IntToPtr(Value, Type),
+    PtrToInt(Value, Type),
+}
+
+/// The values loaded from or stored to memory, keyed by the pointer to that memory.
+type AvailableLoads = FxHashMap<Value, Value>;
+
+#[derive(Default)]
+struct ValueNumbering {
+    /// The expressions available in the block being visited.
+    exprs: FxHashMap<Expr, Value>,
+    /// The first of the equal constants encountered, bucketed by their hash.
+    constants: FxHashMap<u64, Vec<Value>>,
+    /// The redundant instructions and the values replacing them.
+    replacements: FxHashMap<Value, Value>,
+}
+
+pub fn gvn(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let dom_tree: &DomTree = analyses.get_analysis_result(function);
+    let mut numbering = ValueNumbering::default();
+    let entry = function.get_entry_block(context);
+    numbering.visit_block(context, dom_tree, entry, AvailableLoads::default());
+
+    if numbering.replacements.is_empty() {
+        return Ok(false);
+    }
+    function.replace_values(context, &numbering.replacements, None);
+    for block in function.block_iter(context).collect::<Vec<_>>() {
+        block.remove_instructions(context, |inst| numbering.replacements.contains_key(&inst));
+    }
+    Ok(true)
+}
+
+impl ValueNumbering {
+    /// Value number the instructions of `block`, then those of the blocks it dominates.
+    fn visit_block(
+        &mut self,
+        context: &Context,
+        dom_tree: &DomTree,
+        block: Block,
+        mut loads: AvailableLoads,
+    ) {
+        let mut scoped_exprs = Vec::new();
+        for inst in block.instruction_iter(context).collect::<Vec<_>>() {
+            let op = inst.get_instruction(context).unwrap().op.clone();
+            if let Some(expr) = self.expr(context, &op) {
+                match self.exprs.get(&expr) {
+                    Some(available) => {
+                        self.replacements.insert(inst, *available);
+                    }
+                    None => {
+                        self.exprs.insert(expr.clone(), inst);
+                        scoped_exprs.push(expr);
+                    }
+                }
+                continue;
+            }
+            match op {
+                InstOp::Load(ptr) => {
+                    let ptr = self.number(context, ptr);
+                    match loads.get(&ptr) {
+                        Some(available) => {
+                            self.replacements.insert(inst, *available);
+                        }
+                        None => {
+                            loads.insert(ptr, inst);
+                        }
+                    }
+                }
+                InstOp::Store {
+                    dst_val_ptr,
+                    stored_val,
+                } => {
+                    let dst_val_ptr = self.number(context, dst_val_ptr);
+                    let len = memory_utils::pointee_size(context, dst_val_ptr);
+                    clobber(context, &mut loads, dst_val_ptr, Some(len));
+                    let stored_val = self.number(context, stored_val);
+                    loads.insert(dst_val_ptr, stored_val);
+                }
+                InstOp::MemCopyBytes {
+                    dst_val_ptr,
+                    byte_len,
+                    ..
+                } => {
+                    let dst_val_ptr = self.number(context, dst_val_ptr);
+                    clobber(context, &mut loads, dst_val_ptr, Some(byte_len));
+                }
+                InstOp::MemCopyVal { dst_val_ptr, .. } => {
+                    let dst_val_ptr = self.number(context, dst_val_ptr);
+                    let len = memory_utils::pointee_size(context, dst_val_ptr);
+                    clobber(context, &mut loads, dst_val_ptr, Some(len));
+                }
+                // Calls and ASM blocks may write to any memory they can reach.
+                InstOp::Call(..) | InstOp::ContractCall { .. } | InstOp::AsmBlock(..) => {
+                    loads.clear()
+                }
+                _ => {
+                    for ptr in memory_utils::get_stored_ptr_values(context, inst) {
+                        let ptr = self.number(context, ptr);
+                        clobber(context, &mut loads, ptr, None);
+                    }
+                }
+            }
+        }
+
+        let children = dom_tree[&block].children.clone();
+        for child in children {
+            // The memory is unchanged on entry to the child only if it can't be reached
+            // through another block.
+            let child_loads = if child.num_predecessors(context) == 1 {
+                loads.clone()
+            } else {
+                AvailableLoads::default()
+            };
+            self.visit_block(context, dom_tree, child, child_loads);
+        }
+
+        for expr in scoped_exprs {
+            self.exprs.remove(&expr);
+        }
+    }
+
+    /// The value number of `value`: the value replacing it, or the first equal constant.
+    fn number(&mut self, context: &Context, value: Value) -> Value {
+        if let Some(replacement) = self.replacements.get(&value) {
+            return *replacement;
+        }
+        let Some(constant) = value.get_constant(context) else {
+            return value;
+        };
+        let mut hasher = FxHasher::default();
+        constant.hash(&mut hasher);
+        let bucket = self.constants.entry(hasher.finish()).or_default();
+        match bucket
+            .iter()
+            .find(|other| other.get_constant(context).unwrap().eq(context, constant))
+        {
+            Some(other) => *other,
+            None => {
+                bucket.push(value);
+                value
+            }
+        }
+    }
+
+    /// The expression computed by `op`, if it is pure.
+    fn expr(&mut self, context: &Context, op: &InstOp) -> Option<Expr> {
+        let expr = match op {
+            InstOp::UnaryOp { op, arg } => Expr::UnaryOp(*op, self.number(context, *arg)),
+            InstOp::BinaryOp { op, arg1, arg2 } => {
+                let (arg1, arg2) = (self.number(context, *arg1), self.number(context, *arg2));
+                let is_commutative = matches!(
+                    op,
+                    BinaryOpKind::Add
+                        | BinaryOpKind::Mul
+                        | BinaryOpKind::And
+                        | BinaryOpKind::Or
+                        | BinaryOpKind::Xor
+                );
+                let (arg1, arg2) = ordered(is_commutative, arg1, arg2);
+                Expr::BinaryOp(*op, arg1, arg2)
+            }
+            InstOp::BitCast(value, ty) => Expr::BitCast(self.number(context, *value), *ty),
+            InstOp::CastPtr(value, ty) => Expr::CastPtr(self.number(context, *value), *ty),
+            InstOp::Cmp(pred, lhs, rhs) => {
+                let (lhs, rhs) = (self.number(context, *lhs), self.number(context, *rhs));
+                let (lhs, rhs) = ordered(*pred == Predicate::Equal, lhs, rhs);
+                Expr::Cmp(*pred, lhs, rhs)
+            }
+            InstOp::GetLocal(local) => Expr::GetLocal(*local),
+            InstOp::GetElemPtr {
+                base,
+                elem_ptr_ty,
+                indices,
+            } => Expr::GetElemPtr(
+                self.number(context, *base),
+                *elem_ptr_ty,
+                indices
+                    .iter()
+                    .map(|index| self.number(context, *index))
+                    .collect(),
+            ),
+            InstOp::IntToPtr(value, ty) => Expr::IntToPtr(self.number(context, *value), *ty),
+            InstOp::PtrToInt(value, ty) => Expr::PtrToInt(self.number(context, *value), *ty),
+            _ => return None,
+        };
+        Some(expr)
+    }
+}
+
+/// Order the operands of commutative operations, so that `a + b` and `b + a` are equal.
+fn ordered(is_commutative: bool, lhs: Value, rhs: Value) -> (Value, Value) {
+    if is_commutative && rhs.0 < lhs.0 {
+        (rhs, lhs)
+    } else {
+        (lhs, rhs)
+    }
+}
+
+/// Forget the available loads which a write of `len` bytes, or an unknown number of bytes, to
+/// `dst_val_ptr` may clobber.
+fn clobber(context: &Context, loads: &mut AvailableLoads, dst_val_ptr: Value, len: Option<u64>) {
+    loads.retain(|ptr, _| !memory_utils::may_clobber(context, dst_val_ptr, len, *ptr));
+}
@@ -2,7 +2,10 @@
 //!
 //! Function inlining is pretty hairy so these passes must be maintained with care.
 
-use std::{cell::RefCell, collections::HashMap};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+};
 
 use rustc_hash::FxHashMap;
 
@@ -16,9 +19,14 @@ use crate::{
     instruction::{FuelVmInstruction, InstOp},
     irtype::Type,
     local_var::LocalVar,
-    metadata::{combine, MetadataIndex},
+    metadata::{combine, MetadataIndex, Metadatum},
+    optimize::target_fuel::{
+        estimate_instruction_count, is_demotable_type, CALL_GAS, CALL_SITE_OVERHEAD,
+        DEMOTED_ARG_OVERHEAD, FUNCTION_OVERHEAD, INSTRUCTION_SIZE,
+    },
     value::{Value, ValueContent, ValueDatum},
-    AnalysisResults, BlockArgument, Instruction, Module, Pass, PassMutability, ScopedPass,
+    AnalysisResults, BlockArgument, Instruction, LoopInfo, Module, Pass, PassMutability,
+    ScopedPass, LOOPS_NAME,
 };
 
 pub const INLINE_MAIN_NAME: &str = "inline_main";
@@ -38,11 +46,30 @@ pub fn create_inline_in_module_pass() -> Pass {
     Pass {
         name: INLINE_MODULE_NAME,
         descr: "inline function calls in a module.",
-        deps: vec![],
+        deps: vec![LOOPS_NAME],
         runner: ScopedPass::ModulePass(PassMutability::Transform(inline_in_module)),
     }
 }
 
+pub const INLINE_MODULE_SIZE_NAME: &str = "inline_module_size";
+
+pub fn create_inline_in_module_for_size_pass() -> Pass {
+    Pass {
+        name: INLINE_MODULE_SIZE_NAME,
+        descr: "inline function calls in a module which don't grow the code size.",
+        deps: vec![],
+        runner: ScopedPass::ModulePass(PassMutability::Transform(inline_in_module_for_size)),
+    }
+}
+
+/// The configuration of the module inlining passes, set with
+/// [PassManager::set_config](crate::PassManager::set_config).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InlineConfig {
+    /// The size in bytes which inlining mustn't grow the estimated bytecode of the program beyond.
+    pub size_budget: Option<u64>,
+}
+
 /// This is a copy of sway_core::inline::Inline.
 /// TODO: Reuse: Depend on sway_core? Move it to sway_types?
 #[derive(Debug)]
@@ -83,10 +110,44 @@ fn metadata_to_inline(context: &Context, md_idx: Option<MetadataIndex>) -> Optio
     })
 }
 
+/// Inline the calls for which the estimated growth of the bytecode is paid for by the gas saved,
+/// within the size budget of the program.
 pub fn inline_in_module(
     context: &mut Context,
-    _: &AnalysisResults,
+    analyses: &AnalysisResults,
+    module: Module,
+) -> Result<bool, IrError> {
+    inline_in_module_with_heuristic(context, analyses, module, false)
+}
+
+/// Inline only the calls for which the estimated bytecode doesn't grow, such as those to functions
+/// which are called once or which are smaller than a call.
+pub fn inline_in_module_for_size(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    module: Module,
+) -> Result<bool, IrError> {
+    inline_in_module_with_heuristic(context, analyses, module, true)
+}
+
+/// The estimated bytecode growth which is allowed for any call to be inlined, in instructions.
+const MAX_INLINE_GROWTH: i64 = 8;
+
+/// The gas which must be saved by inlining a call to allow the bytecode to grow by another
+/// instruction.
+const GAS_PER_INSTRUCTION_GROWTH: u64 = 2;
+
+/// The number of times a loop is assumed to iterate, when estimating the gas used by the calls in
+/// it.
+const LOOP_ITERATIONS: u64 = 8;
+
+/// Inline the calls in a module.  The bytecode may grow when the gas saved pays for it, unless
+/// optimizing for size, when the loops the calls are in aren't analysed.
+fn inline_in_module_with_heuristic(
+    context: &mut Context,
+    analyses: &AnalysisResults,
     module: Module,
+    optimize_for_size: bool,
 ) -> Result<bool, IrError> {
     // Inspect ALL calls and count how often each function is called.
     let call_counts: HashMap<Function, u64> =
@@ -108,51 +169,181 @@ pub fn inline_in_module(
                 counts
             });
 
-    let inline_heuristic = |ctx: &Context, func: &Function, _call_site: &Value| {
-        let attributed_inline = metadata_to_inline(ctx, func.get_metadata(ctx));
-        match attributed_inline {
-            Some(Inline::Always) => {
-                // TODO: check if inlining of function is possible
-                // return true;
+    // The estimated size of the module in instructions, which grows as calls are inlined.
+    let module_size = Cell::new(
+        module
+            .function_iter(context)
+            .map(|func| function_size(context, &func) as i64)
+            .sum::<i64>(),
+    );
+    let size_budget = analyses
+        .get_config::<InlineConfig>()
+        .and_then(|config| config.size_budget)
+        .map(|budget| (budget / INSTRUCTION_SIZE) as i64);
+
+    let cg =
+        call_graph::build_call_graph(context, &module.function_iter(context).collect::<Vec<_>>());
+    let functions = call_graph::callee_first_order(&cg);
+    let mut modified = false;
+
+    for function in functions {
+        // The number of loops each call is nested in.  The function isn't modified until its
+        // calls have all been considered, so its loops are still valid.
+        let call_loop_depths: FxHashMap<Value, u32> = if optimize_for_size {
+            FxHashMap::default()
+        } else {
+            let loops: &LoopInfo = analyses.get_analysis_result(function);
+            function
+                .instruction_iter(context)
+                .filter_map(|(block, inst)| {
+                    let depth = loops.innermost_loop(&block).map(|id| loops[id].depth)?;
+                    Some((inst, depth as u32))
+                })
+                .collect()
+        };
+
+        let inline_heuristic = |ctx: &Context, func: &Function, call_site: &Value| {
+            // If the function is called only once then it's removed once it's inlined.
+            let is_only_call = call_counts.get(func).copied().unwrap_or(0) == 1;
+            let growth = estimate_inline_growth(ctx, func, call_site, is_only_call);
+
+            let attributed_inline = metadata_to_inline(ctx, func.get_metadata(ctx));
+            match attributed_inline {
+                Some(Inline::Always) => {
+                    module_size.set(module_size.get() + growth);
+                    return true;
+                }
+                Some(Inline::Never) => {
+                    return false;
+                }
+                None => {}
             }
-            Some(Inline::Never) => {
+
+            if growth > 0 && size_budget.is_some_and(|budget| module_size.get() + growth > budget) {
                 return false;
             }
-            None => {}
-        }
 
-        // If the function is called only once then definitely inline it.
-        if call_counts.get(func).copied().unwrap_or(0) == 1 {
-            return true;
-        }
+            // Pay for the growth with the gas saved, weighted by how many times the call is
+            // likely to be made.  Calls passing pointers or aggregates are always inlined unless
+            // optimizing for size, as the memory they refer to can then be promoted to registers
+            // or have its copies removed, which the estimates don't account for.
+            // See https://github.com/FuelLabs/sway/pull/4899
+            let max_growth = if optimize_for_size {
+                0
+            } else if has_pointer_or_aggregate_args(ctx, func) {
+                i64::MAX
+            } else {
+                let depth = call_loop_depths.get(call_site).copied().unwrap_or(0);
+                let gas = estimate_call_gas(ctx, call_site)
+                    .saturating_mul(LOOP_ITERATIONS.saturating_pow(depth));
+                MAX_INLINE_GROWTH.saturating_add((gas / GAS_PER_INSTRUCTION_GROWTH) as i64)
+            };
+            if growth > max_growth {
+                return false;
+            }
+            module_size.set(module_size.get() + growth);
+            true
+        };
 
-        // If the function is (still) small then also inline it.
-        const MAX_INLINE_INSTRS_COUNT: usize = 4;
-        if func.num_instructions(ctx) <= MAX_INLINE_INSTRS_COUNT {
-            return true;
-        }
+        modified |= inline_some_function_calls(context, &function, inline_heuristic)?;
+    }
+    Ok(modified)
+}
 
-        // See https://github.com/FuelLabs/sway/pull/4899
-        if func.args_iter(ctx).any(|(_name, arg_val)| {
-            arg_val.get_type(ctx).map_or(false, |ty| {
-                ty.is_ptr(ctx) || !(ty.is_unit(ctx) | ty.is_bool(ctx) | ty.is_uint(ctx))
-            })
-        }) {
-            return true;
-        }
+/// Whether any of the arguments of `func` is a pointer or of an aggregate type.
+fn has_pointer_or_aggregate_args(context: &Context, func: &Function) -> bool {
+    func.args_iter(context).any(|(_name, arg_val)| {
+        arg_val.get_type(context).map_or(false, |ty| {
+            ty.is_ptr(context) || !(ty.is_unit(context) | ty.is_bool(context) | ty.is_uint(context))
+        })
+    })
+}
+
+/// The estimated number of instructions `func` is compiled to, including the instructions to call
+/// it.
+fn function_size(context: &Context, func: &Function) -> u64 {
+    FUNCTION_OVERHEAD
+        + func.num_args(context) as u64
+        + func
+            .instruction_iter(context)
+            .map(|(_block, inst)| estimate_instruction_count(context, &inst))
+            .sum::<u64>()
+}
 
-        false
+/// The estimated growth in instructions of the bytecode once `call_site` to `func` is inlined.
+///
+/// The call and the demotion of its arguments are replaced by the body of the function, while the
+/// instructions using arguments which are constants at the call site may be folded away.
+fn estimate_inline_growth(
+    context: &Context,
+    func: &Function,
+    call_site: &Value,
+    is_only_call: bool,
+) -> i64 {
+    let call_size = estimate_instruction_count(context, call_site)
+        + DEMOTED_ARG_OVERHEAD * demoted_args(context, func);
+    let body_size =
+        function_size(context, func) - FUNCTION_OVERHEAD - func.num_args(context) as u64;
+
+    let args = match call_site.get_instruction(context) {
+        Some(Instruction {
+            op: InstOp::Call(_, args),
+            ..
+        }) => args.as_slice(),
+        _ => &[],
+    };
+    let constant_params: Vec<Value> = func
+        .args_iter(context)
+        .zip(args)
+        .filter_map(|((_name, param), arg)| arg.is_constant(context).then_some(*param))
+        .collect();
+    let folded = if constant_params.is_empty() {
+        0
+    } else {
+        func.instruction_iter(context)
+            .filter(|(_block, inst)| {
+                inst.get_instruction(context).is_some_and(|instruction| {
+                    instruction
+                        .op
+                        .get_operands()
+                        .iter()
+                        .any(|operand| constant_params.contains(operand))
+                })
+            })
+            .count() as u64
     };
 
-    let cg =
-        call_graph::build_call_graph(context, &module.function_iter(context).collect::<Vec<_>>());
-    let functions = call_graph::callee_first_order(&cg);
-    let mut modified = false;
+    let mut growth = body_size as i64 - call_size as i64 - folded as i64;
+    if is_only_call {
+        growth -= function_size(context, func) as i64;
+    }
+    growth
+}
 
-    for function in functions {
-        modified |= inline_some_function_calls(context, &function, inline_heuristic)?;
+/// The estimated gas saved by inlining `call_site`.
+fn estimate_call_gas(context: &Context, call_site: &Value) -> u64 {
+    match call_site.get_instruction(context) {
+        Some(Instruction {
+            op: InstOp::Call(func, args),
+            ..
+        }) => {
+            CALL_GAS
+                + CALL_SITE_OVERHEAD
+                + 2 * args.len() as u64
+                + DEMOTED_ARG_OVERHEAD * demoted_args(context, func)
+        }
+        _ => 0,
     }
-    Ok(modified)
+}
+
+/// The number of arguments of `func` which are demoted to be passed by reference.
+fn demoted_args(context: &Context, func: &Function) -> u64 {
+    func.args_iter(context)
+        .filter(|(_name, arg)| {
+            arg.get_type(context)
+                .is_some_and(|ty| is_demotable_type(context, &ty))
+        })
+        .count() as u64
 }
 
 pub fn inline_in_main(
@@ -363,6 +554,17 @@ pub fn inline_function_call(
     // from the split above.  We'll start appending inlined instructions to that block rather than
     // a new one (with a redundant branch to it from the `pre_block`).
     let inlined_fn_name = inlined_function.get_name(context).to_owned();
+
+    // Tag the inlined instructions with the name of the function they came from, after the call
+    // site metadata.  Profilers can then attribute them to the inlined function, even though it
+    // no longer exists as a function of its own.
+    let inlined_md = MetadataIndex::new_struct(
+        context,
+        "inlined",
+        vec![Metadatum::String(inlined_fn_name.clone())],
+    );
+    let metadata = combine(context, &metadata, &Some(inlined_md));
+
     let mut block_map = HashMap::new();
     let mut block_iter = context.functions[inlined_function.0]
         .blocks
@@ -0,0 +1,158 @@
+//! ## Loop-Invariant Code Motion
+//!
+//! This optimization hoists the instructions of a loop which compute the same value in every
+//! iteration into the preheader of the loop, so that they're only executed once.  Loops are
+//! visited innermost first, so that an instruction may be hoisted out of several nested loops.
+//!
+//! An instruction is invariant if its operands are all defined outside of the loop or are
+//! invariant themselves.  As the preheader is executed even when the loop body isn't, only
+//! instructions which can't fail are hoisted:
+//!   1. Pure computations which can't trap, such as comparisons, bitwise operations and pointer
+//!      arithmetic.  Arithmetic which may overflow or divide by zero is only hoisted from the start
+//!      of the header, which is executed whenever the preheader is.
+//!   2. Loads from locals which nothing in the loop may store to.  Calls and ASM blocks may store
+//!      to any memory, so nothing is loaded before loops containing them.
+//!
+//! Instructions are hoisted in reverse post-order, so that they remain dominated by their operands.
+
+use rustc_hash::FxHashSet;
+
+use crate::{
+    memory_utils, AnalysisResults, BinaryOpKind, Context, Function, InstOp, IrError, LoopId,
+    LoopInfo, Pass, PassMutability, ScopedPass, Symbol, Value, ValueDatum, LOOPS_NAME,
+};
+
+pub const LICM_NAME: &str = "licm";
+
+pub fn create_licm_pass() -> Pass {
+    Pass {
+        name: LICM_NAME,
+        descr: "Loop-invariant code motion.",
+        deps: vec![LOOPS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(licm)),
+    }
+}
+
+pub fn licm(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let loops: &LoopInfo = analyses.get_analysis_result(function);
+    if loops.is_empty() {
+        return Ok(false);
+    }
+    // Inserting preheaders adds them to the enclosing loops, so work on a copy.
+    let mut loops = loops.clone();
+    let loop_ids: Vec<LoopId> = loops.iter().rev().map(|(id, _)| id).collect();
+
+    let mut modified = false;
+    for id in loop_ids {
+        let invariants = invariant_instructions(context, &loops, id);
+        if invariants.is_empty() {
+            continue;
+        }
+        let Some(preheader) = loops.get_or_insert_preheader(context, id) else {
+            continue;
+        };
+        for inst in invariants {
+            let block = inst.get_instruction(context).unwrap().parent;
+            block.remove_instruction(context, inst);
+            inst.get_instruction_mut(context).unwrap().parent = preheader;
+            let preheader_insts = &mut context.blocks[preheader.0].instructions;
+            preheader_insts.insert(preheader_insts.len() - 1, inst);
+        }
+        modified = true;
+    }
+    Ok(modified)
+}
+
+/// The invariant instructions of the loop `id` which can be hoisted, in the order they're found.
+fn invariant_instructions(context: &Context, loops: &LoopInfo, id: LoopId) -> Vec<Value> {
+    let lp = &loops[id];
+
+    // The pointers stored to within the loop and the number of bytes stored, if known.
+    let mut stores = Vec::new();
+    let mut stores_anywhere = false;
+    for block in &lp.blocks {
+        for inst in block.instruction_iter(context) {
+            match &inst.get_instruction(context).unwrap().op {
+                InstOp::Call(..) | InstOp::ContractCall { .. } | InstOp::AsmBlock(..) => {
+                    stores_anywhere = true;
+                }
+                InstOp::Store { dst_val_ptr, .. } | InstOp::MemCopyVal { dst_val_ptr, .. } => {
+                    stores.push((
+                        *dst_val_ptr,
+                        Some(memory_utils::pointee_size(context, *dst_val_ptr)),
+                    ));
+                }
+                InstOp::MemCopyBytes {
+                    dst_val_ptr,
+                    byte_len,
+                    ..
+                } => stores.push((*dst_val_ptr, Some(*byte_len))),
+                _ => stores.extend(
+                    memory_utils::get_stored_ptr_values(context, inst)
+                        .into_iter()
+                        .map(|ptr| (ptr, None)),
+                ),
+            }
+        }
+    }
+
+    let mut invariants = FxHashSet::default();
+    let mut hoisted = Vec::new();
+    for block in &lp.blocks {
+        // Whether every instruction so far in the header is hoisted.
+        let mut at_header_start = *block == lp.header;
+        for inst in block.instruction_iter(context) {
+            let op = &inst.get_instruction(context).unwrap().op;
+            let is_invariant = |value: &Value| {
+                invariants.contains(value) || !is_defined_in_loop(context, loops, id, *value)
+            };
+            let can_hoist = op.get_operands().iter().all(is_invariant)
+                && match op {
+                    InstOp::UnaryOp { .. }
+                    | InstOp::BinaryOp {
+                        op: BinaryOpKind::And | BinaryOpKind::Or | BinaryOpKind::Xor,
+                        ..
+                    }
+                    | InstOp::BitCast(..)
+                    | InstOp::CastPtr(..)
+                    | InstOp::Cmp(..)
+                    | InstOp::GetLocal(_)
+                    | InstOp::GetElemPtr { .. }
+                    | InstOp::IntToPtr(..)
+                    | InstOp::PtrToInt(..) => true,
+                    // Calls and ASM blocks may change whether arithmetic traps on overflow.
+                    InstOp::BinaryOp { .. } => at_header_start && !stores_anywhere,
+                    InstOp::Load(ptr) => {
+                        let symbols = memory_utils::get_symbols(context, *ptr);
+                        !stores_anywhere
+                            && !symbols.is_empty()
+                            && symbols.iter().all(|sym| matches!(sym, Symbol::Local(_)))
+                            && stores.iter().all(|(dst_val_ptr, len)| {
+                                !memory_utils::may_clobber(context, *dst_val_ptr, *len, *ptr)
+                            })
+                    }
+                    _ => false,
+                };
+            if can_hoist {
+                invariants.insert(inst);
+                hoisted.push(inst);
+            } else {
+                at_header_start = false;
+            }
+        }
+    }
+    hoisted
+}
+
+/// Whether `value` is an instruction or block argument within the loop `id`.
+fn is_defined_in_loop(context: &Context, loops: &LoopInfo, id: LoopId, value: Value) -> bool {
+    match &context.values[value.0].value {
+        ValueDatum::Instruction(inst) => loops.contains(id, &inst.parent),
+        ValueDatum::Argument(arg) => loops.contains(id, &arg.block),
+        ValueDatum::Configurable(_) | ValueDatum::Constant(_) => false,
+    }
+}
@@ -96,6 +96,14 @@ fn local_copy_prop_prememcpy(context: &mut Context, function: Function) -> Resul
                     }
                 }
             }
+            Instruction {
+                op: InstOp::PtrToInt(ptr, _),
+                ..
+            } => {
+                if let Some(local) = get_symbol(context, *ptr) {
+                    escaping_uses.insert(local);
+                }
+            }
             _ => (),
         }
     }
@@ -284,7 +292,6 @@ fn local_copy_prop(
                         available_copies.remove(copy);
                     }
                 }
-                copies.retain(|copy| available_copies.contains(copy));
             }
             if let Some(copies) = dest_to_copies.get_mut(&sym) {
                 for copy in &*copies {
@@ -315,9 +322,16 @@ fn local_copy_prop(
                         available_copies.remove(copy);
                     }
                 }
-                copies.retain(|copy| available_copies.contains(copy));
             }
         }
+        // A copy killed through its source is still listed under its destination
+        // symbol, and vice versa, so it must be removed from both maps.
+        for copies in src_to_copies
+            .values_mut()
+            .chain(dest_to_copies.values_mut())
+        {
+            copies.retain(|copy| available_copies.contains(copy));
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
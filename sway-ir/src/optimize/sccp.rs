@@ -0,0 +1,297 @@
+//! ## Sparse Conditional Constant Propagation
+//!
+//! This optimization finds the instructions and block arguments which always have the same
+//! constant value, and the branches which are never taken.  It is the algorithm from "Constant
+//! Propagation with Conditional Branches" -- Mark N. Wegman and F. Kenneth Zadeck.
+//!
+//! Every value starts as undefined, may then be found to be constant, and is overdefined once it
+//! may have different values.  Only the blocks which may be reached are evaluated, and only the
+//! branches which may be taken pass their arguments to the blocks they branch to, so that the
+//! arguments passed along branches which are never taken don't prevent a block argument from
+//! being constant.
+//!
+//! The constant instructions and block arguments are replaced by their values, and conditional
+//! branches on constant conditions become unconditional branches.  The blocks which are never
+//! reached are left for `simplifycfg` to remove.
+//!
+//! Configurables are overdefined, as their values may be changed after compilation.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    fold_binary_op, fold_cmp, fold_unary_op, AnalysisResults, Block, BranchToWithArgs, Constant,
+    ConstantValue, Context, Function, InstOp, Instruction, IrError, Pass, PassMutability,
+    ScopedPass, Value, ValueDatum,
+};
+
+pub const SCCP_NAME: &str = "sccp";
+
+pub fn create_sccp_pass() -> Pass {
+    Pass {
+        name: SCCP_NAME,
+        descr: "Sparse conditional constant propagation.",
+        deps: vec![],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(sccp)),
+    }
+}
+
+#[derive(Clone)]
+enum LatticeValue {
+    /// The value hasn't been found to be computed yet.
+    Undefined,
+    Constant(Constant),
+    /// The value may differ between executions.
+    Overdefined,
+}
+
+impl LatticeValue {
+    fn meet(&self, context: &Context, other: &LatticeValue) -> LatticeValue {
+        match (self, other) {
+            (LatticeValue::Undefined, value) | (value, LatticeValue::Undefined) => value.clone(),
+            (LatticeValue::Constant(c1), LatticeValue::Constant(c2)) if c1.eq(context, c2) => {
+                LatticeValue::Constant(c1.clone())
+            }
+            _ => LatticeValue::Overdefined,
+        }
+    }
+}
+
+pub fn sccp(
+    context: &mut Context,
+    _: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let mut solver = Solver::new(context, function);
+    solver.solve(context, function);
+
+    let mut replacements = FxHashMap::default();
+    let mut constant_cbrs = Vec::new();
+    for block in function.block_iter(context) {
+        if !solver.executable_blocks.contains(&block) {
+            continue;
+        }
+        let args = block.arg_iter(context).copied();
+        for value in args.chain(block.instruction_iter(context)) {
+            match &context.values[value.0].value {
+                ValueDatum::Argument(_)
+                | ValueDatum::Instruction(Instruction {
+                    op: InstOp::BinaryOp { .. } | InstOp::Cmp(..) | InstOp::UnaryOp { .. },
+                    ..
+                }) => {
+                    if let LatticeValue::Constant(constant) = solver.get(context, value) {
+                        replacements.insert(value, constant);
+                    }
+                }
+                ValueDatum::Instruction(Instruction {
+                    op: InstOp::ConditionalBranch { cond_value, .. },
+                    ..
+                }) => {
+                    if let LatticeValue::Constant(Constant {
+                        value: ConstantValue::Bool(cond),
+                        ..
+                    }) = solver.get(context, *cond_value)
+                    {
+                        constant_cbrs.push((block, value, cond));
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+    if replacements.is_empty() && constant_cbrs.is_empty() {
+        return Ok(false);
+    }
+
+    let replacements: FxHashMap<Value, Value> = replacements
+        .into_iter()
+        .map(|(value, constant)| (value, Value::new_constant(context, constant)))
+        .collect();
+    function.replace_values(context, &replacements, None);
+    for block in function.block_iter(context).collect::<Vec<_>>() {
+        block.remove_instructions(context, |inst| replacements.contains_key(&inst));
+    }
+
+    for (block, cbr, cond) in constant_cbrs {
+        let Some(InstOp::ConditionalBranch {
+            true_block,
+            false_block,
+            ..
+        }) = cbr.get_instruction(context).map(|inst| inst.op.clone())
+        else {
+            unreachable!("Constant conditional branch is no longer a conditional branch.");
+        };
+        let (taken, not_taken) = if cond {
+            (true_block, false_block)
+        } else {
+            (false_block, true_block)
+        };
+        if taken.block != not_taken.block {
+            not_taken.block.remove_pred(context, &block);
+        }
+        cbr.replace(
+            context,
+            ValueDatum::Instruction(Instruction {
+                op: InstOp::Branch(taken),
+                parent: block,
+            }),
+        );
+    }
+    Ok(true)
+}
+
+#[derive(Default)]
+struct Solver {
+    /// The lattice values of the instructions and block arguments found to be computed.
+    lattice: FxHashMap<Value, LatticeValue>,
+    /// The instructions using each value.
+    users: FxHashMap<Value, Vec<Value>>,
+    /// The blocks which may be reached.
+    executable_blocks: FxHashSet<Block>,
+    /// The blocks found to be reachable which haven't been evaluated yet.
+    block_worklist: Vec<Block>,
+    /// The values whose lattice value changed, and whose users must be evaluated again.
+    value_worklist: Vec<Value>,
+}
+
+impl Solver {
+    fn new(context: &Context, function: Function) -> Self {
+        let mut solver = Solver::default();
+        for (_, inst) in function.instruction_iter(context) {
+            for operand in inst.get_instruction(context).unwrap().op.get_operands() {
+                solver.users.entry(operand).or_default().push(inst);
+            }
+        }
+        // The function arguments are passed in by the callers.
+        for arg in function.get_entry_block(context).arg_iter(context) {
+            solver.lattice.insert(*arg, LatticeValue::Overdefined);
+        }
+        solver
+    }
+
+    fn solve(&mut self, context: &Context, function: Function) {
+        let entry = function.get_entry_block(context);
+        self.executable_blocks.insert(entry);
+        self.block_worklist.push(entry);
+        loop {
+            if let Some(block) = self.block_worklist.pop() {
+                for inst in block.instruction_iter(context) {
+                    self.visit_instruction(context, inst);
+                }
+            } else if let Some(value) = self.value_worklist.pop() {
+                for user in self.users.get(&value).cloned().unwrap_or_default() {
+                    let block = user.get_instruction(context).unwrap().parent;
+                    if self.executable_blocks.contains(&block) {
+                        self.visit_instruction(context, user);
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn get(&self, context: &Context, value: Value) -> LatticeValue {
+        match &context.values[value.0].value {
+            ValueDatum::Constant(constant) => LatticeValue::Constant(constant.clone()),
+            ValueDatum::Configurable(_) => LatticeValue::Overdefined,
+            ValueDatum::Argument(_) | ValueDatum::Instruction(_) => self
+                .lattice
+                .get(&value)
+                .cloned()
+                .unwrap_or(LatticeValue::Undefined),
+        }
+    }
+
+    /// Lower the lattice value of `value` to its meet with `new`, so that its users are evaluated
+    /// again if it changes.
+    fn lower(&mut self, context: &Context, value: Value, new: LatticeValue) {
+        let old = self.get(context, value);
+        let met = old.meet(context, &new);
+        if std::mem::discriminant(&old) != std::mem::discriminant(&met) {
+            self.lattice.insert(value, met);
+            self.value_worklist.push(value);
+        }
+    }
+
+    fn visit_instruction(&mut self, context: &Context, inst: Value) {
+        let op = &inst.get_instruction(context).unwrap().op;
+        let result = match op {
+            InstOp::Branch(to_block) => {
+                self.visit_branch(context, to_block);
+                return;
+            }
+            InstOp::ConditionalBranch {
+                cond_value,
+                true_block,
+                false_block,
+            } => {
+                match self.get(context, *cond_value) {
+                    LatticeValue::Undefined => (),
+                    LatticeValue::Constant(Constant {
+                        value: ConstantValue::Bool(true),
+                        ..
+                    }) => self.visit_branch(context, true_block),
+                    LatticeValue::Constant(Constant {
+                        value: ConstantValue::Bool(false),
+                        ..
+                    }) => self.visit_branch(context, false_block),
+                    _ => {
+                        self.visit_branch(context, true_block);
+                        self.visit_branch(context, false_block);
+                    }
+                }
+                return;
+            }
+            InstOp::Cmp(pred, lhs, rhs) => self.fold(context, &[*lhs, *rhs], |constants| {
+                Some(Constant::new_bool(
+                    context,
+                    fold_cmp(context, pred, constants[0], constants[1]),
+                ))
+            }),
+            InstOp::BinaryOp { op, arg1, arg2 } => {
+                self.fold(context, &[*arg1, *arg2], |constants| {
+                    fold_binary_op(op, constants[0], constants[1])
+                })
+            }
+            InstOp::UnaryOp { op, arg } => self.fold(context, &[*arg], |constants| {
+                fold_unary_op(context, op, constants[0])
+            }),
+            _ => LatticeValue::Overdefined,
+        };
+        self.lower(context, inst, result);
+    }
+
+    /// Mark the block branched to as reachable and pass it the arguments of the branch.
+    fn visit_branch(&mut self, context: &Context, to_block: &BranchToWithArgs) {
+        let block_args: Vec<Value> = to_block.block.arg_iter(context).copied().collect();
+        for (param, arg) in to_block.args.iter().zip(block_args) {
+            let value = self.get(context, *param);
+            self.lower(context, arg, value);
+        }
+        if self.executable_blocks.insert(to_block.block) {
+            self.block_worklist.push(to_block.block);
+        }
+    }
+
+    /// The lattice value of an operation on `operands`, evaluated by `fold` once they're all
+    /// constant.  Operations which can't be evaluated are overdefined.
+    fn fold<F>(&self, context: &Context, operands: &[Value], fold: F) -> LatticeValue
+    where
+        F: FnOnce(&[&Constant]) -> Option<Constant>,
+    {
+        let mut constants = Vec::new();
+        let mut is_undefined = false;
+        for operand in operands {
+            match self.get(context, *operand) {
+                LatticeValue::Overdefined => return LatticeValue::Overdefined,
+                LatticeValue::Undefined => is_undefined = true,
+                LatticeValue::Constant(constant) => constants.push(constant),
+            }
+        }
+        if is_undefined {
+            return LatticeValue::Undefined;
+        }
+        let constants: Vec<&Constant> = constants.iter().collect();
+        fold(&constants).map_or(LatticeValue::Overdefined, LatticeValue::Constant)
+    }
+}
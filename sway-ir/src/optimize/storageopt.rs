@@ -0,0 +1,816 @@
+//! ## Storage Access Optimization
+//!
+//! Storage accesses are by far the most expensive instructions a contract executes.  This
+//! optimization
+//!   1. Forwards the words and slots loaded from or stored to storage to the later loads of the
+//!      same keys, which then don't need to read storage again,
+//!   2. Removes the stores to storage which are overwritten by a later store before they may be
+//!      read,
+//!   3. Merges the quad word accesses of consecutive slots from or to adjacent memory into a
+//!      single access.
+//!
+//! Keys are known to be the same when they're the same pointer and the memory it points to isn't
+//! written to in between, or when they're the same constant, either stored or copied to the memory
+//! pointed to or held by a local which is never written to.  Only constant keys are known to be
+//! distinct, so an access to any other key may access any slot.
+//!
+//! Calls and contract calls may access any slot, so nothing is forwarded or removed across them.
+//! Neither is anything across ASM blocks which access storage or call contracts, the same
+//! instructions the CEI analysis considers.
+//!
+//! The known storage contents are found with a forward data-flow analysis, so that they're known
+//! at the start of a block when they're known at the end of all of its predecessors.  Dead stores
+//! and adjacent accesses are only found within blocks.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use sway_types::u256::U256;
+
+use crate::{
+    is_storage_read_opcode, is_storage_write_opcode, memory_utils, AnalysisResults, AsmBlock,
+    Block, Constant, ConstantValue, Context, EscapedSymbols, FuelVmInstruction, Function, InstOp,
+    Instruction, IrError, LocalVar, Pass, PassMutability, PostOrder, ScopedPass, Symbol, Value,
+    ValueDatum, ESCAPED_SYMBOLS_NAME, POSTORDER_NAME,
+};
+
+pub const STORAGEOPT_NAME: &str = "storageopt";
+
+pub fn create_storageopt_pass() -> Pass {
+    Pass {
+        name: STORAGEOPT_NAME,
+        descr: "Storage access optimization.",
+        deps: vec![ESCAPED_SYMBOLS_NAME, POSTORDER_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(storage_opt)),
+    }
+}
+
+/// The size of a storage slot in bytes.
+const SLOT_SIZE: u64 = 32;
+
+/// The key of a storage access.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Key {
+    /// A constant key, held by a local or stored to the memory pointed to.
+    Const(U256),
+    /// The key pointed to, which is only the same while that memory isn't written to.
+    Ptr(Value),
+}
+
+impl Key {
+    /// Whether a write of `len` bytes, or of an unknown number of bytes, to `dst_val_ptr` may
+    /// change the key.
+    fn may_be_clobbered(&self, context: &Context, dst_val_ptr: Value, len: Option<u64>) -> bool {
+        match self {
+            Key::Const(_) => false,
+            Key::Ptr(key_ptr) => memory_utils::may_clobber(context, dst_val_ptr, len, *key_ptr),
+        }
+    }
+}
+
+/// A sequence of consecutive slots.  Word accesses access a single slot.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Slots {
+    key: Key,
+    count: u64,
+}
+
+impl Slots {
+    /// Whether any of these slots may be one of the `other` slots.
+    fn may_overlap(&self, other: &Slots) -> bool {
+        let (Key::Const(start), Key::Const(other_start)) = (&self.key, &other.key) else {
+            return true;
+        };
+        // Keys wrapping around past the largest key are assumed to overlap.
+        match (
+            start.checked_add(&U256::from(self.count)),
+            other_start.checked_add(&U256::from(other.count)),
+        ) {
+            (Some(end), Some(other_end)) => start < &other_end && other_start < &end,
+            _ => true,
+        }
+    }
+}
+
+/// A load from storage which reads what's already known.
+enum Forwarded {
+    /// The word loaded.
+    Word(Value),
+    /// The memory already holding the slots loaded and whether they're all set, which is `None`
+    /// when they were stored.
+    Quad(Value, Option<Value>),
+}
+
+/// The storage contents known at some point of a function.
+#[derive(Clone, Default, PartialEq)]
+struct KnownStorage {
+    keys: KeyContents,
+    /// The words loaded from or stored to slots.
+    words: FxHashMap<Key, Value>,
+    /// The memory holding the slots loaded from or stored to storage, and whether they're all set,
+    /// which is `None` when they were stored.
+    quads: FxHashMap<Slots, (Value, Option<Value>)>,
+}
+
+pub fn storage_opt(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let escaped_symbols: &EscapedSymbols = analyses.get_analysis_result(function);
+    let po: &PostOrder = analyses.get_analysis_result(function);
+    let locals = ConstantLocals::new(context, function, escaped_symbols);
+
+    let mut modified = forward_loads(context, function, po, &locals);
+    modified |= remove_dead_stores(context, function, &locals);
+    modified |= merge_adjacent_accesses(context, function, &locals);
+    Ok(modified)
+}
+
+/// Replace the loads from storage reading known storage contents with those contents.
+fn forward_loads(
+    context: &mut Context,
+    function: Function,
+    po: &PostOrder,
+    locals: &ConstantLocals,
+) -> bool {
+    let rpo: Vec<Block> = po.po_to_block.iter().rev().copied().collect();
+    let entry = function.get_entry_block(context);
+    let known_at_start =
+        |context: &Context, known_at_end: &FxHashMap<Block, KnownStorage>, block: Block| {
+            if block == entry {
+                return KnownStorage::default();
+            }
+            // The predecessors yet to be visited are back edges, which are met once visited.
+            let mut preds = block
+                .pred_iter(context)
+                .filter_map(|pred| known_at_end.get(pred));
+            let mut known = preds.next().cloned().unwrap_or_default();
+            for pred_known in preds {
+                known.meet(pred_known);
+            }
+            known
+        };
+
+    let mut known_at_end: FxHashMap<Block, KnownStorage> = FxHashMap::default();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for block in &rpo {
+            let mut known = known_at_start(context, &known_at_end, *block);
+            for inst in block.instruction_iter(context) {
+                known.visit(context, locals, inst);
+            }
+            if known_at_end.get(block) != Some(&known) {
+                known_at_end.insert(*block, known);
+                changed = true;
+            }
+        }
+    }
+
+    let mut forwarded = Vec::new();
+    for block in &rpo {
+        let mut known = known_at_start(context, &known_at_end, *block);
+        for inst in block.instruction_iter(context) {
+            if let Some(fwd) = known.visit(context, locals, inst) {
+                forwarded.push((inst, fwd));
+            }
+        }
+    }
+    if forwarded.is_empty() {
+        return false;
+    }
+
+    let mut replacements = FxHashMap::default();
+    let mut removals = FxHashSet::default();
+    let mut all_set = None;
+    for (inst, fwd) in forwarded {
+        match fwd {
+            Forwarded::Word(word) => {
+                replacements.insert(inst, word);
+                removals.insert(inst);
+            }
+            Forwarded::Quad(src_val_ptr, is_set) => {
+                let is_set = is_set.unwrap_or_else(|| {
+                    *all_set.get_or_insert_with(|| {
+                        Value::new_constant(context, Constant::new_bool(context, true))
+                    })
+                });
+                replacements.insert(inst, is_set);
+                let Some(Instruction {
+                    op:
+                        InstOp::FuelVm(FuelVmInstruction::StateLoadQuadWord {
+                            load_val,
+                            number_of_slots,
+                            ..
+                        }),
+                    parent,
+                }) = inst.get_instruction(context).cloned()
+                else {
+                    unreachable!("Forwarded quad word load is no longer a quad word load.");
+                };
+                if load_val == src_val_ptr {
+                    removals.insert(inst);
+                } else {
+                    let count = constant_count(context, number_of_slots).unwrap();
+                    inst.replace(
+                        context,
+                        ValueDatum::Instruction(Instruction {
+                            op: InstOp::MemCopyBytes {
+                                dst_val_ptr: load_val,
+                                src_val_ptr,
+                                byte_len: count * SLOT_SIZE,
+                            },
+                            parent,
+                        }),
+                    );
+                }
+            }
+        }
+    }
+    function.replace_values(context, &replacements, None);
+    for block in function.block_iter(context).collect::<Vec<_>>() {
+        block.remove_instructions(context, |inst| removals.contains(&inst));
+    }
+    true
+}
+
+impl KnownStorage {
+    /// Keep only the contents known both here and in `other`.
+    fn meet(&mut self, other: &KnownStorage) {
+        self.keys.meet(&other.keys);
+        self.words
+            .retain(|key, word| other.words.get(key) == Some(word));
+        self.quads
+            .retain(|slots, quad| other.quads.get(slots) == Some(quad));
+    }
+
+    /// Update the known contents after `inst` is executed.  Returns what a load from storage reads
+    /// instead, when already known.
+    fn visit(
+        &mut self,
+        context: &Context,
+        locals: &ConstantLocals,
+        inst: Value,
+    ) -> Option<Forwarded> {
+        let forwarded = self.visit_storage(context, locals, inst);
+        self.keys.visit(context, inst);
+        forwarded
+    }
+
+    fn visit_storage(
+        &mut self,
+        context: &Context,
+        locals: &ConstantLocals,
+        inst: Value,
+    ) -> Option<Forwarded> {
+        match &inst.get_instruction(context).unwrap().op {
+            InstOp::FuelVm(FuelVmInstruction::StateLoadWord(key)) => {
+                let key = self.keys.key(context, locals, *key);
+                if let Some(word) = self.words.get(&key) {
+                    return Some(Forwarded::Word(*word));
+                }
+                self.words.insert(key, inst);
+            }
+            InstOp::FuelVm(FuelVmInstruction::StateStoreWord { stored_val, key }) => {
+                let slots = Slots {
+                    key: self.keys.key(context, locals, *key),
+                    count: 1,
+                };
+                self.forget_slots(Some(&slots));
+                self.words.insert(slots.key, *stored_val);
+            }
+            InstOp::FuelVm(FuelVmInstruction::StateLoadQuadWord {
+                load_val,
+                key,
+                number_of_slots,
+            }) => {
+                let slots = self.keys.slots(context, locals, *key, *number_of_slots);
+                let len = slots.as_ref().map(|slots| slots.count * SLOT_SIZE);
+                // The slots can't be copied from memory overlapping the memory they're loaded to.
+                let forwarded = slots
+                    .as_ref()
+                    .and_then(|slots| self.quads.get(slots))
+                    .filter(|(src_val_ptr, _)| {
+                        src_val_ptr == load_val
+                            || !memory_utils::may_clobber(context, *load_val, len, *src_val_ptr)
+                    })
+                    .map(|(src_val_ptr, is_set)| Forwarded::Quad(*src_val_ptr, *is_set));
+                self.forget_memory(context, *load_val, len);
+                if forwarded.is_some() {
+                    return forwarded;
+                }
+                if let Some(slots) = slots {
+                    if may_remember_quad(context, &slots, *load_val) {
+                        self.quads.insert(slots, (*load_val, Some(inst)));
+                    }
+                }
+            }
+            InstOp::FuelVm(FuelVmInstruction::StateStoreQuadWord {
+                stored_val,
+                key,
+                number_of_slots,
+            }) => {
+                let slots = self.keys.slots(context, locals, *key, *number_of_slots);
+                self.forget_slots(slots.as_ref());
+                if let Some(slots) = slots {
+                    if may_remember_quad(context, &slots, *stored_val) {
+                        self.quads.insert(slots, (*stored_val, None));
+                    }
+                }
+            }
+            InstOp::FuelVm(FuelVmInstruction::StateClear {
+                key,
+                number_of_slots,
+            }) => {
+                let slots = self.keys.slots(context, locals, *key, *number_of_slots);
+                self.forget_slots(slots.as_ref());
+            }
+            InstOp::Call(..) | InstOp::ContractCall { .. } => self.forget_slots(None),
+            InstOp::AsmBlock(asm, _) => {
+                if accesses_storage(asm) {
+                    self.forget_slots(None);
+                } else {
+                    // ASM blocks may write to any memory.
+                    self.words.retain(|key, _| matches!(key, Key::Const(_)));
+                    self.quads.clear();
+                }
+            }
+            _ => {
+                for (dst_val_ptr, len) in stored_ptr_values(context, inst) {
+                    self.forget_memory(context, dst_val_ptr, len);
+                }
+            }
+        }
+        None
+    }
+
+    /// Forget the contents of the slots which may be any of `slots`, or of all slots if unknown.
+    fn forget_slots(&mut self, slots: Option<&Slots>) {
+        let Some(slots) = slots else {
+            self.words.clear();
+            self.quads.clear();
+            return;
+        };
+        self.words.retain(|key, _| {
+            !slots.may_overlap(&Slots {
+                key: key.clone(),
+                count: 1,
+            })
+        });
+        self.quads.retain(|other, _| !slots.may_overlap(other));
+    }
+
+    /// Forget the contents whose keys or memory a write of `len` bytes, or of an unknown number of
+    /// bytes, to `dst_val_ptr` may clobber.
+    fn forget_memory(&mut self, context: &Context, dst_val_ptr: Value, len: Option<u64>) {
+        self.words
+            .retain(|key, _| !key.may_be_clobbered(context, dst_val_ptr, len));
+        self.quads.retain(|slots, (ptr, _)| {
+            !slots.key.may_be_clobbered(context, dst_val_ptr, len)
+                && !memory_utils::may_clobber(context, dst_val_ptr, len, *ptr)
+        });
+    }
+}
+
+/// Whether the quad word access of `slots` from or to the memory at `ptr` can be remembered.
+/// The key of the slots must still be the same once they're loaded, and the memory must be
+/// large enough for all of them, as that's the memory which clobbering it is checked for.
+fn may_remember_quad(context: &Context, slots: &Slots, ptr: Value) -> bool {
+    let len = slots.count * SLOT_SIZE;
+    memory_utils::pointee_size(context, ptr) >= len
+        && !slots.key.may_be_clobbered(context, ptr, Some(len))
+}
+
+/// A store to storage which hasn't been read yet.
+struct PendingStore {
+    inst: Value,
+    slots: Slots,
+    is_word: bool,
+}
+
+/// Remove the stores to storage which are overwritten within their block before they may be read.
+///
+/// As stores return whether the slots were set before, neither the overwritten store nor the store
+/// overwriting it may have its result used.
+fn remove_dead_stores(context: &mut Context, function: Function, locals: &ConstantLocals) -> bool {
+    let used = used_values(context, function);
+    let mut dead_stores = FxHashSet::default();
+    for block in function.block_iter(context) {
+        let mut contents = KeyContents::default();
+        let mut pending: Vec<PendingStore> = Vec::new();
+        for inst in block.instruction_iter(context) {
+            if let Some((slots, is_word)) =
+                visit_storage_access(context, locals, &contents, &mut pending, inst)
+            {
+                match slots {
+                    Some(slots) => {
+                        if !used.contains(&inst) {
+                            for store in &pending {
+                                let is_overwritten = store.slots.key == slots.key
+                                    && if is_word {
+                                        store.is_word
+                                    } else {
+                                        store.slots.count <= slots.count
+                                    };
+                                if is_overwritten && !used.contains(&store.inst) {
+                                    dead_stores.insert(store.inst);
+                                }
+                            }
+                        }
+                        pending.retain(|store| !store.slots.may_overlap(&slots));
+                        pending.push(PendingStore {
+                            inst,
+                            slots,
+                            is_word,
+                        });
+                    }
+                    None => pending.clear(),
+                }
+            }
+            contents.visit(context, inst);
+        }
+    }
+    if dead_stores.is_empty() {
+        return false;
+    }
+    for block in function.block_iter(context).collect::<Vec<_>>() {
+        block.remove_instructions(context, |inst| dead_stores.contains(&inst));
+    }
+    true
+}
+
+/// Forget the pending stores `inst` may read or whose keys it may change.  Returns the slots
+/// `inst` stores to, if known, and whether it's a word store, when `inst` is a store to storage.
+fn visit_storage_access(
+    context: &Context,
+    locals: &ConstantLocals,
+    contents: &KeyContents,
+    pending: &mut Vec<PendingStore>,
+    inst: Value,
+) -> Option<(Option<Slots>, bool)> {
+    match &inst.get_instruction(context).unwrap().op {
+        InstOp::FuelVm(FuelVmInstruction::StateStoreWord { key, .. }) => Some((
+            Some(Slots {
+                key: contents.key(context, locals, *key),
+                count: 1,
+            }),
+            true,
+        )),
+        InstOp::FuelVm(FuelVmInstruction::StateStoreQuadWord {
+            key,
+            number_of_slots,
+            ..
+        })
+        | InstOp::FuelVm(FuelVmInstruction::StateClear {
+            key,
+            number_of_slots,
+        }) => Some((
+            contents.slots(context, locals, *key, *number_of_slots),
+            false,
+        )),
+        InstOp::FuelVm(FuelVmInstruction::StateLoadWord(key)) => {
+            let slots = Slots {
+                key: contents.key(context, locals, *key),
+                count: 1,
+            };
+            pending.retain(|store| !store.slots.may_overlap(&slots));
+            None
+        }
+        InstOp::FuelVm(FuelVmInstruction::StateLoadQuadWord {
+            load_val,
+            key,
+            number_of_slots,
+        }) => {
+            match contents.slots(context, locals, *key, *number_of_slots) {
+                Some(slots) => pending.retain(|store| !store.slots.may_overlap(&slots)),
+                None => pending.clear(),
+            }
+            let len = constant_count(context, *number_of_slots).map(|n| n * SLOT_SIZE);
+            forget_clobbered_keys(context, pending, *load_val, len);
+            None
+        }
+        InstOp::Call(..) | InstOp::ContractCall { .. } => {
+            pending.clear();
+            None
+        }
+        InstOp::AsmBlock(asm, _) => {
+            if accesses_storage(asm) {
+                pending.clear();
+            } else {
+                pending.retain(|store| matches!(store.slots.key, Key::Const(_)));
+            }
+            None
+        }
+        _ => {
+            for (dst_val_ptr, len) in stored_ptr_values(context, inst) {
+                forget_clobbered_keys(context, pending, dst_val_ptr, len);
+            }
+            None
+        }
+    }
+}
+
+/// Forget the pending stores whose keys a write of `len` bytes, or of an unknown number of bytes,
+/// to `dst_val_ptr` may clobber, as a later store can no longer be found to have the same key.
+fn forget_clobbered_keys(
+    context: &Context,
+    pending: &mut Vec<PendingStore>,
+    dst_val_ptr: Value,
+    len: Option<u64>,
+) {
+    pending.retain(|store| !store.slots.key.may_be_clobbered(context, dst_val_ptr, len));
+}
+
+/// Merge the quad word loads or stores of consecutive slots from or to adjacent memory within a
+/// block into a single access, when nothing in between accesses memory or storage and their
+/// results aren't used.
+fn merge_adjacent_accesses(
+    context: &mut Context,
+    function: Function,
+    locals: &ConstantLocals,
+) -> bool {
+    let used = used_values(context, function);
+    let mut modified = false;
+    for block in function.block_iter(context).collect::<Vec<_>>() {
+        let mut contents = KeyContents::default();
+        let mut prev: Option<QuadAccess> = None;
+        for inst in block.instruction_iter(context).collect::<Vec<_>>() {
+            let access = QuadAccess::new(context, locals, &contents, inst);
+            contents.visit(context, inst);
+            let op = &inst.get_instruction(context).unwrap().op;
+            let Some(access) = access else {
+                if op.may_have_side_effect()
+                    || matches!(
+                        op,
+                        InstOp::Load(_) | InstOp::FuelVm(FuelVmInstruction::StateLoadWord(_))
+                    )
+                {
+                    prev = None;
+                }
+                continue;
+            };
+            if used.contains(&inst) {
+                prev = None;
+                continue;
+            }
+            let merged = match prev.take() {
+                Some(prev) if prev.is_followed_by(context, &access) => {
+                    let count = prev.count + access.count;
+                    let count_const = Constant::new_uint(context, 64, count);
+                    let number_of_slots = Value::new_constant(context, count_const);
+                    let op = if access.is_load {
+                        FuelVmInstruction::StateLoadQuadWord {
+                            load_val: prev.ptr,
+                            key: prev.key_ptr,
+                            number_of_slots,
+                        }
+                    } else {
+                        FuelVmInstruction::StateStoreQuadWord {
+                            stored_val: prev.ptr,
+                            key: prev.key_ptr,
+                            number_of_slots,
+                        }
+                    };
+                    inst.get_instruction_mut(context).unwrap().op = InstOp::FuelVm(op);
+                    block.remove_instruction(context, prev.inst);
+                    modified = true;
+                    QuadAccess {
+                        inst,
+                        count,
+                        ..prev
+                    }
+                }
+                _ => access,
+            };
+            prev = Some(merged);
+        }
+    }
+    modified
+}
+
+/// A quad word load or store with a constant key and number of slots.
+struct QuadAccess {
+    inst: Value,
+    is_load: bool,
+    /// The memory loaded to or stored from.
+    ptr: Value,
+    key_ptr: Value,
+    key: U256,
+    count: u64,
+}
+
+impl QuadAccess {
+    fn new(
+        context: &Context,
+        locals: &ConstantLocals,
+        contents: &KeyContents,
+        inst: Value,
+    ) -> Option<QuadAccess> {
+        let (is_load, ptr, key_ptr, number_of_slots) =
+            match &inst.get_instruction(context).unwrap().op {
+                InstOp::FuelVm(FuelVmInstruction::StateLoadQuadWord {
+                    load_val,
+                    key,
+                    number_of_slots,
+                }) => (true, *load_val, *key, *number_of_slots),
+                InstOp::FuelVm(FuelVmInstruction::StateStoreQuadWord {
+                    stored_val,
+                    key,
+                    number_of_slots,
+                }) => (false, *stored_val, *key, *number_of_slots),
+                _ => return None,
+            };
+        let Key::Const(key) = contents.key(context, locals, key_ptr) else {
+            return None;
+        };
+        Some(QuadAccess {
+            inst,
+            is_load,
+            ptr,
+            key_ptr,
+            key,
+            count: constant_count(context, number_of_slots)?,
+        })
+    }
+
+    /// Whether `next` accesses the slots following these, from or to the memory following these,
+    /// and the key of these is still the same at `next`.
+    fn is_followed_by(&self, context: &Context, next: &QuadAccess) -> bool {
+        if self.is_load != next.is_load
+            || self.key.checked_add(&U256::from(self.count)).as_ref() != Some(&next.key)
+            || (self.is_load
+                && memory_utils::may_clobber(
+                    context,
+                    self.ptr,
+                    Some(self.count * SLOT_SIZE),
+                    self.key_ptr,
+                ))
+        {
+            return false;
+        }
+        let offsets = memory_utils::get_memory_offsets(context, self.ptr);
+        let next_offsets = memory_utils::get_memory_offsets(context, next.ptr);
+        match (
+            offsets.iter().collect::<Vec<_>>().as_slice(),
+            next_offsets.iter().collect::<Vec<_>>().as_slice(),
+        ) {
+            ([(symbol, offset)], [(next_symbol, next_offset)]) => {
+                symbol == next_symbol && **offset + self.count * SLOT_SIZE == **next_offset
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The locals which always hold the same constant key: those initialized with a `b256` constant
+/// which are never stored to and don't escape.
+struct ConstantLocals(FxHashMap<LocalVar, U256>);
+
+impl ConstantLocals {
+    fn new(context: &Context, function: Function, escaped_symbols: &EscapedSymbols) -> Self {
+        let stored_symbols: FxHashSet<Symbol> = function
+            .instruction_iter(context)
+            .flat_map(|(_, inst)| memory_utils::get_stored_symbols(context, inst))
+            .collect();
+        let keys = function
+            .locals_iter(context)
+            .filter_map(|(_, local)| {
+                let symbol = Symbol::Local(*local);
+                if stored_symbols.contains(&symbol) || escaped_symbols.contains(&symbol) {
+                    return None;
+                }
+                match local.get_initializer(context) {
+                    Some(Constant {
+                        value: ConstantValue::B256(key),
+                        ..
+                    }) => Some((*local, key.clone())),
+                    _ => None,
+                }
+            })
+            .collect();
+        ConstantLocals(keys)
+    }
+}
+
+/// The constant keys held by memory, as they were stored or copied to the pointers mapped to them.
+#[derive(Clone, Default, PartialEq)]
+struct KeyContents(FxHashMap<Value, U256>);
+
+impl KeyContents {
+    /// Keep only the keys known both here and in `other`.
+    fn meet(&mut self, other: &KeyContents) {
+        self.0.retain(|ptr, key| other.0.get(ptr) == Some(key));
+    }
+
+    /// The key pointed to by `key_ptr`.
+    fn key(&self, context: &Context, locals: &ConstantLocals, key_ptr: Value) -> Key {
+        if let Some(key) = self.0.get(&key_ptr) {
+            return Key::Const(key.clone());
+        }
+        match key_ptr.get_instruction(context).map(|inst| &inst.op) {
+            Some(InstOp::GetLocal(local)) if locals.0.contains_key(local) => {
+                Key::Const(locals.0[local].clone())
+            }
+            _ => Key::Ptr(key_ptr),
+        }
+    }
+
+    /// The slots accessed from the key pointed to by `key_ptr`, if their number is constant.
+    fn slots(
+        &self,
+        context: &Context,
+        locals: &ConstantLocals,
+        key_ptr: Value,
+        number_of_slots: Value,
+    ) -> Option<Slots> {
+        Some(Slots {
+            key: self.key(context, locals, key_ptr),
+            count: constant_count(context, number_of_slots)?,
+        })
+    }
+
+    /// Update the keys held by memory after `inst` is executed.
+    fn visit(&mut self, context: &Context, inst: Value) {
+        match &inst.get_instruction(context).unwrap().op {
+            InstOp::Store {
+                dst_val_ptr,
+                stored_val,
+            } => {
+                let len = memory_utils::pointee_size(context, *dst_val_ptr);
+                self.forget(context, *dst_val_ptr, Some(len));
+                if let Some(Constant {
+                    value: ConstantValue::B256(key),
+                    ..
+                }) = stored_val.get_constant(context)
+                {
+                    self.0.insert(*dst_val_ptr, key.clone());
+                }
+            }
+            InstOp::MemCopyVal {
+                dst_val_ptr,
+                src_val_ptr,
+            } => {
+                let key = self.0.get(src_val_ptr).cloned();
+                let len = memory_utils::pointee_size(context, *dst_val_ptr);
+                self.forget(context, *dst_val_ptr, Some(len));
+                if let Some(key) = key {
+                    self.0.insert(*dst_val_ptr, key);
+                }
+            }
+            InstOp::Call(..) | InstOp::ContractCall { .. } | InstOp::AsmBlock(..) => self.0.clear(),
+            _ => {
+                for (dst_val_ptr, len) in stored_ptr_values(context, inst) {
+                    self.forget(context, dst_val_ptr, len);
+                }
+            }
+        }
+    }
+
+    /// Forget the keys a write of `len` bytes, or of an unknown number of bytes, to `dst_val_ptr`
+    /// may clobber.
+    fn forget(&mut self, context: &Context, dst_val_ptr: Value, len: Option<u64>) {
+        self.0
+            .retain(|ptr, _| !memory_utils::may_clobber(context, dst_val_ptr, len, *ptr));
+    }
+}
+
+fn constant_count(context: &Context, number_of_slots: Value) -> Option<u64> {
+    match &number_of_slots.get_constant(context)?.value {
+        ConstantValue::Uint(count) => Some(*count),
+        _ => None,
+    }
+}
+
+/// Whether an ASM block accesses storage or calls a contract, which may access storage.
+fn accesses_storage(asm: &AsmBlock) -> bool {
+    asm.body.iter().any(|op| {
+        let op_name = op.op_name.as_str();
+        is_storage_read_opcode(op_name) || is_storage_write_opcode(op_name) || op_name == "call"
+    })
+}
+
+/// The pointers `inst` may store to and the number of bytes stored, if known.
+fn stored_ptr_values(context: &Context, inst: Value) -> Vec<(Value, Option<u64>)> {
+    match &inst.get_instruction(context).unwrap().op {
+        InstOp::Store { dst_val_ptr, .. } | InstOp::MemCopyVal { dst_val_ptr, .. } => vec![(
+            *dst_val_ptr,
+            Some(memory_utils::pointee_size(context, *dst_val_ptr)),
+        )],
+        InstOp::MemCopyBytes {
+            dst_val_ptr,
+            byte_len,
+            ..
+        } => vec![(*dst_val_ptr, Some(*byte_len))],
+        _ => memory_utils::get_stored_ptr_values(context, inst)
+            .into_iter()
+            .map(|ptr| (ptr, None))
+            .collect(),
+    }
+}
+
+/// The values used by the instructions of `function`.
+fn used_values(context: &Context, function: Function) -> FxHashSet<Value> {
+    function
+        .instruction_iter(context)
+        .flat_map(|(_, inst)| inst.get_instruction(context).unwrap().op.get_operands())
+        .collect()
+}
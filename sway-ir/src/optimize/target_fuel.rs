@@ -1,7 +1,9 @@
 //! Some Fuel VM specific utilities.
 use crate::{
     context::Context,
+    instruction::{FuelVmInstruction, InstOp},
     irtype::{Type, TypeContent},
+    value::Value,
 };
 
 /// Return whether a `Type` _cannot_ fit in a Fuel VM register and requires 'demotion'.
@@ -12,3 +14,70 @@ pub(super) fn is_demotable_type(context: &Context, ty: &Type) -> bool {
         _ => true,
     }
 }
+
+/// The size of a Fuel VM instruction in bytes.
+pub(super) const INSTRUCTION_SIZE: u64 = 4;
+
+/// The estimated number of instructions to make a call, besides passing the arguments: setting
+/// the return address, jumping to the function and copying the returned value.
+pub(super) const CALL_SITE_OVERHEAD: u64 = 3;
+
+/// The estimated number of instructions of a function besides its body: saving and restoring the
+/// registers, allocating and freeing the stack frame, and returning.
+pub(super) const FUNCTION_OVERHEAD: u64 = 7;
+
+/// The estimated number of instructions to pass an argument which is demoted to a pointer: the
+/// copy of the argument to a temporary, its address, and loading it again in the function.
+pub(super) const DEMOTED_ARG_OVERHEAD: u64 = 3;
+
+/// The estimated gas used by a call, besides passing the arguments.  Saving and restoring the
+/// registers are the most expensive parts.
+pub(super) const CALL_GAS: u64 = 24;
+
+/// Estimate the number of Fuel VM instructions the instruction `inst` is compiled to.
+pub(super) fn estimate_instruction_count(context: &Context, inst: &Value) -> u64 {
+    let Some(instruction) = inst.get_instruction(context) else {
+        return 0;
+    };
+    let is_demotable_ptr = |ptr: &Value| {
+        ptr.get_type(context)
+            .and_then(|ty| ty.get_pointee_type(context))
+            .is_some_and(|ty| is_demotable_type(context, &ty))
+    };
+    match &instruction.op {
+        InstOp::BitCast(..)
+        | InstOp::CastPtr(..)
+        | InstOp::IntToPtr(..)
+        | InstOp::PtrToInt(..)
+        | InstOp::Nop => 0,
+        InstOp::AsmBlock(asm, _) => asm.body.len() as u64,
+        InstOp::Call(_, args) => CALL_SITE_OVERHEAD + args.len() as u64,
+        InstOp::ConditionalBranch { .. } => 2,
+        InstOp::ContractCall { .. } => 4,
+        InstOp::GetElemPtr { indices, .. } => {
+            1 + 2 * indices
+                .iter()
+                .filter(|idx| !idx.is_constant(context))
+                .count() as u64
+        }
+        InstOp::Load(ptr)
+        | InstOp::Store {
+            dst_val_ptr: ptr, ..
+        } => 1 + is_demotable_ptr(ptr) as u64,
+        InstOp::Ret(..) => 2,
+        InstOp::FuelVm(
+            FuelVmInstruction::Log { .. }
+            | FuelVmInstruction::Smo { .. }
+            | FuelVmInstruction::WideBinaryOp { .. }
+            | FuelVmInstruction::WideModularOp { .. }
+            | FuelVmInstruction::WideCmpOp { .. },
+        ) => 3,
+        InstOp::FuelVm(
+            FuelVmInstruction::StateClear { .. }
+            | FuelVmInstruction::StateLoadQuadWord { .. }
+            | FuelVmInstruction::StateStoreQuadWord { .. }
+            | FuelVmInstruction::WideUnaryOp { .. },
+        ) => 2,
+        _ => 1,
+    }
+}
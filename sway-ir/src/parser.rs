@@ -173,6 +173,12 @@ mod ir_builder {
                 / "mod" _ { BinaryOpKind::Mod }
                 / "rsh" _ { BinaryOpKind::Rsh }
                 / "lsh" _ { BinaryOpKind::Lsh }
+                / "sadd" _ { BinaryOpKind::SAdd }
+                / "ssub" _ { BinaryOpKind::SSub }
+                / "smul" _ { BinaryOpKind::SMul }
+                / "sdiv" _ { BinaryOpKind::SDiv }
+                / "smod" _ { BinaryOpKind::SMod }
+                / "srsh" _ { BinaryOpKind::SRsh }
 
             rule operation() -> IrAstOperation
                 = op_asm()
@@ -401,6 +407,8 @@ mod ir_builder {
                 = "eq" _ { Predicate::Equal }
                 / "gt" _ { Predicate::GreaterThan }
                 / "lt" _ { Predicate::LessThan }
+                / "sgt" _ { Predicate::SGreaterThan }
+                / "slt" _ { Predicate::SLessThan }
 
             rule reg_name() -> String
                 = r:$("of" / "pc" / "ssp" / "sp" / "fp" / "hp" / "err" / "ggas" / "cgas" / "bal" / "is" / "ret" / "retl" / "flag") _ {
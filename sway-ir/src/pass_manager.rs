@@ -1,17 +1,21 @@
 use crate::{
     create_arg_demotion_pass, create_const_combine_pass, create_const_demotion_pass,
-    create_dce_pass, create_dom_fronts_pass, create_dominators_pass, create_escaped_symbols_pass,
-    create_fn_dedup_pass, create_func_dce_pass, create_inline_in_main_pass,
-    create_inline_in_module_pass, create_mem2reg_pass, create_memcpyopt_pass,
-    create_misc_demotion_pass, create_module_printer_pass, create_module_verifier_pass,
-    create_postorder_pass, create_ret_demotion_pass, create_simplify_cfg_pass, create_sroa_pass,
-    Context, Function, IrError, Module, CONSTCOMBINE_NAME, DCE_NAME, FNDEDUP_NAME, FUNC_DCE_NAME,
-    INLINE_MODULE_NAME, MEM2REG_NAME, SIMPLIFYCFG_NAME,
+    create_dce_pass, create_dom_fronts_pass, create_dominators_pass, create_dse_pass,
+    create_escaped_symbols_pass, create_fn_dedup_pass, create_func_dce_pass, create_gvn_pass,
+    create_inline_in_main_pass, create_inline_in_module_for_size_pass,
+    create_inline_in_module_pass, create_licm_pass, create_loops_pass, create_mem2reg_pass,
+    create_memcpyopt_pass, create_misc_demotion_pass, create_module_printer_pass,
+    create_module_verifier_pass, create_postorder_pass, create_ret_demotion_pass, create_sccp_pass,
+    create_simplify_cfg_pass, create_sroa_pass, create_storageopt_pass, Context, Function, IrError,
+    Module, ARGDEMOTION_NAME, CONSTCOMBINE_NAME, CONSTDEMOTION_NAME, DCE_NAME, DSE_NAME,
+    FNDEDUP_NAME, FUNC_DCE_NAME, GVN_NAME, INLINE_MODULE_NAME, INLINE_MODULE_SIZE_NAME, LICM_NAME,
+    MEM2REG_NAME, MEMCPYOPT_NAME, MISCDEMOTION_NAME, RETDEMOTION_NAME, SCCP_NAME, SIMPLIFYCFG_NAME,
+    SROA_NAME, STORAGEOPT_NAME,
 };
 use downcast_rs::{impl_downcast, Downcast};
 use rustc_hash::FxHashMap;
 use std::{
-    any::{type_name, TypeId},
+    any::{type_name, Any, TypeId},
     collections::hash_map,
 };
 
@@ -79,9 +83,19 @@ pub struct AnalysisResults {
     // Hash from (AnalysisResultT, (PassScope, Scope Identity)) to an actual result.
     results: FxHashMap<(TypeId, (TypeId, generational_arena::Index)), AnalysisResult>,
     name_typeid_map: FxHashMap<&'static str, TypeId>,
+    // The pass configurations set with `PassManager::set_config`, keyed by their type.  They're
+    // kept here as these results are all that the pass manager gives to a pass.
+    configs: FxHashMap<TypeId, Box<dyn Any>>,
 }
 
 impl AnalysisResults {
+    /// Get the pass configuration of type `T`, if one was set with [PassManager::set_config].
+    pub fn get_config<T: 'static>(&self) -> Option<&T> {
+        self.configs
+            .get(&TypeId::of::<T>())
+            .and_then(|config| config.downcast_ref())
+    }
+
     /// Get the results of an analysis.
     /// Example analyses.get_analysis_result::<DomTreeAnalysis>(foo).
     pub fn get_analysis_result<T: AnalysisResultT, S: PassScope + 'static>(&self, scope: S) -> &T {
@@ -141,10 +155,28 @@ impl AnalysisResults {
     }
 }
 
+/// A transformation pass run over a module or one of its functions.
+#[derive(Clone, Debug)]
+pub struct TransformRun {
+    /// The pass name.
+    pub pass: &'static str,
+    /// The position of the pass among all the passes run.
+    pub pass_idx: usize,
+    /// The name of the function transformed, or `None` when it's a module pass.
+    pub function: Option<String>,
+    /// Whether the pass reported any changes.
+    pub modified: bool,
+}
+
 #[derive(Default)]
 pub struct PassManager {
     passes: FxHashMap<&'static str, Pass>,
     analyses: AnalysisResults,
+    /// The number of passes run so far.
+    pass_count: usize,
+    /// The maximum number of transformations to run, after which they're skipped.
+    transform_limit: Option<usize>,
+    transform_log: Vec<TransformRun>,
 }
 
 impl PassManager {
@@ -199,7 +231,17 @@ impl PassManager {
                         }
                     }
                     PassMutability::Transform(transform) => {
-                        if transform(ir, &self.analyses, m)? {
+                        if self.is_transform_limit_reached() {
+                            continue;
+                        }
+                        let changed = transform(ir, &self.analyses, m)?;
+                        self.transform_log.push(TransformRun {
+                            pass: pass_t.name,
+                            pass_idx: self.pass_count,
+                            function: None,
+                            modified: changed,
+                        });
+                        if changed {
                             self.analyses.invalidate_all_results_at_scope(m);
                             for f in m.function_iter(ir) {
                                 self.analyses.invalidate_all_results_at_scope(f);
@@ -218,7 +260,17 @@ impl PassManager {
                                 }
                             }
                             PassMutability::Transform(transform) => {
-                                if transform(ir, &self.analyses, f)? {
+                                if self.is_transform_limit_reached() {
+                                    continue;
+                                }
+                                let changed = transform(ir, &self.analyses, f)?;
+                                self.transform_log.push(TransformRun {
+                                    pass: pass_t.name,
+                                    pass_idx: self.pass_count,
+                                    function: Some(f.get_name(ir).to_owned()),
+                                    modified: changed,
+                                });
+                                if changed {
                                     self.analyses.invalidate_all_results_at_scope(f);
                                     self.analyses.invalidate_all_results_at_scope(m);
                                     modified = true;
@@ -237,10 +289,38 @@ impl PassManager {
         let mut modified = false;
         for pass in passes.flatten_pass_group() {
             modified |= self.actually_run(ir, pass)?;
+            self.pass_count += 1;
         }
         Ok(modified)
     }
 
+    /// Set the configuration of type `T` for the passes which read it with
+    /// [AnalysisResults::get_config], such as [InlineConfig](crate::InlineConfig) for the module
+    /// inlining passes.
+    pub fn set_config<T: 'static>(&mut self, config: T) {
+        self.analyses
+            .configs
+            .insert(TypeId::of::<T>(), Box::new(config));
+    }
+
+    /// Only run the first `limit` transformations of a module or function, skipping the rest.
+    ///
+    /// The transformations are run in the same order for the same input, so that a
+    /// miscompilation may be bisected to the first transformation with a wrong result.
+    pub fn set_transform_limit(&mut self, limit: Option<usize>) {
+        self.transform_limit = limit;
+    }
+
+    /// The transformations run so far, in order.
+    pub fn transform_log(&self) -> &[TransformRun] {
+        &self.transform_log
+    }
+
+    fn is_transform_limit_reached(&self) -> bool {
+        self.transform_limit
+            .is_some_and(|limit| self.transform_log.len() >= limit)
+    }
+
     /// Get reference to a registered pass.
     pub fn lookup_registered_pass(&self, name: &str) -> Option<&Pass> {
         self.passes.get(name)
@@ -258,6 +338,77 @@ impl PassManager {
     }
 }
 
+/// The outcome of [`bisect_transforms`].
+pub enum BisectOutcome<'eng> {
+    /// The checked result is unchanged by all of the transformations.
+    Unchanged {
+        transforms: Vec<TransformRun>,
+        result: String,
+    },
+    /// The checked result is first changed by `transforms[culprit]`, with `ir` as it is after it.
+    Changed {
+        transforms: Vec<TransformRun>,
+        culprit: usize,
+        before: String,
+        after: String,
+        ir: Context<'eng>,
+    },
+}
+
+/// Find the first transformation run by `passes` which changes the result of `check` on the IR
+/// produced by `parse`, by bisecting a limit on the number of transformations run.
+///
+/// Each run uses a new [`PassManager`] with the passes registered by `register`.
+pub fn bisect_transforms<'eng, E: From<IrError>>(
+    parse: impl Fn() -> Result<Context<'eng>, E>,
+    register: impl Fn(&mut PassManager),
+    passes: &PassGroup,
+    mut check: impl FnMut(&Context) -> Result<String, E>,
+) -> Result<BisectOutcome<'eng>, E> {
+    let run_passes = |limit: Option<usize>| -> Result<_, E> {
+        let mut ir = parse()?;
+        let mut pass_mgr = PassManager::default();
+        register(&mut pass_mgr);
+        pass_mgr.set_transform_limit(limit);
+        pass_mgr.run(&mut ir, passes)?;
+        Ok((ir, pass_mgr.transform_log))
+    };
+
+    let expected = check(&run_passes(Some(0))?.0)?;
+    let (mut bad_ir, transforms) = run_passes(None)?;
+    let mut bad_result = check(&bad_ir)?;
+    if bad_result == expected {
+        return Ok(BisectOutcome::Unchanged {
+            transforms,
+            result: expected,
+        });
+    }
+
+    // Find the fewest transformations which change the result, which are more than `good` and
+    // at most `bad`.
+    let (mut good, mut bad) = (0, transforms.len());
+    while bad - good > 1 {
+        let limit = good + (bad - good) / 2;
+        let (ir, _) = run_passes(Some(limit))?;
+        let result = check(&ir)?;
+        if result == expected {
+            good = limit;
+        } else {
+            bad = limit;
+            bad_result = result;
+            bad_ir = ir;
+        }
+    }
+
+    Ok(BisectOutcome::Changed {
+        transforms,
+        culprit: bad - 1,
+        before: expected,
+        after: bad_result,
+        ir: bad_ir,
+    })
+}
+
 /// A group of passes.
 /// Can contain sub-groups.
 #[derive(Default)]
@@ -302,6 +453,7 @@ pub fn register_known_passes(pm: &mut PassManager) {
     pm.register(create_postorder_pass());
     pm.register(create_dominators_pass());
     pm.register(create_dom_fronts_pass());
+    pm.register(create_loops_pass());
     pm.register(create_escaped_symbols_pass());
     pm.register(create_module_printer_pass());
     pm.register(create_module_verifier_pass());
@@ -310,11 +462,17 @@ pub fn register_known_passes(pm: &mut PassManager) {
     pm.register(create_mem2reg_pass());
     pm.register(create_sroa_pass());
     pm.register(create_inline_in_module_pass());
+    pm.register(create_inline_in_module_for_size_pass());
     pm.register(create_inline_in_main_pass());
     pm.register(create_const_combine_pass());
     pm.register(create_simplify_cfg_pass());
+    pm.register(create_sccp_pass());
+    pm.register(create_gvn_pass());
+    pm.register(create_licm_pass());
+    pm.register(create_storageopt_pass());
     pm.register(create_func_dce_pass());
     pm.register(create_dce_pass());
+    pm.register(create_dse_pass());
     pm.register(create_arg_demotion_pass());
     pm.register(create_const_demotion_pass());
     pm.register(create_ret_demotion_pass());
@@ -322,6 +480,14 @@ pub fn register_known_passes(pm: &mut PassManager) {
     pm.register(create_memcpyopt_pass());
 }
 
+/// Create the pass group run at optimization level 0.
+///
+/// No optimizations are run, so that functions are never inlined and the code generated for
+/// each function follows its source closely.
+pub fn create_o0_pass_group() -> PassGroup {
+    PassGroup::default()
+}
+
 pub fn create_o1_pass_group() -> PassGroup {
     // Create a configuration to specify which passes we want to run now.
     let mut o1 = PassGroup::default();
@@ -331,14 +497,91 @@ pub fn create_o1_pass_group() -> PassGroup {
     o1.append_pass(FNDEDUP_NAME);
     o1.append_pass(CONSTCOMBINE_NAME);
     o1.append_pass(SIMPLIFYCFG_NAME);
+    o1.append_pass(SCCP_NAME);
     o1.append_pass(CONSTCOMBINE_NAME);
     o1.append_pass(SIMPLIFYCFG_NAME);
+    o1.append_pass(GVN_NAME);
+    o1.append_pass(STORAGEOPT_NAME);
+    o1.append_pass(LICM_NAME);
+    o1.append_pass(DSE_NAME);
     o1.append_pass(FUNC_DCE_NAME);
     o1.append_pass(DCE_NAME);
 
     o1
 }
 
+/// Create the pass group run at optimization level 2.
+///
+/// The level 1 passes are followed by another round of inlining and simplification, which picks
+/// up the functions that only became small enough to inline once their callees were optimized.
+pub fn create_o2_pass_group() -> PassGroup {
+    let mut o2 = PassGroup::default();
+    o2.append_group(create_o1_pass_group());
+    o2.append_pass(INLINE_MODULE_NAME);
+    o2.append_pass(CONSTCOMBINE_NAME);
+    o2.append_pass(SCCP_NAME);
+    o2.append_pass(SIMPLIFYCFG_NAME);
+    o2.append_pass(GVN_NAME);
+    o2.append_pass(STORAGEOPT_NAME);
+    o2.append_pass(LICM_NAME);
+    o2.append_pass(DSE_NAME);
+    o2.append_pass(FUNC_DCE_NAME);
+    o2.append_pass(DCE_NAME);
+
+    o2
+}
+
+/// Create the pass group optimizing for bytecode size.
+///
+/// This runs the level 1 passes, but only inlines the functions which are called once or which
+/// are smaller than a call, as inlining other functions duplicates their bodies.
+pub fn create_os_pass_group() -> PassGroup {
+    let mut os = PassGroup::default();
+    os.append_pass(MEM2REG_NAME);
+    os.append_pass(INLINE_MODULE_SIZE_NAME);
+    os.append_pass(FNDEDUP_NAME);
+    os.append_pass(CONSTCOMBINE_NAME);
+    os.append_pass(SIMPLIFYCFG_NAME);
+    os.append_pass(SCCP_NAME);
+    os.append_pass(CONSTCOMBINE_NAME);
+    os.append_pass(SIMPLIFYCFG_NAME);
+    os.append_pass(GVN_NAME);
+    os.append_pass(STORAGEOPT_NAME);
+    os.append_pass(LICM_NAME);
+    os.append_pass(DSE_NAME);
+    os.append_pass(FUNC_DCE_NAME);
+    os.append_pass(DCE_NAME);
+
+    os
+}
+
+/// Create the pass group lowering the IR for the FuelVM target, which is run after the
+/// optimization passes.
+pub fn create_fuel_lowering_pass_group() -> PassGroup {
+    let mut lowering = PassGroup::default();
+    // Demote large by-value constants, arguments and return values to by-reference values
+    // using temporaries.
+    lowering.append_pass(CONSTDEMOTION_NAME);
+    lowering.append_pass(ARGDEMOTION_NAME);
+    lowering.append_pass(RETDEMOTION_NAME);
+    lowering.append_pass(MISCDEMOTION_NAME);
+
+    // Convert loads and stores to mem_copys where possible.
+    lowering.append_pass(MEMCPYOPT_NAME);
+
+    // Remove the initializing copies to the temporaries which are overwritten or never read.
+    lowering.append_pass(DSE_NAME);
+
+    // Run a DCE and simplify-cfg to clean up any obsolete instructions.
+    lowering.append_pass(DCE_NAME);
+    lowering.append_pass(SIMPLIFYCFG_NAME);
+    lowering.append_pass(SROA_NAME);
+    lowering.append_pass(MEM2REG_NAME);
+    lowering.append_pass(DCE_NAME);
+
+    lowering
+}
+
 /// Utility to insert a pass after every pass in the given group
 pub fn insert_after_each(pg: PassGroup, pass: &'static str) -> PassGroup {
     PassGroup(
@@ -457,6 +457,12 @@ fn instruction_to_doc<'a>(
                     BinaryOpKind::Mod => "mod",
                     BinaryOpKind::Rsh => "rsh",
                     BinaryOpKind::Lsh => "lsh",
+                    BinaryOpKind::SAdd => "sadd",
+                    BinaryOpKind::SSub => "ssub",
+                    BinaryOpKind::SMul => "smul",
+                    BinaryOpKind::SDiv => "sdiv",
+                    BinaryOpKind::SMod => "smod",
+                    BinaryOpKind::SRsh => "srsh",
                 };
                 maybe_constant_to_doc(context, md_namer, namer, arg1)
                     .append(maybe_constant_to_doc(context, md_namer, namer, arg2))
@@ -525,6 +531,8 @@ fn instruction_to_doc<'a>(
                     Predicate::Equal => "eq",
                     Predicate::LessThan => "lt",
                     Predicate::GreaterThan => "gt",
+                    Predicate::SLessThan => "slt",
+                    Predicate::SGreaterThan => "sgt",
                 };
                 maybe_constant_to_doc(context, md_namer, namer, lhs_value)
                     .append(maybe_constant_to_doc(context, md_namer, namer, rhs_value))
@@ -775,6 +783,12 @@ fn instruction_to_doc<'a>(
                         BinaryOpKind::Mod => "mod",
                         BinaryOpKind::Rsh => "rsh",
                         BinaryOpKind::Lsh => "lsh",
+                        BinaryOpKind::SAdd => "sadd",
+                        BinaryOpKind::SSub => "ssub",
+                        BinaryOpKind::SMul => "smul",
+                        BinaryOpKind::SDiv => "sdiv",
+                        BinaryOpKind::SMod => "smod",
+                        BinaryOpKind::SRsh => "srsh",
                     };
                     maybe_constant_to_doc(context, md_namer, namer, arg1)
                         .append(maybe_constant_to_doc(context, md_namer, namer, arg2))
@@ -819,6 +833,8 @@ fn instruction_to_doc<'a>(
                         Predicate::Equal => "eq",
                         Predicate::LessThan => "lt",
                         Predicate::GreaterThan => "gt",
+                        Predicate::SLessThan => "slt",
+                        Predicate::SGreaterThan => "sgt",
                     };
                     maybe_constant_to_doc(context, md_namer, namer, arg1)
                         .append(maybe_constant_to_doc(context, md_namer, namer, arg2))
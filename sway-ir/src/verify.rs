@@ -350,7 +350,7 @@ impl<'a, 'eng> InstructionVerifier<'a, 'eng> {
         Ok(())
     }
 
-    fn verify_wide_cmp(&self, _: &Predicate, arg1: &Value, arg2: &Value) -> Result<(), IrError> {
+    fn verify_wide_cmp(&self, pred: &Predicate, arg1: &Value, arg2: &Value) -> Result<(), IrError> {
         let arg1_ty = arg1
             .get_type(self.context)
             .ok_or(IrError::VerifyBinaryOpIncorrectArgType)?;
@@ -358,7 +358,10 @@ impl<'a, 'eng> InstructionVerifier<'a, 'eng> {
             .get_type(self.context)
             .ok_or(IrError::VerifyBinaryOpIncorrectArgType)?;
 
-        if arg1_ty.is_ptr(self.context) && arg2_ty.is_ptr(self.context) {
+        if arg1_ty.is_ptr(self.context)
+            && arg2_ty.is_ptr(self.context)
+            && !matches!(pred, Predicate::SLessThan | Predicate::SGreaterThan)
+        {
             Ok(())
         } else {
             Err(IrError::VerifyBinaryOpIncorrectArgType)
@@ -439,6 +442,15 @@ impl<'a, 'eng> InstructionVerifier<'a, 'eng> {
                     return Err(IrError::VerifyBinaryOpIncorrectArgType);
                 }
             }
+            // Signed operations only exist for 64 bit values.
+            BinaryOpKind::SAdd
+            | BinaryOpKind::SSub
+            | BinaryOpKind::SMul
+            | BinaryOpKind::SDiv
+            | BinaryOpKind::SMod
+            | BinaryOpKind::SRsh => {
+                return Err(IrError::VerifyBinaryOpIncorrectArgType);
+            }
         }
 
         Ok(())
@@ -501,6 +513,20 @@ impl<'a, 'eng> InstructionVerifier<'a, 'eng> {
                     return Err(IrError::VerifyBinaryOpIncorrectArgType);
                 }
             }
+            BinaryOpKind::SRsh => {
+                if !arg1_ty.is_uint64(self.context) || !arg2_ty.is_uint(self.context) {
+                    return Err(IrError::VerifyBinaryOpIncorrectArgType);
+                }
+            }
+            BinaryOpKind::SAdd
+            | BinaryOpKind::SSub
+            | BinaryOpKind::SMul
+            | BinaryOpKind::SDiv
+            | BinaryOpKind::SMod => {
+                if !arg1_ty.is_uint64(self.context) || !arg2_ty.is_uint64(self.context) {
+                    return Err(IrError::VerifyBinaryOpIncorrectArgType);
+                }
+            }
         }
 
         Ok(())
@@ -633,7 +659,7 @@ impl<'a, 'eng> InstructionVerifier<'a, 'eng> {
 
     fn verify_cmp(
         &self,
-        _pred: &Predicate,
+        pred: &Predicate,
         lhs_value: &Value,
         rhs_value: &Value,
     ) -> Result<(), IrError> {
@@ -648,6 +674,15 @@ impl<'a, 'eng> InstructionVerifier<'a, 'eng> {
                         lhs_ty.as_string(self.context),
                         rhs_ty.as_string(self.context),
                     ))
+                } else if matches!(pred, Predicate::SLessThan | Predicate::SGreaterThan) {
+                    if lhs_ty.is_uint64(self.context) {
+                        Ok(())
+                    } else {
+                        Err(IrError::VerifyCmpBadTypes(
+                            lhs_ty.as_string(self.context),
+                            rhs_ty.as_string(self.context),
+                        ))
+                    }
                 } else if lhs_ty.is_bool(self.context)
                     || lhs_ty.is_uint(self.context)
                     || lhs_ty.is_b256(self.context)
@@ -1,11 +1,16 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use sway_ir::{
-    create_arg_demotion_pass, create_const_combine_pass, create_const_demotion_pass,
-    create_dce_pass, create_dom_fronts_pass, create_dominators_pass, create_escaped_symbols_pass,
-    create_mem2reg_pass, create_memcpyopt_pass, create_misc_demotion_pass, create_postorder_pass,
-    create_ret_demotion_pass, create_simplify_cfg_pass, optimize as opt, register_known_passes,
-    Context, PassGroup, PassManager, DCE_NAME, MEM2REG_NAME, SROA_NAME,
+    bisect_transforms, create_arg_demotion_pass, create_const_combine_pass,
+    create_const_demotion_pass, create_dce_pass, create_dom_fronts_pass, create_dominators_pass,
+    create_dse_pass, create_escaped_symbols_pass, create_fuel_lowering_pass_group, create_gvn_pass,
+    create_inline_in_module_for_size_pass, create_licm_pass, create_loops_pass,
+    create_mem2reg_pass, create_memcpyopt_pass, create_misc_demotion_pass, create_o0_pass_group,
+    create_o1_pass_group, create_o2_pass_group, create_os_pass_group, create_postorder_pass,
+    create_ret_demotion_pass, create_sccp_pass, create_simplify_cfg_pass, create_storageopt_pass,
+    optimize as opt, register_known_passes, AnalysisResults, BinaryOpKind, BisectOutcome, Context,
+    Function, InlineConfig, InstOp, Instruction, IrError, Pass, PassGroup, PassManager,
+    PassMutability, ScopedPass, DCE_NAME, INLINE_MODULE_NAME, MEM2REG_NAME, SROA_NAME,
 };
 use sway_types::SourceEngine;
 
@@ -41,27 +46,30 @@ fn run_tests<F: Fn(&str, &mut Context) -> bool>(sub_dir: &str, opt_fn: F) {
         });
 
         let output = sway_ir::printer::to_string(&ir);
+        filecheck(&path, &input, &output);
+    }
+}
 
-        let chkr = filecheck::CheckerBuilder::new()
-            .text(&input)
-            .unwrap()
-            .finish();
-        if chkr.is_empty() {
-            println!("{output}");
-            panic!("No filecheck directives found in test: {}", path.display());
-        }
+fn filecheck(path: &Path, input: &str, output: &str) {
+    let chkr = filecheck::CheckerBuilder::new()
+        .text(input)
+        .unwrap()
+        .finish();
+    if chkr.is_empty() {
+        println!("{output}");
+        panic!("No filecheck directives found in test: {}", path.display());
+    }
 
-        match chkr.explain(&output, filecheck::NO_VARIABLES) {
-            Ok((success, report)) if !success => {
-                println!("--- FILECHECK FAILED FOR {}", path.display());
-                println!("{report}");
-                panic!()
-            }
-            Err(e) => {
-                panic!("filecheck directive error while checking: {e}");
-            }
-            _ => (),
+    match chkr.explain(output, filecheck::NO_VARIABLES) {
+        Ok((success, report)) if !success => {
+            println!("--- FILECHECK FAILED FOR {}", path.display());
+            println!("{report}");
+            panic!()
+        }
+        Err(e) => {
+            panic!("filecheck directive error while checking: {e}");
         }
+        _ => (),
     }
 }
 
@@ -119,6 +127,113 @@ fn inline() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[allow(clippy::needless_collect)]
+#[test]
+fn gvn() {
+    run_tests("gvn", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_postorder_pass());
+        pass_mgr.register(create_dominators_pass());
+        let pass = pass_mgr.register(create_gvn_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn licm() {
+    run_tests("licm", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_postorder_pass());
+        pass_mgr.register(create_dominators_pass());
+        pass_mgr.register(create_loops_pass());
+        let pass = pass_mgr.register(create_licm_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn sccp() {
+    run_tests("sccp", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        let pass = pass_mgr.register(create_sccp_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn storageopt() {
+    run_tests("storageopt", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_postorder_pass());
+        pass_mgr.register(create_escaped_symbols_pass());
+        let pass = pass_mgr.register(create_storageopt_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn dse() {
+    run_tests("dse", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_postorder_pass());
+        pass_mgr.register(create_escaped_symbols_pass());
+        let pass = pass_mgr.register(create_dse_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn inline_size() {
+    run_tests("inline_size", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        let pass = pass_mgr.register(create_inline_in_module_for_size_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn inline_module() {
+    run_tests("inline_module", |first_line, ir: &mut Context| {
+        // The size budget in bytes may be given on the first line, e.g. `// size_budget 400`.
+        let size_budget = first_line
+            .strip_prefix("// size_budget ")
+            .and_then(|budget| budget.trim().parse().ok());
+
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        register_known_passes(&mut pass_mgr);
+        pass_mgr.set_config(InlineConfig { size_budget });
+        pass_group.append_pass(INLINE_MODULE_NAME);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 // Clippy suggests using the map iterator below directly instead of collecting from it first, but
 // if we try that then we have borrowing issues with `ir` which is used within the closure.
 #[allow(clippy::needless_collect)]
@@ -276,3 +391,184 @@ fn serialize() {
 }
 
 // -------------------------------------------------------------------------------------------------
+
+#[test]
+fn interpreter() {
+    // The result of running `main` is checked, and then it must be the same once the module is
+    // optimized at each level and demoted for the Fuel target.
+    let source_engine = SourceEngine::default();
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let dir: PathBuf = format!("{manifest_dir}/tests/interpreter").into();
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let input = std::fs::read_to_string(&path).unwrap();
+
+        let run_main = |ir: &Context| {
+            let main = ir
+                .module_iter()
+                .flat_map(|module| module.function_iter(ir))
+                .find(|function| function.get_name(ir) == "main")
+                .unwrap_or_else(|| panic!("No 'main' function in {}.", path.display()));
+            sway_ir::interpreter::execute(ir, main, &[]).unwrap_or_else(|err| {
+                panic!("Failed to run {}: {err}", path.display());
+            })
+        };
+
+        let ir = sway_ir::parser::parse(&input, &source_engine).unwrap_or_else(|parse_err| {
+            println!("{}: {parse_err}", path.display());
+            panic!()
+        });
+        let expected = run_main(&ir);
+        filecheck(&path, &input, &expected.to_string());
+
+        for (level, mut pass_group) in [
+            ("0", create_o0_pass_group()),
+            ("1", create_o1_pass_group()),
+            ("2", create_o2_pass_group()),
+            ("s", create_os_pass_group()),
+        ] {
+            pass_group.append_group(create_fuel_lowering_pass_group());
+            let mut pass_mgr = PassManager::default();
+            register_known_passes(&mut pass_mgr);
+
+            let mut ir = sway_ir::parser::parse(&input, &source_engine).unwrap();
+            pass_mgr.run(&mut ir, &pass_group).unwrap();
+            let ir = ir.verify().unwrap_or_else(|err| {
+                println!("{err}");
+                panic!();
+            });
+            assert_eq!(
+                run_main(&ir),
+                expected,
+                "Running {} changed once optimized at level {level}.",
+                path.display()
+            );
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Bisecting a miscompilation to the transformation which changes the result of running `main`, as
+// `opt --bisect --run main` does.
+
+// A deliberately miscompiling pass, replacing each `add` with a `sub`.
+fn miscompile(
+    context: &mut Context,
+    _: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let adds = function
+        .instruction_iter(context)
+        .filter(|(_, inst)| {
+            matches!(
+                inst.get_instruction(context),
+                Some(Instruction {
+                    op: InstOp::BinaryOp {
+                        op: BinaryOpKind::Add,
+                        ..
+                    },
+                    ..
+                })
+            )
+        })
+        .map(|(_, inst)| inst)
+        .collect::<Vec<_>>();
+    for inst in &adds {
+        if let Some(Instruction {
+            op: InstOp::BinaryOp { op, .. },
+            ..
+        }) = inst.get_instruction_mut(context)
+        {
+            *op = BinaryOpKind::Sub;
+        }
+    }
+    Ok(!adds.is_empty())
+}
+
+#[test]
+fn bisect() {
+    let source_engine = SourceEngine::default();
+    let input = r#"
+        script {
+            entry fn main() -> u64 {
+                entry():
+                v0 = const u64 20
+                v1 = call inc(v0)
+                ret u64 v1
+            }
+
+            fn inc(x: u64) -> u64 {
+                entry(x: u64):
+                v0 = const u64 1
+                v1 = add x, v0
+                ret u64 v1
+            }
+        }
+    "#;
+
+    let register = |pass_mgr: &mut PassManager| {
+        register_known_passes(pass_mgr);
+        pass_mgr.register(Pass {
+            name: "miscompile",
+            descr: "Replace each add with a sub.",
+            deps: vec![],
+            runner: ScopedPass::FunctionPass(PassMutability::Transform(miscompile)),
+        });
+    };
+    let run_main = |ir: &Context| -> Result<String, IrError> {
+        let main = ir
+            .module_iter()
+            .flat_map(|module| module.function_iter(ir))
+            .find(|function| function.get_name(ir) == "main")
+            .unwrap();
+        Ok(match sway_ir::interpreter::execute(ir, main, &[]) {
+            Ok(execution) => execution.to_string(),
+            Err(err) => format!("error: {err}"),
+        })
+    };
+
+    let mut passes = PassGroup::default();
+    passes.append_pass(MEM2REG_NAME);
+    passes.append_pass("miscompile");
+    passes.append_pass(DCE_NAME);
+    let outcome = bisect_transforms(
+        || sway_ir::parser::parse(input, &source_engine),
+        register,
+        &passes,
+        run_main,
+    )
+    .unwrap();
+
+    let BisectOutcome::Changed {
+        transforms,
+        culprit,
+        before,
+        after,
+        ir,
+    } = outcome
+    else {
+        panic!("The miscompilation wasn't found.");
+    };
+    assert_eq!(before.trim_end(), "ret 21");
+    assert_eq!(after.trim_end(), "ret 19");
+    assert_eq!(transforms.len(), 6);
+    assert_eq!(transforms[culprit].pass, "miscompile");
+    assert_eq!(transforms[culprit].pass_idx, 1);
+    assert_eq!(transforms[culprit].function.as_deref(), Some("inc"));
+    assert_eq!(run_main(&ir).unwrap(), after);
+
+    // Without the miscompiling pass, nothing changes the result.
+    let mut passes = PassGroup::default();
+    passes.append_pass(MEM2REG_NAME);
+    passes.append_pass(DCE_NAME);
+    let outcome = bisect_transforms(
+        || sway_ir::parser::parse(input, &source_engine),
+        register,
+        &passes,
+        run_main,
+    )
+    .unwrap();
+    assert!(matches!(outcome, BisectOutcome::Unchanged { .. }));
+}
+
+// -------------------------------------------------------------------------------------------------
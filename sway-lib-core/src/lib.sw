@@ -5,6 +5,7 @@ pub mod raw_ptr;
 pub mod raw_slice;
 pub mod r#str;
 pub mod ops;
+pub mod range;
 pub mod primitive_conversions;
 pub mod never;
 pub mod r#storage;
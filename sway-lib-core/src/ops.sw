@@ -90,6 +90,67 @@ impl Add for u8 {
     }
 }
 
+impl Add for i64 {
+    fn add(self, other: Self) -> Self {
+        let res = __add(self, other);
+        // adding a negative value must decrease the result and vice versa,
+        // otherwise the addition wrapped around
+        if __eq(__lt(other, 0), __lt(res, self)) {
+            res
+        } else {
+            __revert(0)
+        }
+    }
+}
+
+impl Add for i32 {
+    fn add(self, other: Self) -> Self {
+        // any non-64-bit value is sign extended to a u64 value under-the-hood
+        let res = __add(self, other);
+        if __gt(res, Self::max()) {
+            // integer overflow
+            __revert(0)
+        } else if __lt(res, Self::min()) {
+            // integer underflow
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
+impl Add for i16 {
+    fn add(self, other: Self) -> Self {
+        // any non-64-bit value is sign extended to a u64 value under-the-hood
+        let res = __add(self, other);
+        if __gt(res, Self::max()) {
+            // integer overflow
+            __revert(0)
+        } else if __lt(res, Self::min()) {
+            // integer underflow
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
+impl Add for i8 {
+    fn add(self, other: Self) -> Self {
+        // any non-64-bit value is sign extended to a u64 value under-the-hood
+        let res = __add(self, other);
+        if __gt(res, Self::max()) {
+            // integer overflow
+            __revert(0)
+        } else if __lt(res, Self::min()) {
+            // integer underflow
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
 /// Trait for the subtraction of two values.
 pub trait Subtract {
     /// Subtract two values of the same type.
@@ -160,6 +221,64 @@ impl Subtract for u8 {
     }
 }
 
+impl Subtract for i64 {
+    fn subtract(self, other: Self) -> Self {
+        let res = __sub(self, other);
+        // subtracting a negative value must increase the result and vice versa,
+        // otherwise the subtraction wrapped around
+        if __eq(__lt(other, 0), __gt(res, self)) {
+            res
+        } else {
+            __revert(0)
+        }
+    }
+}
+
+impl Subtract for i32 {
+    fn subtract(self, other: Self) -> Self {
+        let res = __sub(self, other);
+        if __gt(res, Self::max()) {
+            // integer overflow
+            __revert(0)
+        } else if __lt(res, Self::min()) {
+            // integer underflow
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
+impl Subtract for i16 {
+    fn subtract(self, other: Self) -> Self {
+        let res = __sub(self, other);
+        if __gt(res, Self::max()) {
+            // integer overflow
+            __revert(0)
+        } else if __lt(res, Self::min()) {
+            // integer underflow
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
+impl Subtract for i8 {
+    fn subtract(self, other: Self) -> Self {
+        let res = __sub(self, other);
+        if __gt(res, Self::max()) {
+            // integer overflow
+            __revert(0)
+        } else if __lt(res, Self::min()) {
+            // integer underflow
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
 /// Trait for the multiplication of two values.
 pub trait Multiply {
     /// Multiply two values of the same type.
@@ -248,6 +367,73 @@ impl Multiply for u8 {
     }
 }
 
+impl Multiply for i64 {
+    fn multiply(self, other: Self) -> Self {
+        let res = __mul(self, other);
+        // dividing the result by one factor gives back the other one unless
+        // the multiplication wrapped around, except for `-1 * Self::min()`
+        // which wraps around to `Self::min()` and divides back to it
+        if __eq(self, 0) {
+            res
+        } else if __eq(self, -1) {
+            if __eq(other, Self::min()) {
+                __revert(0)
+            } else {
+                res
+            }
+        } else if __eq(__div(res, self), other) {
+            res
+        } else {
+            __revert(0)
+        }
+    }
+}
+
+impl Multiply for i32 {
+    fn multiply(self, other: Self) -> Self {
+        let res = __mul(self, other);
+        if __gt(res, Self::max()) {
+            // integer overflow
+            __revert(0)
+        } else if __lt(res, Self::min()) {
+            // integer underflow
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
+impl Multiply for i16 {
+    fn multiply(self, other: Self) -> Self {
+        let res = __mul(self, other);
+        if __gt(res, Self::max()) {
+            // integer overflow
+            __revert(0)
+        } else if __lt(res, Self::min()) {
+            // integer underflow
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
+impl Multiply for i8 {
+    fn multiply(self, other: Self) -> Self {
+        let res = __mul(self, other);
+        if __gt(res, Self::max()) {
+            // integer overflow
+            __revert(0)
+        } else if __lt(res, Self::min()) {
+            // integer underflow
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
 /// Trait for the division of two values.
 pub trait Divide {
     /// Divide two values of the same type.
@@ -299,9 +485,8 @@ impl Divide for u64 {
 }
 
 // division for unsigned integers cannot overflow,
-// but if signed integers are ever introduced,
-// overflow needs to be handled, since
-// Self::max() / -1 overflows
+// but signed division does, since Self::min() / -1
+// does not fit in Self
 impl Divide for u32 {
     fn divide(self, other: Self) -> Self {
         __div(self, other)
@@ -320,6 +505,53 @@ impl Divide for u8 {
     }
 }
 
+impl Divide for i64 {
+    fn divide(self, other: Self) -> Self {
+        if __eq(other, -1) {
+            if __eq(self, Self::min()) {
+                __revert(0)
+            } else {
+                __sub(0, self)
+            }
+        } else {
+            __div(self, other)
+        }
+    }
+}
+
+impl Divide for i32 {
+    fn divide(self, other: Self) -> Self {
+        let res = __div(self, other);
+        if __gt(res, Self::max()) {
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
+impl Divide for i16 {
+    fn divide(self, other: Self) -> Self {
+        let res = __div(self, other);
+        if __gt(res, Self::max()) {
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
+impl Divide for i8 {
+    fn divide(self, other: Self) -> Self {
+        let res = __div(self, other);
+        if __gt(res, Self::max()) {
+            __revert(0)
+        } else {
+            res
+        }
+    }
+}
+
 /// Trait for the modulo of two values.
 pub trait Mod {
     /// Modulo two values of the same type.
@@ -388,6 +620,30 @@ impl Mod for u8 {
     }
 }
 
+impl Mod for i64 {
+    fn modulo(self, other: Self) -> Self {
+        __mod(self, other)
+    }
+}
+
+impl Mod for i32 {
+    fn modulo(self, other: Self) -> Self {
+        __mod(self, other)
+    }
+}
+
+impl Mod for i16 {
+    fn modulo(self, other: Self) -> Self {
+        __mod(self, other)
+    }
+}
+
+impl Mod for i8 {
+    fn modulo(self, other: Self) -> Self {
+        __mod(self, other)
+    }
+}
+
 /// Trait to invert a type.
 pub trait Not {
     /// Inverts the value of the type.
@@ -465,6 +721,106 @@ impl Not for u8 {
     }
 }
 
+impl Not for i64 {
+    fn not(self) -> Self {
+        __not(self)
+    }
+}
+
+impl Not for i32 {
+    fn not(self) -> Self {
+        __not(self)
+    }
+}
+
+impl Not for i16 {
+    fn not(self) -> Self {
+        __not(self)
+    }
+}
+
+impl Not for i8 {
+    fn not(self) -> Self {
+        __not(self)
+    }
+}
+
+/// Trait to negate a type.
+pub trait Neg {
+    /// Negates the value of the type.
+    ///
+    /// # Returns
+    ///
+    /// * [Self] - The result of the negation.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// struct MyStruct {
+    ///     val: i64,
+    /// }
+    ///
+    /// impl Neg for MyStruct {
+    ///     fn neg(self) -> Self {
+    ///         Self {
+    ///             val: -self.val,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn foo() {
+    ///     let struct = MyStruct { val: 1 };
+    ///     let result_struct = -struct;
+    ///     assert(result_struct.val == -1);
+    /// }
+    /// ```
+    fn neg(self) -> Self;
+}
+
+impl Neg for i64 {
+    fn neg(self) -> Self {
+        // the negation of Self::min() does not fit in Self
+        if __eq(self, Self::min()) {
+            __revert(0)
+        } else {
+            __sub(0, self)
+        }
+    }
+}
+
+impl Neg for i32 {
+    fn neg(self) -> Self {
+        // the negation of Self::min() does not fit in Self
+        if __eq(self, Self::min()) {
+            __revert(0)
+        } else {
+            __sub(0, self)
+        }
+    }
+}
+
+impl Neg for i16 {
+    fn neg(self) -> Self {
+        // the negation of Self::min() does not fit in Self
+        if __eq(self, Self::min()) {
+            __revert(0)
+        } else {
+            __sub(0, self)
+        }
+    }
+}
+
+impl Neg for i8 {
+    fn neg(self) -> Self {
+        // the negation of Self::min() does not fit in Self
+        if __eq(self, Self::min()) {
+            __revert(0)
+        } else {
+            __sub(0, self)
+        }
+    }
+}
+
 /// Trait to evaluate if two types are equal.
 pub trait Eq {
     /// Evaluates if two values of the same type are equal.
@@ -580,6 +936,30 @@ impl Eq for u8 {
     }
 }
 
+impl Eq for i64 {
+    fn eq(self, other: Self) -> bool {
+        __eq(self, other)
+    }
+}
+
+impl Eq for i32 {
+    fn eq(self, other: Self) -> bool {
+        __eq(self, other)
+    }
+}
+
+impl Eq for i16 {
+    fn eq(self, other: Self) -> bool {
+        __eq(self, other)
+    }
+}
+
+impl Eq for i8 {
+    fn eq(self, other: Self) -> bool {
+        __eq(self, other)
+    }
+}
+
 impl Eq for raw_ptr {
     fn eq(self, other: Self) -> bool {
         __eq(self, other)
@@ -709,6 +1089,42 @@ impl Ord for u8 {
     }
 }
 
+impl Ord for i64 {
+    fn gt(self, other: Self) -> bool {
+        __gt(self, other)
+    }
+    fn lt(self, other: Self) -> bool {
+        __lt(self, other)
+    }
+}
+
+impl Ord for i32 {
+    fn gt(self, other: Self) -> bool {
+        __gt(self, other)
+    }
+    fn lt(self, other: Self) -> bool {
+        __lt(self, other)
+    }
+}
+
+impl Ord for i16 {
+    fn gt(self, other: Self) -> bool {
+        __gt(self, other)
+    }
+    fn lt(self, other: Self) -> bool {
+        __lt(self, other)
+    }
+}
+
+impl Ord for i8 {
+    fn gt(self, other: Self) -> bool {
+        __gt(self, other)
+    }
+    fn lt(self, other: Self) -> bool {
+        __lt(self, other)
+    }
+}
+
 /// Trait to bitwise AND two values of the same type.
 pub trait BitwiseAnd {
     /// Bitwise AND two values of the same type.
@@ -783,6 +1199,30 @@ impl BitwiseAnd for u8 {
     }
 }
 
+impl BitwiseAnd for i64 {
+    fn binary_and(self, other: Self) -> Self {
+        __and(self, other)
+    }
+}
+
+impl BitwiseAnd for i32 {
+    fn binary_and(self, other: Self) -> Self {
+        __and(self, other)
+    }
+}
+
+impl BitwiseAnd for i16 {
+    fn binary_and(self, other: Self) -> Self {
+        __and(self, other)
+    }
+}
+
+impl BitwiseAnd for i8 {
+    fn binary_and(self, other: Self) -> Self {
+        __and(self, other)
+    }
+}
+
 /// Trait to bitwise OR two values of the same type.
 pub trait BitwiseOr {
     /// Bitwise OR two values of the same type.
@@ -857,6 +1297,30 @@ impl BitwiseOr for u8 {
     }
 }
 
+impl BitwiseOr for i64 {
+    fn binary_or(self, other: Self) -> Self {
+        __or(self, other)
+    }
+}
+
+impl BitwiseOr for i32 {
+    fn binary_or(self, other: Self) -> Self {
+        __or(self, other)
+    }
+}
+
+impl BitwiseOr for i16 {
+    fn binary_or(self, other: Self) -> Self {
+        __or(self, other)
+    }
+}
+
+impl BitwiseOr for i8 {
+    fn binary_or(self, other: Self) -> Self {
+        __or(self, other)
+    }
+}
+
 /// Trait to bitwise XOR two values of the same type.
 pub trait BitwiseXor {
     /// Bitwise XOR two values of the same type.
@@ -931,6 +1395,30 @@ impl BitwiseXor for u8 {
     }
 }
 
+impl BitwiseXor for i64 {
+    fn binary_xor(self, other: Self) -> Self {
+        __xor(self, other)
+    }
+}
+
+impl BitwiseXor for i32 {
+    fn binary_xor(self, other: Self) -> Self {
+        __xor(self, other)
+    }
+}
+
+impl BitwiseXor for i16 {
+    fn binary_xor(self, other: Self) -> Self {
+        __xor(self, other)
+    }
+}
+
+impl BitwiseXor for i8 {
+    fn binary_xor(self, other: Self) -> Self {
+        __xor(self, other)
+    }
+}
+
 /// Trait to evaluate if one value is greater than or equal, or less than or equal to another of the same type.
 trait OrdEq: Ord + Eq {
 } {
@@ -1032,6 +1520,10 @@ impl OrdEq for u64 {}
 impl OrdEq for u32 {}
 impl OrdEq for u16 {}
 impl OrdEq for u8 {}
+impl OrdEq for i64 {}
+impl OrdEq for i32 {}
+impl OrdEq for i16 {}
+impl OrdEq for i8 {}
 impl OrdEq for b256 {}
 
 /// Trait to bit shift a value.
@@ -1162,6 +1654,51 @@ impl Shift for u8 {
     }
 }
 
+impl Shift for i64 {
+    fn lsh(self, other: u64) -> Self {
+        __lsh(self, other)
+    }
+    fn rsh(self, other: u64) -> Self {
+        __rsh(self, other)
+    }
+}
+
+impl Shift for i32 {
+    fn lsh(self, other: u64) -> Self {
+        // shift the result to the top of the word and back down
+        // to sign extend it from the sign bit of Self
+        let shift = __sub(64, Self::bits());
+        __rsh(__lsh(__lsh(self, other), shift), shift)
+    }
+    fn rsh(self, other: u64) -> Self {
+        __rsh(self, other)
+    }
+}
+
+impl Shift for i16 {
+    fn lsh(self, other: u64) -> Self {
+        // shift the result to the top of the word and back down
+        // to sign extend it from the sign bit of Self
+        let shift = __sub(64, Self::bits());
+        __rsh(__lsh(__lsh(self, other), shift), shift)
+    }
+    fn rsh(self, other: u64) -> Self {
+        __rsh(self, other)
+    }
+}
+
+impl Shift for i8 {
+    fn lsh(self, other: u64) -> Self {
+        // shift the result to the top of the word and back down
+        // to sign extend it from the sign bit of Self
+        let shift = __sub(64, Self::bits());
+        __rsh(__lsh(__lsh(self, other), shift), shift)
+    }
+    fn rsh(self, other: u64) -> Self {
+        __rsh(self, other)
+    }
+}
+
 /////////////////////////////////////////////////
 // Internal Helpers
 /////////////////////////////////////////////////
@@ -285,6 +285,238 @@ impl u8 {
     }
 }
 
+impl i64 {
+    /// The smallest value that can be represented by this integer type,
+    /// -2<sup>63</sup>.
+    ///
+    /// # Returns
+    ///
+    /// * [i64] - The smallest `i64` value.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let val = i64::min();
+    ///     assert(val == -9223372036854775808i64);
+    /// }
+    /// ```
+    pub fn min() -> Self {
+        -9223372036854775808
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>63</sup> - 1.
+    ///
+    /// # Returns
+    ///
+    /// * [i64] - The largest `i64` value.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let val = i64::max();
+    ///     assert(val == 9223372036854775807i64);
+    /// }
+    /// ```
+    pub fn max() -> Self {
+        9223372036854775807
+    }
+
+    /// The size of this integer type in bits.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of bits for an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let bits = i64::bits();
+    ///     assert(bits == 64);
+    /// }
+    /// ```
+    pub fn bits() -> u64 {
+        64
+    }
+}
+
+impl i32 {
+    /// The smallest value that can be represented by this integer type,
+    /// -2<sup>31</sup>.
+    ///
+    /// # Returns
+    ///
+    /// * [i32] - The smallest `i32` value.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let val = i32::min();
+    ///     assert(val == -2147483648i32);
+    /// }
+    /// ```
+    pub fn min() -> Self {
+        -2147483648
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>31</sup> - 1.
+    ///
+    /// # Returns
+    ///
+    /// * [i32] - The largest `i32` value.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let val = i32::max();
+    ///     assert(val == 2147483647i32);
+    /// }
+    /// ```
+    pub fn max() -> Self {
+        2147483647
+    }
+
+    /// The size of this integer type in bits.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of bits for an `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let bits = i32::bits();
+    ///     assert(bits == 32);
+    /// }
+    /// ```
+    pub fn bits() -> u64 {
+        32
+    }
+}
+
+impl i16 {
+    /// The smallest value that can be represented by this integer type,
+    /// -2<sup>15</sup>.
+    ///
+    /// # Returns
+    ///
+    /// * [i16] - The smallest `i16` value.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let val = i16::min();
+    ///     assert(val == -32768i16);
+    /// }
+    /// ```
+    pub fn min() -> Self {
+        -32768
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>15</sup> - 1.
+    ///
+    /// # Returns
+    ///
+    /// * [i16] - The largest `i16` value.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let val = i16::max();
+    ///     assert(val == 32767i16);
+    /// }
+    /// ```
+    pub fn max() -> Self {
+        32767
+    }
+
+    /// The size of this integer type in bits.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of bits for an `i16`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let bits = i16::bits();
+    ///     assert(bits == 16);
+    /// }
+    /// ```
+    pub fn bits() -> u64 {
+        16
+    }
+}
+
+impl i8 {
+    /// The smallest value that can be represented by this integer type,
+    /// -2<sup>7</sup>.
+    ///
+    /// # Returns
+    ///
+    /// * [i8] - The smallest `i8` value.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let val = i8::min();
+    ///     assert(val == -128i8);
+    /// }
+    /// ```
+    pub fn min() -> Self {
+        -128
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>7</sup> - 1.
+    ///
+    /// # Returns
+    ///
+    /// * [i8] - The largest `i8` value.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let val = i8::max();
+    ///     assert(val == 127i8);
+    /// }
+    /// ```
+    pub fn max() -> Self {
+        127
+    }
+
+    /// The size of this integer type in bits.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of bits for an `i8`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let bits = i8::bits();
+    ///     assert(bits == 8);
+    /// }
+    /// ```
+    pub fn bits() -> u64 {
+        8
+    }
+}
+
 impl b256 {
     /// The smallest value that can be represented by this type.
     ///
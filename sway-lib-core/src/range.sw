@@ -0,0 +1,112 @@
+//! Integer ranges, created by the range expressions `start..end` and `start..=end`.
+//!
+//! The ranges implement `Iterator` in the `iterator` module of the standard
+//! library, together with its other iterators.
+library;
+
+use ::ops::Add;
+
+/// An integer type whose values can be stepped through one by one.
+pub trait Step {
+    /// Returns the value directly after `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [Self] - The value directly after `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is the largest value of the type.
+    fn successor(self) -> Self;
+}
+
+impl Step for u8 {
+    fn successor(self) -> Self {
+        self.add(1)
+    }
+}
+
+impl Step for u16 {
+    fn successor(self) -> Self {
+        self.add(1)
+    }
+}
+
+impl Step for u32 {
+    fn successor(self) -> Self {
+        self.add(1)
+    }
+}
+
+impl Step for u64 {
+    fn successor(self) -> Self {
+        self.add(1)
+    }
+}
+
+impl Step for i8 {
+    fn successor(self) -> Self {
+        self.add(1)
+    }
+}
+
+impl Step for i16 {
+    fn successor(self) -> Self {
+        self.add(1)
+    }
+}
+
+impl Step for i32 {
+    fn successor(self) -> Self {
+        self.add(1)
+    }
+}
+
+impl Step for i64 {
+    fn successor(self) -> Self {
+        self.add(1)
+    }
+}
+
+/// The values from `start` up to, but excluding, `end`, written as `start..end`.
+///
+/// # Examples
+///
+/// ```sway
+/// fn foo() {
+///     let mut sum = 0;
+///     for i in 0..4 {
+///         sum += i;
+///     }
+///     assert(sum == 6);
+/// }
+/// ```
+pub struct Range<T> {
+    /// The first value of the range.
+    start: T,
+    /// The end of the range, which is not part of the range.
+    end: T,
+}
+
+/// The values from `start` up to, and including, `end`, written as `start..=end`.
+///
+/// # Examples
+///
+/// ```sway
+/// fn foo() {
+///     let mut sum = 0;
+///     for i in 0..=4 {
+///         sum += i;
+///     }
+///     assert(sum == 10);
+/// }
+/// ```
+pub struct RangeInclusive<T> {
+    /// The first value of the range.
+    start: T,
+    /// The last value of the range.
+    end: T,
+    /// Whether `end` has already been returned. Stepping past `end` could
+    /// overflow when `end` is the largest value of `T`.
+    exhausted: bool,
+}
@@ -6,6 +6,7 @@ use ::assert::assert;
 use ::intrinsics::size_of_val;
 use ::option::Option::{self, *};
 use ::convert::From;
+use ::iterator::BytesIter;
 
 struct RawBytes {
     ptr: raw_ptr,
@@ -537,6 +538,33 @@ impl Bytes {
     pub fn is_empty(self) -> bool {
         self.len == 0
     }
+
+    /// Returns an iterator over the bytes.
+    ///
+    /// # Returns
+    ///
+    /// * [BytesIter] - An iterator over the bytes, from the first to the last.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std:bytes::Bytes;
+    ///
+    /// fn foo() {
+    ///     let mut bytes = Bytes::new();
+    ///     bytes.push(5);
+    ///     bytes.push(7);
+    ///
+    ///     let mut sum = 0;
+    ///     for byte in bytes.iter() {
+    ///         sum += byte;
+    ///     }
+    ///     assert(sum == 12);
+    /// }
+    /// ```
+    pub fn iter(self) -> BytesIter {
+        BytesIter::new(self.buf.ptr(), self.len)
+    }
 }
 
 // Need to use seperate impl blocks for now: https://github.com/FuelLabs/sway/issues/1548
@@ -0,0 +1,189 @@
+//! The `Iterator` trait, which `for` loops are desugared against.
+//!
+//! A `for` loop `for pattern in iterator { ... }` calls `next` on the
+//! iterator until it returns `None`, binding every returned value to the
+//! pattern:
+//!
+//! ```sway
+//! fn sum(values: Vec<u64>) -> u64 {
+//!     let mut sum = 0;
+//!     for value in values.iter() {
+//!         sum += value;
+//!     }
+//!     sum
+//! }
+//! ```
+//!
+//! Arrays can be iterated over directly, while collections like `Vec` and
+//! `Bytes` provide an `iter` method. Ranges like `0..len` and `1..=10` are
+//! iterators themselves.
+//!
+//! All the `Iterator` implementations of the standard library are in this
+//! module, so that importing `Iterator`, which the prelude does, makes them
+//! available.
+library;
+
+use ::option::Option::{self, *};
+use core::range::{Range, RangeInclusive, Step};
+
+/// A type that produces a sequence of values.
+pub trait Iterator {
+    /// The type of the values produced by the iterator.
+    type Item;
+
+    /// Advances the iterator and returns the next value.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Self::Item>] - The next value, or `None` when the iteration is finished.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let mut range = 0..2;
+    ///     assert(range.next().unwrap() == 0);
+    ///     assert(range.next().unwrap() == 1);
+    ///     assert(range.next().is_none());
+    /// }
+    /// ```
+    fn next(ref mut self) -> Option<Self::Item>;
+}
+
+/// An iterator over elements of type `T` stored contiguously in memory,
+/// like the elements of an array or of a `Vec<T>`.
+///
+/// # Additional Information
+///
+/// A `for` loop over an array iterates over a copy of the array with a
+/// `SliceIter`, so there is usually no need to create one directly.
+pub struct SliceIter<T> {
+    ptr: raw_ptr,
+    len: u64,
+    index: u64,
+}
+
+impl<T> SliceIter<T> {
+    /// Creates an iterator over `len` elements of type `T` stored contiguously at `ptr`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ptr`: [raw_ptr] - A pointer to the first element.
+    /// * `len`: [u64] - The number of elements.
+    ///
+    /// # Returns
+    ///
+    /// * [SliceIter<T>] - An iterator over the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::iterator::SliceIter;
+    ///
+    /// fn foo() {
+    ///     let array = [1, 2, 3];
+    ///     let mut iter = SliceIter::<u64>::new(__addr_of(array), 3);
+    ///     assert(iter.next().unwrap() == 1);
+    /// }
+    /// ```
+    pub fn new(ptr: raw_ptr, len: u64) -> Self {
+        Self {
+            ptr,
+            len,
+            index: 0,
+        }
+    }
+}
+
+impl<T> Iterator for SliceIter<T> {
+    type Item = T;
+
+    fn next(ref mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let value = self.ptr.add::<T>(self.index).read::<T>();
+        self.index += 1;
+        Some(value)
+    }
+}
+
+/// An iterator over tightly-packed bytes, created by `Bytes::iter`.
+pub struct BytesIter {
+    ptr: raw_ptr,
+    len: u64,
+    index: u64,
+}
+
+impl BytesIter {
+    /// Creates an iterator over `len` bytes stored at `ptr`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ptr`: [raw_ptr] - A pointer to the first byte.
+    /// * `len`: [u64] - The number of bytes.
+    ///
+    /// # Returns
+    ///
+    /// * [BytesIter] - An iterator over the bytes.
+    pub fn new(ptr: raw_ptr, len: u64) -> Self {
+        Self {
+            ptr,
+            len,
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for BytesIter {
+    type Item = u8;
+
+    fn next(ref mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let value = self.ptr.add_uint_offset(self.index).read_byte();
+        self.index += 1;
+        Some(value)
+    }
+}
+
+impl<T> Iterator for Range<T>
+where
+    T: Step + Ord,
+{
+    type Item = T;
+
+    fn next(ref mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            let value = self.start;
+            self.start = self.start.successor();
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Iterator for RangeInclusive<T>
+where
+    T: Step + Ord + Eq,
+{
+    type Item = T;
+
+    fn next(ref mut self) -> Option<Self::Item> {
+        if self.exhausted || self.start > self.end {
+            return None;
+        }
+
+        let value = self.start;
+        if self.start == self.end {
+            self.exhausted = true;
+        } else {
+            self.start = self.start.successor();
+        }
+        Some(value)
+    }
+}
@@ -5,6 +5,7 @@ pub mod logging;
 pub mod revert;
 pub mod result;
 pub mod option;
+pub mod iterator;
 pub mod assert;
 pub mod convert;
 pub mod intrinsics;
@@ -25,6 +25,10 @@ use ::revert::{require, revert};
 // Convert
 use ::convert::From;
 
+// Iteration
+use ::iterator::Iterator;
+use core::range::Step;
+
 // Primitive conversions
 use ::primitive_conversions::*;
 
@@ -5,6 +5,7 @@ use ::alloc::{alloc, realloc};
 use ::assert::assert;
 use ::option::Option::{self, *};
 use ::convert::From;
+use ::iterator::SliceIter;
 
 struct RawVec<T> {
     ptr: raw_ptr,
@@ -587,6 +588,33 @@ impl<T> Vec<T> {
 
         index_ptr.write::<T>(value);
     }
+
+    /// Returns an iterator over the elements of the vector.
+    ///
+    /// # Returns
+    ///
+    /// * [SliceIter<T>] - An iterator over the elements, from the first to the last.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(5);
+    ///     vec.push(10);
+    ///
+    ///     let mut sum = 0;
+    ///     for value in vec.iter() {
+    ///         sum += value;
+    ///     }
+    ///     assert(sum == 15);
+    /// }
+    /// ```
+    pub fn iter(self) -> SliceIter<T> {
+        SliceIter::new(self.buf.ptr(), self.len)
+    }
 }
 
 impl<T> AsRawSlice for Vec<T> {
@@ -271,9 +271,10 @@ pub fn type_info_to_symbol_kind(
     }
 
     match type_info {
-        TypeInfo::UnsignedInteger(..) | TypeInfo::Boolean | TypeInfo::B256 => {
-            SymbolKind::BuiltinType
-        }
+        TypeInfo::UnsignedInteger(..)
+        | TypeInfo::SignedInteger(..)
+        | TypeInfo::Boolean
+        | TypeInfo::B256 => SymbolKind::BuiltinType,
         TypeInfo::Numeric | TypeInfo::StringArray(..) => SymbolKind::NumericLiteral,
         TypeInfo::Custom { .. } | TypeInfo::Struct { .. } | TypeInfo::Contract => {
             SymbolKind::Struct
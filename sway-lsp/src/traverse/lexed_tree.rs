@@ -159,6 +159,10 @@ impl Parse for Expr {
                 value.parse(ctx);
                 branches.get().iter().par_bridge().for_each(|branch| {
                     branch.pattern.parse(ctx);
+                    if let Some((if_token, guard)) = &branch.guard_opt {
+                        insert_keyword(ctx, if_token.span());
+                        guard.parse(ctx);
+                    }
                     branch.kind.parse(ctx);
                 });
             }
@@ -171,6 +175,19 @@ impl Parse for Expr {
                 condition.parse(ctx);
                 block.get().parse(ctx);
             }
+            Expr::For {
+                for_token,
+                value_pattern,
+                in_token,
+                iterator,
+                block,
+            } => {
+                insert_keyword(ctx, for_token.span());
+                value_pattern.parse(ctx);
+                insert_keyword(ctx, in_token.span());
+                iterator.parse(ctx);
+                block.get().parse(ctx);
+            }
             Expr::FuncApp { func, args } => {
                 func.parse(ctx);
                 args.get()
@@ -207,15 +224,18 @@ impl Parse for Expr {
             Expr::TupleFieldProjection { target, .. } => {
                 target.parse(ctx);
             }
-            Expr::Ref { ref_token, expr } => {
-                insert_keyword(ctx, ref_token.span());
+            Expr::Ref {
+                mut_token, expr, ..
+            } => {
+                if let Some(mut_token) = mut_token {
+                    insert_keyword(ctx, mut_token.span());
+                }
                 expr.parse(ctx);
             }
-            Expr::Deref { deref_token, expr } => {
-                insert_keyword(ctx, deref_token.span());
+            Expr::Deref { expr, .. } => {
                 expr.parse(ctx);
             }
-            Expr::Not { expr, .. } => {
+            Expr::Not { expr, .. } | Expr::Neg { expr, .. } => {
                 expr.parse(ctx);
             }
             Expr::Mul { lhs, rhs, .. }
@@ -236,7 +256,12 @@ impl Parse for Expr {
             | Expr::LessThanEq { lhs, rhs, .. }
             | Expr::GreaterThanEq { lhs, rhs, .. }
             | Expr::LogicalAnd { lhs, rhs, .. }
-            | Expr::LogicalOr { lhs, rhs, .. } => {
+            | Expr::LogicalOr { lhs, rhs, .. }
+            | Expr::Range {
+                start: lhs,
+                end: rhs,
+                ..
+            } => {
                 lhs.parse(ctx);
                 rhs.parse(ctx);
             }
@@ -506,6 +531,12 @@ impl Parse for Ty {
                 insert_keyword(ctx, str_token.span());
                 length.get().parse(ctx);
             }
+            Ty::Ref { mut_token, ty, .. } => {
+                if let Some(mut_token) = mut_token {
+                    insert_keyword(ctx, mut_token.span());
+                }
+                ty.parse(ctx);
+            }
             _ => {}
         }
     }
@@ -760,6 +791,9 @@ impl Parse for Assignable {
             | Assignable::TupleFieldProjection { target, .. } => {
                 target.parse(ctx);
             }
+            Assignable::Deref { expr, .. } => {
+                expr.parse(ctx);
+            }
             _ => {}
         }
     }
@@ -12,7 +12,8 @@ use crate::{
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use sway_core::{
     compiler_generated::{
-        is_generated_any_match_expression_var_name, is_generated_destructured_struct_var_name,
+        is_generated_any_for_loop_var_name, is_generated_any_match_expression_var_name,
+        is_generated_destructured_param_var_name, is_generated_destructured_struct_var_name,
         is_generated_tuple_var_name,
     },
     language::{
@@ -20,16 +21,16 @@ use sway_core::{
             AbiCastExpression, AbiDeclaration, AmbiguousPathExpression, ArrayExpression,
             ArrayIndexExpression, AstNode, AstNodeContent, ConstantDeclaration, Declaration,
             DelineatedPathExpression, EnumDeclaration, EnumVariant, Expression, ExpressionKind,
-            FunctionApplicationExpression, FunctionDeclaration, FunctionParameter, IfExpression,
-            ImplItem, ImplSelf, ImplTrait, ImportType, IncludeStatement,
-            IntrinsicFunctionExpression, LazyOperatorExpression, MatchExpression,
+            ForLoopExpression, FunctionApplicationExpression, FunctionDeclaration,
+            FunctionParameter, IfExpression, ImplItem, ImplSelf, ImplTrait, ImportType,
+            IncludeStatement, IntrinsicFunctionExpression, LazyOperatorExpression, MatchExpression,
             MethodApplicationExpression, MethodName, ParseModule, ParseProgram, ParseSubmodule,
-            QualifiedPathRootTypes, ReassignmentExpression, ReassignmentTarget, Scrutinee,
-            StorageAccessExpression, StorageDeclaration, StorageField, StructDeclaration,
-            StructExpression, StructExpressionField, StructField, StructScrutineeField,
-            SubfieldExpression, Supertrait, TraitDeclaration, TraitFn, TraitItem,
-            TraitTypeDeclaration, TupleIndexExpression, TypeAliasDeclaration, UseStatement,
-            VariableDeclaration, WhileLoopExpression,
+            QualifiedPathRootTypes, ReassignmentExpression, ReassignmentTarget, RefExpression,
+            Scrutinee, StorageAccessExpression, StorageDeclaration, StorageField,
+            StructDeclaration, StructExpression, StructExpressionField, StructField,
+            StructScrutineeField, SubfieldExpression, Supertrait, TraitDeclaration, TraitFn,
+            TraitItem, TraitTypeDeclaration, TupleIndexExpression, TypeAliasDeclaration,
+            UseStatement, VariableDeclaration, WhileLoopExpression,
         },
         CallPathTree, HasSubmodules, Literal,
     },
@@ -209,7 +210,9 @@ impl Parse for Expression {
             }
             ExpressionKind::Variable(name) => {
                 if !(is_generated_tuple_var_name(name.as_str())
-                    || is_generated_any_match_expression_var_name(name.as_str()))
+                    || is_generated_any_match_expression_var_name(name.as_str())
+                    || is_generated_destructured_param_var_name(name.as_str())
+                    || is_generated_any_for_loop_var_name(name.as_str()))
                 {
                     let symbol_kind = if is_generated_destructured_struct_var_name(name.as_str()) {
                         SymbolKind::Struct
@@ -269,6 +272,9 @@ impl Parse for Expression {
                 value.parse(ctx);
                 branches.par_iter().for_each(|branch| {
                     branch.scrutinee.parse(ctx);
+                    if let Some(guard) = &branch.guard {
+                        guard.parse(ctx);
+                    }
                     branch.result.parse(ctx);
                 });
             }
@@ -338,12 +344,22 @@ impl Parse for Expression {
                 body.contents.par_iter().for_each(|node| node.parse(ctx));
                 condition.parse(ctx);
             }
+            ExpressionKind::ForLoop(ForLoopExpression { iterable, body, .. }) => {
+                iterable.parse(ctx);
+                body.contents.par_iter().for_each(|node| node.parse(ctx));
+            }
             ExpressionKind::Reassignment(reassignment) => {
                 reassignment.parse(ctx);
             }
             ExpressionKind::Return(expr) => {
                 expr.parse(ctx);
             }
+            ExpressionKind::Ref(RefExpression { value, .. }) => {
+                value.parse(ctx);
+            }
+            ExpressionKind::Deref(expr) => {
+                expr.parse(ctx);
+            }
             // We are collecting these tokens in the lexed phase.
             ExpressionKind::Break | ExpressionKind::Continue => {}
         }
@@ -354,7 +370,7 @@ impl Parse for ReassignmentExpression {
     fn parse(&self, ctx: &ParseContext) {
         self.rhs.parse(ctx);
         match &self.lhs {
-            ReassignmentTarget::VariableExpression(exp) => {
+            ReassignmentTarget::VariableExpression(exp) | ReassignmentTarget::Deref(exp) => {
                 exp.parse(ctx);
             }
         }
@@ -526,6 +542,16 @@ impl Parse for Scrutinee {
                 ctx.tokens
                     .insert(ctx.ident(&Ident::new(span.clone())), token);
             }
+            Scrutinee::Range {
+                ref start, span, ..
+            } => {
+                let token = Token::from_parsed(
+                    AstToken::Scrutinee(self.clone()),
+                    literal_to_symbol_kind(start),
+                );
+                ctx.tokens
+                    .insert(ctx.ident(&Ident::new(span.clone())), token);
+            }
             Scrutinee::Variable { name, .. } => {
                 ctx.tokens.insert(
                     ctx.ident(name),
@@ -691,7 +717,8 @@ impl Parse for VariableDeclaration {
         // Don't collect tokens if the idents are generated tuple or match desugaring names.
         // The individual elements are handled in the subsequent VariableDeclaration's.
         if !(is_generated_tuple_var_name(self.name.as_str())
-            || is_generated_any_match_expression_var_name(self.name.as_str()))
+            || is_generated_any_match_expression_var_name(self.name.as_str())
+            || is_generated_any_for_loop_var_name(self.name.as_str()))
         {
             let symbol_kind = if is_generated_destructured_struct_var_name(self.name.as_str()) {
                 SymbolKind::Struct
@@ -993,13 +1020,15 @@ impl Parse for TraitConstraint {
 
 impl Parse for FunctionParameter {
     fn parse(&self, ctx: &ParseContext) {
-        ctx.tokens.insert(
-            ctx.ident(&self.name),
-            Token::from_parsed(
-                AstToken::FunctionParameter(self.clone()),
-                SymbolKind::ValueParam,
-            ),
-        );
+        if !is_generated_destructured_param_var_name(self.name.as_str()) {
+            ctx.tokens.insert(
+                ctx.ident(&self.name),
+                Token::from_parsed(
+                    AstToken::FunctionParameter(self.clone()),
+                    SymbolKind::ValueParam,
+                ),
+            );
+        }
         self.type_argument.parse(ctx);
     }
 }
@@ -1055,6 +1084,11 @@ impl Parse for TypeArgument {
                     type_arg.parse(ctx);
                 });
             }
+            TypeInfo::Ref {
+                referenced_type, ..
+            } => {
+                referenced_type.parse(ctx);
+            }
             _ => {
                 let symbol_kind = type_info_to_symbol_kind(ctx.engines.te(), &type_info, None);
                 if let Some(tree) = &self.call_path_tree {
@@ -1104,6 +1138,11 @@ fn collect_type_info_token(ctx: &ParseContext, type_info: &TypeInfo, type_span:
                 type_arg.parse(ctx);
             });
         }
+        TypeInfo::Ref {
+            referenced_type, ..
+        } => {
+            referenced_type.parse(ctx);
+        }
         TypeInfo::Custom {
             qualified_call_path,
             type_arguments,
@@ -1178,7 +1217,12 @@ fn literal_to_symbol_kind(value: &Literal) -> SymbolKind {
         | Literal::U32(..)
         | Literal::U64(..)
         | Literal::U256(..)
-        | Literal::Numeric(..) => SymbolKind::NumericLiteral,
+        | Literal::I8(..)
+        | Literal::I16(..)
+        | Literal::I32(..)
+        | Literal::I64(..)
+        | Literal::Numeric(..)
+        | Literal::NegativeNumeric(..) => SymbolKind::NumericLiteral,
         Literal::String(..) => SymbolKind::StringLiteral,
         Literal::B256(..) => SymbolKind::ByteLiteral,
         Literal::Boolean(..) => SymbolKind::BoolLiteral,
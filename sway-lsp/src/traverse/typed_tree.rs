@@ -593,6 +593,9 @@ impl Parse for ty::TyExpression {
                 reassignment.parse(ctx);
             }
             ty::TyExpressionVariant::Return(exp) => exp.parse(ctx),
+            ty::TyExpressionVariant::Ref(exp) | ty::TyExpressionVariant::Deref(exp) => {
+                exp.parse(ctx)
+            }
         }
     }
 }
@@ -1021,7 +1024,7 @@ impl Parse for ty::TyScrutinee {
                         Some(TypeDefinition::Ident(const_decl.call_path.suffix.clone()));
                 }
             }
-            Literal(_) => {
+            Literal(_) | Range { .. } => {
                 if let Some(mut token) = ctx
                     .tokens
                     .try_get_mut(&ctx.ident(&Ident::new(self.span.clone())))
@@ -1102,31 +1105,39 @@ impl Parse for ty::TyStructScrutineeField {
 impl Parse for ty::TyReassignment {
     fn parse(&self, ctx: &ParseContext) {
         self.rhs.parse(ctx);
-        if let Some(mut token) = ctx
-            .tokens
-            .try_get_mut(&ctx.ident(&self.lhs_base_name))
-            .try_unwrap()
-        {
-            token.typed = Some(TypedAstToken::TypedReassignment(self.clone()));
-        }
-        self.lhs_indices.iter().for_each(|proj_kind| {
-            if let ty::ProjectionKind::StructField { name } = proj_kind {
-                if let Some(mut token) = ctx.tokens.try_get_mut(&ctx.ident(name)).try_unwrap() {
+        match &self.lhs {
+            ty::TyReassignmentTarget::Deref(exp) => exp.parse(ctx),
+            ty::TyReassignmentTarget::ElementAccess {
+                base_name,
+                base_type,
+                indices,
+            } => {
+                if let Some(mut token) = ctx.tokens.try_get_mut(&ctx.ident(base_name)).try_unwrap()
+                {
                     token.typed = Some(TypedAstToken::TypedReassignment(self.clone()));
-                    if let Some(struct_decl) = &ctx
-                        .tokens
-                        .struct_declaration_of_type_id(ctx.engines, &self.lhs_type)
-                    {
-                        struct_decl.fields.iter().for_each(|decl_field| {
-                            if &decl_field.name == name {
-                                token.type_def =
-                                    Some(TypeDefinition::Ident(decl_field.name.clone()));
+                }
+                indices.iter().for_each(|proj_kind| {
+                    if let ty::ProjectionKind::StructField { name } = proj_kind {
+                        if let Some(mut token) =
+                            ctx.tokens.try_get_mut(&ctx.ident(name)).try_unwrap()
+                        {
+                            token.typed = Some(TypedAstToken::TypedReassignment(self.clone()));
+                            if let Some(struct_decl) = &ctx
+                                .tokens
+                                .struct_declaration_of_type_id(ctx.engines, base_type)
+                            {
+                                struct_decl.fields.iter().for_each(|decl_field| {
+                                    if &decl_field.name == name {
+                                        token.type_def =
+                                            Some(TypeDefinition::Ident(decl_field.name.clone()));
+                                    }
+                                });
                             }
-                        });
+                        }
                     }
-                }
+                });
             }
-        });
+        }
     }
 }
 
@@ -1256,7 +1267,11 @@ fn collect_type_id(
     let type_info = ctx.engines.te().get(type_id);
     let symbol_kind = type_info_to_symbol_kind(ctx.engines.te(), &type_info, Some(&type_span));
     match &type_info {
-        TypeInfo::Array(type_arg, ..) => {
+        TypeInfo::Array(type_arg, ..)
+        | TypeInfo::Ref {
+            referenced_type: type_arg,
+            ..
+        } => {
             collect_type_argument(ctx, type_arg);
         }
         TypeInfo::Tuple(type_arguments) => {
@@ -51,7 +51,12 @@ fn literal_to_string(literal: &Literal) -> String {
         Literal::U32(_) => "u32".into(),
         Literal::U64(_) => "u64".into(),
         Literal::U256(_) => "u256".into(),
+        Literal::I8(_) => "i8".into(),
+        Literal::I16(_) => "i16".into(),
+        Literal::I32(_) => "i32".into(),
+        Literal::I64(_) => "i64".into(),
         Literal::Numeric(_) => "u64".into(),
+        Literal::NegativeNumeric(_) => "i64".into(),
         Literal::String(len) => format!("str[{}]", len.as_str().len()),
         Literal::Boolean(_) => "bool".into(),
         Literal::B256(_) => "b256".into(),
@@ -685,11 +685,51 @@ impl KeywordDocs {
             mod str_keyword {}
         };
 
-        // TODO
         let for_keyword: ItemMod = parse_quote! {
+            /// Iteration with [`in`], trait implementation with [`impl`].
+            ///
+            /// The `for` keyword is used in many syntactic locations:
+            ///
+            /// * `for` is used in for-in-loops (see below).
+            /// * `for` is used when implementing traits as in `impl Trait for Type` (see [`impl`] for more info
+            ///   on that).
+            ///
+            /// for-in-loops are a commonly used loop in Sway. They loop over the values returned by an
+            /// iterator, which is any type implementing the `Iterator` trait, until it returns `None`.
+            /// Arrays can be iterated over directly, and ranges like `0..10` are iterators themselves.
+            ///
+            /// ```sway
+            /// let mut sum = 0;
+            ///
+            /// for i in 0..10 {
+            ///     sum += i;
+            /// }
+            ///
+            /// for (a, b) in [(1, 2), (3, 4)] {
+            ///     sum += a * b;
+            /// }
+            /// ```
+            ///
+            /// Collections like `Vec` and `Bytes` provide an `iter` method that returns an iterator
+            /// over their elements. Like [`while`] loops, for-in-loops support [`break`] and
+            /// [`continue`], and always evaluate to `()`.
             mod for_keyword {}
         };
 
+        let in_keyword: ItemMod = parse_quote! {
+            /// Iterate over a series of values with [`for`].
+            ///
+            /// The expression immediately following `in` must be an array or implement the `Iterator`
+            /// trait.
+            ///
+            /// ```sway
+            /// for value in vec.iter() {
+            ///     log(value);
+            /// }
+            /// ```
+            mod in_keyword {}
+        };
+
         // TODO
         let where_keyword: ItemMod = parse_quote! {
             mod where_keyword {}
@@ -776,6 +816,7 @@ impl KeywordDocs {
             mut_keyword,
             let_keyword,
             while_keyword,
+            in_keyword,
             where_keyword,
             ref_keyword,
             true_keyword,
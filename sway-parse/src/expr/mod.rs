@@ -3,17 +3,18 @@ use crate::{Parse, ParseBracket, ParseResult, ParseToEnd, Parser, ParserConsumed
 use sway_ast::brackets::{Braces, Parens, SquareBrackets};
 use sway_ast::expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant};
 use sway_ast::keywords::{
-    AbiToken, AddEqToken, AsmToken, CommaToken, ConfigurableToken, ConstToken, DivEqToken,
-    DoubleColonToken, EnumToken, EqToken, FalseToken, FnToken, IfToken, ImplToken, LetToken,
+    AbiToken, AddEqToken, AmpersandToken, AsmToken, CommaToken, ConfigurableToken, ConstToken,
+    DivEqToken, DoubleAmpersandToken, DoubleColonToken, DoubleDotEqToken, DoubleDotToken,
+    EnumToken, EqToken, FalseToken, FnToken, ForToken, IfToken, ImplToken, LetToken,
     OpenAngleBracketToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken, StarEqToken,
-    StorageToken, StructToken, SubEqToken, TraitToken, TrueToken, TypeToken, UseToken,
+    StorageToken, StructToken, SubEqToken, Token, TraitToken, TrueToken, TypeToken, UseToken,
 };
 use sway_ast::literal::{LitBool, LitBoolType};
 use sway_ast::punctuated::Punctuated;
 use sway_ast::{
     AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
     ExprTupleDescriptor, GenericArgs, IfCondition, IfExpr, LitInt, Literal, MatchBranch,
-    MatchBranchKind, PathExpr, PathExprSegment, Statement, StatementLet,
+    MatchBranchKind, PathExpr, PathExprSegment, RangeLimits, Statement, StatementLet,
 };
 use sway_error::parser_error::ParseErrorKind;
 use sway_types::{ast::Delimiter, Ident, Span, Spanned};
@@ -280,7 +281,7 @@ fn take_reassignment_op(parser: &mut Parser) -> Option<ReassignmentOp> {
 }
 
 fn parse_reassignment(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
-    let expr = parse_logical_or(parser, ctx)?;
+    let expr = parse_range(parser, ctx)?;
 
     if let Some(reassignment_op) = take_reassignment_op(parser) {
         let assignable = match expr.try_into_assignable() {
@@ -302,6 +303,26 @@ fn parse_reassignment(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Exp
     Ok(expr)
 }
 
+fn parse_range(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
+    let start = parse_logical_or(parser, ctx)?;
+    if start.is_control_flow() && ctx.at_start_of_statement {
+        return Ok(start);
+    }
+    let limits = if let Some(token) = parser.take::<DoubleDotEqToken>() {
+        RangeLimits::Closed(token)
+    } else if let Some(token) = parser.take::<DoubleDotToken>() {
+        RangeLimits::HalfOpen(token)
+    } else {
+        return Ok(start);
+    };
+    let end = Box::new(parse_logical_or(parser, ctx.not_statement())?);
+    Ok(Expr::Range {
+        start: Box::new(start),
+        limits,
+        end,
+    })
+}
+
 fn parse_op_rhs<O: Peek>(
     parser: &mut Parser,
     ctx: ParseExprCtx,
@@ -507,15 +528,43 @@ fn parse_mul(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
 }
 
 fn parse_unary_op(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
-    if let Some((ref_token, expr)) = parse_op_rhs(parser, ctx, parse_unary_op)? {
-        return Ok(Expr::Ref { ref_token, expr });
+    // `&&` is lexed as a single token, so `&&expr` has to be split into two references.
+    if let Some(double_ampersand_token) = parser.take::<DoubleAmpersandToken>() {
+        let span = double_ampersand_token.span();
+        let split_span = |start, end| {
+            Span::new(span.src().clone(), start, end, span.source_id().cloned())
+                .expect("splitting `&&` results in valid spans")
+        };
+        let mut_token = parser.take();
+        let expr = Box::new(parse_unary_op(parser, ctx.not_statement())?);
+        return Ok(Expr::Ref {
+            ampersand_token: AmpersandToken::new(split_span(span.start(), span.start() + 1)),
+            mut_token: None,
+            expr: Box::new(Expr::Ref {
+                ampersand_token: AmpersandToken::new(split_span(span.start() + 1, span.end())),
+                mut_token,
+                expr,
+            }),
+        });
     }
-    if let Some((deref_token, expr)) = parse_op_rhs(parser, ctx, parse_unary_op)? {
-        return Ok(Expr::Deref { deref_token, expr });
+    if let Some(ampersand_token) = parser.take() {
+        let mut_token = parser.take();
+        let expr = Box::new(parse_unary_op(parser, ctx.not_statement())?);
+        return Ok(Expr::Ref {
+            ampersand_token,
+            mut_token,
+            expr,
+        });
+    }
+    if let Some((star_token, expr)) = parse_op_rhs(parser, ctx, parse_unary_op)? {
+        return Ok(Expr::Deref { star_token, expr });
     }
     if let Some((bang_token, expr)) = parse_op_rhs(parser, ctx, parse_unary_op)? {
         return Ok(Expr::Not { bang_token, expr });
     }
+    if let Some((sub_token, expr)) = parse_op_rhs(parser, ctx, parse_unary_op)? {
+        return Ok(Expr::Neg { sub_token, expr });
+    }
     parse_projection(parser, ctx)
 }
 
@@ -717,6 +766,19 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
             block,
         });
     }
+    if let Some(for_token) = parser.take::<ForToken>() {
+        let value_pattern = parser.parse()?;
+        let in_token = parser.parse()?;
+        let iterator = Box::new(parse_condition(parser)?);
+        let block = parser.parse()?;
+        return Ok(Expr::For {
+            for_token,
+            value_pattern,
+            in_token,
+            iterator,
+            block,
+        });
+    }
     if parser.peek::<OpenAngleBracketToken>().is_some()
         || parser.peek::<DoubleColonToken>().is_some()
         || parser.peek::<Ident>().is_some()
@@ -806,10 +868,15 @@ impl ParseToEnd for ExprArrayDescriptor {
 impl Parse for MatchBranch {
     fn parse(parser: &mut Parser) -> ParseResult<MatchBranch> {
         let pattern = parser.parse()?;
+        let guard_opt = match parser.take::<IfToken>() {
+            Some(if_token) => Some((if_token, parser.parse()?)),
+            None => None,
+        };
         let fat_right_arrow_token = parser.parse()?;
         let kind = parser.parse()?;
         Ok(MatchBranch {
             pattern,
+            guard_opt,
             fat_right_arrow_token,
             kind,
         })
@@ -63,6 +63,7 @@ keyword_impls! {
     MutToken,
     LetToken,
     WhileToken,
+    InToken,
     WhereToken,
     RefToken,
     DerefToken,
@@ -134,6 +135,7 @@ token_impls! {
     FatRightArrowToken,
     DotToken,
     DoubleDotToken,
+    DoubleDotEqToken,
     BangToken,
     PercentToken,
     AddToken,
@@ -183,6 +185,7 @@ pub const RESERVED_KEYWORDS: phf::Set<&'static str> = phf::phf_set! {
     "mut",
     "let",
     "while",
+    "in",
     "where",
     "ref",
     "deref",
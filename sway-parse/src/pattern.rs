@@ -1,10 +1,10 @@
 use crate::{Parse, ParseBracket, ParseResult, Parser, Peek};
 
 use sway_ast::brackets::{Braces, Parens};
-use sway_ast::keywords::{DoubleDotToken, FalseToken, TrueToken};
+use sway_ast::keywords::{DoubleDotEqToken, DoubleDotToken, FalseToken, SubToken, TrueToken};
 use sway_ast::literal::{LitBool, LitBoolType};
 use sway_ast::punctuated::Punctuated;
-use sway_ast::{Literal, PathExpr, Pattern, PatternStructField};
+use sway_ast::{Literal, PathExpr, Pattern, PatternStructField, RangeLimits};
 use sway_error::parser_error::ParseErrorKind;
 use sway_types::Spanned;
 
@@ -63,8 +63,15 @@ fn parse_atomic_pattern(parser: &mut Parser) -> ParseResult<Pattern> {
         if let Some(ident) = parser.take::<FalseToken>() {
             return lit_bool(ident.span(), LitBoolType::False);
         }
-        if let Some(literal) = parser.take() {
-            return Ok(Pattern::Literal(literal));
+        if let Some(literal) = parser.take::<Literal>() {
+            return match literal {
+                Literal::Int(_) => parse_range_pattern_end(parser, Pattern::Literal(literal)),
+                _ => Ok(Pattern::Literal(literal)),
+            };
+        }
+        if parser.peek::<SubToken>().is_some() {
+            let start = parse_negative_literal_pattern(parser)?;
+            return parse_range_pattern_end(parser, start);
         }
         if let Some(tuple) = Parens::try_parse(parser)? {
             return Ok(Pattern::Tuple(tuple));
@@ -106,6 +113,37 @@ fn parse_atomic_pattern(parser: &mut Parser) -> ParseResult<Pattern> {
     }
 }
 
+fn parse_negative_literal_pattern(parser: &mut Parser) -> ParseResult<Pattern> {
+    let sub_token = parser.parse::<SubToken>()?;
+    match parser.take() {
+        Some(Literal::Int(literal)) => Ok(Pattern::NegativeLiteral { sub_token, literal }),
+        _ => Err(parser.emit_error(ParseErrorKind::ExpectedIntLiteral)),
+    }
+}
+
+/// Parses the `..end` or `..=end` of a range pattern whose `start` has already
+/// been parsed. Returns `start` unchanged if it is not followed by a range.
+fn parse_range_pattern_end(parser: &mut Parser, start: Pattern) -> ParseResult<Pattern> {
+    let limits = if let Some(token) = parser.take::<DoubleDotEqToken>() {
+        RangeLimits::Closed(token)
+    } else if let Some(token) = parser.take::<DoubleDotToken>() {
+        RangeLimits::HalfOpen(token)
+    } else {
+        return Ok(start);
+    };
+    let end = match parser.take() {
+        Some(Literal::Int(literal)) => Pattern::Literal(Literal::Int(literal)),
+        Some(_) => return Err(parser.emit_error(ParseErrorKind::ExpectedIntLiteral)),
+        None if parser.peek::<SubToken>().is_some() => parse_negative_literal_pattern(parser)?,
+        None => return Err(parser.emit_error(ParseErrorKind::ExpectedIntLiteral)),
+    };
+    Ok(Pattern::Range {
+        start: Box::new(start),
+        limits,
+        end: Box::new(end),
+    })
+}
+
 impl Parse for PatternStructField {
     fn parse(parser: &mut Parser) -> ParseResult<PatternStructField> {
         if let Some(token) = parser.take::<DoubleDotToken>() {
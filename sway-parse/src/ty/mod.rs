@@ -60,6 +60,15 @@ impl Parse for Ty {
             })?;
             return Ok(Ty::Slice { slice_token, ty });
         }
+        if let Some(ampersand_token) = parser.take() {
+            let mut_token = parser.take();
+            let ty = Box::new(parser.parse()?);
+            return Ok(Ty::Ref {
+                ampersand_token,
+                mut_token,
+                ty,
+            });
+        }
         if parser.peek::<OpenAngleBracketToken>().is_some()
             || parser.peek::<DoubleColonToken>().is_some()
             || parser.peek::<Ident>().is_some()
@@ -118,4 +127,37 @@ mod tests {
         );
         assert_matches!(item, Ty::Slice { .. });
     }
+
+    #[test]
+    fn parse_ref() {
+        let item = parse::<Ty>(
+            r#"
+            &T
+            "#,
+        );
+        assert_matches!(
+            item,
+            Ty::Ref {
+                mut_token: None,
+                ..
+            }
+        );
+    }
+
+    #[test]
+    fn parse_mut_ref() {
+        let item = parse::<Ty>(
+            r#"
+            &mut &T
+            "#,
+        );
+        assert_matches!(
+            item,
+            Ty::Ref {
+                mut_token: Some(_),
+                ty,
+                ..
+            } if matches!(*ty, Ty::Ref { .. })
+        );
+    }
 }
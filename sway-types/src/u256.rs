@@ -39,6 +39,10 @@ impl U256 {
         other.0.is_zero().not().then(|| Self(&self.0 / &other.0))
     }
 
+    pub fn checked_rem(&self, other: &U256) -> Option<U256> {
+        other.0.is_zero().not().then(|| Self(&self.0 % &other.0))
+    }
+
     pub fn shr(&self, other: &u64) -> U256 {
         U256((&self.0).shr(other))
     }
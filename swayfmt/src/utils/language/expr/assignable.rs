@@ -48,6 +48,10 @@ impl Format for Assignable {
                     field_span.as_str()
                 )?;
             }
+            Assignable::Deref { star_token, expr } => {
+                write!(formatted_code, "{}", star_token.span().as_str())?;
+                expr.format(formatted_code, formatter)?;
+            }
         }
         Ok(())
     }
@@ -92,6 +96,10 @@ impl LeafSpans for Assignable {
                 collected_spans.push(ByteSpan::from(dot_token.span()));
                 collected_spans.push(ByteSpan::from(field_span.clone()));
             }
+            Assignable::Deref { star_token, expr } => {
+                collected_spans.push(ByteSpan::from(star_token.span()));
+                collected_spans.append(&mut expr.leaf_spans());
+            }
         };
         collected_spans
     }
@@ -204,6 +204,29 @@ impl Format for Expr {
                     },
                 )?;
             }
+            Self::For {
+                for_token,
+                value_pattern,
+                in_token,
+                iterator,
+                block,
+            } => {
+                formatter.with_shape(
+                    formatter
+                        .shape
+                        .with_code_line_from(LineStyle::Normal, ExprKind::Function),
+                    |formatter| -> Result<(), FormatterError> {
+                        write!(formatted_code, "{} ", for_token.span().as_str())?;
+                        value_pattern.format(formatted_code, formatter)?;
+                        write!(formatted_code, " {} ", in_token.span().as_str())?;
+                        iterator.format(formatted_code, formatter)?;
+                        IfExpr::open_curly_brace(formatted_code, formatter)?;
+                        block.get().format(formatted_code, formatter)?;
+                        IfExpr::close_curly_brace(formatted_code, formatter)?;
+                        Ok(())
+                    },
+                )?;
+            }
             Self::FuncApp { func, args } => {
                 formatter.with_shape(
                     formatter
@@ -335,18 +358,29 @@ impl Format for Expr {
                     field_span.as_str(),
                 )?;
             }
-            Self::Ref { ref_token, expr } => {
-                write!(formatted_code, "{} ", ref_token.span().as_str())?;
+            Self::Ref {
+                ampersand_token,
+                mut_token,
+                expr,
+            } => {
+                write!(formatted_code, "{}", ampersand_token.span().as_str())?;
+                if let Some(mut_token) = mut_token {
+                    write!(formatted_code, "{} ", mut_token.span().as_str())?;
+                }
                 expr.format(formatted_code, formatter)?;
             }
-            Self::Deref { deref_token, expr } => {
-                write!(formatted_code, "{} ", deref_token.span().as_str())?;
+            Self::Deref { star_token, expr } => {
+                write!(formatted_code, "{}", star_token.span().as_str())?;
                 expr.format(formatted_code, formatter)?;
             }
             Self::Not { bang_token, expr } => {
                 write!(formatted_code, "{}", bang_token.span().as_str())?;
                 expr.format(formatted_code, formatter)?;
             }
+            Self::Neg { sub_token, expr } => {
+                write!(formatted_code, "{}", sub_token.span().as_str())?;
+                expr.format(formatted_code, formatter)?;
+            }
             Self::Pow {
                 lhs,
                 double_star_token,
@@ -586,6 +620,11 @@ impl Format for Expr {
                 }
                 rhs.format(formatted_code, formatter)?;
             }
+            Self::Range { start, limits, end } => {
+                start.format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", limits.span().as_str())?;
+                end.format(formatted_code, formatter)?;
+            }
             Self::Reassignment {
                 assignable,
                 reassignment_op,
@@ -944,6 +983,20 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut block.leaf_spans());
             collected_spans
         }
+        Expr::For {
+            for_token,
+            value_pattern,
+            in_token,
+            iterator,
+            block,
+        } => {
+            let mut collected_spans = vec![ByteSpan::from(for_token.span())];
+            collected_spans.append(&mut value_pattern.leaf_spans());
+            collected_spans.push(ByteSpan::from(in_token.span()));
+            collected_spans.append(&mut iterator.leaf_spans());
+            collected_spans.append(&mut block.leaf_spans());
+            collected_spans
+        }
         Expr::FuncApp { func, args } => {
             let mut collected_spans = Vec::new();
             collected_spans.append(&mut func.leaf_spans());
@@ -996,13 +1049,20 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.push(ByteSpan::from(field_span.clone()));
             collected_spans
         }
-        Expr::Ref { ref_token, expr } => {
-            let mut collected_spans = vec![ByteSpan::from(ref_token.span())];
+        Expr::Ref {
+            ampersand_token,
+            mut_token,
+            expr,
+        } => {
+            let mut collected_spans = vec![ByteSpan::from(ampersand_token.span())];
+            if let Some(mut_token) = mut_token {
+                collected_spans.push(ByteSpan::from(mut_token.span()));
+            }
             collected_spans.append(&mut expr.leaf_spans());
             collected_spans
         }
-        Expr::Deref { deref_token, expr } => {
-            let mut collected_spans = vec![ByteSpan::from(deref_token.span())];
+        Expr::Deref { star_token, expr } => {
+            let mut collected_spans = vec![ByteSpan::from(star_token.span())];
             collected_spans.append(&mut expr.leaf_spans());
             collected_spans
         }
@@ -1011,6 +1071,11 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut expr.leaf_spans());
             collected_spans
         }
+        Expr::Neg { sub_token, expr } => {
+            let mut collected_spans = vec![ByteSpan::from(sub_token.span())];
+            collected_spans.append(&mut expr.leaf_spans());
+            collected_spans
+        }
         Expr::Pow {
             lhs,
             double_star_token,
@@ -1201,6 +1266,12 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut rhs.leaf_spans());
             collected_spans
         }
+        Expr::Range { start, limits, end } => {
+            let mut collected_spans = start.leaf_spans();
+            collected_spans.push(ByteSpan::from(limits.span()));
+            collected_spans.append(&mut end.leaf_spans());
+            collected_spans
+        }
         Expr::Reassignment {
             assignable,
             reassignment_op,
@@ -256,6 +256,24 @@ intermediate_whitespace
 let i = 42;
 }");
 
+fmt_test_expr!(basic_for_loop
+"for (a, b) in pairs.iter() {
+    sum += a * b;
+}",
+intermediate_whitespace
+"for(a,b)in   pairs.iter(){
+sum += a * b;
+}");
+
+fmt_test_expr!(for_loop_over_range
+"for i in 0..=len - 1 {
+    sum += i;
+}",
+intermediate_whitespace
+"for i in 0 ..= len-1{
+sum += i;
+}");
+
 fmt_test_expr!(scoped_block
 "{
     let i = 42;
@@ -264,3 +282,43 @@ intermediate_whitespace
 "{
 let i = 42;
 }");
+
+fmt_test_expr!(negation
+"-a * -1",
+intermediate_whitespace
+"-  a*  - 1");
+
+fmt_test_expr!(match_negative_literal
+"match foo {
+    -1 => {}
+    _ => {}
+}",
+intermediate_whitespace
+"match foo {
+   - 1 =>{}
+ _=> {}
+}");
+
+fmt_test_expr!(match_range_patterns
+"match foo {
+    0..=9 => {}
+    -8..0 => {}
+    _ => {}
+}",
+intermediate_whitespace
+"match foo {
+    0 ..= 9 =>{}
+   - 8 .. 0=> {}
+ _=> {}
+}");
+
+fmt_test_expr!(match_branch_guard
+"match foo {
+    Some(x) if x > 5 => {}
+    _ => {}
+}",
+intermediate_whitespace
+"match foo {
+    Some(x)   if   x>5 =>{}
+ _=> {}
+}");
@@ -10,7 +10,8 @@ use crate::{
 };
 use std::fmt::Write;
 use sway_ast::{
-    Braces, CommaToken, ExprTupleDescriptor, PathExpr, Pattern, PatternStructField, Punctuated,
+    Braces, CommaToken, ExprTupleDescriptor, Literal, PathExpr, Pattern, PatternStructField,
+    Punctuated,
 };
 use sway_types::{ast::Delimiter, Spanned};
 
@@ -50,6 +51,15 @@ impl Format for Pattern {
                 ident.format(formatted_code, formatter)?;
             }
             Self::Literal(lit) => lit.format(formatted_code, formatter)?,
+            Self::NegativeLiteral { sub_token, literal } => {
+                write!(formatted_code, "{}", sub_token.span().as_str())?;
+                Literal::Int(literal.clone()).format(formatted_code, formatter)?;
+            }
+            Self::Range { start, limits, end } => {
+                start.format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", limits.span().as_str())?;
+                end.format(formatted_code, formatter)?;
+            }
             Self::Constant(path) => path.format(formatted_code, formatter)?,
             Self::Constructor { path, args } => {
                 // TODO: add a check for width of whether to be normal or multiline
@@ -293,6 +303,15 @@ impl LeafSpans for Pattern {
             Pattern::Literal(literal) => {
                 collected_spans.append(&mut literal.leaf_spans());
             }
+            Pattern::NegativeLiteral { sub_token, literal } => {
+                collected_spans.push(ByteSpan::from(sub_token.span()));
+                collected_spans.push(ByteSpan::from(literal.span()));
+            }
+            Pattern::Range { start, limits, end } => {
+                collected_spans.append(&mut start.leaf_spans());
+                collected_spans.push(ByteSpan::from(limits.span()));
+                collected_spans.append(&mut end.leaf_spans());
+            }
             Pattern::Constant(constant) => {
                 collected_spans.append(&mut constant.leaf_spans());
             }
@@ -6,7 +6,7 @@ use std::fmt::Write;
 use sway_ast::{
     brackets::SquareBrackets,
     expr::Expr,
-    keywords::{PtrToken, SliceToken, StrToken, Token, UnderscoreToken},
+    keywords::{AmpersandToken, MutToken, PtrToken, SliceToken, StrToken, Token, UnderscoreToken},
     ty::{Ty, TyArrayDescriptor, TyTupleDescriptor},
 };
 use sway_types::{ast::Delimiter, Spanned};
@@ -45,6 +45,11 @@ impl Format for Ty {
             Self::Slice { slice_token, ty } => {
                 format_slice(formatted_code, slice_token.clone(), ty.clone())
             }
+            Self::Ref {
+                ampersand_token,
+                mut_token,
+                ty,
+            } => format_ref(formatted_code, ampersand_token, mut_token, ty, formatter),
         }
     }
 }
@@ -115,6 +120,21 @@ fn format_slice(
     Ok(())
 }
 
+fn format_ref(
+    formatted_code: &mut FormattedCode,
+    ampersand_token: &AmpersandToken,
+    mut_token: &Option<MutToken>,
+    ty: &Ty,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    write!(formatted_code, "{}", ampersand_token.span().as_str())?;
+    if let Some(mut_token) = mut_token {
+        write!(formatted_code, "{} ", mut_token.span().as_str())?;
+    }
+    ty.format(formatted_code, formatter)?;
+    Ok(())
+}
+
 impl Format for TyTupleDescriptor {
     fn format(
         &self,
@@ -172,6 +192,18 @@ impl LeafSpans for Ty {
                 collected_spans.append(&mut ty.leaf_spans());
                 collected_spans
             }
+            Ty::Ref {
+                ampersand_token,
+                mut_token,
+                ty,
+            } => {
+                let mut collected_spans = vec![ByteSpan::from(ampersand_token.span())];
+                if let Some(mut_token) = mut_token {
+                    collected_spans.push(ByteSpan::from(mut_token.span()));
+                }
+                collected_spans.append(&mut ty.leaf_spans());
+                collected_spans
+            }
         }
     }
 }
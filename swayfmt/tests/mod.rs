@@ -2231,3 +2231,39 @@ fn main() {
 "#,
     )
 }
+
+#[test]
+fn references() {
+    check(
+        r#"library;
+
+fn swap(a:&mut u64,b : & mut u64) {
+    let tmp =*a;
+    * a= *b;
+    *b = tmp;
+}
+
+fn sum(v :&Vec<u64>) -> u64 {
+    let x = & mut 1;
+    let y = & v.len();
+    let z = &&mut 2;
+    *x + * y + **z
+}
+"#,
+        r#"library;
+
+fn swap(a: &mut u64, b: &mut u64) {
+    let tmp = *a;
+    *a = *b;
+    *b = tmp;
+}
+
+fn sum(v: &Vec<u64>) -> u64 {
+    let x = &mut 1;
+    let y = &v.len();
+    let z = &&mut 2;
+    *x + *y + **z
+}
+"#,
+    );
+}
@@ -0,0 +1,11 @@
+script;
+
+fn main() -> bool {
+    let a: i16 = i16::min();
+    let b: i16 = 1;
+
+    let result: i16 = a - b;
+    log(result);
+
+    true
+}
@@ -0,0 +1,11 @@
+script;
+
+fn main() -> bool {
+    let a: i32 = i32::max();
+    let b: i32 = 2;
+
+    let result: i32 = a * b;
+    log(result);
+
+    true
+}
@@ -0,0 +1,11 @@
+script;
+
+fn main() -> bool {
+    let a: i64 = i64::min();
+    let b: i64 = -1;
+
+    let result: i64 = a / b;
+    log(result);
+
+    true
+}
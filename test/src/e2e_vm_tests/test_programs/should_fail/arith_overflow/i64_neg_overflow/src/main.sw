@@ -0,0 +1,10 @@
+script;
+
+fn main() -> bool {
+    let a: i64 = i64::min();
+
+    let result: i64 = -a;
+    log(result);
+
+    true
+}
@@ -0,0 +1,11 @@
+script;
+
+fn main() -> bool {
+    let a: i8 = i8::min();
+    let b: i8 = -1;
+
+    let result: i8 = a / b;
+    log(result);
+
+    true
+}
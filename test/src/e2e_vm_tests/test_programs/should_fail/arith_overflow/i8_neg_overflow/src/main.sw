@@ -0,0 +1,10 @@
+script;
+
+fn main() -> bool {
+    let a: i8 = i8::min();
+
+    let result: i8 = -a;
+    log(result);
+
+    true
+}
@@ -0,0 +1,9 @@
+script;
+
+fn duplicate_param((a, b): (u64, u64), a: u64) -> u64 {
+    a + b
+}
+
+fn main() {
+    let _ = duplicate_param((1, 2), 3);
+}
@@ -0,0 +1,25 @@
+contract;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+abi MyContract {
+    fn take((a, b): (u64, u64));
+}
+
+impl MyContract for Contract {
+    fn take((a, b): (u64, u64)) {}
+}
+
+abi OtherContract {
+    fn take_point(p: Point) -> u64;
+}
+
+// the parameter names of contract methods are part of the ABI
+impl OtherContract for Contract {
+    fn take_point(Point { x, y }: Point) -> u64 {
+        x + y
+    }
+}
@@ -0,0 +1,6 @@
+script;
+
+// the parameter names of `main()` are part of the ABI
+fn main((a, b): (u64, u64)) -> u64 {
+    a + b
+}
@@ -0,0 +1,25 @@
+library;
+
+struct Counter {
+    count: u64,
+}
+
+fn for_loops() {
+    let vec: Vec<u64> = Vec::new();
+    for value in vec {
+        log(value);
+    }
+
+    for value in 5u64 {
+        log(value);
+    }
+
+    let counter = Counter { count: 0 };
+    for value in counter {
+        log(value);
+    }
+
+    for value in 0..3 {
+        value
+    }
+}
@@ -0,0 +1,11 @@
+library;
+
+fn for_loops() {
+    for Some(value) in [Some(1u64), None] {
+        log(value);
+    }
+
+    for (0, value) in [(0u64, 1u64), (2, 3)] {
+        log(value);
+    }
+}
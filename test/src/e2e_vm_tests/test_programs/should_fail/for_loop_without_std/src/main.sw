@@ -0,0 +1,13 @@
+script;
+
+use core::range::Range;
+
+fn main() -> u64 {
+    // Ranges are part of `core`, and can be created without `std`.
+    let range: Range<u64> = 0..3;
+    let mut sum = 0;
+    for i in range {
+        sum += i;
+    }
+    sum
+}
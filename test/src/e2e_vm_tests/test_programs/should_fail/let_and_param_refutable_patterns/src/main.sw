@@ -0,0 +1,26 @@
+script;
+
+enum MyOption {
+    Some: u64,
+    None: (),
+}
+
+struct Point {
+    x: u64,
+    y: u32,
+}
+
+fn refutable_param(MyOption::Some(v): MyOption) -> u64 {
+    v
+}
+
+fn main() {
+    let opt = MyOption::Some(1);
+    let MyOption::Some(x) = opt;
+
+    let (1, y) = (2, 3u32);
+
+    let Point { x: 0, y: z } = Point { x: 4, y: 5 };
+
+    let _ = refutable_param(opt);
+}
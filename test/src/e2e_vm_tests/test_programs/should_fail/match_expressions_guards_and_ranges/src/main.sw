@@ -0,0 +1,57 @@
+script;
+
+fn empty_ranges(x: u64) -> u64 {
+    match x {
+        5..5 => 0,
+        9..=1 => 1,
+        _ => 2,
+    }
+}
+
+fn out_of_range(x: u8) -> u64 {
+    match x {
+        0..=300 => 0,
+        _ => 1,
+    }
+}
+
+fn only_guarded(x: u8) -> u64 {
+    match x {
+        0..=127 => 0,
+        y if y > 127 => 1,
+    }
+}
+
+fn partial_ranges(x: i64) -> u64 {
+    match x {
+        -10..0 => 0,
+        1..=10 => 1,
+    }
+}
+
+fn unreachable_ranges(x: u64) -> u64 {
+    match x {
+        0..=10 => 0,
+        5 => 1,
+        3..8 => 2,
+        y if y > 20 => 3,
+        11..=20 => 4,
+        _ => 5,
+    }
+}
+
+fn non_bool_guard(x: u64) -> u64 {
+    match x {
+        y if y => 0,
+        _ => 1,
+    }
+}
+
+fn main() {
+    let _ = empty_ranges(0);
+    let _ = out_of_range(0);
+    let _ = only_guarded(0);
+    let _ = partial_ranges(0);
+    let _ = unreachable_ranges(0);
+    let _ = non_bool_guard(0);
+}
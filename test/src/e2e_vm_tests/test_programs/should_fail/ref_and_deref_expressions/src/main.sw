@@ -1,16 +1,24 @@
-contract;
+script;
 
-abi MyContract {
-    fn test_function() -> bool;
+struct S {
+    x: u64,
 }
 
-impl MyContract for Contract {
-    fn test_function() -> bool {
-        foo(ref Vec::new());
-        true
-    }
-}
+fn main() {
+    let x = 1;
+    let r_mut = &mut x;
+
+    let s = S { x: 0 };
+    let f = &mut s.x;
+
+    let r = &x;
+    *r = 2;
+
+    let rm = &mut *r;
+
+    let n = 5;
+    let d = *n;
+    *n = 3;
 
-pub fn foo(ref mut vec: Vec<u64>) {
-    vec.push(1);
+    let y: &mut u64 = &x;
 }
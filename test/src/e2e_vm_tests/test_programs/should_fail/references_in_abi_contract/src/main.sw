@@ -0,0 +1,30 @@
+contract;
+
+// references are not allowed in configurables
+configurable {
+    C: &u64 = &5,
+}
+
+// references are not allowed on ABI method arguments or return types
+abi RefAbi {
+    fn take_ref(r: &u64) -> u64;
+    fn take_nested(t: (u64, &mut u64));
+    fn return_ref() -> (bool, &u64);
+}
+
+impl RefAbi for Contract {
+    fn take_ref(r: &u64) -> u64 {
+        *r
+    }
+
+    fn take_nested(t: (u64, &mut u64)) {
+        *t.1 = t.0;
+    }
+
+    fn return_ref() -> (bool, &u64) {
+        let x = 5;
+        // references are not allowed in logged types
+        __log(&x);
+        (true, &x)
+    }
+}
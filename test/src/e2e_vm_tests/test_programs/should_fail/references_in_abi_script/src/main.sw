@@ -0,0 +1,12 @@
+script;
+
+struct Wrapper {
+    r: &u64,
+}
+
+// references are not allowed in main args
+// main returning a reference is not allowed
+fn main(a: &u64, w: Wrapper) -> &u64 {
+    let _ = *w.r;
+    a
+}
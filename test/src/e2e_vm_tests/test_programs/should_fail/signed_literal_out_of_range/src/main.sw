@@ -0,0 +1,7 @@
+script;
+
+fn main() {
+    let _a: i8 = 128;
+    let _b: i8 = -129;
+    let _c: u64 = -1;
+}
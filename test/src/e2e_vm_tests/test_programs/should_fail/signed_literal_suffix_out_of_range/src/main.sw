@@ -0,0 +1,8 @@
+script;
+
+fn main() {
+    let _a = 128i8;
+    let _b = -32769i16;
+    let _c = 2147483648i32;
+    let _d = -9223372036854775809;
+}
@@ -0,0 +1,110 @@
+script;
+
+use std::bytes::Bytes;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn sum_array(values: [u64; 4]) -> u64 {
+    let mut sum = 0;
+    for value in values {
+        sum += value;
+    }
+    sum
+}
+
+fn main() -> bool {
+    assert(sum_array([1, 2, 3, 4]) == 10);
+
+    let mut vec = Vec::new();
+    vec.push(5);
+    vec.push(6);
+    vec.push(7);
+    let mut sum = 0;
+    for value in vec.iter() {
+        sum += value;
+    }
+    assert(sum == 18);
+
+    let empty: Vec<u64> = Vec::new();
+    let mut count = 0;
+    for _ in empty.iter() {
+        count += 1;
+    }
+    assert(count == 0);
+
+    let mut bytes = Bytes::new();
+    bytes.push(1u8);
+    bytes.push(2u8);
+    bytes.push(250u8);
+    let mut sum = 0;
+    for byte in bytes.iter() {
+        sum += byte.as_u64();
+    }
+    assert(sum == 253);
+
+    let mut sum = 0;
+    for i in 0..5 {
+        sum += i;
+    }
+    assert(sum == 10);
+
+    let len = 3;
+    let mut sum = 0;
+    for i in 1..=len {
+        sum += i;
+    }
+    assert(sum == 6);
+
+    let mut count = 0;
+    for _ in 0..=255u8 {
+        count += 1;
+    }
+    assert(count == 256);
+
+    let mut count = 0;
+    for _ in 5..5 {
+        count += 1;
+    }
+    for _ in 6..=5 {
+        count += 1;
+    }
+    assert(count == 0);
+
+    let mut sum = 0;
+    for (a, b) in [(1, 2), (3, 4)] {
+        sum += a * b;
+    }
+    assert(sum == 14);
+
+    let mut sum = 0;
+    for Point { x, y } in [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }] {
+        sum += x * y;
+    }
+    assert(sum == 14);
+
+    let mut sum = 0;
+    for i in 0..10 {
+        if i == 7 {
+            break;
+        }
+        if i % 2 == 0 {
+            continue;
+        }
+        for j in 0..i {
+            sum += j;
+        }
+    }
+    assert(sum == 13);
+
+    let mut sum = 0;
+    for x in 1..3 {
+        let x = x * 10;
+        sum += x;
+    }
+    assert(sum == 30);
+
+    true
+}
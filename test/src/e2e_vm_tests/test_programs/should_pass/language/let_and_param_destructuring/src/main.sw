@@ -0,0 +1,83 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u32,
+}
+
+struct Line {
+    start: Point,
+    end: Point,
+}
+
+enum Wrapper {
+    Value: (u64, u32),
+}
+
+enum Either {
+    Left: u64,
+    Right: u64,
+}
+
+fn sum_point(Point { x, y }: Point) -> u64 {
+    x + y.as_u64()
+}
+
+fn sum_pair((a, (b, _)): (u64, (u64, bool))) -> u64 {
+    a + b
+}
+
+fn unwrap(Wrapper::Value((a, b)): Wrapper, _: u64) -> u64 {
+    a + b.as_u64()
+}
+
+fn either_value(Either::Left(v) | Either::Right(v): Either) -> u64 {
+    v
+}
+
+fn main() -> bool {
+    let p = Point { x: 1, y: 2 };
+    let Point { x, y } = p;
+    assert(x == 1);
+    assert(y == 2);
+
+    let line = Line {
+        start: Point { x: 3, y: 4 },
+        end: Point { x: 5, y: 6 },
+    };
+    let Line {
+        start: Point { x: x1, y: y1 },
+        end: Point { x: x2, .. },
+    } = line;
+    assert(x1 == 3);
+    assert(y1 == 4);
+    assert(x2 == 5);
+
+    let (a, (b, (c, _))) = (7, (8u32, (true, 9)));
+    assert(a == 7);
+    assert(b == 8);
+    assert(c);
+
+    let Wrapper::Value((w1, w2)) = Wrapper::Value((10, 11));
+    assert(w1 == 10);
+    assert(w2 == 11);
+
+    let Either::Left(e) | Either::Right(e) = Either::Right(12);
+    assert(e == 12);
+
+    let (mut m, n) = (13, 14u32);
+    m = m + n.as_u64();
+    assert(m == 27);
+
+    let Wrapper::Value((mut total, _)) = Wrapper::Value((15, 0));
+    total += 1;
+    assert(total == 16);
+
+    assert(sum_point(p) == 3);
+    assert(sum_pair((20, (21, false))) == 41);
+    assert(unwrap(Wrapper::Value((22, 23)), 0) == 45);
+    assert(either_value(Either::Left(24)) == 24);
+    assert(either_value(Either::Right(25)) == 25);
+
+    true
+}
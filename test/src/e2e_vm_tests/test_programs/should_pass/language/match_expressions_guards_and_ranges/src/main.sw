@@ -0,0 +1,112 @@
+script;
+
+enum Shape {
+    Circle: u64,
+    Square: u64,
+}
+
+fn classify_u8(v: u8) -> u64 {
+    // Exhaustive without a catch-all arm.
+    match v {
+        0 => 0,
+        1..10 => 1,
+        10..=99 => 2,
+        100..=255 => 3,
+    }
+}
+
+fn classify_i64(v: i64) -> u64 {
+    match v {
+        -10..0 => 1,
+        0 => 2,
+        1..=10 => 3,
+        _ => 4,
+    }
+}
+
+fn overlapping(v: u64) -> u64 {
+    match v {
+        0..=10 => 1,
+        5..=20 => 2,
+        15..=30 => 3,
+        _ => 4,
+    }
+}
+
+fn guarded(shape: Shape) -> u64 {
+    match shape {
+        Shape::Circle(r) if r > 10 => 1,
+        Shape::Circle(r) if r == 0 => 2,
+        Shape::Circle(_) => 3,
+        Shape::Square(s) if s % 2 == 0 => s,
+        Shape::Square(_) => 0,
+    }
+}
+
+fn guarded_range(a: u64, b: u32) -> u64 {
+    match (a, b) {
+        (0..=9, y) if y.as_u64() > a => 1,
+        (0..=9, _) => 2,
+        (x, y) if x == y.as_u64() => 3,
+        _ => 4,
+    }
+}
+
+fn full_range_and_wildcard(v: (u8, bool)) -> u64 {
+    // Exhaustive, the `_` row covers every `u8` value together with `false`.
+    match v {
+        (0..=255, true) => 1,
+        (_, false) => 2,
+    }
+}
+
+fn split_ranges_and_wildcard(v: (u8, bool)) -> u64 {
+    match v {
+        (0..=127, _) => 1,
+        (128..=255, true) => 2,
+        (_, false) => 3,
+    }
+}
+
+fn main() -> bool {
+    assert(classify_u8(0) == 0);
+    assert(classify_u8(1) == 1);
+    assert(classify_u8(9) == 1);
+    assert(classify_u8(10) == 2);
+    assert(classify_u8(99) == 2);
+    assert(classify_u8(100) == 3);
+    assert(classify_u8(255) == 3);
+
+    assert(classify_i64(-11) == 4);
+    assert(classify_i64(-10) == 1);
+    assert(classify_i64(-1) == 1);
+    assert(classify_i64(0) == 2);
+    assert(classify_i64(10) == 3);
+    assert(classify_i64(11) == 4);
+
+    assert(overlapping(7) == 1);
+    assert(overlapping(11) == 2);
+    assert(overlapping(20) == 2);
+    assert(overlapping(21) == 3);
+    assert(overlapping(31) == 4);
+
+    assert(guarded(Shape::Circle(11)) == 1);
+    assert(guarded(Shape::Circle(0)) == 2);
+    assert(guarded(Shape::Circle(5)) == 3);
+    assert(guarded(Shape::Square(4)) == 4);
+    assert(guarded(Shape::Square(5)) == 0);
+
+    assert(guarded_range(1, 2) == 1);
+    assert(guarded_range(2, 1) == 2);
+    assert(guarded_range(20, 20) == 3);
+    assert(guarded_range(20, 21) == 4);
+
+    assert(full_range_and_wildcard((0, true)) == 1);
+    assert(full_range_and_wildcard((255, false)) == 2);
+
+    assert(split_ranges_and_wildcard((127, false)) == 1);
+    assert(split_ranges_and_wildcard((128, true)) == 2);
+    assert(split_ranges_and_wildcard((255, false)) == 3);
+
+    true
+}
@@ -0,0 +1,36 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn sum(p: Point) -> u64 {
+    p.x + p.y
+}
+
+fn fib(n: u64) -> u64 {
+    let mut a = 0;
+    let mut b = 1;
+    let mut i = 0;
+    while i < n {
+        let t = a + b;
+        a = b;
+        b = t;
+        i += 1;
+    }
+    a
+}
+
+fn main() -> u64 {
+    let p = Point { x: fib(5), y: fib(9) };
+    let arr = [p, Point { x: 1, y: 2 }];
+    let mut total = 0;
+    let mut i = 0;
+    while i < 2 {
+        total += sum(arr[i]);
+        i += 1;
+    }
+    // fib(5) + fib(9) + 1 + 2
+    if total == 42 { total + fib(7) } else { 0 }
+}
@@ -0,0 +1,141 @@
+script;
+
+struct S {
+    x: u64,
+    y: (u8, bool),
+}
+
+struct Holder {
+    r: &u64,
+}
+
+const C: u64 = 77;
+
+fn inc(r: &mut u64) {
+    *r = *r + 1;
+}
+
+fn read(r: &u64) -> u64 {
+    *r
+}
+
+fn set_struct(r: &mut S) {
+    *r = S {
+        x: 42,
+        y: (7, true),
+    };
+}
+
+fn pick(c: bool, a: &mut u64, b: &mut u64) -> &mut u64 {
+    if c { a } else { b }
+}
+
+fn id<T>(t: T) -> T {
+    t
+}
+
+fn bump_ref_mut(ref mut v: u64) {
+    let r = &mut v;
+    *r = *r + 100;
+}
+
+fn copy_types() {
+    let mut x = 5;
+    let r = &mut x;
+    *r = 10;
+    assert(x == 10);
+    inc(&mut x);
+    assert(x == 11);
+    assert(read(&x) == 11);
+
+    // A `&mut` reference coerces to a `&` reference.
+    let r2: &u64 = &mut x;
+    assert(*r2 == 11);
+
+    let mut k = 0x0000000000000000000000000000000000000000000000000000000000000001;
+    let rk = &mut k;
+    *rk = 0x0000000000000000000000000000000000000000000000000000000000000002;
+    assert(k == 0x0000000000000000000000000000000000000000000000000000000000000002);
+}
+
+fn aggregates() {
+    let mut s = S {
+        x: 1,
+        y: (2, false),
+    };
+    let rx = &mut s.x;
+    *rx = 3;
+    assert(s.x == 3);
+    set_struct(&mut s);
+    assert(s.x == 42 && s.y.0 == 7 && s.y.1);
+    let rs = &s;
+    assert((*rs).x == 42);
+
+    let mut a = [1u64, 2, 3];
+    let ra = &mut a[1];
+    *ra = 20;
+    assert(a[1] == 20);
+    let whole = &a;
+    assert((*whole)[2] == 3);
+
+    let t = &mut (1, 2);
+    *t = (3, 4);
+    assert((*t).1 == 4);
+}
+
+fn temporaries_and_nested_refs() {
+    let r = &(1 + 2);
+    assert(*r == 3);
+
+    let mut x = 1u8;
+    let rr = &&mut x;
+    **rr = 9;
+    assert(x == 9);
+
+    let t = *&(1, 2);
+    assert(t.1 == 2);
+
+    let rc = &C;
+    assert(*rc == 77);
+}
+
+fn loops() {
+    let mut sum = 0;
+    let r = &mut sum;
+    let mut i = 0;
+    while i < 10 {
+        *r = *r + i;
+        i += 1;
+    }
+    assert(sum == 45);
+}
+
+fn generics_params_and_fields() {
+    let mut a = 1;
+    let mut b = 2;
+    *pick(true, &mut a, &mut b) = 10;
+    *pick(false, &mut a, &mut b) = 20;
+    assert(a == 10 && b == 20);
+
+    let r = id(&a);
+    assert(*r == 10);
+
+    let h = Holder { r: &b };
+    assert(*h.r == 20);
+
+    let arr = [&a, &b];
+    assert(*arr[0] + *arr[1] == 30);
+
+    let mut v = 1;
+    bump_ref_mut(v);
+    assert(v == 101);
+}
+
+fn main() -> bool {
+    copy_types();
+    aggregates();
+    temporaries_and_nested_refs();
+    loops();
+    generics_params_and_fields();
+    true
+}
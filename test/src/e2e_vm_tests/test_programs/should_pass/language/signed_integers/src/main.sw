@@ -0,0 +1,106 @@
+script;
+
+use std::logging::log;
+
+const NEGATIVE: i64 = -42;
+const FOLDED: i32 = -7i32 * 3 + 1;
+
+struct Point {
+    x: i16,
+    y: i16,
+}
+
+fn sign(v: i64) -> u64 {
+    match v {
+        -1 => 10,
+        0 => 0,
+        _ => 1,
+    }
+}
+
+fn arithmetic() -> bool {
+    let a: i64 = -7;
+    let b: i64 = 2;
+    assert(a + b == -5);
+    assert(a - b == -9);
+    assert(b - a == 9);
+    assert(a * b == -14);
+    assert(a * a == 49);
+    assert(a / b == -3);
+    assert(a % b == -1);
+    assert(-a == 7);
+    assert(-(-a) == a);
+
+    let c: i8 = -100;
+    let d: i8 = 27;
+    assert(c - d == -127);
+    assert(c + d == -73);
+    assert(c / -3 == 33);
+    assert(d * -4 == -108);
+
+    let e = -5i16;
+    let f = 300i16;
+    assert(e * f == -1500);
+
+    let g: i32 = i32::min() + 1;
+    assert(g - 1 == i32::min());
+    assert(-g == i32::max());
+
+    true
+}
+
+fn comparisons() -> bool {
+    let a: i64 = -1;
+    let b: i64 = 1;
+    assert(a < b);
+    assert(b > a);
+    assert(a <= a);
+    assert(!(a >= b));
+    assert(i64::min() < i64::max());
+    assert(i8::min() < 0i8);
+
+    let c: i8 = -128;
+    assert(c == i8::min());
+    assert(i8::max() == 127);
+    assert(i16::min() == -32768);
+    assert(i32::max() == 2147483647);
+
+    true
+}
+
+fn bitwise() -> bool {
+    let a: i64 = -16;
+    assert(a >> 2 == -4);
+    assert(a << 2 == -64);
+    assert(!a == 15);
+    assert(a & 0xff == 240);
+    assert(a | 1 == -15);
+
+    let b: i8 = 64;
+    assert(b << 1 == -128);
+    assert(-128i8 >> 7 == -1);
+    assert(!0i8 == -1);
+
+    true
+}
+
+fn constants_and_structs() -> bool {
+    assert(NEGATIVE == -42);
+    assert(FOLDED == -20);
+
+    let p = Point { x: -3, y: 4 };
+    assert(p.x * p.y == -12);
+
+    assert(sign(-1) == 10);
+    assert(sign(0) == 0);
+    assert(sign(-2) == 1);
+    assert(sign(5) == 1);
+
+    true
+}
+
+fn main() -> bool {
+    log(NEGATIVE);
+    log(FOLDED);
+    arithmetic() && comparisons() && bitwise() && constants_and_structs()
+}
@@ -14,8 +14,8 @@ use sway_core::{
 use sway_error::handler::Handler;
 
 use sway_ir::{
-    create_inline_in_module_pass, register_known_passes, PassGroup, PassManager, ARGDEMOTION_NAME,
-    CONSTDEMOTION_NAME, DCE_NAME, MEMCPYOPT_NAME, MISCDEMOTION_NAME, RETDEMOTION_NAME,
+    register_known_passes, PassGroup, PassManager, ARGDEMOTION_NAME, CONSTDEMOTION_NAME, DCE_NAME,
+    INLINE_MODULE_NAME, MEMCPYOPT_NAME, MISCDEMOTION_NAME, RETDEMOTION_NAME,
 };
 
 enum Checker {
@@ -369,9 +369,9 @@ pub(super) async fn run(filter_regex: Option<&regex::Regex>, verbose: bool) -> R
                         (Checker::Asm, Some(checker)) => {
                             if optimisation_inline {
                                 let mut pass_mgr = PassManager::default();
+                                register_known_passes(&mut pass_mgr);
                                 let mut pmgr_config = PassGroup::default();
-                                let inline = pass_mgr.register(create_inline_in_module_pass());
-                                pmgr_config.append_pass(inline);
+                                pmgr_config.append_pass(INLINE_MODULE_NAME);
                                 let inline_res = pass_mgr.run(&mut ir, &pmgr_config);
                                 if inline_res.is_err() {
                                     panic!(
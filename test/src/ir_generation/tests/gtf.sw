@@ -37,7 +37,6 @@ fn main() -> (u64, b256) {
 
 // check: load $(two=$REG) data_0
 // check: gtf  $(b256_ptr=$REG) $two i119
-// check: movi $(len_reg=$REG) i32
-// check: mcp  $REG $b256_ptr $len_reg
+// check: mcpi $REG $b256_ptr i32
 
 // check: data_0 .word 2